@@ -0,0 +1,87 @@
+//! Outbound framing: fresh `Vec` per frame vs. [`FrameBufferPool`].
+//!
+//! Allocator churn, not raw copy speed, is what the pool exists to remove,
+//! so a counting global allocator first checks that pooled framing
+//! allocates less per message than a fresh `Vec`, then criterion times both.
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicU64, Ordering};
+use x0x::network::FrameBufferPool;
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+// SAFETY: delegates every call to the system allocator unchanged; the
+// counter is the only addition.
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const MESSAGES: u64 = 10_000;
+const STREAM_TYPE: u8 = 0x00;
+
+fn fresh_frame(body: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(1 + body.len());
+    buf.push(STREAM_TYPE);
+    buf.extend_from_slice(body);
+    buf
+}
+
+fn allocations_per_message(mut send: impl FnMut()) -> f64 {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..MESSAGES {
+        send();
+    }
+    let after = ALLOCATIONS.load(Ordering::Relaxed);
+    (after - before) as f64 / MESSAGES as f64
+}
+
+fn bench_framing(c: &mut Criterion) {
+    let body = vec![0x5a_u8; 1024];
+    let pool = FrameBufferPool::default();
+
+    let fresh = allocations_per_message(|| {
+        black_box(fresh_frame(black_box(&body)));
+    });
+    let pooled = allocations_per_message(|| {
+        black_box(pool.frame(&[&[STREAM_TYPE], black_box(&body)]));
+    });
+    assert!(
+        pooled < fresh,
+        "pooled framing must allocate less per message (fresh={fresh:.3} pooled={pooled:.3})"
+    );
+
+    let mut group = c.benchmark_group("frame_buffer_pool");
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("fresh_vec_1k", |b| {
+        b.iter(|| black_box(fresh_frame(black_box(&body))));
+    });
+    group.bench_function("pooled_1k", |b| {
+        b.iter(|| {
+            let frame = pool.frame(&[&[STREAM_TYPE], black_box(&body)]);
+            black_box(frame.len())
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_framing);
+criterion_main!(benches);
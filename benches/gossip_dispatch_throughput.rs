@@ -6,10 +6,11 @@ use saorsa_gossip_types::{MessageHeader, MessageKind, PeerId, TopicId};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::runtime::Runtime;
-use x0x::gossip::PubSubManager;
+use x0x::gossip::{PubSubManager, SenderOrder};
 use x0x::network::{NetworkConfig, NetworkNode};
 
 const BATCH_SIZE: usize = 256;
+const CONCURRENT_SENDERS: u8 = 64;
 
 fn encode_v1_payload(topic: &str, payload: &[u8]) -> Bytes {
     let topic_bytes = topic.as_bytes();
@@ -101,5 +102,82 @@ fn bench_handle_incoming(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_handle_incoming);
+/// Drain `frames` with `workers` concurrent workers that keep per-sender
+/// order, each verifying an ML-DSA-65 signature as the expensive step.
+async fn drain_ordered(
+    workers: usize,
+    frames: Vec<(PeerId, Bytes)>,
+    public_key: Arc<Vec<u8>>,
+    signature: Arc<Vec<u8>>,
+) {
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    for frame in frames {
+        tx.send(frame).expect("bench queue accepts frame");
+    }
+    drop(tx);
+    let rx = Arc::new(tokio::sync::Mutex::new(rx));
+    let order = Arc::new(SenderOrder::new());
+    let handles = (0..workers)
+        .map(|_| {
+            let (rx, order) = (Arc::clone(&rx), Arc::clone(&order));
+            let (public_key, signature) = (Arc::clone(&public_key), Arc::clone(&signature));
+            tokio::spawn(async move {
+                loop {
+                    let recv = async { rx.lock().await.recv().await.ok_or(()) };
+                    let Ok((_peer, data, ticket)) = order.dequeue(recv).await else {
+                        break;
+                    };
+                    ticket.wait_turn().await;
+                    let valid = MlDsaKeyPair::verify(&public_key, &data, &signature)
+                        .expect("bench verify runs");
+                    std::hint::black_box(valid);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.await.expect("bench worker completes");
+    }
+}
+
+fn bench_concurrent_senders(c: &mut Criterion) {
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("tokio runtime starts");
+    let signing_key = MlDsaKeyPair::generate().expect("bench signing key generates");
+    let body = Bytes::from_static(b"bench-concurrent-sender-frame");
+    let signature = Arc::new(signing_key.sign(&body).expect("bench body signs"));
+    let public_key = Arc::new(signing_key.public_key().to_vec());
+    let frames = (0..BATCH_SIZE)
+        .map(|i| {
+            (
+                peer((i % usize::from(CONCURRENT_SENDERS)) as u8),
+                body.clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("gossip_dispatch_concurrent_senders");
+    group.throughput(Throughput::Elements(BATCH_SIZE as u64));
+    for workers in [1, 4, 8] {
+        group.bench_function(format!("ordered_verify_{workers}_workers"), |b| {
+            b.iter_batched(
+                || frames.clone(),
+                |batch| {
+                    rt.block_on(drain_ordered(
+                        workers,
+                        batch,
+                        Arc::clone(&public_key),
+                        Arc::clone(&signature),
+                    ));
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_handle_incoming, bench_concurrent_senders);
 criterion_main!(benches);
@@ -57,6 +57,13 @@ pub const ENDPOINTS: &[EndpointDef] = &[
         description: "Health check",
         category: "status",
     },
+    EndpointDef {
+        method: Method::Get,
+        path: "/health/persistence",
+        cli_name: "health persistence",
+        description: "Snapshot save activity, storage use and unrecovered task lists",
+        category: "status",
+    },
     EndpointDef {
         method: Method::Get,
         path: "/status",
@@ -64,6 +71,13 @@ pub const ENDPOINTS: &[EndpointDef] = &[
         description: "Runtime status with uptime",
         category: "status",
     },
+    EndpointDef {
+        method: Method::Get,
+        path: "/metrics",
+        cli_name: "metrics",
+        description: "Prometheus metrics",
+        category: "status",
+    },
     EndpointDef {
         method: Method::Post,
         path: "/shutdown",
@@ -100,6 +114,13 @@ pub const ENDPOINTS: &[EndpointDef] = &[
         description: "Current agent user ID",
         category: "identity",
     },
+    EndpointDef {
+        method: Method::Get,
+        path: "/agent/words",
+        cli_name: "agent words",
+        description: "Agent, machine and user IDs as four words",
+        category: "identity",
+    },
     EndpointDef {
         method: Method::Get,
         path: "/agent/card",
@@ -193,6 +214,20 @@ pub const ENDPOINTS: &[EndpointDef] = &[
         description: "Get local cache presence status for an agent",
         category: "presence",
     },
+    EndpointDef {
+        method: Method::Post,
+        path: "/presence/status",
+        cli_name: "presence set-status",
+        description: "Set the status advertised on this agent's announcements",
+        category: "presence",
+    },
+    EndpointDef {
+        method: Method::Delete,
+        path: "/presence/status",
+        cli_name: "presence clear-status",
+        description: "Clear the advertised status",
+        category: "presence",
+    },
     EndpointDef {
         method: Method::Get,
         path: "/presence/events",
@@ -214,6 +249,13 @@ pub const ENDPOINTS: &[EndpointDef] = &[
         description: "Bootstrap peer cache stats",
         category: "network",
     },
+    EndpointDef {
+        method: Method::Get,
+        path: "/bootstrap/health",
+        cli_name: "network bootstrap-health",
+        description: "Probe configured bootstrap peers: per-peer reachability and latency",
+        category: "network",
+    },
     EndpointDef {
         method: Method::Get,
         path: "/diagnostics/connectivity",
@@ -306,6 +348,13 @@ pub const ENDPOINTS: &[EndpointDef] = &[
         description: "WebSocket outbound-queue health: capacity and drop/slow-consumer-close counters",
         category: "network",
     },
+    EndpointDef {
+        method: Method::Get,
+        path: "/diagnostics/sse",
+        cli_name: "diagnostics sse",
+        description: "/events SSE health: connected clients and lagged-event counters",
+        category: "network",
+    },
     EndpointDef {
         method: Method::Post,
         path: "/peers/:peer_id/probe",
@@ -327,6 +376,20 @@ pub const ENDPOINTS: &[EndpointDef] = &[
         description: "SSE stream of peer lifecycle events (ant-quic 0.27.1 #171)",
         category: "network",
     },
+    EndpointDef {
+        method: Method::Get,
+        path: "/peers/cache",
+        cli_name: "peer cache",
+        description: "Human-readable bootstrap peer cache (id, addresses, outcomes, last seen)",
+        category: "network",
+    },
+    EndpointDef {
+        method: Method::Post,
+        path: "/peers/disconnect-all",
+        cli_name: "peer disconnect-all",
+        description: "Disconnect every peer without stopping the node (emergency isolation)",
+        category: "network",
+    },
     // ── Messaging ───────────────────────────────────────────────────────
     EndpointDef {
         method: Method::Post,
@@ -349,6 +412,13 @@ pub const ENDPOINTS: &[EndpointDef] = &[
         description: "Unsubscribe by ID",
         category: "messaging",
     },
+    EndpointDef {
+        method: Method::Get,
+        path: "/subscriptions",
+        cli_name: "subscriptions",
+        description: "List subscriptions with per-subscription dropped/buffered counts",
+        category: "messaging",
+    },
     EndpointDef {
         method: Method::Get,
         path: "/events",
@@ -470,6 +540,34 @@ pub const ENDPOINTS: &[EndpointDef] = &[
         description: "List revocations",
         category: "contacts",
     },
+    EndpointDef {
+        method: Method::Get,
+        path: "/contacts/:agent_id/reachability",
+        cli_name: "contacts reachability",
+        description: "Check whether a contact is connected or connectable",
+        category: "contacts",
+    },
+    EndpointDef {
+        method: Method::Get,
+        path: "/contacts/audit",
+        cli_name: "contacts audit",
+        description: "List recent trust-level changes",
+        category: "contacts",
+    },
+    EndpointDef {
+        method: Method::Get,
+        path: "/contacts/export",
+        cli_name: "contacts export",
+        description: "Export contacts and revocations",
+        category: "contacts",
+    },
+    EndpointDef {
+        method: Method::Post,
+        path: "/contacts/import",
+        cli_name: "contacts import",
+        description: "Import contacts with a merge strategy",
+        category: "contacts",
+    },
     // ── Machines ────────────────────────────────────────────────────────
     EndpointDef {
         method: Method::Get,
@@ -929,8 +1027,64 @@ pub const ENDPOINTS: &[EndpointDef] = &[
     EndpointDef {
         method: Method::Patch,
         path: "/task-lists/:id/tasks/:tid",
-        cli_name: "tasks claim / tasks complete",
-        description: "Claim or complete a task (action: claim|complete)",
+        cli_name: "tasks claim / tasks complete / tasks reopen / tasks assign / tasks priority",
+        description: "Update a task (action: claim|complete|reopen|assign|set_priority)",
+        category: "tasks",
+    },
+    EndpointDef {
+        method: Method::Delete,
+        path: "/task-lists/:id/tasks/:tid",
+        cli_name: "tasks remove",
+        description: "Remove a task from the list",
+        category: "tasks",
+    },
+    EndpointDef {
+        method: Method::Get,
+        path: "/task-lists/:id/tasks/:tid/history",
+        cli_name: "tasks history",
+        description: "Audit trail of a task's claims and completions",
+        category: "tasks",
+    },
+    EndpointDef {
+        method: Method::Get,
+        path: "/task-lists/:id/validate",
+        cli_name: "tasks validate",
+        description: "Check task list replica consistency",
+        category: "tasks",
+    },
+    EndpointDef {
+        method: Method::Get,
+        path: "/task-lists/:id/sync-stats",
+        cli_name: "tasks sync-stats",
+        description: "Delta traffic counters for a task list",
+        category: "tasks",
+    },
+    EndpointDef {
+        method: Method::Get,
+        path: "/task-lists/:id/export",
+        cli_name: "tasks export",
+        description: "Read-only JSON snapshot of a task list",
+        category: "tasks",
+    },
+    EndpointDef {
+        method: Method::Post,
+        path: "/task-lists/:id/checkpoint",
+        cli_name: "tasks checkpoint",
+        description: "Save a task list to disk now",
+        category: "tasks",
+    },
+    EndpointDef {
+        method: Method::Post,
+        path: "/checkpoint-all",
+        cli_name: "tasks checkpoint-all",
+        description: "Save every open task list to disk now",
+        category: "tasks",
+    },
+    EndpointDef {
+        method: Method::Patch,
+        path: "/persistence/policy",
+        cli_name: "persistence policy",
+        description: "Change how often task lists are saved, live",
         category: "tasks",
     },
     // ── Key-value stores ────────────────────────────────────────────────
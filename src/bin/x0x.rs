@@ -79,9 +79,14 @@ enum Commands {
         remove: bool,
     },
     /// Health check.
-    Health,
+    Health {
+        #[command(subcommand)]
+        sub: Option<HealthSub>,
+    },
     /// Runtime status with uptime and connectivity.
     Status,
+    /// Prometheus metrics.
+    Metrics,
     /// Show agent identity.
     Agent {
         #[command(subcommand)]
@@ -180,6 +185,8 @@ enum Commands {
         /// Subscription ID.
         id: String,
     },
+    /// List active subscriptions with dropped/buffered message counts.
+    Subscriptions,
     /// Stream all gossip events to stdout.
     Events,
     /// Remote non-interactive exec over the x0x mesh.
@@ -235,6 +242,11 @@ enum Commands {
         #[command(subcommand)]
         sub: Option<TasksSub>,
     },
+    /// Task-list persistence settings.
+    Persistence {
+        #[command(subcommand)]
+        sub: PersistenceSub,
+    },
     /// Check for updates and upgrade (no daemon needed).
     Upgrade {
         /// Just check for updates, don't apply.
@@ -332,10 +344,18 @@ enum Commands {
 
 // ── Nested subcommands ──────────────────────────────────────────────────
 
+#[derive(Subcommand)]
+enum HealthSub {
+    /// Snapshot saves, storage use and task lists that failed to recover.
+    Persistence,
+}
+
 #[derive(Subcommand)]
 enum AgentSub {
     /// Show current agent's user ID.
     UserId,
+    /// Show the agent, machine and user IDs as four words.
+    Words,
     /// Generate a shareable identity card.
     Card {
         /// Your display name (e.g. "David").
@@ -420,6 +440,12 @@ enum NetworkSub {
     Status,
     /// Bootstrap peer cache stats.
     Cache,
+    /// Probe every configured bootstrap peer: reachability and latency.
+    BootstrapHealth {
+        /// Per-peer probe timeout in milliseconds (default 2000, clamped 100..30000).
+        #[arg(long)]
+        timeout_ms: Option<u64>,
+    },
 }
 
 /// Peer subcommands (ant-quic 0.27 surface).
@@ -440,6 +466,10 @@ enum PeerSub {
     },
     /// Stream peer lifecycle events via SSE (ant-quic 0.27.1 #171).
     Events,
+    /// Dump the bootstrap peer cache in human-readable JSON.
+    Cache,
+    /// Disconnect every peer without stopping the daemon.
+    DisconnectAll,
 }
 
 #[derive(Subcommand)]
@@ -460,6 +490,8 @@ enum DiagnosticsSub {
     Connect,
     /// Print WebSocket outbound-queue health (capacity, drops, slow-consumer closes).
     Ws,
+    /// Print /events SSE health (connected clients, lagged events).
+    Sse,
     /// Print durable-history writer/reaper counters (ADR-0023).
     History,
 }
@@ -519,7 +551,8 @@ enum AuthSub {
     Session,
 }
 
-/// Key lifecycle sub-actions (`x0x identity revoke`, `x0x identity revocations`).
+/// Key lifecycle sub-actions (`x0x identity revoke`, `x0x identity revocations`,
+/// `x0x identity wipe`).
 #[derive(Subcommand)]
 enum IdentitySub {
     /// Issue a signed revocation for an agent-id or machine-id keypair.
@@ -541,6 +574,13 @@ enum IdentitySub {
     },
     /// List all revocation records held by this daemon.
     Revocations,
+    /// Securely delete this instance's keys, certificates, contacts and peer
+    /// cache (for decommissioning a machine). Stop the daemon first.
+    Wipe {
+        /// Confirm the wipe; without it nothing is touched.
+        #[arg(long)]
+        yes: bool,
+    },
 }
 
 /// `x0x forward` sub-actions.
@@ -616,6 +656,13 @@ enum PresenceSub {
         /// Agent ID (hex, 64 chars).
         id: String,
     },
+    /// Set the status advertised on this agent's announcements.
+    SetStatus {
+        /// Status text (at most 256 bytes, no control characters).
+        status: String,
+    },
+    /// Clear the advertised status.
+    ClearStatus,
     /// Stream presence online/offline events (Server-Sent Events).
     Events,
 }
@@ -702,6 +749,28 @@ enum ContactsSub {
         /// Agent ID (hex).
         agent_id: String,
     },
+    /// Check whether a contact is connected or connectable right now.
+    Reachability {
+        /// Agent ID (hex).
+        agent_id: String,
+    },
+    /// Show recent trust-level changes.
+    Audit,
+    /// Export contacts and revocations for sharing or backup.
+    Export {
+        /// Write the export to this file instead of printing it.
+        #[arg(long)]
+        output: Option<String>,
+    },
+    /// Import an exported trust list.
+    Import {
+        /// Path to an export file (`-` for stdin).
+        path: String,
+        /// Conflict handling: keep_existing, prefer_incoming or
+        /// highest_trust_wins.
+        #[arg(long, default_value = "keep_existing")]
+        strategy: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -855,6 +924,10 @@ enum GroupsSub {
         /// Epoch number.
         #[arg(long)]
         epoch: u64,
+        /// Nonce counter returned by `groups encrypt` (omit for ciphertexts
+        /// from older daemons).
+        #[arg(long)]
+        counter: Option<u64>,
     },
     /// Create a welcome message for a new member.
     Welcome {
@@ -1215,6 +1288,9 @@ enum TasksSub {
         name: String,
         /// Gossip topic for sync.
         topic: String,
+        /// Seconds between state requests while unsynced (1-3600, default 30).
+        #[arg(long)]
+        sync_interval_secs: Option<u64>,
     },
     /// Show tasks in a list.
     Show {
@@ -1245,6 +1321,81 @@ enum TasksSub {
         /// Task ID.
         task_id: String,
     },
+    /// Reopen a completed task.
+    Reopen {
+        /// Task list ID.
+        list_id: String,
+        /// Task ID.
+        task_id: String,
+    },
+    /// Assign a task to an agent (omit --agent to unassign).
+    Assign {
+        /// Task list ID.
+        list_id: String,
+        /// Task ID.
+        task_id: String,
+        /// Assignee agent ID (hex, 64 chars).
+        #[arg(long)]
+        agent: Option<String>,
+    },
+    /// Set a task's priority.
+    Priority {
+        /// Task list ID.
+        list_id: String,
+        /// Task ID.
+        task_id: String,
+        /// New priority (0-255).
+        priority: u8,
+    },
+    /// Remove a task from a list.
+    Remove {
+        /// Task list ID.
+        list_id: String,
+        /// Task ID.
+        task_id: String,
+    },
+    /// Check a task list replica for inconsistencies.
+    Validate {
+        /// Task list ID.
+        list_id: String,
+    },
+    /// Show delta traffic counters for a task list.
+    SyncStats {
+        /// Task list ID.
+        list_id: String,
+    },
+    /// Show who added, claimed and completed a task.
+    History {
+        /// Task list ID.
+        list_id: String,
+        /// Task ID.
+        task_id: String,
+    },
+    /// Export a read-only JSON snapshot of a task list.
+    Export {
+        /// Task list ID.
+        list_id: String,
+        /// Write the snapshot to this file instead of printing it.
+        #[arg(long)]
+        output: Option<String>,
+    },
+    /// Save a task list to disk now (e.g. before a planned restart).
+    Checkpoint {
+        /// Task list ID.
+        list_id: String,
+    },
+    /// Save every open task list to disk now.
+    CheckpointAll,
+}
+
+#[derive(Subcommand)]
+enum PersistenceSub {
+    /// Change how often task lists are saved, without a restart.
+    Policy {
+        /// Seconds between saves of each open list (1-3600).
+        #[arg(long)]
+        save_interval_secs: u64,
+    },
 }
 
 #[derive(Subcommand)]
@@ -1355,6 +1506,9 @@ async fn run(
                 return Ok(());
             }
         },
+        Commands::Identity {
+            sub: IdentitySub::Wipe { yes },
+        } => return commands::identity::wipe(name, *yes, format).await,
         _ => {}
     }
 
@@ -1406,11 +1560,16 @@ async fn run(
             }
             Ok(())
         }
-        Commands::Health => commands::network::health(&client).await,
+        Commands::Health { sub } => match sub {
+            None => commands::network::health(&client).await,
+            Some(HealthSub::Persistence) => commands::network::health_persistence(&client).await,
+        },
         Commands::Status => commands::network::status(&client).await,
+        Commands::Metrics => commands::network::metrics(&client).await,
         Commands::Agent { sub } => match sub {
             None => commands::identity::agent(&client).await,
             Some(AgentSub::UserId) => commands::identity::user_id(&client).await,
+            Some(AgentSub::Words) => commands::identity::words(&client).await,
             Some(AgentSub::Card {
                 display_name,
                 include_groups,
@@ -1462,11 +1621,18 @@ async fn run(
                 timeout_ms,
             }) => commands::presence::find(&client, &id, ttl, timeout_ms).await,
             Some(PresenceSub::Status { id }) => commands::presence::status(&client, &id).await,
+            Some(PresenceSub::SetStatus { status }) => {
+                commands::presence::set_status(&client, &status).await
+            }
+            Some(PresenceSub::ClearStatus) => commands::presence::clear_status(&client).await,
             Some(PresenceSub::Events) => commands::presence::events(&client).await,
         },
         Commands::Network { sub } => match sub {
             NetworkSub::Status => commands::network::network_status(&client).await,
             NetworkSub::Cache => commands::network::bootstrap_cache(&client).await,
+            NetworkSub::BootstrapHealth { timeout_ms } => {
+                commands::network::bootstrap_health(&client, timeout_ms).await
+            }
         },
         Commands::Peer { sub } => match sub {
             PeerSub::Probe {
@@ -1475,6 +1641,8 @@ async fn run(
             } => commands::network::peers_probe(&client, &peer_id, timeout_ms).await,
             PeerSub::Health { peer_id } => commands::network::peers_health(&client, &peer_id).await,
             PeerSub::Events => commands::network::peers_events(&client).await,
+            PeerSub::Cache => commands::network::peers_cache(&client).await,
+            PeerSub::DisconnectAll => commands::network::peers_disconnect_all(&client).await,
         },
         Commands::Diagnostics { sub } => match sub {
             DiagnosticsSub::Connectivity => {
@@ -1487,6 +1655,7 @@ async fn run(
             DiagnosticsSub::Exec => commands::exec::diagnostics(&client).await,
             DiagnosticsSub::Connect => commands::network::diagnostics_connect(&client).await,
             DiagnosticsSub::Ws => commands::network::diagnostics_ws(&client).await,
+            DiagnosticsSub::Sse => commands::network::diagnostics_sse(&client).await,
             DiagnosticsSub::History => commands::history::diagnostics(&client).await,
         },
         Commands::History { sub } => match sub {
@@ -1571,6 +1740,16 @@ async fn run(
             Some(ContactsSub::Revocations { agent_id }) => {
                 commands::contacts::revocations(&client, &agent_id).await
             }
+            Some(ContactsSub::Reachability { agent_id }) => {
+                commands::contacts::reachability(&client, &agent_id).await
+            }
+            Some(ContactsSub::Audit) => commands::contacts::audit(&client).await,
+            Some(ContactsSub::Export { output }) => {
+                commands::contacts::export(&client, output.as_deref()).await
+            }
+            Some(ContactsSub::Import { path, strategy }) => {
+                commands::contacts::import(&client, &path, &strategy).await
+            }
         },
         Commands::Machines { sub } => match sub {
             MachinesSub::Discovered { unfiltered } => {
@@ -1616,6 +1795,7 @@ async fn run(
         }
         Commands::Subscribe { topic } => commands::messaging::subscribe(&client, &topic).await,
         Commands::Unsubscribe { id } => commands::messaging::unsubscribe(&client, &id).await,
+        Commands::Subscriptions => commands::messaging::subscriptions(&client).await,
         Commands::Events => commands::messaging::events(&client).await,
         Commands::Exec {
             agent_id,
@@ -1672,7 +1852,8 @@ async fn run(
                 group_id,
                 ciphertext,
                 epoch,
-            }) => commands::groups::decrypt(&client, &group_id, &ciphertext, epoch).await,
+                counter,
+            }) => commands::groups::decrypt(&client, &group_id, &ciphertext, epoch, counter).await,
             Some(GroupsSub::Welcome { group_id, agent_id }) => {
                 commands::groups::welcome(&client, &group_id, &agent_id).await
             }
@@ -1884,9 +2065,11 @@ async fn run(
         Commands::Tasks { sub } => match sub {
             None => commands::tasks::list(&client).await,
             Some(TasksSub::List) => commands::tasks::list(&client).await,
-            Some(TasksSub::Create { name, topic }) => {
-                commands::tasks::create(&client, &name, &topic).await
-            }
+            Some(TasksSub::Create {
+                name,
+                topic,
+                sync_interval_secs,
+            }) => commands::tasks::create(&client, &name, &topic, sync_interval_secs).await,
             Some(TasksSub::Show { list_id }) => commands::tasks::show(&client, &list_id).await,
             Some(TasksSub::Add {
                 list_id,
@@ -1899,10 +2082,47 @@ async fn run(
             Some(TasksSub::Complete { list_id, task_id }) => {
                 commands::tasks::update(&client, &list_id, &task_id, "complete").await
             }
+            Some(TasksSub::Reopen { list_id, task_id }) => {
+                commands::tasks::update(&client, &list_id, &task_id, "reopen").await
+            }
+            Some(TasksSub::Assign {
+                list_id,
+                task_id,
+                agent,
+            }) => commands::tasks::assign(&client, &list_id, &task_id, agent.as_deref()).await,
+            Some(TasksSub::Priority {
+                list_id,
+                task_id,
+                priority,
+            }) => commands::tasks::set_priority(&client, &list_id, &task_id, priority).await,
+            Some(TasksSub::Remove { list_id, task_id }) => {
+                commands::tasks::remove(&client, &list_id, &task_id).await
+            }
+            Some(TasksSub::Validate { list_id }) => {
+                commands::tasks::validate(&client, &list_id).await
+            }
+            Some(TasksSub::SyncStats { list_id }) => {
+                commands::tasks::sync_stats(&client, &list_id).await
+            }
+            Some(TasksSub::History { list_id, task_id }) => {
+                commands::tasks::history(&client, &list_id, &task_id).await
+            }
+            Some(TasksSub::Export { list_id, output }) => {
+                commands::tasks::export(&client, &list_id, output.as_deref()).await
+            }
+            Some(TasksSub::Checkpoint { list_id }) => {
+                commands::tasks::checkpoint(&client, &list_id).await
+            }
+            Some(TasksSub::CheckpointAll) => commands::tasks::checkpoint_all(&client).await,
         },
         Commands::Upgrade { .. } => {
             anyhow::bail!("command dispatched earlier — dispatch table out of sync")
         }
+        Commands::Persistence { sub } => match sub {
+            PersistenceSub::Policy { save_interval_secs } => {
+                commands::tasks::persistence_policy(&client, save_interval_secs).await
+            }
+        },
         Commands::Ws { sub } => match sub {
             None => commands::ws::general(&client).await,
             Some(WsSub::Sessions) => commands::ws::sessions(&client).await,
@@ -1946,6 +2166,9 @@ async fn run(
                 .await
             }
             IdentitySub::Revocations => commands::identity::revocations(&client).await,
+            IdentitySub::Wipe { .. } => {
+                anyhow::bail!("command dispatched earlier — dispatch table out of sync")
+            }
         },
         Commands::Forward { sub } => match sub {
             ForwardSub::Add {
@@ -2004,19 +2227,25 @@ x0x (v{VERSION})
 |   +-- user-id create     Create user identity keypair
 |   +-- user-id inspect    Validate a user identity file (daemonless)
 |   +-- announce           Announce identity to network
+|   +-- identity wipe      Securely delete keys, certs, contacts (daemonless)
 |
 +-- Network
 |   +-- health             Health check
+|   +-- health persistence Snapshot saves, storage use, unrecovered task lists
 |   +-- status             Runtime status (uptime, peers, addresses)
+|   +-- metrics            Prometheus metrics (text exposition format)
 |   +-- peers              Connected gossip peers
 |   +-- network status     NAT type, connectivity diagnostics
 |   +-- network cache      Bootstrap peer cache stats
+|   +-- network bootstrap-health  Probe bootstrap peers (reachability, latency)
 |
 +-- Presence
 |   +-- presence online    Online agents (network view, non-blocked)
 |   +-- presence foaf      FOAF discovery (social view: Trusted + Known)
 |   +-- presence find      Find agent by ID via FOAF random walk
 |   +-- presence status    Local cache lookup for an agent (no network I/O)
+|   +-- presence set-status  Set the status advertised on announcements
+|   +-- presence clear-status  Clear the advertised status
 |
 +-- Discovery
 |   +-- agents list        List discovered agents
@@ -2033,6 +2262,10 @@ x0x (v{VERSION})
 |   +-- contacts remove    Remove a contact
 |   +-- contacts revoke    Revoke a contact (with reason)
 |   +-- contacts revocations  List revocations
+|   +-- contacts reachability  Check a contact is connected or connectable
+|   +-- contacts audit     Show recent trust-level changes
+|   +-- contacts export    Export contacts and revocations
+|   +-- contacts import    Import a trust list with a merge strategy
 |   +-- trust set          Quick-set trust level
 |   +-- trust evaluate     Evaluate agent+machine trust
 |   +-- machines discovered  List discovered machine endpoints
@@ -2092,6 +2325,16 @@ x0x (v{VERSION})
 |   +-- tasks add          Add a task
 |   +-- tasks claim        Claim a task
 |   +-- tasks complete     Mark task as done
+|   +-- tasks reopen       Reopen a done task
+|   +-- tasks assign       Assign a task to an agent
+|   +-- tasks priority     Set a task's priority
+|   +-- tasks remove       Remove a task
+|   +-- tasks history      Who added, claimed and completed a task
+|   +-- tasks sync-stats   Delta traffic counters for a list
+|   +-- tasks export       JSON snapshot of a list
+|   +-- tasks checkpoint   Save a list to disk now
+|   +-- tasks checkpoint-all  Save every open list to disk now
+|   +-- persistence policy    Change how often lists are saved
 |
 +-- Files
 |   +-- send-file          Send file to an agent
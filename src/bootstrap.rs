@@ -1,14 +1,93 @@
 //! Bootstrap node discovery and connection logic.
 //!
 //! This module handles initial connection to bootstrap nodes with
-//! retry logic and peer cache integration.
+//! retry logic and peer cache integration, plus a fast reachability
+//! [`probe`] used before committing to retry rounds.
 
 use crate::error::{NetworkError, NetworkResult};
 use crate::network::NetworkNode;
+use serde::Serialize;
+use std::future::Future;
 use std::net::SocketAddr;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::time::{sleep, timeout};
 
+/// Per-peer dial bound for [`probe`] during `join_network`.
+pub const DEFAULT_PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Reachability of one bootstrap peer, as measured by [`probe`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BootstrapHealth {
+    /// The probed address.
+    pub addr: SocketAddr,
+    /// Whether a connection was established (or already open) in time.
+    pub reachable: bool,
+    /// Time to establish or confirm the connection, when reachable.
+    pub latency_ms: Option<u64>,
+    /// Why the peer was unreachable.
+    pub error: Option<String>,
+}
+
+/// Dial every peer in parallel, each bounded by `timeout`, and report
+/// reachability and latency in input order.
+///
+/// A single attempt per peer with no backoff, so a machine without
+/// connectivity learns so within `timeout`. At most
+/// [`NetworkConfig::max_concurrent_connects`](crate::network::NetworkConfig::max_concurrent_connects)
+/// dials are in flight; a peer's timeout starts once its dial does. A
+/// successful probe leaves the connection open.
+pub async fn probe(
+    node: &NetworkNode,
+    peers: &[SocketAddr],
+    timeout: Duration,
+) -> Vec<BootstrapHealth> {
+    probe_with(
+        peers,
+        timeout,
+        node.config().max_concurrent_connects,
+        |addr| async move { node.connect_addr(addr).await.map(|_peer_id| ()) },
+    )
+    .await
+}
+
+async fn probe_with<F, Fut, E>(
+    peers: &[SocketAddr],
+    limit: Duration,
+    max_in_flight: usize,
+    dial: F,
+) -> Vec<BootstrapHealth>
+where
+    F: Fn(SocketAddr) -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: std::fmt::Display,
+{
+    let permits = tokio::sync::Semaphore::new(max_in_flight.max(1));
+    futures::future::join_all(peers.iter().map(|&addr| {
+        let attempt = dial(addr);
+        let permits = &permits;
+        async move {
+            let _permit = permits.acquire().await;
+            let started = Instant::now();
+            let (reachable, latency_ms, error) = match timeout(limit, attempt).await {
+                Ok(Ok(())) => (
+                    true,
+                    Some(u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX)),
+                    None,
+                ),
+                Ok(Err(e)) => (false, None, Some(e.to_string())),
+                Err(_elapsed) => (false, None, Some(format!("no answer within {limit:?}"))),
+            };
+            BootstrapHealth {
+                addr,
+                reachable,
+                latency_ms,
+                error,
+            }
+        }
+    }))
+    .await
+}
+
 /// Bootstrap configuration for connecting to initial peers.
 ///
 /// Controls retry behavior and connection strategy for bootstrap nodes.
@@ -143,7 +222,9 @@ impl BootstrapConnector {
         }
     }
 
-    /// Connect to multiple bootstrap addresses in parallel.
+    /// Connect to multiple bootstrap addresses in parallel, with at most
+    /// [`NetworkConfig::max_concurrent_connects`](crate::network::NetworkConfig::max_concurrent_connects)
+    /// retry loops running at once.
     ///
     /// # Arguments
     ///
@@ -154,26 +235,25 @@ impl BootstrapConnector {
     ///
     /// Number of successful connections.
     pub async fn connect_multiple(&self, node: &NetworkNode, addrs: &[SocketAddr]) -> usize {
-        let handles: Vec<_> = addrs
-            .iter()
-            .map(|&addr| {
+        crate::connect_bounded(
+            addrs.iter().copied(),
+            node.config().max_concurrent_connects,
+            |addr| {
                 let node_clone = node.clone();
                 let config = self.config.clone();
-                tokio::spawn(async move {
+                async move {
                     let connector = BootstrapConnector::with_config(config);
                     connector
                         .connect_with_retry(&node_clone, addr)
                         .await
                         .is_ok()
-                })
-            })
-            .collect();
-
-        futures::future::join_all(handles)
-            .await
-            .into_iter()
-            .filter(|r| matches!(r, Ok(true)))
-            .count()
+                }
+            },
+        )
+        .await
+        .into_iter()
+        .filter(|connected| *connected)
+        .count()
     }
 }
 
@@ -261,6 +341,66 @@ mod tests {
         assert_eq!(backoff, Duration::from_millis(800));
     }
 
+    #[tokio::test]
+    async fn probe_reports_each_peer_in_order() {
+        let peers: Vec<SocketAddr> = ["10.0.0.1:5483", "10.0.0.2:5483", "10.0.0.3:5483"]
+            .iter()
+            .map(|a| a.parse().unwrap())
+            .collect();
+        let health = probe_with(&peers, Duration::from_millis(50), 16, |addr| async move {
+            match addr.ip().to_string().as_str() {
+                "10.0.0.1" => Ok(()),
+                "10.0.0.2" => Err("network unreachable"),
+                _ => {
+                    sleep(Duration::from_secs(5)).await;
+                    Ok(())
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(health.len(), 3);
+        assert!(health[0].reachable);
+        assert!(health[0].latency_ms.is_some());
+        assert_eq!(health[0].error, None);
+        assert!(!health[1].reachable);
+        assert_eq!(health[1].error.as_deref(), Some("network unreachable"));
+        assert!(!health[2].reachable);
+        assert_eq!(health[2].latency_ms, None);
+        assert!(health[2].error.as_deref().unwrap().contains("no answer"));
+        assert_eq!(
+            health.iter().map(|h| h.addr).collect::<Vec<_>>(),
+            peers,
+            "results follow input order"
+        );
+    }
+
+    #[tokio::test]
+    async fn probe_caps_dials_in_flight() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let peers: Vec<SocketAddr> = (0..40u16)
+            .map(|port| SocketAddr::from(([10, 0, 0, 1], 9000 + port)))
+            .collect();
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let health = probe_with(&peers, Duration::from_secs(5), 4, |_addr| {
+            let (in_flight, peak) = (&in_flight, &peak);
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                sleep(Duration::from_millis(5)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok::<_, NetworkError>(())
+            }
+        })
+        .await;
+
+        assert_eq!(health.len(), peers.len());
+        assert!(health.iter().all(|h| h.reachable));
+        assert_eq!(peak.load(Ordering::SeqCst), 4);
+    }
+
     #[test]
     fn test_max_backoff_clamping() {
         let config = BootstrapConfig {
@@ -2,7 +2,6 @@
 
 use crate::cli::{print_value, DaemonClient};
 use anyhow::{bail, Context, Result};
-use four_word_networking::FourWordAdaptiveEncoder;
 
 /// `x0x connect <words...>` — decode location words to IP:port and connect.
 pub async fn connect(client: &DaemonClient, words: &[String]) -> Result<()> {
@@ -15,12 +14,8 @@ pub async fn connect(client: &DaemonClient, words: &[String]) -> Result<()> {
 
     client.ensure_running().await?;
 
-    let addr_encoder =
-        FourWordAdaptiveEncoder::new().context("failed to initialise address encoder")?;
-
     let words_str = words.join(" ");
-    let addr = addr_encoder
-        .decode(&words_str)
+    let addr = crate::words::decode_location(&words_str)
         .context("failed to decode location words — check spelling")?;
 
     eprintln!("Decoded location: {addr}");
@@ -60,11 +55,7 @@ pub async fn connect(client: &DaemonClient, words: &[String]) -> Result<()> {
         }
     };
 
-    let id_encoder = four_word_networking::IdentityEncoder::new();
-    let identity = id_encoder
-        .encode_hex(&agent_id)
-        .map(|w| w.to_string())
-        .unwrap_or_default();
+    let identity = crate::words::render_hex_id(&agent_id);
 
     eprintln!("Found agent: {identity} ({agent_id})");
     eprintln!("Connecting...");
@@ -106,13 +97,13 @@ mod tests {
         assert!(result.is_err(), "connect with 0 words should fail");
     }
 
+    #[cfg(feature = "words")]
     #[tokio::test]
     async fn connect_with_valid_words_and_matching_agent() {
-        // Use the FourWordAdaptiveEncoder to encode a known address,
-        // then decode those words to get valid input for the connect function.
-        let addr_encoder = FourWordAdaptiveEncoder::new().unwrap();
+        // Encode a known address, then decode those words to get valid
+        // input for the connect function.
         let test_addr = "192.168.1.1:5483";
-        let words_str = addr_encoder.encode(test_addr).unwrap();
+        let words_str = crate::words::encode_location(test_addr).unwrap();
         let words: Vec<String> = words_str
             .split_whitespace()
             .map(|s| s.to_string())
@@ -130,11 +121,11 @@ mod tests {
         assert!(result.is_ok(), "connect should succeed: {:?}", result);
     }
 
+    #[cfg(feature = "words")]
     #[tokio::test]
     async fn connect_with_valid_words_no_matching_agent() {
-        let addr_encoder = FourWordAdaptiveEncoder::new().unwrap();
         let test_addr = "10.0.0.1:5483";
-        let words_str = addr_encoder.encode(test_addr).unwrap();
+        let words_str = crate::words::encode_location(test_addr).unwrap();
         let words: Vec<String> = words_str
             .split_whitespace()
             .map(|s| s.to_string())
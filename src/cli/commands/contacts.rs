@@ -1,7 +1,7 @@
 //! Contact and trust management CLI commands.
 
 use crate::cli::{print_value, DaemonClient};
-use anyhow::Result;
+use anyhow::{Context, Result};
 
 /// `x0x contacts [list]` — GET /contacts
 pub async fn list(client: &DaemonClient) -> Result<()> {
@@ -72,6 +72,16 @@ pub async fn revoke(client: &DaemonClient, agent_id: &str, reason: &str) -> Resu
     Ok(())
 }
 
+/// `x0x contacts reachability` — GET /contacts/:agent_id/reachability
+pub async fn reachability(client: &DaemonClient, agent_id: &str) -> Result<()> {
+    client.ensure_running().await?;
+    let resp = client
+        .get(&format!("/contacts/{agent_id}/reachability"))
+        .await?;
+    print_value(client.format(), &resp);
+    Ok(())
+}
+
 /// `x0x contacts revocations` — GET /contacts/:agent_id/revocations
 pub async fn revocations(client: &DaemonClient, agent_id: &str) -> Result<()> {
     client.ensure_running().await?;
@@ -82,6 +92,50 @@ pub async fn revocations(client: &DaemonClient, agent_id: &str) -> Result<()> {
     Ok(())
 }
 
+/// `x0x contacts audit` — GET /contacts/audit
+pub async fn audit(client: &DaemonClient) -> Result<()> {
+    client.run_get("/contacts/audit").await
+}
+
+/// `x0x contacts export` — GET /contacts/export
+///
+/// With `output`, writes the export as JSON to that file instead of
+/// printing it, ready for `x0x contacts import` on another agent.
+pub async fn export(client: &DaemonClient, output: Option<&str>) -> Result<()> {
+    let Some(path) = output else {
+        return client.run_get("/contacts/export").await;
+    };
+    client.ensure_running().await?;
+    let resp = client.get("/contacts/export").await?;
+    let json = serde_json::to_string_pretty(&resp)?;
+    std::fs::write(path, json).with_context(|| format!("write export to {path}"))?;
+    Ok(())
+}
+
+/// `x0x contacts import` — POST /contacts/import
+///
+/// Reads an export from `path` (`-` for stdin) and merges it using
+/// `strategy`: keep_existing, prefer_incoming or highest_trust_wins.
+pub async fn import(client: &DaemonClient, path: &str, strategy: &str) -> Result<()> {
+    client.ensure_running().await?;
+    let raw = if path == "-" {
+        use std::io::Read as _;
+        let mut s = String::new();
+        std::io::stdin()
+            .read_to_string(&mut s)
+            .context("read export from stdin")?;
+        s
+    } else {
+        std::fs::read_to_string(path).with_context(|| format!("read export from {path}"))?
+    };
+    let mut body: serde_json::Value = serde_json::from_str(&raw).context("parse export JSON")?;
+    anyhow::ensure!(body.is_object(), "export must be a JSON object");
+    body["strategy"] = serde_json::Value::String(strategy.to_string());
+    let resp = client.post("/contacts/import", &body).await?;
+    print_value(client.format(), &resp);
+    Ok(())
+}
+
 /// `x0x trust set` — POST /contacts/trust
 pub async fn trust_set(client: &DaemonClient, agent_id: &str, level: &str) -> Result<()> {
     client.ensure_running().await?;
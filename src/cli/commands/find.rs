@@ -2,14 +2,11 @@
 
 use crate::cli::{print_value, DaemonClient};
 use anyhow::{bail, Context, Result};
-use four_word_networking::IdentityEncoder;
 
 /// `x0x find <words...>` — decode identity words and search for matching agents.
 pub async fn find(client: &DaemonClient, words: &[String]) -> Result<()> {
     client.ensure_running().await?;
 
-    let encoder = IdentityEncoder::new();
-
     // Validate input: exactly 4 words, or 4 + "@" + 4 = 9 tokens.
     let has_separator = words.iter().any(|w| w == "@");
 
@@ -36,18 +33,15 @@ pub async fn find(client: &DaemonClient, words: &[String]) -> Result<()> {
 
     // Decode agent prefix (first 4 words).
     let agent_words = words[..4].join(" ");
-    let agent_prefix = encoder
-        .decode_to_prefix(&agent_words)
+    let agent_prefix_hex = crate::words::decode_identity_prefix(&agent_words)
         .context("failed to decode agent identity words — check spelling")?;
-    let agent_prefix_hex = hex::encode(agent_prefix);
 
     // Optionally decode user prefix (last 4 words after @).
     let user_prefix_hex = if has_separator {
         let user_words = words[5..9].join(" ");
-        let user_prefix = encoder
-            .decode_to_prefix(&user_words)
+        let user_prefix_hex = crate::words::decode_identity_prefix(&user_words)
             .context("failed to decode user identity words — check spelling")?;
-        Some(hex::encode(user_prefix))
+        Some(user_prefix_hex)
     } else {
         None
     };
@@ -89,7 +83,7 @@ pub async fn find(client: &DaemonClient, words: &[String]) -> Result<()> {
         }
 
         let mut entry = agent.clone();
-        super::identity::inject_identity_words(&encoder, &mut entry);
+        super::identity::inject_identity_words(&mut entry);
         matches.push(entry);
     }
 
@@ -65,11 +65,13 @@ pub async fn decrypt(
     group_id: &str,
     ciphertext: &str,
     epoch: u64,
+    counter: Option<u64>,
 ) -> Result<()> {
     client.ensure_running().await?;
     let body = serde_json::json!({
         "ciphertext": ciphertext,
         "epoch": epoch,
+        "counter": counter,
     });
     let resp = client
         .post(&format!("/mls/groups/{group_id}/decrypt"), &body)
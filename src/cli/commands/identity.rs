@@ -3,37 +3,38 @@
 use crate::cli::{print_value, DaemonClient};
 use anyhow::{bail, Context, Result};
 use base64::Engine;
-use four_word_networking::IdentityEncoder;
 use std::io::Read;
 
 /// Compute 4-word speakable identity from a hex agent/user ID.
-fn identity_words(encoder: &IdentityEncoder, hex_id: &str) -> Option<String> {
-    encoder.encode_hex(hex_id).ok().map(|w| w.to_string())
+fn identity_words(hex_id: &str) -> Option<String> {
+    crate::words::encode_hex_id(hex_id)
 }
 
 /// Inject `identity_words` field into a JSON object next to an `agent_id` field.
-pub fn inject_identity_words(encoder: &IdentityEncoder, value: &mut serde_json::Value) {
+///
+/// When words are unavailable the field still appears, as `hex:<id>`.
+pub fn inject_identity_words(value: &mut serde_json::Value) {
     if let Some(obj) = value.as_object_mut() {
         if let Some(agent_hex) = obj
             .get("agent_id")
             .and_then(|v| v.as_str())
             .map(String::from)
         {
-            if let Some(words) = identity_words(encoder, &agent_hex) {
-                obj.insert(
-                    "identity_words".to_string(),
-                    serde_json::Value::String(words),
-                );
-            }
+            let words = identity_words(&agent_hex)
+                .unwrap_or_else(|| crate::words::hex_fallback(&agent_hex));
+            obj.insert(
+                "identity_words".to_string(),
+                serde_json::Value::String(words),
+            );
         }
         if let Some(user_hex) = obj
             .get("user_id")
             .and_then(|v| v.as_str())
             .map(String::from)
         {
-            if let Some(words) = identity_words(encoder, &user_hex) {
-                obj.insert("user_words".to_string(), serde_json::Value::String(words));
-            }
+            let words =
+                identity_words(&user_hex).unwrap_or_else(|| crate::words::hex_fallback(&user_hex));
+            obj.insert("user_words".to_string(), serde_json::Value::String(words));
         }
     }
 }
@@ -42,8 +43,7 @@ pub fn inject_identity_words(encoder: &IdentityEncoder, value: &mut serde_json::
 pub async fn agent(client: &DaemonClient) -> Result<()> {
     client.ensure_running().await?;
     let mut resp = client.get("/agent").await?;
-    let encoder = IdentityEncoder::new();
-    inject_identity_words(&encoder, &mut resp);
+    inject_identity_words(&mut resp);
     print_value(client.format(), &resp);
     Ok(())
 }
@@ -53,6 +53,11 @@ pub async fn user_id(client: &DaemonClient) -> Result<()> {
     client.run_get("/agent/user-id").await
 }
 
+/// `x0x agent words` — GET /agent/words
+pub async fn words(client: &DaemonClient) -> Result<()> {
+    client.run_get("/agent/words").await
+}
+
 /// `x0x announce` — POST /announce
 pub async fn announce(client: &DaemonClient, include_user: bool, consent: bool) -> Result<()> {
     client.ensure_running().await?;
@@ -215,6 +220,45 @@ pub async fn revocations(client: &DaemonClient) -> Result<()> {
     client.run_get("/identity/revocations").await
 }
 
+/// `x0x identity wipe` — daemonless secure delete of this instance's identity.
+///
+/// Overwrites and removes the key files, then removes certificates,
+/// revocations, contacts and the peer cache. Refuses without `--yes`.
+pub async fn wipe(name: Option<&str>, yes: bool, format: crate::cli::OutputFormat) -> Result<()> {
+    if !yes {
+        bail!(
+            "this permanently destroys the identity of this instance; re-run with --yes to confirm"
+        );
+    }
+    let report = crate::storage::wipe_identity(name, true)
+        .await
+        .context("failed to wipe identity")?;
+    let paths = |paths: &[std::path::PathBuf]| -> Vec<String> {
+        paths.iter().map(|p| p.display().to_string()).collect()
+    };
+    match format {
+        crate::cli::OutputFormat::Json => print_value(
+            format,
+            &serde_json::json!({
+                "overwritten": paths(&report.overwritten),
+                "removed": paths(&report.removed),
+            }),
+        ),
+        crate::cli::OutputFormat::Text => {
+            if report.is_empty() {
+                println!("No identity files found; nothing to wipe.");
+            }
+            for path in &report.overwritten {
+                println!("overwritten: {}", path.display());
+            }
+            for path in &report.removed {
+                println!("removed:     {}", path.display());
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Resolve the payload for `sign`/`verify` from `--file <PATH>` (with `-`
 /// meaning stdin) or `--payload-b64 <BASE64>`, returning base64 bytes.
 fn payload_b64_from_args(file: Option<&str>, payload_b64: Option<&str>) -> Result<String> {
@@ -244,11 +288,11 @@ mod tests {
     use crate::cli::{DaemonClient, OutputFormat};
 
     use crate::cli::commands::test_support::{start_capturing_mock_server, start_mock_server};
+    #[cfg(feature = "words")]
     #[test]
     fn identity_words_encodes_known_hex() {
-        let encoder = IdentityEncoder::new();
         let hex_id = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
-        let result = identity_words(&encoder, hex_id);
+        let result = identity_words(hex_id);
         assert!(result.is_some(), "should encode valid hex");
         let words = result.unwrap();
         assert!(!words.is_empty(), "should produce non-empty words");
@@ -258,40 +302,41 @@ mod tests {
 
     #[test]
     fn identity_words_rejects_invalid_hex() {
-        let encoder = IdentityEncoder::new();
-        let result = identity_words(&encoder, "not-hex");
+        let result = identity_words("not-hex");
         assert!(result.is_none(), "should reject invalid hex");
     }
 
     #[test]
     fn identity_words_rejects_short_hex() {
-        let encoder = IdentityEncoder::new();
-        let result = identity_words(&encoder, "aabb");
+        let result = identity_words("aabb");
         assert!(result.is_none(), "should reject short hex");
     }
 
     #[test]
     fn inject_identity_words_adds_words_to_object() {
-        let encoder = IdentityEncoder::new();
         let agent_hex = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
         let mut value = serde_json::json!({
             "agent_id": agent_hex,
             "name": "test-agent"
         });
-        inject_identity_words(&encoder, &mut value);
+        inject_identity_words(&mut value);
         assert!(
             value.get("identity_words").is_some(),
             "should add identity_words"
         );
         let words = value["identity_words"].as_str().unwrap().to_string();
         assert!(!words.is_empty(), "should produce non-empty words: {words}");
+        assert_eq!(
+            crate::words::is_hex_fallback(&words),
+            !crate::words::WORDS_ENABLED,
+            "words when enabled, hex fallback otherwise: {words}"
+        );
     }
 
     #[test]
     fn inject_identity_words_skips_missing_agent_id() {
-        let encoder = IdentityEncoder::new();
         let mut value = serde_json::json!({"name": "no-id"});
-        inject_identity_words(&encoder, &mut value);
+        inject_identity_words(&mut value);
         assert!(
             value.get("identity_words").is_none(),
             "should not add words without agent_id"
@@ -300,13 +345,12 @@ mod tests {
 
     #[test]
     fn inject_identity_words_adds_user_words() {
-        let encoder = IdentityEncoder::new();
         let user_hex = "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb";
         let mut value = serde_json::json!({
             "agent_id": "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
             "user_id": user_hex,
         });
-        inject_identity_words(&encoder, &mut value);
+        inject_identity_words(&mut value);
         assert!(
             value.get("identity_words").is_some(),
             "should add identity_words"
@@ -316,9 +360,8 @@ mod tests {
 
     #[test]
     fn inject_identity_words_handles_non_object() {
-        let encoder = IdentityEncoder::new();
         let mut value = serde_json::json!([1, 2, 3]);
-        inject_identity_words(&encoder, &mut value);
+        inject_identity_words(&mut value);
         // Should not panic, should not modify array
         assert!(value.is_array());
     }
@@ -43,6 +43,11 @@ pub async fn unsubscribe(client: &DaemonClient, id: &str) -> Result<()> {
     client.run_delete(&format!("/subscribe/{id}")).await
 }
 
+/// `x0x subscriptions` — GET /subscriptions
+pub async fn subscriptions(client: &DaemonClient) -> Result<()> {
+    client.run_get("/subscriptions").await
+}
+
 /// `x0x events` — stream GET /events
 pub async fn events(client: &DaemonClient) -> Result<()> {
     client.ensure_running().await?;
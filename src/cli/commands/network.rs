@@ -2,15 +2,12 @@
 
 use crate::cli::{print_value, DaemonClient};
 use anyhow::Result;
-use four_word_networking::FourWordAdaptiveEncoder;
 
 /// Inject `location_words` for each address in `external_addrs`.
+///
+/// Omitted when words are unavailable; the addresses themselves are
+/// already in the response.
 fn inject_location_words(value: &mut serde_json::Value) {
-    let addr_encoder = match FourWordAdaptiveEncoder::new() {
-        Ok(e) => e,
-        Err(_) => return,
-    };
-
     if let Some(obj) = value.as_object_mut() {
         if let Some(addrs) = obj
             .get("external_addrs")
@@ -21,7 +18,7 @@ fn inject_location_words(value: &mut serde_json::Value) {
                 .iter()
                 .filter_map(|a| a.as_str())
                 .filter_map(|addr| {
-                    addr_encoder.encode(addr).ok().map(|words| {
+                    crate::words::encode_location(addr).map(|words| {
                         serde_json::json!({
                             "addr": addr,
                             "location_words": words,
@@ -44,17 +41,28 @@ pub async fn health(client: &DaemonClient) -> Result<()> {
     client.run_get("/health").await
 }
 
+/// `x0x health persistence` — GET /health/persistence
+pub async fn health_persistence(client: &DaemonClient) -> Result<()> {
+    client.run_get("/health/persistence").await
+}
+
 /// `x0x status` — GET /status
 pub async fn status(client: &DaemonClient) -> Result<()> {
     client.ensure_running().await?;
     let mut resp = client.get("/status").await?;
-    let encoder = four_word_networking::IdentityEncoder::new();
-    super::identity::inject_identity_words(&encoder, &mut resp);
+    super::identity::inject_identity_words(&mut resp);
     inject_location_words(&mut resp);
     print_value(client.format(), &resp);
     Ok(())
 }
 
+/// `x0x metrics` — GET /metrics (Prometheus text, printed as-is)
+pub async fn metrics(client: &DaemonClient) -> Result<()> {
+    client.ensure_running().await?;
+    print!("{}", client.get_text("/metrics").await?);
+    Ok(())
+}
+
 /// `x0x peers` — GET /peers
 pub async fn peers(client: &DaemonClient) -> Result<()> {
     client.run_get("/peers").await
@@ -75,6 +83,18 @@ pub async fn bootstrap_cache(client: &DaemonClient) -> Result<()> {
     client.run_get("/network/bootstrap-cache").await
 }
 
+/// `x0x network bootstrap-health` — GET /bootstrap/health
+pub async fn bootstrap_health(client: &DaemonClient, timeout_ms: Option<u64>) -> Result<()> {
+    match timeout_ms {
+        Some(ms) => {
+            client
+                .run_get(&format!("/bootstrap/health?timeout_ms={ms}"))
+                .await
+        }
+        None => client.run_get("/bootstrap/health").await,
+    }
+}
+
 /// `x0x diagnostics connectivity` — GET /diagnostics/connectivity
 ///
 /// Prints the ant-quic NodeStatus snapshot as JSON. Includes UPnP port-mapping
@@ -134,6 +154,13 @@ pub async fn diagnostics_ws(client: &DaemonClient) -> Result<()> {
     client.run_get("/diagnostics/ws").await
 }
 
+/// `x0x diagnostics sse` — GET /diagnostics/sse
+///
+/// `/events` client count plus how many events lagging clients have lost.
+pub async fn diagnostics_sse(client: &DaemonClient) -> Result<()> {
+    client.run_get("/diagnostics/sse").await
+}
+
 /// `x0x diagnostics connect` — GET /diagnostics/connect
 ///
 /// Connect-ACL policy summary (enabled flag, loaded-from path, allow-entry
@@ -175,6 +202,19 @@ pub async fn peers_health(client: &DaemonClient, peer_id: &str) -> Result<()> {
     client.run_get(&format!("/peers/{peer_id}/health")).await
 }
 
+/// `x0x peer cache` — GET /peers/cache
+pub async fn peers_cache(client: &DaemonClient) -> Result<()> {
+    client.run_get("/peers/cache").await
+}
+
+/// `x0x peer disconnect-all` — POST /peers/disconnect-all
+pub async fn peers_disconnect_all(client: &DaemonClient) -> Result<()> {
+    client.ensure_running().await?;
+    let resp = client.post_empty("/peers/disconnect-all").await?;
+    print_value(client.format(), &resp);
+    Ok(())
+}
+
 /// `x0x peers events` — GET /peers/events (SSE).
 ///
 /// Streams peer lifecycle transitions (`Established`, `Replaced`, `Closing`,
@@ -305,6 +345,20 @@ async fn bootstrap_cache_returns_mock_response() {
     );
 }
 
+#[tokio::test]
+async fn bootstrap_health_returns_mock_response() {
+    let mock_resp = serde_json::json!({"ok": true, "total": 1, "reachable": 0, "peers": []});
+    let (url, _shutdown) = start_mock_server(mock_resp).await;
+    let client = DaemonClient::new(None, Some(&url), crate::cli::OutputFormat::Json).unwrap();
+
+    let result = bootstrap_health(&client, Some(500)).await;
+    assert!(
+        result.is_ok(),
+        "bootstrap_health should succeed: {:?}",
+        result
+    );
+}
+
 #[tokio::test]
 async fn diagnostics_connectivity_returns_mock_response() {
     let mock_resp = serde_json::json!({"nat_type": "FullCone", "upnp": true});
@@ -1,6 +1,6 @@
 //! Presence CLI commands — wrappers around the `/presence/*` REST endpoints.
 
-use crate::cli::DaemonClient;
+use crate::cli::{print_value, DaemonClient};
 use anyhow::Result;
 
 /// `x0x presence online` — GET /presence/online
@@ -47,6 +47,22 @@ pub async fn status(client: &DaemonClient, id: &str) -> Result<()> {
     client.run_get(&format!("/presence/status/{id}")).await
 }
 
+/// `x0x presence set-status <status>` — POST /presence/status
+///
+/// Sets the status carried on this agent's identity announcements.
+pub async fn set_status(client: &DaemonClient, status: &str) -> Result<()> {
+    client.ensure_running().await?;
+    let body = serde_json::json!({ "status": status });
+    let resp = client.post("/presence/status", &body).await?;
+    print_value(client.format(), &resp);
+    Ok(())
+}
+
+/// `x0x presence clear-status` — DELETE /presence/status
+pub async fn clear_status(client: &DaemonClient) -> Result<()> {
+    client.run_delete("/presence/status").await
+}
+
 /// `x0x presence events` — GET /presence/events (SSE stream).
 ///
 /// Streams presence online/offline events as they happen. Each line on
@@ -1,7 +1,7 @@
 //! Collaborative task list CLI commands.
 
 use crate::cli::{print_value, DaemonClient};
-use anyhow::Result;
+use anyhow::{Context, Result};
 
 /// `x0x tasks [list]` — GET /task-lists
 pub async fn list(client: &DaemonClient) -> Result<()> {
@@ -9,11 +9,17 @@ pub async fn list(client: &DaemonClient) -> Result<()> {
 }
 
 /// `x0x tasks create` — POST /task-lists
-pub async fn create(client: &DaemonClient, name: &str, topic: &str) -> Result<()> {
+pub async fn create(
+    client: &DaemonClient,
+    name: &str,
+    topic: &str,
+    sync_interval_secs: Option<u64>,
+) -> Result<()> {
     client.ensure_running().await?;
     let body = serde_json::json!({
         "name": name,
         "topic": topic,
+        "sync_interval_secs": sync_interval_secs,
     });
     let resp = client.post("/task-lists", &body).await?;
     print_value(client.format(), &resp);
@@ -27,6 +33,43 @@ pub async fn show(client: &DaemonClient, list_id: &str) -> Result<()> {
         .await
 }
 
+/// `x0x tasks validate` — GET /task-lists/:id/validate
+pub async fn validate(client: &DaemonClient, list_id: &str) -> Result<()> {
+    client
+        .run_get(&format!("/task-lists/{list_id}/validate"))
+        .await
+}
+
+/// `x0x tasks sync-stats` — GET /task-lists/:id/sync-stats
+pub async fn sync_stats(client: &DaemonClient, list_id: &str) -> Result<()> {
+    client
+        .run_get(&format!("/task-lists/{list_id}/sync-stats"))
+        .await
+}
+
+/// `x0x tasks history` — GET /task-lists/:id/tasks/:tid/history
+pub async fn history(client: &DaemonClient, list_id: &str, task_id: &str) -> Result<()> {
+    client
+        .run_get(&format!("/task-lists/{list_id}/tasks/{task_id}/history"))
+        .await
+}
+
+/// `x0x tasks export` — GET /task-lists/:id/export
+///
+/// With `output`, writes just the snapshot as JSON to that file instead of
+/// printing the response.
+pub async fn export(client: &DaemonClient, list_id: &str, output: Option<&str>) -> Result<()> {
+    let path = format!("/task-lists/{list_id}/export");
+    let Some(output) = output else {
+        return client.run_get(&path).await;
+    };
+    client.ensure_running().await?;
+    let resp = client.get(&path).await?;
+    let json = serde_json::to_string_pretty(&resp["snapshot"])?;
+    std::fs::write(output, json).with_context(|| format!("write snapshot to {output}"))?;
+    Ok(())
+}
+
 /// `x0x tasks add` — POST /task-lists/:id/tasks
 pub async fn add(
     client: &DaemonClient,
@@ -46,7 +89,7 @@ pub async fn add(
     Ok(())
 }
 
-/// `x0x tasks claim/complete` — PATCH /task-lists/:id/tasks/:tid
+/// `x0x tasks claim/complete/reopen` — PATCH /task-lists/:id/tasks/:tid
 pub async fn update(
     client: &DaemonClient,
     list_id: &str,
@@ -62,6 +105,77 @@ pub async fn update(
     Ok(())
 }
 
+/// `x0x tasks assign` — PATCH /task-lists/:id/tasks/:tid (action: assign)
+pub async fn assign(
+    client: &DaemonClient,
+    list_id: &str,
+    task_id: &str,
+    agent: Option<&str>,
+) -> Result<()> {
+    client.ensure_running().await?;
+    let body = serde_json::json!({ "action": "assign", "assignee": agent });
+    let resp = client
+        .patch(&format!("/task-lists/{list_id}/tasks/{task_id}"), &body)
+        .await?;
+    print_value(client.format(), &resp);
+    Ok(())
+}
+
+/// `x0x tasks priority` — PATCH /task-lists/:id/tasks/:tid (action: set_priority)
+pub async fn set_priority(
+    client: &DaemonClient,
+    list_id: &str,
+    task_id: &str,
+    priority: u8,
+) -> Result<()> {
+    client.ensure_running().await?;
+    let body = serde_json::json!({ "action": "set_priority", "priority": priority });
+    let resp = client
+        .patch(&format!("/task-lists/{list_id}/tasks/{task_id}"), &body)
+        .await?;
+    print_value(client.format(), &resp);
+    Ok(())
+}
+
+/// `x0x tasks checkpoint` — POST /task-lists/:id/checkpoint
+pub async fn checkpoint(client: &DaemonClient, list_id: &str) -> Result<()> {
+    client.ensure_running().await?;
+    let resp = client
+        .post(
+            &format!("/task-lists/{list_id}/checkpoint"),
+            &serde_json::json!({}),
+        )
+        .await?;
+    print_value(client.format(), &resp);
+    Ok(())
+}
+
+/// `x0x tasks checkpoint-all` — POST /checkpoint-all
+pub async fn checkpoint_all(client: &DaemonClient) -> Result<()> {
+    client.ensure_running().await?;
+    let resp = client
+        .post("/checkpoint-all", &serde_json::json!({}))
+        .await?;
+    print_value(client.format(), &resp);
+    Ok(())
+}
+
+/// `x0x persistence policy` — PATCH /persistence/policy
+pub async fn persistence_policy(client: &DaemonClient, save_interval_secs: u64) -> Result<()> {
+    client.ensure_running().await?;
+    let body = serde_json::json!({ "save_interval_secs": save_interval_secs });
+    let resp = client.patch("/persistence/policy", &body).await?;
+    print_value(client.format(), &resp);
+    Ok(())
+}
+
+/// `x0x tasks remove` — DELETE /task-lists/:id/tasks/:tid
+pub async fn remove(client: &DaemonClient, list_id: &str, task_id: &str) -> Result<()> {
+    client
+        .run_delete(&format!("/task-lists/{list_id}/tasks/{task_id}"))
+        .await
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
@@ -53,7 +53,8 @@ pub struct InspectReport {
     pub path: String,
     /// Full hex-encoded UserId (SHA-256 of the ML-DSA-65 public key).
     pub user_id: String,
-    /// Four-word speakable form of the UserId, when derivable.
+    /// Four-word speakable form of the UserId, or `hex:<user_id>` when
+    /// words are unavailable.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_words: Option<String>,
     /// Always true on success; failures return an error (non-zero exit).
@@ -75,10 +76,7 @@ pub async fn inspect(input: Option<PathBuf>) -> Result<InspectReport> {
         .await
         .with_context(|| format!("invalid user identity file {}", path.display()))?;
     let user_id = hex::encode(keypair.user_id().as_bytes());
-    let user_words = four_word_networking::IdentityEncoder::new()
-        .encode_hex(&user_id)
-        .ok()
-        .map(|w| w.to_string());
+    let user_words = Some(crate::words::render_hex_id(&user_id));
 
     Ok(InspectReport {
         path: path.display().to_string(),
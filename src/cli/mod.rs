@@ -142,6 +142,23 @@ impl DaemonClient {
         self.handle_response(resp).await
     }
 
+    /// Send a GET request and return the raw body (for non-JSON endpoints).
+    pub async fn get_text(&self, path: &str) -> Result<String> {
+        let resp = self
+            .client
+            .get(format!("{}{}", self.base_url, path))
+            .headers(self.auth_headers())
+            .send()
+            .await
+            .context("request failed — is x0xd running?")?;
+        let status = resp.status();
+        if !status.is_success() {
+            let body: serde_json::Value = resp.json().await.unwrap_or_default();
+            return Err(error_from_body(status, &body));
+        }
+        resp.text().await.context("failed to read response body")
+    }
+
     /// Send a GET request with query parameters.
     pub async fn get_query(&self, path: &str, query: &[(&str, &str)]) -> Result<serde_json::Value> {
         let resp = self
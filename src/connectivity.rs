@@ -139,6 +139,58 @@ impl std::fmt::Display for ConnectOutcome {
     }
 }
 
+/// How an agent was reached by [`crate::Agent::verify_contact_reachability`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReachabilityStatus {
+    /// A QUIC connection to the agent's machine was already open.
+    Connected,
+    /// Connected directly to a cached address.
+    Direct,
+    /// Connected through a coordinated hole-punch via another peer.
+    Relayed,
+    /// Known, but no address could be reached.
+    Unreachable,
+    /// Not in the discovery cache, so there was nothing to try.
+    NotFound,
+}
+
+/// Result of [`crate::Agent::verify_contact_reachability`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ReachabilityReport {
+    /// How the agent was reached, if at all.
+    pub status: ReachabilityStatus,
+    /// Address the new connection used (`direct` and `relayed` only).
+    pub address: Option<std::net::SocketAddr>,
+}
+
+impl ReachabilityReport {
+    /// True when the agent is connected now (`connected`, `direct` or
+    /// `relayed`).
+    #[must_use]
+    pub fn is_reachable(&self) -> bool {
+        matches!(
+            self.status,
+            ReachabilityStatus::Connected
+                | ReachabilityStatus::Direct
+                | ReachabilityStatus::Relayed
+        )
+    }
+}
+
+impl From<ConnectOutcome> for ReachabilityReport {
+    fn from(outcome: ConnectOutcome) -> Self {
+        let (status, address) = match outcome {
+            ConnectOutcome::Direct(addr) => (ReachabilityStatus::Direct, Some(addr)),
+            ConnectOutcome::Coordinated(addr) => (ReachabilityStatus::Relayed, Some(addr)),
+            ConnectOutcome::AlreadyConnected => (ReachabilityStatus::Connected, None),
+            ConnectOutcome::Unreachable => (ReachabilityStatus::Unreachable, None),
+            ConnectOutcome::NotFound => (ReachabilityStatus::NotFound, None),
+        };
+        Self { status, address }
+    }
+}
+
 // ─── Transport environment assessment (ADR-0011 §4) ──────────────────────────
 
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
@@ -506,6 +558,7 @@ mod tests {
             cert_not_after: None,
             agent_certificate: None,
             agent_public_key: Vec::new(),
+            status: None,
         }
     }
 
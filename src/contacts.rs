@@ -18,10 +18,64 @@
 //! machines an agent has been observed running on. When an agent's
 //! `IdentityType` is set to `Pinned`, messages are only
 //! accepted from machine IDs that appear in the contact's machine list.
+//!
+//! # Capacity
+//!
+//! Contacts can be created implicitly (e.g. a machine record for a sender
+//! seen for the first time), so a mesh flooding an agent with distinct
+//! agent IDs could otherwise grow the store without bound. The store holds
+//! at most [`ContactStore::max_contacts`] entries (default
+//! [`DEFAULT_MAX_CONTACTS`]). When a new contact pushes it over the cap,
+//! contacts are evicted in this order until it fits:
+//!
+//! 1. `Unknown` contacts, least recently seen first (`last_seen`, falling
+//!    back to `added_at`);
+//! 2. then `Known` contacts, in the same order.
+//!
+//! `Blocked` and `Trusted` contacts, and contacts whose key is revoked, are
+//! never evicted, and neither is the contact being added. If only such
+//! contacts remain, the store may exceed the cap. Revocation records are
+//! kept regardless.
+//!
+//! # Sharing Trust Lists
+//!
+//! [`ContactStore::export_json`] writes contacts and revocations in the same
+//! shape as the contacts file, so an export, a peer's export or a backup of
+//! `contacts.json` can all be fed to [`ContactStore::import_json`]. A
+//! [`MergeStrategy`] decides what happens when an incoming contact's agent
+//! ID is already in the store. Imported revocations are always applied:
+//! like local ones, they are permanent and force the contact to `Blocked`.
+//!
+//! # Trust Audit Log
+//!
+//! Every change to a contact's trust level made through the store (adding,
+//! removing, [`ContactStore::set_trust`], revocation, import, eviction and
+//! auto-promotion) appends an [`AuditEntry`] to `<stem>-audit.jsonl` beside the contacts
+//! file (`contacts-audit.jsonl` for `contacts.json`). Calls that leave the
+//! level unchanged are not logged, and neither are edits made through
+//! [`ContactStore::get_mut`]. Once the file holds
+//! [`ContactStore::max_audit_entries`] lines it is rotated to
+//! `<stem>-audit.jsonl.1`, replacing any older rotation, and the same
+//! number of recent entries is kept in memory. Entries are also broadcast to
+//! [`ContactStore::subscribe_audit`] receivers as they are recorded.
+//!
+//! # Auto-Promotion
+//!
+//! Off by default. With [`ContactStore::set_auto_promote_after`] set to
+//! `Some(n)`, a sender whose trust level is `Unknown` is promoted to `Known`
+//! once `n` distinct verified messages from it have been delivered (counted
+//! by message ID, so replays and fan-out to several subscriptions count
+//! once). Promotion never goes beyond `Known` and never applies to
+//! `Blocked` or revoked senders. Progress is held in memory only, is
+//! forgotten after a day without messages from the sender, and is tracked
+//! for at most [`ContactStore::max_contacts`] senders at a time: when full,
+//! the sender heard from least recently makes room, so a flood of throwaway
+//! identities cannot lock out new senders.
 
 use crate::identity::{AgentId, MachineId};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::io::Write as _;
 use std::path::{Path, PathBuf};
 
 /// Trust level assigned to a contact.
@@ -31,7 +85,9 @@ use std::path::{Path, PathBuf};
 /// - `Unknown`: Delivered but flagged (consumer decides)
 /// - `Known`: Delivered normally
 /// - `Trusted`: Full delivery, can trigger actions
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+///
+/// Levels are ordered from `Blocked` (lowest) to `Trusted` (highest).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum TrustLevel {
     /// Messages silently dropped, never rebroadcast.
@@ -215,23 +271,128 @@ pub struct RevocationRecord {
     pub revoker_id: Option<AgentId>,
 }
 
+/// Default for [`ContactStore::max_contacts`].
+pub const DEFAULT_MAX_CONTACTS: usize = 10_000;
+
+/// Default for [`ContactStore::max_audit_entries`].
+pub const DEFAULT_MAX_AUDIT_ENTRIES: usize = 10_000;
+
+/// Buffer of the [`ContactStore::subscribe_audit`] broadcast channel.
+const AUDIT_BROADCAST_CAPACITY: usize = 64;
+
+/// Auto-promotion progress of a sender idle this long is discarded.
+const PROMOTION_PROGRESS_TTL_SECS: u64 = 24 * 60 * 60;
+
+/// Auto-promotion progress of one `Unknown` sender.
+#[derive(Debug, Default)]
+struct PromotionProgress {
+    /// Distinct verified message IDs seen.
+    seen: HashSet<[u8; 32]>,
+    /// Unix seconds of the sender's latest message, for expiry.
+    last_seen: u64,
+    /// Store-wide update sequence, for least-recently-active eviction.
+    touched: u64,
+}
+
+/// What caused a logged trust change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditSource {
+    /// [`ContactStore::add`].
+    Add,
+    /// [`ContactStore::set_trust`].
+    SetTrust,
+    /// [`ContactStore::remove`].
+    Remove,
+    /// [`ContactStore::revoke`] or [`ContactStore::revoke_with_revoker`].
+    Revoke,
+    /// [`ContactStore::import`].
+    Import,
+    /// Capacity eviction (see the module docs).
+    Evict,
+    /// Auto-promotion after enough verified messages (see the module docs).
+    AutoPromote,
+}
+
+/// One trust-level change in the [`ContactStore`] audit log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Unix timestamp of the change.
+    pub timestamp: u64,
+    /// The contact whose trust changed.
+    pub agent_id: AgentId,
+    /// Trust level before the change; `None` if the contact did not exist.
+    pub old_level: Option<TrustLevel>,
+    /// Trust level after the change; `None` if the contact was removed.
+    pub new_level: Option<TrustLevel>,
+    /// The operation that made the change.
+    pub source: AuditSource,
+}
+
 /// Persistent contact store backed by a JSON file.
 ///
 /// Thread-safe access is managed externally (e.g., via `Arc<RwLock<ContactStore>>`).
+/// Bounded by [`ContactStore::max_contacts`]; see the module docs for the
+/// eviction rules.
 #[derive(Debug)]
 pub struct ContactStore {
     contacts: HashMap<[u8; 32], Contact>,
     revoked_keys: HashSet<[u8; 32]>,
     revocations: Vec<RevocationRecord>,
     storage_path: PathBuf,
+    max_contacts: usize,
+    audit: Vec<AuditEntry>,
+    /// Lines in the current (unrotated) audit file.
+    audit_file_entries: usize,
+    max_audit_entries: usize,
+    audit_tx: tokio::sync::broadcast::Sender<AuditEntry>,
+    auto_promote_after: Option<u32>,
+    /// Auto-promotion progress per `Unknown` sender.
+    promotion_progress: HashMap<[u8; 32], PromotionProgress>,
+    /// Source of [`PromotionProgress::touched`].
+    promotion_clock: u64,
 }
 
-/// Serializable format for the contacts file.
-#[derive(Serialize, Deserialize)]
-struct ContactsFile {
-    contacts: Vec<Contact>,
+/// Serializable format for the contacts file, also used for
+/// [`ContactStore::export_json`] and [`ContactStore::import_json`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContactsExport {
+    /// Contacts, sorted by agent ID in exports.
+    pub contacts: Vec<Contact>,
+    /// Key revocations.
     #[serde(default)]
-    revocations: Vec<RevocationRecord>,
+    pub revocations: Vec<RevocationRecord>,
+}
+
+/// How [`ContactStore::import`] resolves an incoming contact whose agent ID
+/// is already present.
+///
+/// Duplicates within one import are resolved the same way, in file order:
+/// each entry is merged against whatever the earlier entries left behind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeStrategy {
+    /// Leave the existing contact untouched.
+    #[default]
+    KeepExisting,
+    /// Replace the existing contact with the incoming one.
+    PreferIncoming,
+    /// Replace the existing contact only if the incoming trust level is
+    /// strictly higher; ties keep the existing contact.
+    HighestTrustWins,
+}
+
+/// Outcome of a [`ContactStore::import`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ContactImportSummary {
+    /// Contacts that were not in the store before.
+    pub added: usize,
+    /// Existing contacts replaced by an incoming entry.
+    pub replaced: usize,
+    /// Incoming entries discarded in favour of an existing contact.
+    pub kept: usize,
+    /// Revocations not previously known.
+    pub revocations_added: usize,
 }
 
 fn now_secs() -> u64 {
@@ -252,17 +413,194 @@ impl ContactStore {
             revoked_keys: HashSet::new(),
             revocations: Vec::new(),
             storage_path,
+            max_contacts: DEFAULT_MAX_CONTACTS,
+            audit: Vec::new(),
+            audit_file_entries: 0,
+            max_audit_entries: DEFAULT_MAX_AUDIT_ENTRIES,
+            audit_tx: tokio::sync::broadcast::channel(AUDIT_BROADCAST_CAPACITY).0,
+            auto_promote_after: None,
+            promotion_progress: HashMap::new(),
+            promotion_clock: 0,
         };
         // Best-effort load from disk
         let _ = store.load();
+        let _ = store.load_audit();
         store
     }
 
-    /// Add or update a contact.
-    ///
-    /// If the agent's key has been revoked, the contact is added with
-    /// trust level forced to `Blocked`.
-    pub fn add(&mut self, mut contact: Contact) {
+    /// Set the capacity, evicting straight away if the loaded store
+    /// already exceeds it. `0` is treated as 1.
+    #[must_use]
+    pub fn with_max_contacts(mut self, max_contacts: usize) -> Self {
+        self.max_contacts = max_contacts.max(1);
+        if self.evict_over_capacity(None) > 0 {
+            let _ = self.save();
+        }
+        self
+    }
+
+    /// Maximum number of contacts kept before eviction.
+    pub fn max_contacts(&self) -> usize {
+        self.max_contacts
+    }
+
+    /// Set the audit log bound: entries kept in memory and lines written
+    /// before the file rotates. `0` is treated as 1.
+    #[must_use]
+    pub fn with_max_audit_entries(mut self, max_audit_entries: usize) -> Self {
+        self.max_audit_entries = max_audit_entries.max(1);
+        self.trim_audit();
+        self
+    }
+
+    /// Maximum number of audit entries kept in memory and per file.
+    pub fn max_audit_entries(&self) -> usize {
+        self.max_audit_entries
+    }
+
+    /// Recent trust changes, oldest first, bounded by
+    /// [`Self::max_audit_entries`].
+    pub fn audit_entries(&self) -> &[AuditEntry] {
+        &self.audit
+    }
+
+    /// Receive each audit entry as it is recorded. Receivers that fall
+    /// behind skip entries; [`Self::audit_entries`] keeps the full record.
+    pub fn subscribe_audit(&self) -> tokio::sync::broadcast::Receiver<AuditEntry> {
+        self.audit_tx.subscribe()
+    }
+
+    /// Path of the current audit log file.
+    pub fn audit_path(&self) -> PathBuf {
+        let stem = self
+            .storage_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "contacts".to_string());
+        self.storage_path
+            .with_file_name(format!("{stem}-audit.jsonl"))
+    }
+
+    /// Log a trust change, unless the level did not actually change.
+    fn record_trust_change(
+        &mut self,
+        agent_id: AgentId,
+        old_level: Option<TrustLevel>,
+        new_level: Option<TrustLevel>,
+        source: AuditSource,
+    ) {
+        if old_level == new_level {
+            return;
+        }
+        let entry = AuditEntry {
+            timestamp: now_secs(),
+            agent_id,
+            old_level,
+            new_level,
+            source,
+        };
+        if let Err(e) = self.append_audit(&entry) {
+            tracing::warn!("failed to append contact audit entry: {e}");
+        }
+        // No receivers is not an error.
+        let _ = self.audit_tx.send(entry.clone());
+        self.audit.push(entry);
+        self.trim_audit();
+    }
+
+    fn trim_audit(&mut self) {
+        let excess = self.audit.len().saturating_sub(self.max_audit_entries);
+        self.audit.drain(..excess);
+    }
+
+    /// Append one entry to the audit file, rotating it first if full.
+    fn append_audit(&mut self, entry: &AuditEntry) -> std::io::Result<()> {
+        let path = self.audit_path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        if self.audit_file_entries >= self.max_audit_entries {
+            let mut rotated = path.clone().into_os_string();
+            rotated.push(".1");
+            std::fs::rename(&path, rotated)?;
+            self.audit_file_entries = 0;
+        }
+        let mut line = serde_json::to_vec(entry)
+            .map_err(|e| std::io::Error::other(format!("serialize: {e}")))?;
+        line.push(b'\n');
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?
+            .write_all(&line)?;
+        self.audit_file_entries += 1;
+        Ok(())
+    }
+
+    /// Load the most recent audit entries from the current audit file.
+    /// Unparseable lines are skipped.
+    fn load_audit(&mut self) -> std::io::Result<()> {
+        let path = self.audit_path();
+        if !path.exists() {
+            return Ok(());
+        }
+        let text = std::fs::read_to_string(path)?;
+        for line in text.lines().filter(|l| !l.trim().is_empty()) {
+            self.audit_file_entries += 1;
+            if let Ok(entry) = serde_json::from_str(line) {
+                self.audit.push(entry);
+            }
+        }
+        self.trim_audit();
+        Ok(())
+    }
+
+    /// Evict contacts until the store fits [`Self::max_contacts`], following
+    /// the order in the module docs. `keep` is never evicted. Returns how
+    /// many contacts were removed; the caller persists.
+    fn evict_over_capacity(&mut self, keep: Option<&AgentId>) -> usize {
+        let excess = self.contacts.len().saturating_sub(self.max_contacts);
+        if excess == 0 {
+            return 0;
+        }
+        let mut candidates: Vec<(u8, u64, [u8; 32])> = self
+            .contacts
+            .values()
+            .filter(|c| keep.is_none_or(|keep| c.agent_id != *keep))
+            .filter(|c| !self.revoked_keys.contains(&c.agent_id.0))
+            .filter_map(|c| {
+                let class = match c.trust_level {
+                    TrustLevel::Unknown => 0,
+                    TrustLevel::Known => 1,
+                    TrustLevel::Blocked | TrustLevel::Trusted => return None,
+                };
+                Some((class, c.last_seen.unwrap_or(c.added_at), c.agent_id.0))
+            })
+            .collect();
+        candidates.sort_unstable();
+        let evicted = candidates.len().min(excess);
+        for (_, _, key) in candidates.into_iter().take(evicted) {
+            if let Some(contact) = self.contacts.remove(&key) {
+                self.record_trust_change(
+                    contact.agent_id,
+                    Some(contact.trust_level),
+                    None,
+                    AuditSource::Evict,
+                );
+            }
+        }
+        if evicted > 0 {
+            tracing::debug!(
+                evicted,
+                max_contacts = self.max_contacts,
+                "contact store over capacity; evicted least recently seen contacts"
+            );
+        }
+        evicted
+    }
+
+    /// Apply the invariants every stored contact must satisfy.
+    fn normalize(&self, contact: &mut Contact) {
         if self.revoked_keys.contains(&contact.agent_id.0) {
             contact.trust_level = TrustLevel::Blocked;
         }
@@ -273,7 +611,22 @@ impl ContactStore {
         {
             contact.identity_type = IdentityType::Known;
         }
-        self.contacts.insert(contact.agent_id.0, contact);
+    }
+
+    /// Add or update a contact.
+    ///
+    /// If the agent's key has been revoked, the contact is added with
+    /// trust level forced to `Blocked`.
+    pub fn add(&mut self, mut contact: Contact) {
+        self.normalize(&mut contact);
+        let agent_id = contact.agent_id;
+        let new_level = contact.trust_level;
+        let old_level = self
+            .contacts
+            .insert(agent_id.0, contact)
+            .map(|c| c.trust_level);
+        self.record_trust_change(agent_id, old_level, Some(new_level), AuditSource::Add);
+        self.evict_over_capacity(Some(&agent_id));
         let _ = self.save();
     }
 
@@ -283,7 +636,13 @@ impl ContactStore {
     /// Note: removing a contact does NOT remove a revocation.
     pub fn remove(&mut self, agent_id: &AgentId) -> Option<Contact> {
         let result = self.contacts.remove(&agent_id.0);
-        if result.is_some() {
+        if let Some(contact) = &result {
+            self.record_trust_change(
+                *agent_id,
+                Some(contact.trust_level),
+                None,
+                AuditSource::Remove,
+            );
             let _ = self.save();
         }
         result
@@ -294,11 +653,16 @@ impl ContactStore {
     /// If the agent's key has been revoked, the trust level is forced to
     /// `Blocked` regardless of the requested level.
     pub fn set_trust(&mut self, agent_id: &AgentId, trust_level: TrustLevel) {
+        self.set_trust_from(agent_id, trust_level, AuditSource::SetTrust);
+    }
+
+    fn set_trust_from(&mut self, agent_id: &AgentId, trust_level: TrustLevel, source: AuditSource) {
         let effective_trust = if self.revoked_keys.contains(&agent_id.0) {
             TrustLevel::Blocked
         } else {
             trust_level
         };
+        let old_level = self.contacts.get(&agent_id.0).map(|c| c.trust_level);
         let entry = self.contacts.entry(agent_id.0).or_insert_with(|| Contact {
             agent_id: *agent_id,
             trust_level: effective_trust,
@@ -317,6 +681,8 @@ impl ContactStore {
         {
             entry.identity_type = IdentityType::Known;
         }
+        self.record_trust_change(*agent_id, old_level, Some(effective_trust), source);
+        self.evict_over_capacity(Some(agent_id));
         let _ = self.save();
     }
 
@@ -336,7 +702,7 @@ impl ContactStore {
             timestamp: now_secs(),
             revoker_id: None,
         });
-        self.set_trust(agent_id, TrustLevel::Blocked);
+        self.set_trust_from(agent_id, TrustLevel::Blocked, AuditSource::Revoke);
     }
 
     /// Revoke an agent's key with an explicit revoker identity.
@@ -354,7 +720,7 @@ impl ContactStore {
             timestamp: now_secs(),
             revoker_id: Some(*revoker_id),
         });
-        self.set_trust(agent_id, TrustLevel::Blocked);
+        self.set_trust_from(agent_id, TrustLevel::Blocked, AuditSource::Revoke);
     }
 
     /// Check if an agent's key has been revoked.
@@ -450,6 +816,7 @@ impl ContactStore {
             false
         } else {
             contact.machines.push(record);
+            self.evict_over_capacity(Some(agent_id));
             let _ = self.save();
             true
         }
@@ -545,12 +912,181 @@ impl ContactStore {
             dm_capabilities: None,
         });
         contact.identity_type = identity_type;
+        self.evict_over_capacity(Some(agent_id));
+        let _ = self.save();
+    }
+
+    /// Enable (`Some(n)`) or disable (`None`) auto-promotion of `Unknown`
+    /// senders to `Known` after `n` verified messages. `Some(0)` is treated
+    /// as `Some(1)`. Disabling discards progress.
+    pub fn set_auto_promote_after(&mut self, threshold: Option<u32>) {
+        self.auto_promote_after = threshold.map(|n| n.max(1));
+        if self.auto_promote_after.is_none() {
+            self.promotion_progress.clear();
+        }
+    }
+
+    /// Auto-promotion threshold, or `None` when disabled (the default).
+    pub fn auto_promote_after(&self) -> Option<u32> {
+        self.auto_promote_after
+    }
+
+    /// Count a delivered message whose signature from `sender` verified,
+    /// promoting the sender to `Known` if this reaches the
+    /// [`Self::auto_promote_after`] threshold.
+    ///
+    /// Returns `true` if the sender was promoted. Does nothing when
+    /// auto-promotion is disabled or the sender's level is not `Unknown`.
+    pub fn record_verified_message(&mut self, sender: &AgentId, message_id: [u8; 32]) -> bool {
+        let Some(threshold) = self.auto_promote_after else {
+            return false;
+        };
+        if self.trust_level(sender) != TrustLevel::Unknown || self.is_revoked(sender) {
+            self.promotion_progress.remove(&sender.0);
+            return false;
+        }
+        let now = now_secs();
+        if !self.promotion_progress.contains_key(&sender.0)
+            && self.promotion_progress.len() >= self.max_contacts
+        {
+            self.evict_promotion_progress(now);
+        }
+        self.promotion_clock += 1;
+        let progress = self.promotion_progress.entry(sender.0).or_default();
+        if now.saturating_sub(progress.last_seen) >= PROMOTION_PROGRESS_TTL_SECS {
+            progress.seen.clear();
+        }
+        progress.last_seen = now;
+        progress.touched = self.promotion_clock;
+        progress.seen.insert(message_id);
+        if progress.seen.len() < threshold as usize {
+            return false;
+        }
+        self.promotion_progress.remove(&sender.0);
+        self.set_trust_from(sender, TrustLevel::Known, AuditSource::AutoPromote);
+        tracing::info!(
+            agent_id = %sender,
+            threshold,
+            "auto-promoted contact from Unknown to Known"
+        );
+        true
+    }
+
+    /// Make room in a full `promotion_progress`: drop expired entries, or
+    /// failing that the sender heard from least recently.
+    fn evict_promotion_progress(&mut self, now: u64) {
+        self.promotion_progress
+            .retain(|_, p| now.saturating_sub(p.last_seen) < PROMOTION_PROGRESS_TTL_SECS);
+        if self.promotion_progress.len() < self.max_contacts {
+            return;
+        }
+        let oldest = self
+            .promotion_progress
+            .iter()
+            .min_by_key(|(_, p)| p.touched)
+            .map(|(id, _)| *id);
+        if let Some(oldest) = oldest {
+            self.promotion_progress.remove(&oldest);
+        }
+    }
+
+    /// Snapshot contacts (sorted by agent ID) and revocations for sharing.
+    pub fn export(&self) -> ContactsExport {
+        let mut contacts: Vec<Contact> = self.contacts.values().cloned().collect();
+        contacts.sort_by_key(|c| c.agent_id.0);
+        ContactsExport {
+            contacts,
+            revocations: self.revocations.clone(),
+        }
+    }
+
+    /// Serialize [`Self::export`] as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn export_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(&self.export())
+    }
+
+    /// Merge an exported trust list into the store.
+    ///
+    /// Revocations are applied first, so an incoming contact for a revoked
+    /// key is stored as `Blocked` whatever the strategy. Conflicting agent
+    /// IDs are resolved by `strategy`, then the store is evicted down to
+    /// [`Self::max_contacts`] and persisted once.
+    pub fn import(
+        &mut self,
+        export: ContactsExport,
+        strategy: MergeStrategy,
+    ) -> ContactImportSummary {
+        let mut summary = ContactImportSummary::default();
+        for record in export.revocations {
+            if self.revoked_keys.insert(record.agent_id.0) {
+                if let Some(contact) = self.contacts.get_mut(&record.agent_id.0) {
+                    let old_level =
+                        std::mem::replace(&mut contact.trust_level, TrustLevel::Blocked);
+                    self.record_trust_change(
+                        record.agent_id,
+                        Some(old_level),
+                        Some(TrustLevel::Blocked),
+                        AuditSource::Import,
+                    );
+                }
+                self.revocations.push(record);
+                summary.revocations_added += 1;
+            }
+        }
+        for mut incoming in export.contacts {
+            self.normalize(&mut incoming);
+            let replace = match self.contacts.get(&incoming.agent_id.0) {
+                None => {
+                    summary.added += 1;
+                    true
+                }
+                Some(existing) => match strategy {
+                    MergeStrategy::KeepExisting => false,
+                    MergeStrategy::PreferIncoming => true,
+                    MergeStrategy::HighestTrustWins => incoming.trust_level > existing.trust_level,
+                },
+            };
+            if !replace {
+                summary.kept += 1;
+                continue;
+            }
+            let (agent_id, new_level) = (incoming.agent_id, incoming.trust_level);
+            let old_level = self
+                .contacts
+                .insert(agent_id.0, incoming)
+                .map(|c| c.trust_level);
+            if old_level.is_some() {
+                summary.replaced += 1;
+            }
+            self.record_trust_change(agent_id, old_level, Some(new_level), AuditSource::Import);
+        }
+        self.evict_over_capacity(None);
         let _ = self.save();
+        summary
+    }
+
+    /// Parse a JSON export and merge it with [`Self::import`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` is not a valid export; the store is left
+    /// unchanged.
+    pub fn import_json(
+        &mut self,
+        json: &str,
+        strategy: MergeStrategy,
+    ) -> serde_json::Result<ContactImportSummary> {
+        let export: ContactsExport = serde_json::from_str(json)?;
+        Ok(self.import(export, strategy))
     }
 
     /// Persist contacts and revocations to disk.
     fn save(&self) -> std::io::Result<()> {
-        let file = ContactsFile {
+        let file = ContactsExport {
             contacts: self.contacts.values().cloned().collect(),
             revocations: self.revocations.clone(),
         };
@@ -575,7 +1111,7 @@ impl ContactStore {
             return Ok(());
         }
         let json = std::fs::read_to_string(&self.storage_path)?;
-        let file: ContactsFile = serde_json::from_str(&json)
+        let file: ContactsExport = serde_json::from_str(&json)
             .map_err(|e| std::io::Error::other(format!("deserialize: {e}")))?;
         for contact in file.contacts {
             self.contacts.insert(contact.agent_id.0, contact);
@@ -651,6 +1187,61 @@ mod tests {
         assert_eq!(IdentityType::default(), IdentityType::Anonymous);
     }
 
+    #[test]
+    fn test_contact_store_evicts_unknown_contacts_over_capacity() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let path = dir.path().join("contacts.json");
+        let mut store = ContactStore::new(path.clone()).with_max_contacts(4);
+        let contact = |agent_id, trust_level, added_at| Contact {
+            agent_id,
+            trust_level,
+            label: None,
+            added_at,
+            last_seen: None,
+            identity_type: IdentityType::default(),
+            machines: Vec::new(),
+            dm_capabilities: None,
+        };
+
+        let blocked = AgentId([1; 32]);
+        let trusted = AgentId([2; 32]);
+        store.add(contact(blocked, TrustLevel::Blocked, 1));
+        store.add(contact(trusted, TrustLevel::Trusted, 2));
+        let unknown: Vec<AgentId> = (10..20u8).map(|n| AgentId([n; 32])).collect();
+        for (i, id) in unknown.iter().enumerate() {
+            store.add(contact(*id, TrustLevel::Unknown, 100 + i as u64));
+        }
+
+        assert_eq!(store.list().len(), 4);
+        assert!(store.is_blocked(&blocked));
+        assert!(store.is_trusted(&trusted));
+        // The two most recently added unknown contacts survive.
+        for id in &unknown[..8] {
+            assert!(store.get(id).is_none(), "older unknown contact evicted");
+        }
+        assert!(store.get(&unknown[8]).is_some());
+        assert!(store.get(&unknown[9]).is_some());
+
+        // A contact seen recently outlives one added later but never seen.
+        store.touch(&unknown[8]);
+        store.add(contact(AgentId([30; 32]), TrustLevel::Unknown, 200));
+        assert!(store.get(&unknown[8]).is_some());
+        assert!(store.get(&unknown[9]).is_none());
+
+        // Protected contacts are kept even beyond the cap.
+        store.set_trust(&AgentId([31; 32]), TrustLevel::Trusted);
+        store.set_trust(&AgentId([32; 32]), TrustLevel::Blocked);
+        store.set_trust(&AgentId([33; 32]), TrustLevel::Trusted);
+        assert!(store.is_trusted(&AgentId([31; 32])));
+        assert!(store.is_blocked(&AgentId([32; 32])));
+        assert!(store.is_trusted(&AgentId([33; 32])));
+        assert!(store.is_blocked(&blocked) && store.is_trusted(&trusted));
+        assert_eq!(store.list().len(), 5);
+
+        let reloaded = ContactStore::new(path);
+        assert_eq!(reloaded.list().len(), 5);
+    }
+
     #[test]
     fn test_contact_store_add_get_remove() {
         let dir = tempfile::tempdir().expect("tmpdir");
@@ -1037,4 +1628,273 @@ mod tests {
         assert_eq!(store.revocations().len(), 1);
         assert_eq!(store.revocations()[0].reason, "first revocation");
     }
+
+    fn contact(agent_id: AgentId, trust_level: TrustLevel, label: &str) -> Contact {
+        Contact {
+            agent_id,
+            trust_level,
+            label: Some(label.to_string()),
+            added_at: 1000,
+            last_seen: None,
+            identity_type: IdentityType::default(),
+            machines: Vec::new(),
+            dm_capabilities: None,
+        }
+    }
+
+    #[test]
+    fn test_export_import_round_trip_with_revocations() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let mut source = ContactStore::new(dir.path().join("source.json"));
+        let (trusted, revoked) = (test_agent_id(), test_agent_id());
+        source.set_trust(&trusted, TrustLevel::Trusted);
+        source.revoke(&revoked, "compromised");
+
+        let json = source.export_json().expect("export");
+        // Exports are deterministic: contacts are sorted by agent ID.
+        assert_eq!(json, source.export_json().expect("export again"));
+        let ids: Vec<_> = source
+            .export()
+            .contacts
+            .iter()
+            .map(|c| c.agent_id.0)
+            .collect();
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+
+        let path = dir.path().join("target.json");
+        let mut target = ContactStore::new(path.clone());
+        // A fresh agent has already been told to trust the revoked key.
+        target.set_trust(&revoked, TrustLevel::Trusted);
+        let summary = target
+            .import_json(&json, MergeStrategy::PreferIncoming)
+            .expect("import");
+        assert_eq!(
+            summary,
+            ContactImportSummary {
+                added: 1,
+                replaced: 1,
+                kept: 0,
+                revocations_added: 1,
+            }
+        );
+        assert!(target.is_trusted(&trusted));
+        assert!(target.is_revoked(&revoked));
+        assert!(target.is_blocked(&revoked));
+
+        // Persisted, and the contacts file itself is a valid export.
+        let reloaded = ContactStore::new(path.clone());
+        assert!(reloaded.is_trusted(&trusted));
+        assert!(reloaded.is_revoked(&revoked));
+        let backup = std::fs::read_to_string(&path).expect("read contacts file");
+        let mut restored = ContactStore::new(dir.path().join("restored.json"));
+        restored
+            .import_json(&backup, MergeStrategy::KeepExisting)
+            .expect("import backup");
+        assert_eq!(restored.list().len(), 2);
+    }
+
+    #[test]
+    fn test_import_merge_strategies() {
+        let existing_id = test_agent_id();
+        let incoming = ContactsExport {
+            contacts: vec![
+                contact(existing_id, TrustLevel::Known, "first"),
+                contact(existing_id, TrustLevel::Blocked, "second"),
+                contact(existing_id, TrustLevel::Trusted, "third"),
+                contact(existing_id, TrustLevel::Trusted, "fourth"),
+            ],
+            revocations: Vec::new(),
+        };
+        let label_after = |strategy: MergeStrategy, existing: TrustLevel| {
+            let dir = tempfile::tempdir().expect("tmpdir");
+            let mut store = ContactStore::new(dir.path().join("contacts.json"));
+            store.add(contact(existing_id, existing, "existing"));
+            let summary = store.import(incoming.clone(), strategy);
+            assert_eq!(summary.added, 0);
+            assert_eq!(summary.replaced + summary.kept, 4);
+            let c = store.get(&existing_id).expect("contact");
+            (c.label.clone().expect("label"), c.trust_level)
+        };
+
+        assert_eq!(
+            label_after(MergeStrategy::KeepExisting, TrustLevel::Unknown),
+            ("existing".to_string(), TrustLevel::Unknown)
+        );
+        // Last duplicate wins.
+        assert_eq!(
+            label_after(MergeStrategy::PreferIncoming, TrustLevel::Unknown),
+            ("fourth".to_string(), TrustLevel::Trusted)
+        );
+        // Strictly higher trust wins; the first of equal entries stays.
+        assert_eq!(
+            label_after(MergeStrategy::HighestTrustWins, TrustLevel::Unknown),
+            ("third".to_string(), TrustLevel::Trusted)
+        );
+        assert_eq!(
+            label_after(MergeStrategy::HighestTrustWins, TrustLevel::Trusted),
+            ("existing".to_string(), TrustLevel::Trusted)
+        );
+    }
+
+    #[test]
+    fn test_audit_log_records_trust_changes_and_persists() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let path = dir.path().join("contacts.json");
+        let id = test_agent_id();
+        {
+            let mut store = ContactStore::new(path.clone());
+            store.add(contact(id, TrustLevel::Known, "alice"));
+            // Same level: not a trust change.
+            store.add(contact(id, TrustLevel::Known, "alice again"));
+            store.set_trust(&id, TrustLevel::Trusted);
+            store.revoke(&id, "compromised");
+            store.remove(&id);
+
+            let changes: Vec<_> = store
+                .audit_entries()
+                .iter()
+                .map(|e| (e.old_level, e.new_level, e.source))
+                .collect();
+            assert_eq!(
+                changes,
+                vec![
+                    (None, Some(TrustLevel::Known), AuditSource::Add),
+                    (
+                        Some(TrustLevel::Known),
+                        Some(TrustLevel::Trusted),
+                        AuditSource::SetTrust
+                    ),
+                    (
+                        Some(TrustLevel::Trusted),
+                        Some(TrustLevel::Blocked),
+                        AuditSource::Revoke
+                    ),
+                    (Some(TrustLevel::Blocked), None, AuditSource::Remove),
+                ]
+            );
+            assert!(store.audit_entries().iter().all(|e| e.agent_id == id));
+        }
+
+        assert!(dir.path().join("contacts-audit.jsonl").exists());
+        let reloaded = ContactStore::new(path);
+        assert_eq!(reloaded.audit_entries().len(), 4);
+        assert_eq!(reloaded.audit_entries()[3].source, AuditSource::Remove);
+    }
+
+    #[test]
+    fn test_audit_log_rotates_at_bound() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let path = dir.path().join("contacts.json");
+        let mut store = ContactStore::new(path.clone()).with_max_audit_entries(3);
+        let ids: Vec<AgentId> = (0..5).map(|_| test_agent_id()).collect();
+        for id in &ids {
+            store.set_trust(id, TrustLevel::Known);
+        }
+
+        // Memory keeps the newest three entries.
+        let logged: Vec<AgentId> = store.audit_entries().iter().map(|e| e.agent_id).collect();
+        assert_eq!(logged, ids[2..]);
+        // The file rotated after three lines: the rotation holds the first
+        // three changes and the current file the last two.
+        let lines = |name: &str| {
+            std::fs::read_to_string(dir.path().join(name))
+                .expect("audit file")
+                .lines()
+                .count()
+        };
+        assert_eq!(lines("contacts-audit.jsonl.1"), 3);
+        assert_eq!(lines("contacts-audit.jsonl"), 2);
+
+        // A reload resumes counting from the current file.
+        let mut store = ContactStore::new(path).with_max_audit_entries(3);
+        assert_eq!(store.audit_entries().len(), 2);
+        store.set_trust(&ids[0], TrustLevel::Trusted);
+        store.set_trust(&ids[1], TrustLevel::Trusted);
+        assert_eq!(lines("contacts-audit.jsonl.1"), 3);
+        assert_eq!(lines("contacts-audit.jsonl"), 1);
+    }
+
+    #[test]
+    fn test_auto_promotion_is_opt_in_and_capped_at_known() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let mut store = ContactStore::new(dir.path().join("contacts.json"));
+        let mut audit = store.subscribe_audit();
+        let (unknown, blocked, revoked) = (test_agent_id(), test_agent_id(), test_agent_id());
+        store.set_trust(&blocked, TrustLevel::Blocked);
+        store.revoke(&revoked, "compromised");
+        while audit.try_recv().is_ok() {}
+
+        // Disabled by default.
+        for n in 0..5u8 {
+            assert!(!store.record_verified_message(&unknown, [n; 32]));
+        }
+        assert!(store.get(&unknown).is_none());
+
+        store.set_auto_promote_after(Some(3));
+        for n in 0..10u8 {
+            assert!(!store.record_verified_message(&blocked, [n; 32]));
+            assert!(!store.record_verified_message(&revoked, [n; 32]));
+        }
+        assert!(!store.record_verified_message(&unknown, [1; 32]));
+        assert!(!store.record_verified_message(&unknown, [2; 32]));
+        assert!(!store.record_verified_message(&unknown, [2; 32]));
+        assert!(store.record_verified_message(&unknown, [3; 32]));
+        assert_eq!(store.trust_level(&unknown), TrustLevel::Known);
+        assert_eq!(store.trust_level(&blocked), TrustLevel::Blocked);
+        assert_eq!(store.trust_level(&revoked), TrustLevel::Blocked);
+
+        // Never beyond Known.
+        for n in 10..20u8 {
+            assert!(!store.record_verified_message(&unknown, [n; 32]));
+        }
+        assert_eq!(store.trust_level(&unknown), TrustLevel::Known);
+
+        let entry = audit.try_recv().expect("promotion broadcast");
+        assert_eq!(entry.agent_id, unknown);
+        assert_eq!(entry.old_level, None);
+        assert_eq!(entry.new_level, Some(TrustLevel::Known));
+        assert_eq!(entry.source, AuditSource::AutoPromote);
+        assert!(audit.try_recv().is_err());
+    }
+
+    /// A full progress table must not lock out new senders: the least
+    /// recently active sender makes room, and idle progress expires.
+    #[test]
+    fn test_auto_promotion_progress_evicts_idle_senders() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let mut store = ContactStore::new(dir.path().join("contacts.json")).with_max_contacts(2);
+        store.set_auto_promote_after(Some(2));
+        let (sybil_a, sybil_b, honest) = (test_agent_id(), test_agent_id(), test_agent_id());
+
+        assert!(!store.record_verified_message(&sybil_a, [1; 32]));
+        assert!(!store.record_verified_message(&sybil_b, [2; 32]));
+        assert!(!store.record_verified_message(&honest, [3; 32]));
+        assert!(!store.promotion_progress.contains_key(&sybil_a.0));
+        assert!(store.record_verified_message(&honest, [4; 32]));
+        assert_eq!(store.trust_level(&honest), TrustLevel::Known);
+
+        // Progress older than the TTL starts over.
+        store
+            .promotion_progress
+            .get_mut(&sybil_b.0)
+            .expect("tracked")
+            .last_seen -= PROMOTION_PROGRESS_TTL_SECS;
+        assert!(!store.record_verified_message(&sybil_b, [5; 32]));
+        assert_eq!(store.promotion_progress[&sybil_b.0].seen.len(), 1);
+    }
+
+    #[test]
+    fn test_import_json_rejects_malformed_input() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let mut store = ContactStore::new(dir.path().join("contacts.json"));
+        store.set_trust(&test_agent_id(), TrustLevel::Known);
+        assert!(store
+            .import_json("{\"contacts\": 7}", MergeStrategy::PreferIncoming)
+            .is_err());
+        assert_eq!(store.list().len(), 1);
+        assert_eq!(
+            serde_json::to_string(&MergeStrategy::HighestTrustWins).expect("serialize"),
+            "\"highest_trust_wins\""
+        );
+    }
 }
@@ -3,6 +3,7 @@
 //! Implements the state transitions for task checkboxes:
 //! - Empty → Claimed (agent claims a task)
 //! - Claimed → Done (agent completes a task)
+//! - Done → Reopened (agent reopens a completed task, which reads as Empty)
 //!
 //! Invalid transitions return errors.
 
@@ -23,6 +24,10 @@ pub enum CheckboxError {
     /// Attempted to complete without claiming first.
     #[error("task must be claimed before completion")]
     MustClaimFirst,
+
+    /// Attempted to reopen a task that is not done.
+    #[error("only a done task can be reopened")]
+    NotDone,
 }
 
 /// Checkbox state for a task item.
@@ -30,16 +35,22 @@ pub enum CheckboxError {
 /// Represents the lifecycle of a task:
 /// - `Empty`: Task is available for claiming
 /// - `Claimed`: Task is claimed by an agent (in progress)
-/// - `Done`: Task is completed by an agent (final state)
+/// - `Done`: Task is completed by an agent
+/// - `Reopened`: A completed task was reopened by an agent
 ///
 /// # State Machine
 ///
 /// ```text
 /// Empty ──claim──> Claimed ──complete──> Done
-///   │                                      │
-///   └──────────── (immutable) ─────────────┘
+///   ^                                      │
+///   └───────────────── reopen ─────────────┘
 /// ```
 ///
+/// A `Reopened` element is never a task's current state. It cancels every
+/// `Claimed`/`Done` element with the same or an earlier timestamp, so the
+/// task reads as `Empty` until it is claimed again (see
+/// `TaskItem::current_state`).
+///
 /// # Concurrent Claims
 ///
 /// When using OR-Set semantics, concurrent claims from different agents
@@ -65,6 +76,15 @@ pub enum CheckboxState {
         /// When the task was completed (Unix timestamp in milliseconds).
         timestamp: u64,
     },
+
+    /// A completed task was reopened. Kept last so existing bincode
+    /// variant indices are unchanged.
+    Reopened {
+        /// The agent who reopened this task.
+        agent_id: AgentId,
+        /// When the task was reopened (Unix timestamp in milliseconds).
+        timestamp: u64,
+    },
 }
 
 impl std::fmt::Display for CheckboxState {
@@ -77,6 +97,9 @@ impl std::fmt::Display for CheckboxState {
             CheckboxState::Done { agent_id, .. } => {
                 write!(f, "done:{}", hex::encode(agent_id.as_bytes()))
             }
+            CheckboxState::Reopened { agent_id, .. } => {
+                write!(f, "reopened:{}", hex::encode(agent_id.as_bytes()))
+            }
         }
     }
 }
@@ -134,6 +157,19 @@ impl CheckboxState {
         })
     }
 
+    /// Create a new Reopened state.
+    ///
+    /// # Arguments
+    ///
+    /// * `agent_id` - The agent reopening the task
+    /// * `timestamp` - The reopen timestamp (Unix milliseconds)
+    pub fn reopen(agent_id: AgentId, timestamp: u64) -> Result<Self> {
+        Ok(Self::Reopened {
+            agent_id,
+            timestamp,
+        })
+    }
+
     /// Check if the checkbox is empty (unclaimed).
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -152,27 +188,37 @@ impl CheckboxState {
         matches!(self, Self::Done { .. })
     }
 
+    /// Check if this is a reopen element.
+    #[must_use]
+    pub fn is_reopened(&self) -> bool {
+        matches!(self, Self::Reopened { .. })
+    }
+
     /// Get the agent who claimed this task, if any.
     ///
-    /// Returns `Some(agent_id)` if the state is Claimed or Done,
+    /// Returns `Some(agent_id)` if the state is Claimed, Done or Reopened,
     /// otherwise `None`.
     #[must_use]
     pub fn claimed_by(&self) -> Option<&AgentId> {
         match self {
             Self::Empty => None,
-            Self::Claimed { agent_id, .. } | Self::Done { agent_id, .. } => Some(agent_id),
+            Self::Claimed { agent_id, .. }
+            | Self::Done { agent_id, .. }
+            | Self::Reopened { agent_id, .. } => Some(agent_id),
         }
     }
 
     /// Get the timestamp of this state, if any.
     ///
-    /// Returns `Some(timestamp)` if the state is Claimed or Done,
+    /// Returns `Some(timestamp)` if the state is Claimed, Done or Reopened,
     /// otherwise `None`.
     #[must_use]
     pub fn timestamp(&self) -> Option<u64> {
         match self {
             Self::Empty => None,
-            Self::Claimed { timestamp, .. } | Self::Done { timestamp, .. } => Some(*timestamp),
+            Self::Claimed { timestamp, .. }
+            | Self::Done { timestamp, .. }
+            | Self::Reopened { timestamp, .. } => Some(*timestamp),
         }
     }
 
@@ -184,14 +230,15 @@ impl CheckboxState {
     /// - `Claimed -> Claimed`: OK (advisory re-claim; the production path
     ///   accumulates candidates in an OR-Set and resolves a deterministic
     ///   winner — see `TaskItem::claim`)
-    /// - `Done -> Claimed`: Error (immutable)
+    /// - `Done -> Claimed`: Error (reopen first)
+    /// - `Reopened -> Claimed`: OK (a reopened task reads as Empty)
     ///
     /// # Errors
     ///
     /// Returns [`CheckboxError::AlreadyDone`] if the task is already completed.
     pub fn transition_to_claimed(&self, agent_id: AgentId, timestamp: u64) -> Result<Self> {
         match self {
-            Self::Empty | Self::Claimed { .. } => Ok(Self::Claimed {
+            Self::Empty | Self::Claimed { .. } | Self::Reopened { .. } => Ok(Self::Claimed {
                 agent_id,
                 timestamp,
             }),
@@ -206,13 +253,14 @@ impl CheckboxState {
     /// - `Empty -> Done`: Error (must claim first)
     /// - `Claimed -> Done`: OK
     /// - `Done -> Done`: Error (immutable)
+    /// - `Reopened -> Done`: Error (must claim first)
     ///
     /// # Errors
     ///
     /// Returns an error if the transition is invalid.
     pub fn transition_to_done(&self, agent_id: AgentId, timestamp: u64) -> Result<Self> {
         match self {
-            Self::Empty => Err(CheckboxError::MustClaimFirst),
+            Self::Empty | Self::Reopened { .. } => Err(CheckboxError::MustClaimFirst),
             Self::Claimed { .. } => Ok(Self::Done {
                 agent_id,
                 timestamp,
@@ -220,52 +268,53 @@ impl CheckboxState {
             Self::Done { .. } => Err(CheckboxError::AlreadyDone),
         }
     }
+
+    /// Attempt to transition from this state to Reopened.
+    ///
+    /// # State Transitions
+    ///
+    /// - `Done -> Reopened`: OK
+    /// - anything else: Error (only a done task can be reopened)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CheckboxError::NotDone`] unless the task is done.
+    pub fn transition_to_reopened(&self, agent_id: AgentId, timestamp: u64) -> Result<Self> {
+        match self {
+            Self::Done { .. } => Ok(Self::Reopened {
+                agent_id,
+                timestamp,
+            }),
+            _ => Err(CheckboxError::NotDone),
+        }
+    }
+
+    /// Position of the variant in the ordering (see the `Ord` impl).
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Empty => 0,
+            Self::Claimed { .. } => 1,
+            Self::Done { .. } => 2,
+            Self::Reopened { .. } => 3,
+        }
+    }
 }
 
 /// Implement Ord for deterministic tiebreaking in concurrent scenarios.
 ///
 /// Ordering rules:
-/// 1. Empty < Claimed < Done (by variant)
-/// 2. Within Claimed/Done: earlier timestamp < later timestamp
+/// 1. Empty < Claimed < Done < Reopened (by variant)
+/// 2. Within a variant: earlier timestamp < later timestamp
 /// 3. If timestamps equal: lexicographic ordering of agent_id bytes
 impl Ord for CheckboxState {
     fn cmp(&self, other: &Self) -> Ordering {
-        match (self, other) {
-            (Self::Empty, Self::Empty) => Ordering::Equal,
-            (Self::Empty, _) => Ordering::Less,
-            (_, Self::Empty) => Ordering::Greater,
-
-            (
-                Self::Claimed {
-                    agent_id: aid1,
-                    timestamp: ts1,
-                },
-                Self::Claimed {
-                    agent_id: aid2,
-                    timestamp: ts2,
-                },
-            ) => match ts1.cmp(ts2) {
-                Ordering::Equal => aid1.as_bytes().cmp(aid2.as_bytes()),
-                ordering => ordering,
-            },
-
-            (Self::Claimed { .. }, Self::Done { .. }) => Ordering::Less,
-            (Self::Done { .. }, Self::Claimed { .. }) => Ordering::Greater,
-
-            (
-                Self::Done {
-                    agent_id: aid1,
-                    timestamp: ts1,
-                },
-                Self::Done {
-                    agent_id: aid2,
-                    timestamp: ts2,
-                },
-            ) => match ts1.cmp(ts2) {
-                Ordering::Equal => aid1.as_bytes().cmp(aid2.as_bytes()),
-                ordering => ordering,
-            },
-        }
+        self.rank()
+            .cmp(&other.rank())
+            .then_with(|| self.timestamp().cmp(&other.timestamp()))
+            .then_with(|| {
+                let agent = |s: &Self| s.claimed_by().map(|a| *a.as_bytes());
+                agent(self).cmp(&agent(other))
+            })
     }
 }
 
@@ -436,6 +485,44 @@ mod tests {
         assert!(done1 < done2);
     }
 
+    #[test]
+    fn test_reopen_transition_and_ordering() {
+        let done = CheckboxState::complete(agent(1), 1000).ok().unwrap();
+        let reopened = done.transition_to_reopened(agent(2), 2000).ok().unwrap();
+        assert!(reopened.is_reopened());
+        assert_eq!(reopened.claimed_by(), Some(&agent(2)));
+        assert_eq!(reopened.timestamp(), Some(2000));
+        assert_eq!(
+            reopened.to_string(),
+            format!("reopened:{}", hex::encode([2u8; 32]))
+        );
+
+        // Only a done task can be reopened; a reopened task can be claimed.
+        assert_eq!(
+            CheckboxState::Empty.transition_to_reopened(agent(2), 2000),
+            Err(CheckboxError::NotDone)
+        );
+        assert_eq!(
+            reopened.transition_to_reopened(agent(2), 3000),
+            Err(CheckboxError::NotDone)
+        );
+        assert!(reopened
+            .transition_to_claimed(agent(3), 3000)
+            .ok()
+            .unwrap()
+            .is_claimed());
+        assert_eq!(
+            reopened.transition_to_done(agent(3), 3000),
+            Err(CheckboxError::MustClaimFirst)
+        );
+
+        // Reopened sorts after every other variant, even with an earlier
+        // timestamp.
+        let early_reopen = CheckboxState::reopen(agent(1), 1).ok().unwrap();
+        assert!(done < early_reopen);
+        assert!(early_reopen < reopened);
+    }
+
     #[test]
     fn test_checkbox_equality() {
         let agent = agent(1);
@@ -477,6 +564,7 @@ mod tests {
             CheckboxState::Empty,
             CheckboxState::claim(agent, timestamp).ok().unwrap(),
             CheckboxState::complete(agent, timestamp).ok().unwrap(),
+            CheckboxState::reopen(agent, timestamp).ok().unwrap(),
         ];
 
         for state in states {
@@ -85,6 +85,17 @@ impl TaskListDelta {
         delta
     }
 
+    /// Create a delta for a remove_task operation.
+    ///
+    /// Receivers tombstone every tag they have observed for the task, so no
+    /// tags need to be carried.
+    #[must_use]
+    pub fn for_remove(task_id: TaskId, version: u64) -> Self {
+        let mut delta = Self::new(version);
+        delta.removed_tasks.insert(task_id, HashSet::new());
+        delta
+    }
+
     /// Create a delta for a reorder operation.
     ///
     /// Takes the post-reorder ordering register (with its vector clock) so the
@@ -487,6 +498,30 @@ mod tests {
         assert_eq!(list1.task_count(), 1);
     }
 
+    #[test]
+    fn remove_delta_converges_replicas() {
+        let peer1 = peer(1);
+        let peer2 = peer(2);
+        let id = list_id(1);
+
+        let mut list1 = TaskList::new(id, "List".to_string(), peer1);
+        let mut list2 = TaskList::new(id, "List".to_string(), peer2);
+        let task = make_task(1, peer1);
+        let task_id = *task.id();
+        list1.add_task(task, peer1, 1).expect("add");
+        list2
+            .merge_delta(&list1.full_delta(), peer1)
+            .expect("merge add");
+        assert_eq!(list2.task_count(), 1);
+
+        list1.remove_task(&task_id).expect("remove");
+        let delta = TaskListDelta::for_remove(task_id, list1.version());
+        list2.merge_delta(&delta, peer1).expect("merge remove");
+
+        assert_eq!(list2.task_count(), 0);
+        assert!(list2.get_task(&task_id).is_none());
+    }
+
     #[test]
     fn test_delta_serialization() {
         let delta = TaskListDelta::new(5);
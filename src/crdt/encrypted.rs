@@ -19,6 +19,9 @@ pub struct EncryptedTaskListDelta {
     group_id: Vec<u8>,
     /// Epoch when this delta was encrypted.
     epoch: u64,
+    /// Per-epoch nonce counter the ciphertext was sealed under.
+    #[serde(default)]
+    counter: u64,
     /// Encrypted delta ciphertext (includes authentication tag).
     ciphertext: Vec<u8>,
     /// Additional authenticated data (group_id + epoch).
@@ -44,6 +47,8 @@ impl EncryptedTaskListDelta {
     /// - Delta serialization fails
     /// - Encryption operation fails
     ///
+    /// Returns `MlsError::NonceExhausted` if the epoch has no nonce counters left.
+    ///
     /// # Security
     /// The group_id and epoch are included in the AAD (Additional Authenticated Data),
     /// binding the ciphertext to a specific group and epoch. This prevents replay attacks
@@ -63,12 +68,15 @@ impl EncryptedTaskListDelta {
         aad.extend_from_slice(&group_id);
         aad.extend_from_slice(&epoch.to_le_bytes());
 
-        // Encrypt with counter 0 (each delta is a single message)
-        let ciphertext = cipher.encrypt(&plaintext, &aad, 0)?;
+        // Every delta in an epoch shares the key, so each needs its own
+        // nonce counter from the group.
+        let counter = group.next_nonce_counter()?;
+        let ciphertext = cipher.encrypt(&plaintext, &aad, counter)?;
 
         Ok(Self {
             group_id,
             epoch,
+            counter,
             ciphertext,
             aad,
         })
@@ -96,8 +104,7 @@ impl EncryptedTaskListDelta {
     /// - Wrong epoch key is being used
     /// - The AAD doesn't match (wrong group or epoch)
     pub fn decrypt(&self, cipher: &MlsCipher) -> MlsResult<TaskListDelta> {
-        // Decrypt with counter 0
-        let plaintext = cipher.decrypt(&self.ciphertext, &self.aad, 0)?;
+        let plaintext = cipher.decrypt(&self.ciphertext, &self.aad, self.counter)?;
 
         // Deserialize the delta
         bincode::deserialize(&plaintext)
@@ -264,6 +271,24 @@ mod tests {
         assert_eq!(decrypted.added_tasks.len(), delta.added_tasks.len());
     }
 
+    #[tokio::test]
+    async fn test_same_epoch_deltas_never_share_a_nonce() {
+        let (group, _group_id) = create_test_group().await;
+        let delta = create_test_delta();
+
+        let first =
+            EncryptedTaskListDelta::encrypt_with_group(&delta, &group).expect("encryption failed");
+        let second =
+            EncryptedTaskListDelta::encrypt_with_group(&delta, &group).expect("encryption failed");
+
+        // Same key, same plaintext: identical ciphertexts would mean a
+        // reused nonce.
+        assert_eq!(first.epoch(), second.epoch());
+        assert_ne!(first.ciphertext(), second.ciphertext());
+        assert!(first.decrypt_with_group(&group).is_ok());
+        assert!(second.decrypt_with_group(&group).is_ok());
+    }
+
     #[tokio::test]
     async fn test_encrypted_delta_includes_group_metadata() {
         let (group, group_id) = create_test_group().await;
@@ -1,6 +1,8 @@
 //! Error types for CRDT task list operations.
 
-use crate::crdt::{CheckboxState, TaskId};
+use std::path::PathBuf;
+
+use crate::crdt::{CheckboxState, TaskId, TaskListId};
 
 /// Result type for CRDT operations.
 pub type Result<T> = std::result::Result<T, CrdtError>;
@@ -25,6 +27,24 @@ pub enum CrdtError {
     #[error("serialization error: {0}")]
     Serialization(#[from] bincode::Error),
 
+    /// Serialization error while persisting or loading a task list file.
+    #[error("failed to {operation} task list {list_id} at {}: {source}", path.display())]
+    PersistedSerialization {
+        /// Whether the list was being written or read.
+        operation: SerializationOp,
+        /// The task list involved.
+        list_id: TaskListId,
+        /// The file being written or read.
+        path: PathBuf,
+        /// The underlying bincode error.
+        #[source]
+        source: bincode::Error,
+    },
+
+    /// JSON serialization error (see [`crate::crdt::TaskList::to_json_snapshot`]).
+    #[error("JSON serialization error: {0}")]
+    Json(#[from] serde_json::Error),
+
     /// CRDT merge operation failed.
     #[error("CRDT merge error: {0}")]
     Merge(String),
@@ -37,11 +57,37 @@ pub enum CrdtError {
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
 
+    /// Task-list sync options out of bounds.
+    #[error("invalid sync options: {0}")]
+    InvalidSyncOptions(String),
+
+    /// Task-list persistence policy out of bounds.
+    #[error("invalid persistence policy: {0}")]
+    InvalidPersistencePolicy(String),
+
     /// System clock error (e.g., clock set before Unix epoch).
     #[error("system clock error: {0}")]
     SystemClock(String),
 }
 
+/// Direction of a failed (de)serialization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SerializationOp {
+    /// Encoding a value to bytes.
+    Serialize,
+    /// Decoding bytes to a value.
+    Deserialize,
+}
+
+impl std::fmt::Display for SerializationOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Serialize => "serialize",
+            Self::Deserialize => "deserialize",
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,12 +45,18 @@ pub use checkbox::{CheckboxError, CheckboxState};
 pub use delta::TaskListDelta;
 pub use encrypted::EncryptedTaskListDelta;
 pub use error::{CrdtError, Result};
-pub use persistence::TaskListStorage;
+pub use persistence::{
+    PersistencePolicy, TaskListCheckpoint, TaskListPersistenceStats, TaskListStorage,
+    MAX_SAVE_INTERVAL_SECS, MIN_SAVE_INTERVAL_SECS,
+};
 pub use provenance::{
     canonical_op_bytes, purge_unattested_elements, sign_attestation, verify_attestation,
     OpAttestation, OpKind, CLAIM_DOMAIN, COMPLETE_DOMAIN,
 };
-pub use sync::TaskListSync;
+pub use sync::{
+    task_list_sync_topic, SyncOptions, SyncStats, TaskListSync, MAX_SYNC_INTERVAL_SECS,
+    MIN_SYNC_INTERVAL_SECS, TASK_LIST_SYNC_TOPIC_PREFIX,
+};
 pub use task::{TaskId, TaskMetadata};
-pub use task_item::{forge_unattested_delta_bytes, TaskItem};
-pub use task_list::{TaskList, TaskListId};
+pub use task_item::{forge_unattested_delta_bytes, TaskEvent, TaskEventKind, TaskItem};
+pub use task_list::{Inconsistency, TaskList, TaskListId, JSON_SNAPSHOT_FORMAT};
@@ -3,10 +3,18 @@
 //! Provides local storage for `TaskList` instances with atomic writes,
 //! automatic directory creation, and graceful error handling for corrupted files.
 
+use crate::crdt::error::{CrdtError, SerializationOp};
 use crate::crdt::{TaskList, TaskListId};
+use serde::Serialize;
+use std::collections::HashSet;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
 use tokio::fs;
 
+/// Distinguishes the temporary files of concurrent saves (see
+/// [`TaskListStorage::save_task_list`]).
+static TEMP_FILE_SEQ: AtomicU64 = AtomicU64::new(0);
+
 /// Storage backend for task lists with atomic writes and error recovery.
 ///
 /// Stores task lists as bincode-serialized files in a local directory.
@@ -41,7 +49,9 @@ impl TaskListStorage {
     /// Save a task list to persistent storage with atomic writes.
     ///
     /// Writes to a temporary file first, then atomically renames it to the
-    /// final location to prevent partial writes from crashes.
+    /// final location to prevent partial writes from crashes. Each call gets
+    /// its own temporary file, so concurrent saves of one list never write
+    /// into or rename away each other's; the last rename wins.
     ///
     /// # Arguments
     ///
@@ -63,12 +73,21 @@ impl TaskListStorage {
         fs::create_dir_all(&self.storage_path).await?;
 
         // Serialize task list
-        let serialized =
-            bincode::serialize(task_list).map_err(crate::crdt::error::CrdtError::Serialization)?;
-
-        // Write to temporary file
         let file_path = self.list_file_path(list_id);
-        let temp_path = file_path.with_extension("tmp");
+        let serialized =
+            bincode::serialize(task_list).map_err(|source| CrdtError::PersistedSerialization {
+                operation: SerializationOp::Serialize,
+                list_id: *list_id,
+                path: file_path.clone(),
+                source,
+            })?;
+
+        // Write to a temporary file unique to this save
+        let temp_path = file_path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            TEMP_FILE_SEQ.fetch_add(1, Ordering::Relaxed)
+        ));
 
         fs::write(&temp_path, &serialized).await?;
 
@@ -100,8 +119,14 @@ impl TaskListStorage {
 
         let serialized = fs::read(&file_path).await?;
 
-        let mut list: TaskList = bincode::deserialize(&serialized)
-            .map_err(crate::crdt::error::CrdtError::Serialization)?;
+        let mut list: TaskList = bincode::deserialize(&serialized).map_err(|source| {
+            CrdtError::PersistedSerialization {
+                operation: SerializationOp::Deserialize,
+                list_id: *list_id,
+                path: file_path.clone(),
+                source,
+            }
+        })?;
 
         // Run the fail-closed admission gate on every task so a tampered or
         // corrupted on-disk state cannot bypass provenance verification.
@@ -157,7 +182,51 @@ impl TaskListStorage {
         Ok(list_ids)
     }
 
-    /// Delete a task list from persistent storage.
+    /// Record the gossip topic `list_id` syncs over next to its snapshot.
+    ///
+    /// A list id is a hash of its topic, so without this a stored list
+    /// cannot be traced back to its topic (and the group scope in it)
+    /// until it is reopened.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if directory creation or the write fails.
+    pub async fn save_task_list_topic(
+        &self,
+        list_id: &TaskListId,
+        topic: &str,
+    ) -> crate::crdt::error::Result<()> {
+        fs::create_dir_all(&self.storage_path).await?;
+        let file_path = self.topic_file_path(list_id);
+        let temp_path = file_path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            TEMP_FILE_SEQ.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&temp_path, topic).await?;
+        fs::rename(&temp_path, &file_path).await?;
+        Ok(())
+    }
+
+    /// The topic recorded by [`Self::save_task_list_topic`], or `None` if
+    /// none was recorded (e.g. a snapshot saved by an older version) or the
+    /// record does not hash to `list_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record exists but cannot be read.
+    pub async fn load_task_list_topic(
+        &self,
+        list_id: &TaskListId,
+    ) -> crate::crdt::error::Result<Option<String>> {
+        match fs::read_to_string(self.topic_file_path(list_id)).await {
+            Ok(topic) => Ok((TaskListId::from_topic(&topic) == *list_id).then_some(topic)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Delete a task list from persistent storage, with its recorded topic.
     ///
     /// # Arguments
     ///
@@ -170,14 +239,163 @@ impl TaskListStorage {
         let file_path = self.list_file_path(list_id);
 
         fs::remove_file(file_path).await?;
+        if let Err(e) = fs::remove_file(self.topic_file_path(list_id)).await {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(e.into());
+            }
+        }
 
         Ok(())
     }
 
+    /// Total size in bytes of the stored task lists.
+    ///
+    /// Counts every `.bin` file in the storage directory; zero if the
+    /// directory does not exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if directory reading fails.
+    pub async fn usage_bytes(&self) -> crate::crdt::error::Result<u64> {
+        if !self.storage_path.exists() {
+            return Ok(0);
+        }
+
+        let mut dir_entries = fs::read_dir(&self.storage_path).await?;
+        let mut total = 0u64;
+        while let Some(entry) = dir_entries.next_entry().await? {
+            if entry.path().extension().is_some_and(|ext| ext == "bin") {
+                total = total.saturating_add(entry.metadata().await?.len());
+            }
+        }
+
+        Ok(total)
+    }
+
     /// Get the file path for a task list by its ID.
     fn list_file_path(&self, list_id: &TaskListId) -> PathBuf {
         self.storage_path.join(format!("{}.bin", list_id))
     }
+
+    /// Get the file path of a task list's recorded topic.
+    fn topic_file_path(&self, list_id: &TaskListId) -> PathBuf {
+        self.storage_path.join(format!("{}.topic", list_id))
+    }
+}
+
+/// Smallest accepted [`PersistencePolicy::save_interval_secs`].
+pub const MIN_SAVE_INTERVAL_SECS: u64 = 1;
+
+/// Largest accepted [`PersistencePolicy::save_interval_secs`].
+pub const MAX_SAVE_INTERVAL_SECS: u64 = 3600;
+
+/// How often an agent saves its task lists to [`TaskListStorage`]; see
+/// [`crate::Agent::set_persistence_policy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PersistencePolicy {
+    /// Seconds between checks for unsaved changes in each open list. A
+    /// short interval loses less on a crash; a long one writes less often
+    /// for lists that change constantly.
+    /// Must lie within [`MIN_SAVE_INTERVAL_SECS`]..=[`MAX_SAVE_INTERVAL_SECS`].
+    /// Default: 2
+    pub save_interval_secs: u64,
+}
+
+impl Default for PersistencePolicy {
+    fn default() -> Self {
+        Self {
+            save_interval_secs: 2,
+        }
+    }
+}
+
+impl PersistencePolicy {
+    /// Check the policy is within bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CrdtError::InvalidPersistencePolicy`] if
+    /// `save_interval_secs` is outside
+    /// `MIN_SAVE_INTERVAL_SECS..=MAX_SAVE_INTERVAL_SECS`.
+    pub fn validate(&self) -> crate::crdt::error::Result<()> {
+        if !(MIN_SAVE_INTERVAL_SECS..=MAX_SAVE_INTERVAL_SECS).contains(&self.save_interval_secs) {
+            return Err(CrdtError::InvalidPersistencePolicy(format!(
+                "save_interval_secs must be between {MIN_SAVE_INTERVAL_SECS} and \
+                 {MAX_SAVE_INTERVAL_SECS}, got {}",
+                self.save_interval_secs
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Save activity of an agent's task-list snapshots, as returned by
+/// [`crate::Agent::task_list_persistence_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct TaskListPersistenceStats {
+    /// Unix ms of the last successful save; `None` if none yet.
+    pub last_save_at: Option<u64>,
+    /// Successful saves since the agent started.
+    pub saves: u64,
+    /// Failed saves since the agent started.
+    pub save_failures: u64,
+    /// Lists whose most recent save failed, so the copy on disk is behind
+    /// the live list.
+    pub unsaved_lists: usize,
+}
+
+/// Result of an explicit task-list save, as returned by
+/// [`crate::Agent::checkpoint_task_list`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskListCheckpoint {
+    /// The list that was saved.
+    pub list_id: TaskListId,
+    /// The list's version counter at the time of the save.
+    pub version: u64,
+    /// Tasks in the saved list.
+    pub task_count: usize,
+    /// Unix ms at which the save completed.
+    pub saved_at: u64,
+}
+
+/// Counters behind [`TaskListPersistenceStats`], shared with the savers.
+#[derive(Debug, Default)]
+pub(crate) struct PersistenceCounters {
+    /// Unix ms; 0 until the first save.
+    last_save_at: AtomicU64,
+    saves: AtomicU64,
+    save_failures: AtomicU64,
+    unsaved: std::sync::Mutex<HashSet<TaskListId>>,
+}
+
+impl PersistenceCounters {
+    pub(crate) fn record_save(&self, list_id: &TaskListId) {
+        self.saves.fetch_add(1, Ordering::Relaxed);
+        self.last_save_at
+            .store(crate::dm::now_unix_ms(), Ordering::Relaxed);
+        self.unsaved
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .remove(list_id);
+    }
+
+    pub(crate) fn record_failure(&self, list_id: &TaskListId) {
+        self.save_failures.fetch_add(1, Ordering::Relaxed);
+        self.unsaved
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .insert(*list_id);
+    }
+
+    pub(crate) fn snapshot(&self) -> TaskListPersistenceStats {
+        let last_save_at = self.last_save_at.load(Ordering::Relaxed);
+        TaskListPersistenceStats {
+            last_save_at: (last_save_at > 0).then_some(last_save_at),
+            saves: self.saves.load(Ordering::Relaxed),
+            save_failures: self.save_failures.load(Ordering::Relaxed),
+            unsaved_lists: self.unsaved.lock().unwrap_or_else(|p| p.into_inner()).len(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -224,6 +442,33 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn load_corrupted_file_reports_path_and_operation() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskListStorage::new(dir.path().to_path_buf());
+        let list_id = test_list_id(0x07);
+        let file_path = dir.path().join(format!("{}.bin", list_id));
+        tokio::fs::write(&file_path, b"\xff").await.unwrap();
+
+        let err = storage.load_task_list(&list_id).await.unwrap_err();
+        match &err {
+            CrdtError::PersistedSerialization {
+                operation,
+                list_id: failed_id,
+                path,
+                ..
+            } => {
+                assert_eq!(*operation, SerializationOp::Deserialize);
+                assert_eq!(*failed_id, list_id);
+                assert_eq!(path, &file_path);
+            }
+            other => panic!("expected PersistedSerialization, got {other:?}"),
+        }
+        let display = err.to_string();
+        assert!(display.contains("deserialize"));
+        assert!(display.contains(&file_path.display().to_string()));
+    }
+
     #[tokio::test]
     async fn list_task_lists_empty_dir() {
         let dir = tempfile::tempdir().unwrap();
@@ -261,6 +506,31 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn task_list_topic_roundtrips_and_is_deleted_with_the_list() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskListStorage::new(dir.path().to_path_buf());
+        let topic = "x0x.group.abcd.symphony.plan";
+        let list_id = TaskListId::from_topic(topic);
+        let list = create_test_list(list_id, "scoped");
+
+        storage.save_task_list(&list_id, &list).await.unwrap();
+        assert_eq!(storage.load_task_list_topic(&list_id).await.unwrap(), None);
+        storage.save_task_list_topic(&list_id, topic).await.unwrap();
+        assert_eq!(
+            storage.load_task_list_topic(&list_id).await.unwrap(),
+            Some(topic.to_string())
+        );
+        // A record that does not hash to the id is not trusted.
+        let other = test_list_id(0x09);
+        storage.save_task_list_topic(&other, topic).await.unwrap();
+        assert_eq!(storage.load_task_list_topic(&other).await.unwrap(), None);
+
+        storage.delete_task_list(&list_id).await.unwrap();
+        assert_eq!(storage.load_task_list_topic(&list_id).await.unwrap(), None);
+        assert_eq!(storage.list_task_lists().await.unwrap().len(), 0);
+    }
+
     #[tokio::test]
     async fn save_creates_directory_automatically() {
         let dir = tempfile::tempdir().unwrap();
@@ -274,6 +544,34 @@ mod tests {
         assert_eq!(loaded.name(), "nested-test");
     }
 
+    #[tokio::test]
+    async fn concurrent_saves_of_one_list_all_succeed() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskListStorage::new(dir.path().to_path_buf());
+        let list_id = test_list_id(0x0A);
+
+        let mut saves = tokio::task::JoinSet::new();
+        for i in 0..16 {
+            let storage = storage.clone();
+            let list = create_test_list(list_id, &format!("save-{i}"));
+            saves.spawn(async move { storage.save_task_list(&list_id, &list).await });
+        }
+        while let Some(result) = saves.join_next().await {
+            result.unwrap().unwrap();
+        }
+
+        // One complete snapshot survives and no temporary file is left.
+        let loaded = storage.load_task_list(&list_id).await.unwrap();
+        assert!(loaded.name().starts_with("save-"));
+        let mut entries = tokio::fs::read_dir(dir.path()).await.unwrap();
+        let mut files = 0;
+        while let Some(entry) = entries.next_entry().await.unwrap() {
+            assert!(entry.path().extension().is_some_and(|ext| ext == "bin"));
+            files += 1;
+        }
+        assert_eq!(files, 1);
+    }
+
     #[tokio::test]
     async fn list_skips_tmp_files() {
         let dir = tempfile::tempdir().unwrap();
@@ -363,4 +661,62 @@ mod tests {
         let lists = storage.list_task_lists().await.unwrap();
         assert_eq!(lists.len(), 2);
     }
+
+    #[tokio::test]
+    async fn usage_bytes_sums_stored_lists() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskListStorage::new(dir.path().join("lists"));
+        assert_eq!(storage.usage_bytes().await.unwrap(), 0);
+
+        let list_id = test_list_id(0x0C);
+        storage
+            .save_task_list(&list_id, &create_test_list(list_id, "sized"))
+            .await
+            .unwrap();
+        tokio::fs::write(dir.path().join("lists").join("stale.tmp"), b"partial")
+            .await
+            .unwrap();
+
+        let expected = std::fs::metadata(dir.path().join("lists").join(format!("{list_id}.bin")))
+            .unwrap()
+            .len();
+        assert_eq!(storage.usage_bytes().await.unwrap(), expected);
+    }
+
+    #[test]
+    fn persistence_policy_bounds() {
+        assert!(PersistencePolicy::default().validate().is_ok());
+        for secs in [MIN_SAVE_INTERVAL_SECS, MAX_SAVE_INTERVAL_SECS] {
+            let policy = PersistencePolicy {
+                save_interval_secs: secs,
+            };
+            assert!(policy.validate().is_ok());
+        }
+        for secs in [0, MAX_SAVE_INTERVAL_SECS + 1] {
+            let policy = PersistencePolicy {
+                save_interval_secs: secs,
+            };
+            assert!(matches!(
+                policy.validate(),
+                Err(CrdtError::InvalidPersistencePolicy(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn counters_track_unsaved_lists_until_a_save_succeeds() {
+        let counters = PersistenceCounters::default();
+        assert_eq!(counters.snapshot(), TaskListPersistenceStats::default());
+
+        let list_id = test_list_id(0x0D);
+        counters.record_failure(&list_id);
+        let stats = counters.snapshot();
+        assert_eq!((stats.save_failures, stats.unsaved_lists), (1, 1));
+        assert_eq!(stats.last_save_at, None);
+
+        counters.record_save(&list_id);
+        let stats = counters.snapshot();
+        assert_eq!((stats.saves, stats.unsaved_lists), (1, 0));
+        assert!(stats.last_save_at.is_some());
+    }
 }
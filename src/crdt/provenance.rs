@@ -1,4 +1,4 @@
-//! Authenticated operation provenance for task claims/completions.
+//! Authenticated operation provenance for task claims/completions/reopens.
 //!
 //! Without this module, the inner `TaskItem` claimant `AgentId` carried in a
 //! [`crate::crdt::TaskListDelta`] is self-asserted data: any mesh peer that can
@@ -7,7 +7,7 @@
 //! impersonation. The signed outer transport sender is discarded at the merge
 //! boundary and may be a relay (not the author), so it cannot be the trust root.
 //!
-//! This module supplies the trust root: every `Claimed`/`Done` element that
+//! This module supplies the trust root: every `Claimed`/`Done`/`Reopened` element that
 //! enters a `TaskItem`'s checkbox OR-Set must carry an [`OpAttestation`] signed
 //! by the ML-DSA-65 secret key whose public key hashes to the element's
 //! `agent_id` (`AgentId::from_public_key`). This is the exact "derived ==
@@ -60,6 +60,10 @@ pub const CLAIM_DOMAIN: &[u8] = b"x0x.task.claim.v2";
 /// Domain separator for completion attestations. See [`CLAIM_DOMAIN`].
 pub const COMPLETE_DOMAIN: &[u8] = b"x0x.task.complete.v2";
 
+/// Domain separator for reopen attestations. Same v2 layout as
+/// [`CLAIM_DOMAIN`].
+pub const REOPEN_DOMAIN: &[u8] = b"x0x.task.reopen.v2";
+
 /// The kind of operation an attestation covers. Determines the domain separator
 /// in [`canonical_op_bytes`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -68,6 +72,8 @@ pub enum OpKind {
     Claim,
     /// A task completion (`Claimed` → `Done`).
     Complete,
+    /// Reopening a completed task (`Done` → `Reopened`).
+    Reopen,
 }
 
 impl OpKind {
@@ -77,6 +83,7 @@ impl OpKind {
         match self {
             OpKind::Claim => CLAIM_DOMAIN,
             OpKind::Complete => COMPLETE_DOMAIN,
+            OpKind::Reopen => REOPEN_DOMAIN,
         }
     }
 }
@@ -210,7 +217,7 @@ pub fn verify_attestation(
     verify_with_ml_dsa(&pubkey, &msg, &sig).is_ok()
 }
 
-/// If `state` is a Claimed/Done element, return its `(kind, agent_id, ts)` so
+/// If `state` is a Claimed/Done/Reopened element, return its `(kind, agent_id, ts)` so
 /// the gate can look up and verify its attestation. `Empty` is never added to
 /// the OR-Set, so it maps to `None`.
 #[must_use]
@@ -224,6 +231,10 @@ fn provenance_fields(state: &CheckboxState) -> Option<(OpKind, AgentId, u64)> {
             agent_id,
             timestamp,
         } => Some((OpKind::Complete, *agent_id, *timestamp)),
+        CheckboxState::Reopened {
+            agent_id,
+            timestamp,
+        } => Some((OpKind::Reopen, *agent_id, *timestamp)),
         CheckboxState::Empty => None,
     }
 }
@@ -346,6 +357,9 @@ mod tests {
             claim, complete,
             "claim and complete domains must not collide"
         );
+        let reopen = canonical_op_bytes(OpKind::Reopen, &scope(), &tid, &aid, 1000);
+        assert_ne!(reopen, complete, "reopen must not replay as a completion");
+        assert_ne!(reopen, claim, "reopen must not replay as a claim");
     }
 
     #[test]
@@ -13,15 +13,46 @@
 //!
 //! This provides eventual consistency across all peers sharing the same topic.
 
-use crate::crdt::{Result, TaskList, TaskListDelta};
+use crate::crdt::{CrdtError, Result, TaskList, TaskListDelta};
+use crate::gossip::pubsub::LOCAL_TOPIC_PREFIX;
 use crate::gossip::wire::{decode_delta, encode_delta};
 use crate::gossip::PubSubManager;
 use saorsa_gossip_types::PeerId;
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-/// Suffix appended to a task-list topic to form its state-sync side channel.
+/// Namespace for task-list sync traffic.
+///
+/// A task list on topic `T` replicates over `x0x/tasklist/v1/T` (plus its
+/// state-sync side channel), never over `T` itself. An application using
+/// `T` for ordinary pub/sub therefore never receives CRDT deltas as
+/// messages, and cannot feed the sync garbage: `Agent::publish`,
+/// `POST /publish` and WebSocket publishes refuse this prefix (see
+/// [`crate::gossip::check_app_topic`]).
+///
+/// This is a wire break: peers built before this namespace existed sync on
+/// `T` directly and do not replicate with newer peers, so every replica of
+/// a list must be upgraded together. Sync is deliberately not mirrored onto
+/// `T` during a transition, as that would hand app subscribers of `T` the
+/// raw deltas again.
+pub const TASK_LIST_SYNC_TOPIC_PREFIX: &str = "x0x/tasklist/v1/";
+
+/// The gossip topic carrying sync traffic for the task list on `topic`.
+///
+/// `local:` topics keep their prefix, so a same-daemon task list stays off
+/// the mesh.
+#[must_use]
+pub fn task_list_sync_topic(topic: &str) -> String {
+    match topic.strip_prefix(LOCAL_TOPIC_PREFIX) {
+        Some(rest) => format!("{LOCAL_TOPIC_PREFIX}{TASK_LIST_SYNC_TOPIC_PREFIX}{rest}"),
+        None => format!("{TASK_LIST_SYNC_TOPIC_PREFIX}{topic}"),
+    }
+}
+
+/// Suffix appended to a task-list sync topic to form its state-sync side
+/// channel.
 ///
 /// State requests travel on a separate topic so the main topic keeps its
 /// existing `(PeerId, TaskListDelta)` wire format — peers that predate this
@@ -33,7 +64,8 @@ const STATE_SYNC_TOPIC_SUFFIX: &str = "/state-sync";
 /// flooding.
 const STATE_REQUEST_RETRY_SECS: [u64; 4] = [1, 5, 15, 30];
 
-/// First persistent-tail delay after the front-loaded schedule exhausts.
+/// First persistent-tail delay after the front-loaded schedule exhausts,
+/// unless [`SyncOptions::sync_interval_secs`] overrides it.
 const STATE_REQUEST_TAIL_START_SECS: u64 = 30;
 
 /// Ceiling for the persistent tail's exponential backoff. While a list is
@@ -42,7 +74,9 @@ const STATE_REQUEST_TAIL_START_SECS: u64 = 30;
 const STATE_REQUEST_TAIL_CAP_SECS: u64 = 300;
 
 /// The complete state-request delay schedule: the front-loaded burst, then
-/// an infinite exponential tail (30s doubling to a 300s ceiling).
+/// an infinite exponential tail (`tail_start` seconds, 30 by default,
+/// doubling to a 300s ceiling, or holding at `tail_start` if that is
+/// longer).
 ///
 /// Infinite BY DESIGN (issue #238): holders answer state requests only
 /// reactively and never volunteer state to late subscribers, so a bounded
@@ -51,19 +85,76 @@ const STATE_REQUEST_TAIL_CAP_SECS: u64 = 300;
 /// cap expired. Convergence — a `StateServed` marker plus local state — is
 /// the only legitimate stop condition, and the requester loop owns that
 /// check.
-fn state_request_delays() -> impl Iterator<Item = u64> {
-    let tail = std::iter::successors(Some(STATE_REQUEST_TAIL_START_SECS), |d| {
-        Some(d.saturating_mul(2).min(STATE_REQUEST_TAIL_CAP_SECS))
+fn state_request_delays(tail_start: u64) -> impl Iterator<Item = u64> {
+    let cap = STATE_REQUEST_TAIL_CAP_SECS.max(tail_start);
+    let tail = std::iter::successors(Some(tail_start), move |d| {
+        Some(d.saturating_mul(2).min(cap))
     });
     STATE_REQUEST_RETRY_SECS.into_iter().chain(tail)
 }
 
+/// Smallest accepted [`SyncOptions::sync_interval_secs`].
+pub const MIN_SYNC_INTERVAL_SECS: u64 = 1;
+
+/// Largest accepted [`SyncOptions::sync_interval_secs`].
+pub const MAX_SYNC_INTERVAL_SECS: u64 = 3600;
+
+/// Per-list synchronization tuning for [`TaskListSync::with_options`].
+///
+/// Live deltas are pushed as soon as a mutation commits whatever these say;
+/// the options only pace the recovery traffic of a replica that has not yet
+/// converged with a holder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncOptions {
+    /// Seconds between state requests once the front-loaded bootstrap
+    /// burst is spent; later requests back off from here to at most 300s
+    /// (or this value, if longer). A tight interval recovers sooner when a
+    /// holder comes back online; a loose one saves the per-request
+    /// side-topic message and the full-state response it triggers.
+    /// Must lie within [`MIN_SYNC_INTERVAL_SECS`]..=[`MAX_SYNC_INTERVAL_SECS`].
+    /// Default: 30
+    pub sync_interval_secs: u64,
+}
+
+impl Default for SyncOptions {
+    fn default() -> Self {
+        Self {
+            sync_interval_secs: STATE_REQUEST_TAIL_START_SECS,
+        }
+    }
+}
+
+impl SyncOptions {
+    /// Check the options are within bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CrdtError::InvalidSyncOptions`] if `sync_interval_secs` is
+    /// outside `MIN_SYNC_INTERVAL_SECS..=MAX_SYNC_INTERVAL_SECS`.
+    pub fn validate(&self) -> Result<()> {
+        if !(MIN_SYNC_INTERVAL_SECS..=MAX_SYNC_INTERVAL_SECS).contains(&self.sync_interval_secs) {
+            return Err(CrdtError::InvalidSyncOptions(format!(
+                "sync_interval_secs must be between {MIN_SYNC_INTERVAL_SECS} and \
+                 {MAX_SYNC_INTERVAL_SECS}, got {}",
+                self.sync_interval_secs
+            )));
+        }
+        Ok(())
+    }
+}
+
 /// Minimum spacing between full-state responses from ONE holder for ONE
 /// list — the same response-storm damping as `KvStoreSync` (issue #238
 /// review): the response is a broadcast on the main topic, so one response
 /// per window serves every concurrently-bootstrapping replica.
 const STATE_RESPONSE_COOLDOWN_SECS: u64 = 15;
 
+/// Seconds between anti-entropy rounds. Each round a non-empty replica
+/// publishes its served digest on the side topic; a peer whose own digest
+/// differs asks for full state. Slow by design: live deltas carry the
+/// normal traffic, this only repairs deltas a replica missed while offline.
+const ANTI_ENTROPY_INTERVAL_SECS: u64 = 180;
+
 /// Sleep duration for a scheduled delay with ±20% jitter, so a fleet of
 /// replicas restarted together does not phase-lock its request (and thus
 /// full-state response) schedule. Mirrors the reconnect-backoff jitter in
@@ -128,6 +219,58 @@ enum TaskListSyncMessage {
         /// verified full-replace adopt path (and useful in logs).
         entry_count: u32,
     },
+    /// Anti-entropy: a non-empty replica's current served digest, published
+    /// every [`ANTI_ENTROPY_INTERVAL_SECS`] and when the sync starts. A
+    /// receiver whose own digest differs sends a `StateRequest` (unless its
+    /// bootstrap requester is still running and asking anyway), so it
+    /// merges the sender's full state; the sender learns the receiver's
+    /// side from the receiver's own next digest. The first digest from a
+    /// sender not heard from before is answered at once with the
+    /// receiver's digest, so a peer that (re)joins reconciles in both
+    /// directions without waiting for a timer.
+    ///
+    /// Unlike `StateServedV2` this is NOT convergence evidence: nothing was
+    /// broadcast, so it never feeds the bootstrap requester.
+    ///
+    /// Wire compatibility: additive variant, same precedent as the served
+    /// markers — older peers fail to deserialize it and skip the message.
+    Digest {
+        /// The publishing replica (receivers skip their own echo).
+        sender: PeerId,
+        /// Canonical BLAKE3 digest over the sender's task set.
+        digest: [u8; 32],
+        /// Number of tasks in the sender's set (for logs).
+        entry_count: u32,
+    },
+}
+
+/// Serialize and publish a side-topic message, logging failures.
+async fn publish_sync_message(pubsub: &PubSubManager, topic: &str, msg: &TaskListSyncMessage) {
+    match bincode::serialize(msg) {
+        Ok(serialized) => {
+            if let Err(e) = pubsub
+                .publish(topic.to_string(), bytes::Bytes::from(serialized))
+                .await
+            {
+                tracing::debug!("TaskList sync message publish failed: {e}");
+            }
+        }
+        Err(e) => tracing::warn!("TaskList sync message serialize failed: {e}"),
+    }
+}
+
+/// This replica's anti-entropy digest, or `None` while it has no tasks
+/// (an empty replica recovers through its bootstrap requester instead).
+async fn local_digest_message(
+    list: &RwLock<TaskList>,
+    local_peer_id: PeerId,
+) -> Option<TaskListSyncMessage> {
+    let list = list.read().await;
+    (list.task_count() > 0).then(|| TaskListSyncMessage::Digest {
+        sender: local_peer_id,
+        digest: list.served_digest(),
+        entry_count: list.task_count() as u32,
+    })
 }
 
 /// One responder's latest v2 digest declaration.
@@ -172,6 +315,85 @@ fn tasklist_converged(ev: &TaskServedEvidence, task_count: usize, local_digest:
     ev.saw_v1 && task_count > 0
 }
 
+/// Delta traffic for one task list, as returned by [`TaskListSync::stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct SyncStats {
+    /// Deltas published, including full-state responses to joiners.
+    pub deltas_sent: u64,
+    /// Remote deltas merged into the local list.
+    pub deltas_received: u64,
+    /// Encoded size of the published deltas.
+    pub bytes_sent: u64,
+    /// Encoded size of the merged deltas that arrived over gossip.
+    pub bytes_received: u64,
+    /// Unix ms of the last delta published or merged; `None` if none yet.
+    pub last_sync_at: Option<u64>,
+    /// Merged deltas that changed a task this replica already held, i.e.
+    /// concurrent edits reconciled by the CRDT merge.
+    pub merge_conflicts_resolved: u64,
+}
+
+/// Counters behind [`SyncStats`], shared with the background loops.
+#[derive(Debug, Default)]
+struct SyncCounters {
+    deltas_sent: AtomicU64,
+    deltas_received: AtomicU64,
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
+    /// Unix ms; 0 until the first delta.
+    last_sync_at: AtomicU64,
+    merge_conflicts_resolved: AtomicU64,
+}
+
+impl SyncCounters {
+    fn record_sent(&self, bytes: usize) {
+        self.deltas_sent.fetch_add(1, Ordering::Relaxed);
+        self.bytes_sent.fetch_add(bytes as u64, Ordering::Relaxed);
+        self.touch();
+    }
+
+    fn record_received(&self, bytes: usize, reconciled: bool) {
+        self.deltas_received.fetch_add(1, Ordering::Relaxed);
+        self.bytes_received
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+        if reconciled {
+            self.merge_conflicts_resolved
+                .fetch_add(1, Ordering::Relaxed);
+        }
+        self.touch();
+    }
+
+    fn touch(&self) {
+        self.last_sync_at
+            .store(crate::dm::now_unix_ms(), Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> SyncStats {
+        let last_sync_at = self.last_sync_at.load(Ordering::Relaxed);
+        SyncStats {
+            deltas_sent: self.deltas_sent.load(Ordering::Relaxed),
+            deltas_received: self.deltas_received.load(Ordering::Relaxed),
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            last_sync_at: (last_sync_at > 0).then_some(last_sync_at),
+            merge_conflicts_resolved: self.merge_conflicts_resolved.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Merge a remote delta, returning whether it changed a task the list
+/// already held (see [`SyncStats::merge_conflicts_resolved`]).
+fn merge_remote(list: &mut TaskList, delta: &TaskListDelta, peer_id: PeerId) -> Result<bool> {
+    let touches_held = delta
+        .added_tasks
+        .keys()
+        .chain(delta.task_updates.keys())
+        .any(|task_id| list.get_task(task_id).is_some());
+    let before = list.current_version();
+    list.merge_delta(delta, peer_id)?;
+    Ok(touches_held && list.current_version() != before)
+}
+
 /// Disarms the bootstrap-active flag on ANY requester exit path (converged,
 /// silenced, cancelled, torn down) so the listener's digest-verified
 /// full-replace adopt can never fire outside the bootstrap window.
@@ -197,6 +419,10 @@ pub struct TaskListSync {
     /// Topic name for this task list.
     topic: String,
 
+    /// Gossip topic the sync traffic travels on; see
+    /// [`task_list_sync_topic`].
+    sync_topic: String,
+
     /// This node's gossip peer id — identifies our deltas and state
     /// requests on the wire.
     local_peer_id: PeerId,
@@ -214,6 +440,16 @@ pub struct TaskListSync {
     /// string (round-4 review: flag-only teardown left ghost listeners and a
     /// live responder until daemon shutdown).
     cancel: tokio_util::sync::CancellationToken,
+
+    /// Bumped after every remote delta merged into the list; see
+    /// [`subscribe_changes`](Self::subscribe_changes).
+    changes: Arc<tokio::sync::watch::Sender<u64>>,
+
+    /// Delta traffic counters; see [`stats`](Self::stats).
+    counters: Arc<SyncCounters>,
+
+    /// Recovery pacing; see [`SyncOptions`].
+    options: SyncOptions,
 }
 
 /// Structural teardown (parallel-review finding): the background loops hold
@@ -263,22 +499,77 @@ impl TaskListSync {
         topic: String,
         local_peer_id: PeerId,
     ) -> Result<Self> {
+        Self::with_options(
+            task_list,
+            pubsub,
+            topic,
+            local_peer_id,
+            SyncOptions::default(),
+        )
+    }
+
+    /// Create a synchronization manager with explicit [`SyncOptions`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CrdtError::InvalidSyncOptions`] if `options` fail
+    /// [`SyncOptions::validate`].
+    pub fn with_options(
+        task_list: TaskList,
+        pubsub: Arc<PubSubManager>,
+        topic: String,
+        local_peer_id: PeerId,
+        options: SyncOptions,
+    ) -> Result<Self> {
+        options.validate()?;
         // Wrap task list for concurrent access
         let task_list = Arc::new(RwLock::new(task_list));
 
         Ok(Self {
             task_list,
             pubsub,
+            sync_topic: task_list_sync_topic(&topic),
             topic,
             local_peer_id,
             stopped: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             cancel: tokio_util::sync::CancellationToken::new(),
+            changes: Arc::new(tokio::sync::watch::channel(0).0),
+            counters: Arc::new(SyncCounters::default()),
+            options,
         })
     }
 
+    /// The options this sync was created with.
+    #[must_use]
+    pub fn options(&self) -> SyncOptions {
+        self.options
+    }
+
+    /// Delta traffic counters for this list since the sync was created.
+    #[must_use]
+    pub fn stats(&self) -> SyncStats {
+        self.counters.snapshot()
+    }
+
+    /// Watch for remote changes to the list.
+    ///
+    /// The value is a counter bumped after every remote delta merged into
+    /// the local replica — live gossip, state-sync serves, and
+    /// [`apply_remote_delta`](Self::apply_remote_delta) alike. A bump does
+    /// not guarantee the merge changed anything (duplicate deltas are
+    /// idempotent), so watchers should compare snapshots. Local mutations
+    /// made through [`write`](Self::write) do not fire it.
+    ///
+    /// The sender drops with the sync, so `changed()` returns an error once
+    /// the list is torn down.
+    #[must_use]
+    pub fn subscribe_changes(&self) -> tokio::sync::watch::Receiver<u64> {
+        self.changes.subscribe()
+    }
+
     /// The state-sync side topic for this task list.
     fn state_sync_topic(&self) -> String {
-        format!("{}{}", self.topic, STATE_SYNC_TOPIC_SUFFIX)
+        format!("{}{}", self.sync_topic, STATE_SYNC_TOPIC_SUFFIX)
     }
 
     /// Start background synchronization.
@@ -321,7 +612,7 @@ impl TaskListSync {
             + Sync,
     {
         // Subscribe to topic — received messages will contain serialized deltas.
-        let mut sub = self.pubsub.subscribe(self.topic.clone()).await;
+        let mut sub = self.pubsub.subscribe(self.sync_topic.clone()).await;
         let task_list = Arc::clone(&self.task_list);
         let listener_cancel = self.cancel.clone();
         // StateServed evidence: written by the responder loop (which owns
@@ -336,6 +627,8 @@ impl TaskListSync {
         let bootstrap_active = Arc::new(std::sync::atomic::AtomicBool::new(false));
         let listener_served = Arc::clone(&served_evidence);
         let listener_bootstrap_active = Arc::clone(&bootstrap_active);
+        let listener_changes = Arc::clone(&self.changes);
+        let listener_counters = Arc::clone(&self.counters);
 
         spawn(Box::pin(async move {
             loop {
@@ -359,11 +652,15 @@ impl TaskListSync {
                 match decode_delta::<TaskListDelta>(&msg.payload) {
                     Ok((peer_id, delta)) => {
                         let mut list = task_list.write().await;
-                        if let Err(e) = list.merge_delta(&delta, peer_id) {
-                            tracing::warn!("Failed to merge remote delta: {}", e);
-                        } else if listener_bootstrap_active
-                            .load(std::sync::atomic::Ordering::Relaxed)
-                        {
+                        let reconciled = match merge_remote(&mut list, &delta, peer_id) {
+                            Ok(reconciled) => reconciled,
+                            Err(e) => {
+                                tracing::warn!("Failed to merge remote delta: {}", e);
+                                continue;
+                            }
+                        };
+                        listener_counters.record_received(msg.payload.len(), reconciled);
+                        if listener_bootstrap_active.load(std::sync::atomic::Ordering::Relaxed) {
                             // Digest-verified full-replace adopt (issue
                             // #240, deletion cold-sync): while
                             // bootstrapping, when the sender's latest v2
@@ -397,6 +694,8 @@ impl TaskListSync {
                                 }
                             }
                         }
+                        drop(list);
+                        listener_changes.send_modify(|n| *n = n.wrapping_add(1));
                     }
                     Err(e) => {
                         tracing::warn!("Failed to deserialize delta from topic: {}", e);
@@ -413,10 +712,13 @@ impl TaskListSync {
         let mut sync_sub = self.pubsub.subscribe(self.state_sync_topic()).await;
         let responder_list = Arc::clone(&self.task_list);
         let responder_pubsub = Arc::clone(&self.pubsub);
-        let responder_topic = self.topic.clone();
+        let responder_topic = self.sync_topic.clone();
         let sync_topic = self.state_sync_topic();
         let responder_served = Arc::clone(&served_evidence);
         let responder_cancel = self.cancel.clone();
+        let responder_counters = Arc::clone(&self.counters);
+        let responder_stopped = Arc::clone(&self.stopped);
+        let responder_bootstrap_active = Arc::clone(&bootstrap_active);
         let local_peer_id = self.local_peer_id;
         spawn(Box::pin(async move {
             // Response-storm damping (issue #238 review): one full-state
@@ -427,6 +729,13 @@ impl TaskListSync {
             // review); the requester is served by its next scheduled
             // attempt.
             let mut last_full_response: Option<tokio::time::Instant> = None;
+            // Anti-entropy: senders whose digest we have seen (bounded by
+            // mesh size), and when we last asked for state on a mismatch —
+            // damped like full responses, so a burst of mismatching digests
+            // costs one request.
+            let mut digest_senders: std::collections::HashSet<PeerId> =
+                std::collections::HashSet::new();
+            let mut last_reconcile_request: Option<tokio::time::Instant> = None;
             loop {
                 let msg = tokio::select! {
                     // cancel_sync tears down every loop (round-4 review).
@@ -499,6 +808,7 @@ impl TaskListSync {
                             let Ok(serialized) = encode_delta(local_peer_id, &full) else {
                                 continue;
                             };
+                            let len = serialized.len();
                             if let Err(e) = responder_pubsub
                                 .publish(responder_topic.clone(), bytes::Bytes::from(serialized))
                                 .await
@@ -506,6 +816,7 @@ impl TaskListSync {
                                 tracing::warn!("TaskList state-response publish failed: {e}");
                                 continue;
                             }
+                            responder_counters.record_sent(len);
                             last_full_response = Some(tokio::time::Instant::now());
                             markers.push(TaskListSyncMessage::StateServed {
                                 responder: local_peer_id,
@@ -578,6 +889,75 @@ impl TaskListSync {
                                 },
                             );
                     }
+                    TaskListSyncMessage::Digest {
+                        sender,
+                        digest,
+                        entry_count,
+                    } => {
+                        if sender == local_peer_id
+                            || responder_stopped.load(std::sync::atomic::Ordering::Relaxed)
+                        {
+                            continue; // own echo, or silenced
+                        }
+                        if digest_senders.insert(sender) {
+                            // A peer we have not heard from: answer with our
+                            // digest now so it can fetch what it lacks.
+                            if let Some(reply) =
+                                local_digest_message(&responder_list, local_peer_id).await
+                            {
+                                publish_sync_message(&responder_pubsub, &sync_topic, &reply).await;
+                            }
+                        }
+                        // While bootstrapping, the requester is already
+                        // asking on its own schedule.
+                        if responder_bootstrap_active.load(std::sync::atomic::Ordering::Relaxed)
+                            || responder_list.read().await.served_digest() == digest
+                        {
+                            continue;
+                        }
+                        let damped = last_reconcile_request.is_some_and(|t| {
+                            t.elapsed()
+                                < std::time::Duration::from_secs(STATE_RESPONSE_COOLDOWN_SECS)
+                        });
+                        if damped {
+                            continue;
+                        }
+                        tracing::debug!(
+                            "TaskList digest from {sender:?} ({entry_count} task(s)) differs; \
+                             requesting full state"
+                        );
+                        last_reconcile_request = Some(tokio::time::Instant::now());
+                        let request = TaskListSyncMessage::StateRequest {
+                            requester: local_peer_id,
+                        };
+                        publish_sync_message(&responder_pubsub, &sync_topic, &request).await;
+                    }
+                }
+            }
+        }));
+
+        // Anti-entropy: deltas reach only the peers online when they are
+        // published, so a replica offline at the time misses them for good
+        // unless something re-offers the state. Publish our digest at start
+        // (the join announcement) and then on a slow timer; the responder
+        // turns a mismatching digest into a StateRequest. Silenced syncs
+        // skip their rounds, like the bootstrap requester.
+        let anti_entropy_list = Arc::clone(&self.task_list);
+        let anti_entropy_pubsub = Arc::clone(&self.pubsub);
+        let anti_entropy_topic = self.state_sync_topic();
+        let anti_entropy_stopped = Arc::clone(&self.stopped);
+        let anti_entropy_cancel = self.cancel.clone();
+        spawn(Box::pin(async move {
+            loop {
+                if !anti_entropy_stopped.load(std::sync::atomic::Ordering::Relaxed) {
+                    if let Some(msg) = local_digest_message(&anti_entropy_list, local_peer_id).await
+                    {
+                        publish_sync_message(&anti_entropy_pubsub, &anti_entropy_topic, &msg).await;
+                    }
+                }
+                tokio::select! {
+                    () = anti_entropy_cancel.cancelled() => return,
+                    () = tokio::time::sleep(jittered_secs(ANTI_ENTROPY_INTERVAL_SECS)) => {}
                 }
             }
         }));
@@ -609,13 +989,14 @@ impl TaskListSync {
             let requester_cancel = self.cancel.clone();
             let requester_served = Arc::clone(&served_evidence);
             let requester_bootstrap_active = Arc::clone(&bootstrap_active);
+            let tail_start = self.options.sync_interval_secs;
             bootstrap_active.store(true, std::sync::atomic::Ordering::Relaxed);
             spawn(Box::pin(async move {
                 // Disarms the adopt window on ANY exit (converged, silenced,
                 // cancelled, torn down) — the listener's verified
                 // full-replace adopt must never fire outside bootstrap.
                 let _guard = BootstrapGuard(requester_bootstrap_active);
-                for (attempt, delay_secs) in state_request_delays().enumerate() {
+                for (attempt, delay_secs) in state_request_delays(tail_start).enumerate() {
                     tokio::select! {
                         // cancel_sync tears down every loop promptly, even
                         // mid-sleep (round-4 review).
@@ -688,7 +1069,7 @@ impl TaskListSync {
         // infinite while the list is empty (issue #238), and unsubscribing
         // does not end that loop (it holds no subscription).
         self.cancel_sync();
-        self.pubsub.unsubscribe(&self.topic).await;
+        self.pubsub.unsubscribe(&self.sync_topic).await;
         self.pubsub.unsubscribe(&self.state_sync_topic()).await;
         Ok(())
     }
@@ -716,6 +1097,12 @@ impl TaskListSync {
         self.cancel.cancel();
     }
 
+    /// Whether [`cancel_sync`](Self::cancel_sync) has torn down this sync.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.is_cancelled()
+    }
+
     /// Apply a delta received from a remote peer.
     ///
     /// This is called when a delta is received via the gossip topic.
@@ -734,8 +1121,10 @@ impl TaskListSync {
     ///
     /// Returns an error if the merge fails.
     pub async fn apply_remote_delta(&self, peer_id: PeerId, delta: TaskListDelta) -> Result<()> {
-        let mut task_list = self.task_list.write().await;
-        task_list.merge_delta(&delta, peer_id)?;
+        let reconciled = merge_remote(&mut *self.task_list.write().await, &delta, peer_id)?;
+        // Off-wire: there are no received bytes to count.
+        self.counters.record_received(0, reconciled);
+        self.changes.send_modify(|n| *n = n.wrapping_add(1));
         Ok(())
     }
 
@@ -760,10 +1149,12 @@ impl TaskListSync {
             crate::crdt::CrdtError::Gossip(format!("failed to serialize delta: {e}"))
         })?;
 
+        let len = serialized.len();
         self.pubsub
-            .publish(self.topic.clone(), bytes::Bytes::from(serialized))
+            .publish(self.sync_topic.clone(), bytes::Bytes::from(serialized))
             .await
             .map_err(|e| crate::crdt::CrdtError::Gossip(format!("failed to publish delta: {e}")))?;
+        self.counters.record_sent(len);
 
         Ok(())
     }
@@ -796,6 +1187,13 @@ impl TaskListSync {
     pub fn topic(&self) -> &str {
         &self.topic
     }
+
+    /// The gossip topic this list's deltas travel on; see
+    /// [`task_list_sync_topic`].
+    #[must_use]
+    pub fn sync_topic(&self) -> &str {
+        &self.sync_topic
+    }
 }
 
 #[cfg(test)]
@@ -970,11 +1368,17 @@ mod tests {
     async fn state_sync_topic_appends_side_channel_suffix() {
         let sync = make_sync("tasks/B").await;
         // The private helper forms the side channel by appending the suffix.
-        assert_eq!(sync.state_sync_topic(), "tasks/B/state-sync");
+        assert_eq!(
+            sync.state_sync_topic(),
+            "x0x/tasklist/v1/tasks/B/state-sync"
+        );
 
         // Suffix is appended exactly once, regardless of slashes in topic.
         let sync2 = make_sync("tasks/B/nested").await;
-        assert_eq!(sync2.state_sync_topic(), "tasks/B/nested/state-sync");
+        assert_eq!(
+            sync2.state_sync_topic(),
+            "x0x/tasklist/v1/tasks/B/nested/state-sync"
+        );
     }
 
     // ------------------------------------------------------------------
@@ -1008,6 +1412,78 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn apply_remote_delta_notifies_change_watchers() {
+        let sync = make_sync("tasks/C2").await;
+        let mut changes = sync.subscribe_changes();
+        assert!(!changes.has_changed().expect("sync alive"));
+
+        let remote = peer(2);
+        let task = make_task(8, remote);
+        let mut delta = TaskListDelta::new(1);
+        delta.added_tasks.insert(*task.id(), (task, (remote, 1)));
+        sync.apply_remote_delta(remote, delta)
+            .await
+            .expect("apply_remote_delta");
+
+        assert!(changes.has_changed().expect("sync alive"));
+        assert_eq!(*changes.borrow_and_update(), 1);
+
+        // Local writes are not remote changes.
+        sync.write()
+            .await
+            .update_name("Renamed".to_string(), peer(1));
+        assert!(!changes.has_changed().expect("sync alive"));
+
+        // Tearing the sync down ends the watch.
+        drop(sync);
+        assert!(changes.changed().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn stats_count_deltas_and_reconciled_merges() {
+        let sync = make_sync("tasks/C3").await;
+        assert_eq!(sync.stats(), SyncStats::default());
+
+        let remote = peer(2);
+        let task = make_task(9, remote);
+        let task_id = *task.id();
+        let mut added = TaskListDelta::new(1);
+        added
+            .added_tasks
+            .insert(task_id, (task.clone(), (remote, 1)));
+        sync.apply_remote_delta(remote, added.clone())
+            .await
+            .expect("apply add");
+        // A duplicate delivery touches a held task but changes nothing.
+        sync.apply_remote_delta(remote, added)
+            .await
+            .expect("apply duplicate");
+
+        let stats = sync.stats();
+        assert_eq!(stats.deltas_received, 2);
+        assert_eq!(stats.merge_conflicts_resolved, 0);
+        assert!(stats.last_sync_at.is_some());
+
+        // A concurrent edit to the held task is reconciled by the merge.
+        let mut edited = task;
+        edited.update_title("Renamed remotely".to_string(), remote);
+        let mut update = TaskListDelta::new(2);
+        update.task_updates.insert(task_id, edited);
+        sync.apply_remote_delta(remote, update)
+            .await
+            .expect("apply update");
+        assert_eq!(sync.stats().merge_conflicts_resolved, 1);
+
+        sync.publish_delta(peer(1), TaskListDelta::new(3))
+            .await
+            .expect("publish");
+        let stats = sync.stats();
+        assert_eq!(stats.deltas_sent, 1);
+        assert!(stats.bytes_sent > 0);
+        assert_eq!(stats.bytes_received, 0, "off-wire merges carry no bytes");
+    }
+
     // ------------------------------------------------------------------
     // publish_delta(): wire round-trip observed by a subscriber
     // ------------------------------------------------------------------
@@ -1016,9 +1492,9 @@ mod tests {
     async fn publish_delta_delivers_encoded_pair_to_subscriber() {
         let (sync, pubsub) = make_sync_with_pubsub("tasks/D").await;
 
-        // Subscribe to the main topic BEFORE publishing so we observe the
+        // Subscribe to the sync topic BEFORE publishing so we observe the
         // exact bytes TaskListSync places on the wire.
-        let mut sub = pubsub.subscribe("tasks/D".to_string()).await;
+        let mut sub = pubsub.subscribe(sync.sync_topic().to_string()).await;
 
         let sender = peer(7);
         let task = make_task(3, sender);
@@ -1045,7 +1521,53 @@ mod tests {
             observed_delta.added_tasks.contains_key(&task_id),
             "published delta must carry the task"
         );
-        assert_eq!(msg.topic, "tasks/D");
+        assert_eq!(msg.topic, "x0x/tasklist/v1/tasks/D");
+    }
+
+    #[tokio::test]
+    async fn app_pubsub_on_the_list_topic_is_isolated_from_sync() {
+        let (sync, pubsub) = make_sync_with_pubsub("tasks/shared").await;
+        sync.start().await.expect("start");
+        let mut app = pubsub.subscribe("tasks/shared".to_string()).await;
+
+        // An application message on the list's own topic name is not
+        // mistaken for a delta.
+        pubsub
+            .publish(
+                "tasks/shared".to_string(),
+                bytes::Bytes::from_static(b"chat"),
+            )
+            .await
+            .expect("app publish");
+        let msg = tokio::time::timeout(Duration::from_secs(2), app.recv())
+            .await
+            .expect("timed out waiting for app message")
+            .expect("subscriber stream closed");
+        assert_eq!(msg.payload.as_ref(), b"chat");
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(sync.stats().deltas_received, 0);
+        assert_eq!(sync.read().await.task_count(), 0);
+
+        // CRDT traffic does not reach the application subscriber.
+        let sender = peer(7);
+        let task = make_task(5, sender);
+        let mut delta = TaskListDelta::new(1);
+        delta.added_tasks.insert(*task.id(), (task, (sender, 1)));
+        sync.publish_delta(sender, delta)
+            .await
+            .expect("publish_delta");
+        assert!(
+            tokio::time::timeout(Duration::from_millis(200), app.recv())
+                .await
+                .is_err(),
+            "sync delta leaked to an application subscriber"
+        );
+
+        // Applications cannot publish into the sync namespace.
+        let err = pubsub
+            .validate_publish(sync.sync_topic(), b"garbage")
+            .expect_err("sync topic is reserved");
+        assert!(err.to_string().contains("reserved"), "{err}");
     }
 
     // ------------------------------------------------------------------
@@ -1191,22 +1713,52 @@ mod tests {
     /// an infinite capped tail — convergence is the only stop condition.
     #[test]
     fn state_request_schedule_never_terminates_while_unconverged() {
-        let front: Vec<u64> = state_request_delays().take(4).collect();
+        let front: Vec<u64> = state_request_delays(STATE_REQUEST_TAIL_START_SECS)
+            .take(4)
+            .collect();
         assert_eq!(front, STATE_REQUEST_RETRY_SECS, "front burst unchanged");
-        let tail: Vec<u64> = state_request_delays().skip(4).take(8).collect();
+        let tail: Vec<u64> = state_request_delays(STATE_REQUEST_TAIL_START_SECS)
+            .skip(4)
+            .take(8)
+            .collect();
         assert_eq!(
             tail,
             [30, 60, 120, 240, 300, 300, 300, 300],
             "tail doubles to the cap, then holds it"
         );
         assert_eq!(
-            state_request_delays().nth(10_000),
+            state_request_delays(STATE_REQUEST_TAIL_START_SECS).nth(10_000),
             Some(STATE_REQUEST_TAIL_CAP_SECS),
             "the schedule is infinite — convergence, not the schedule, \
              is what ends the requester"
         );
     }
 
+    #[test]
+    fn sync_interval_sets_the_tail_and_is_bounds_checked() {
+        let tight: Vec<u64> = state_request_delays(5).skip(4).take(8).collect();
+        assert_eq!(tight, [5, 10, 20, 40, 80, 160, 300, 300]);
+        let loose: Vec<u64> = state_request_delays(3600).skip(4).take(3).collect();
+        assert_eq!(loose, [3600, 3600, 3600], "a long interval is its own cap");
+
+        assert!(SyncOptions::default().validate().is_ok());
+        for secs in [MIN_SYNC_INTERVAL_SECS, MAX_SYNC_INTERVAL_SECS] {
+            let options = SyncOptions {
+                sync_interval_secs: secs,
+            };
+            assert!(options.validate().is_ok(), "{secs}s is in bounds");
+        }
+        for secs in [0, MAX_SYNC_INTERVAL_SECS + 1] {
+            let options = SyncOptions {
+                sync_interval_secs: secs,
+            };
+            assert!(matches!(
+                options.validate(),
+                Err(CrdtError::InvalidSyncOptions(_))
+            ));
+        }
+    }
+
     /// WHY (round-2 review — P1: one incremental delta must not silence
     /// recovery): a live task arriving before any holder has actually
     /// SERVED full state makes the list non-empty, and the round-1 tail
@@ -1237,7 +1789,7 @@ mod tests {
         bait.added_tasks.insert(bait_id, (bait_task, (peer(3), 1)));
         let encoded = encode_delta(peer(3), &bait).expect("encode bait");
         pubsub
-            .publish(topic.to_string(), bytes::Bytes::from(encoded))
+            .publish(task_list_sync_topic(topic), bytes::Bytes::from(encoded))
             .await
             .expect("publish bait");
         tokio::time::sleep(Duration::from_secs(30)).await;
@@ -1332,6 +1884,59 @@ mod tests {
         );
     }
 
+    /// WHY: a delta is delivered only to peers online when it is published,
+    /// and a non-empty replica never bootstraps again — so before
+    /// anti-entropy a missed delta was missed for good. The periodic digest
+    /// exposes the difference and the mismatching replica pulls full state.
+    #[tokio::test(start_paused = true)]
+    async fn replica_that_missed_a_delta_recovers_via_anti_entropy() {
+        let node = make_node().await;
+        let pubsub = Arc::new(PubSubManager::new(node, None).expect("pubsub"));
+        let topic = "tasks-anti-entropy";
+
+        // Both replicas start converged on {t1}, so neither bootstraps.
+        let mut a_list = TaskList::new(list_id(1), "Test List".to_string(), peer(1));
+        a_list
+            .add_task(make_task(1, peer(1)), peer(1), 1)
+            .expect("a t1");
+        let mut b_list = TaskList::new(list_id(1), "Test List".to_string(), peer(2));
+        b_list
+            .add_task(make_task(1, peer(1)), peer(2), 1)
+            .expect("b t1");
+        assert_eq!(a_list.served_digest(), b_list.served_digest());
+        let a = TaskListSync::new(a_list, Arc::clone(&pubsub), topic.to_string(), peer(1))
+            .expect("a sync");
+        let b = TaskListSync::new(b_list, Arc::clone(&pubsub), topic.to_string(), peer(2))
+            .expect("b sync");
+        a.start().await.expect("start a");
+        b.start().await.expect("start b");
+        tokio::time::sleep(Duration::from_secs(5)).await;
+
+        // A adds t2 while B is "offline": the delta is never published.
+        a.write()
+            .await
+            .add_task(make_task(2, peer(1)), peer(1), 2)
+            .expect("a t2");
+
+        let missed = TaskId::from_bytes([2; 32]);
+        let mut recovered = false;
+        for _ in 0..30 {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            if b.read().await.get_task(&missed).is_some() {
+                recovered = true;
+                break;
+            }
+        }
+        assert!(
+            recovered,
+            "the next anti-entropy round must deliver the missed task"
+        );
+        assert_eq!(
+            a.read().await.served_digest(),
+            b.read().await.served_digest()
+        );
+    }
+
     // ------------------------------------------------------------------
     // Issue #240: digest-verified convergence evidence
     // ------------------------------------------------------------------
@@ -1476,7 +2081,7 @@ mod tests {
         let node = make_node().await;
         let pubsub = Arc::new(PubSubManager::new(node, None).expect("pubsub"));
         let topic = "tasks-240-lost-broadcast";
-        let side = format!("{topic}{STATE_SYNC_TOPIC_SUFFIX}");
+        let side = format!("{}{STATE_SYNC_TOPIC_SUFFIX}", task_list_sync_topic(topic));
 
         // The full holder state (offline for now): {t1, t2}.
         let mut holder_list = TaskList::new(list_id(1), "Test List".to_string(), peer(1));
@@ -1589,7 +2194,7 @@ mod tests {
         let node = make_node().await;
         let pubsub = Arc::new(PubSubManager::new(node, None).expect("pubsub"));
         let topic = "tasks-240-prune-stale";
-        let side = format!("{topic}{STATE_SYNC_TOPIC_SUFFIX}");
+        let side = format!("{}{STATE_SYNC_TOPIC_SUFFIX}", task_list_sync_topic(topic));
 
         // Holder: only t1.
         let mut holder_list = TaskList::new(list_id(1), "Test List".to_string(), peer(1));
@@ -1673,7 +2278,7 @@ mod tests {
         let node = make_node().await;
         let pubsub = Arc::new(PubSubManager::new(node, None).expect("pubsub"));
         let topic = "tasks-240-empty-silence";
-        let side = format!("{topic}{STATE_SYNC_TOPIC_SUFFIX}");
+        let side = format!("{}{STATE_SYNC_TOPIC_SUFFIX}", task_list_sync_topic(topic));
 
         // Both lists genuinely EMPTY.
         let joiner_list = TaskList::new(list_id(1), "Test List".to_string(), peer(2));
@@ -1714,7 +2319,7 @@ mod tests {
         let node = make_node().await;
         let pubsub = Arc::new(PubSubManager::new(node, None).expect("pubsub"));
         let topic = "tasks-240-v1-compat";
-        let side = format!("{topic}{STATE_SYNC_TOPIC_SUFFIX}");
+        let side = format!("{}{STATE_SYNC_TOPIC_SUFFIX}", task_list_sync_topic(topic));
 
         let joiner_list = TaskList::new(list_id(1), "Test List".to_string(), peer(2));
         let joiner =
@@ -1733,7 +2338,7 @@ mod tests {
         let full = holder_list.full_delta();
         let encoded = encode_delta(peer(1), &full).expect("encode full");
         pubsub
-            .publish(topic.to_string(), bytes::Bytes::from(encoded))
+            .publish(task_list_sync_topic(topic), bytes::Bytes::from(encoded))
             .await
             .expect("publish full delta");
         let marker = TaskListSyncMessage::StateServed { responder: peer(1) };
@@ -1779,7 +2384,7 @@ mod tests {
         let node = make_node().await;
         let pubsub = Arc::new(PubSubManager::new(node, None).expect("pubsub"));
         let topic = "tasks-240-tampered";
-        let side = format!("{topic}{STATE_SYNC_TOPIC_SUFFIX}");
+        let side = format!("{}{STATE_SYNC_TOPIC_SUFFIX}", task_list_sync_topic(topic));
 
         let joiner_list = TaskList::new(list_id(1), "Test List".to_string(), peer(2));
         let joiner =
@@ -45,6 +45,30 @@ use saorsa_gossip_types::PeerId;
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashSet};
 
+/// The kind of a [`TaskEvent`] in a task's audit history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum TaskEventKind {
+    /// The task was created.
+    Added,
+    /// An agent claimed the task.
+    Claimed,
+    /// An agent completed the task.
+    Completed,
+    /// An agent reopened the completed task.
+    Reopened,
+}
+
+/// One entry in a task's audit history (see [`TaskItem::history`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskEvent {
+    /// What happened.
+    pub kind: TaskEventKind,
+    /// The agent that performed the operation.
+    pub agent_id: AgentId,
+    /// When it happened (Unix milliseconds, as recorded by the actor).
+    pub timestamp: u64,
+}
+
 /// A task item in a collaborative task list.
 ///
 /// TaskItem combines multiple CRDTs to represent a task:
@@ -264,11 +288,9 @@ impl TaskItem {
         seq: u64,
         signing: &SigningContext,
     ) -> Result<()> {
-        // Generate Unix timestamp for conflict resolution (globally comparable)
-        let timestamp = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .map_err(|e| CrdtError::SystemClock(format!("clock before Unix epoch: {e}")))?
-            .as_millis() as u64;
+        // Unix timestamp for conflict resolution (globally comparable),
+        // kept after the latest reopen so the claim is not cancelled by it.
+        let timestamp = self.op_timestamp(self.reopen_cutoff())?;
 
         // Check current state - can't claim if already done
         let current = self.current_state();
@@ -353,11 +375,9 @@ impl TaskItem {
         seq: u64,
         signing: &SigningContext,
     ) -> Result<()> {
-        // Generate Unix timestamp for conflict resolution (globally comparable)
-        let timestamp = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .map_err(|e| CrdtError::SystemClock(format!("clock before Unix epoch: {e}")))?
-            .as_millis() as u64;
+        // Unix timestamp for conflict resolution (globally comparable),
+        // kept after the latest reopen (see claim).
+        let timestamp = self.op_timestamp(self.reopen_cutoff())?;
 
         // Check current state
         let current = self.current_state();
@@ -410,6 +430,99 @@ impl TaskItem {
         Ok(())
     }
 
+    /// Reopen this completed task.
+    ///
+    /// Adds a signed `Reopened` element to the OR-Set. It cancels every
+    /// claim and completion recorded at or before its timestamp, so the
+    /// task reads as `Empty` and can be claimed again; those elements stay
+    /// in [`TaskItem::history`]. The timestamp is kept after the latest
+    /// claim or completion this replica knows of, so a reopen always
+    /// cancels the completion it was issued against even under clock skew.
+    ///
+    /// Concurrent operations converge by timestamp alone: a completion
+    /// from another replica stamped after the reopen wins, one stamped at
+    /// or before it is cancelled, whatever the merge order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CrdtError::InvalidStateTransition` unless the task is Done.
+    pub fn reopen(
+        &mut self,
+        scope: TaskListId,
+        agent_id: AgentId,
+        peer_id: PeerId,
+        seq: u64,
+        signing: &SigningContext,
+    ) -> Result<()> {
+        let latest = self
+            .attestations
+            .keys()
+            .filter_map(CheckboxState::timestamp)
+            .max();
+        let timestamp = self.op_timestamp(latest)?;
+
+        let current = self.current_state();
+        if !current.is_done() {
+            return Err(CrdtError::InvalidStateTransition {
+                current,
+                attempted: CheckboxState::Reopened {
+                    agent_id,
+                    timestamp,
+                },
+            });
+        }
+
+        // Provenance: self-sign the reopen (agent_id == signing.agent_id).
+        let att = sign_attestation(
+            signing,
+            OpKind::Reopen,
+            &scope,
+            &self.id,
+            &agent_id,
+            timestamp,
+        )?;
+
+        let reopened_state = CheckboxState::Reopened {
+            agent_id,
+            timestamp,
+        };
+        let tag = (peer_id, seq); // seq used for OR-Set uniqueness
+        self.checkbox
+            .add(reopened_state.clone(), tag)
+            .map_err(|e| CrdtError::Merge(format!("Failed to add reopened state: {}", e)))?;
+        self.attestations.insert(reopened_state, att);
+
+        Ok(())
+    }
+
+    /// Current Unix time in milliseconds, raised past `after` if needed.
+    fn op_timestamp(&self, after: Option<u64>) -> Result<u64> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| CrdtError::SystemClock(format!("clock before Unix epoch: {e}")))?
+            .as_millis() as u64;
+        Ok(after.map_or(now, |after| now.max(after.saturating_add(1))))
+    }
+
+    /// Timestamp of the latest reopen, if any. Claims and completions at or
+    /// before it are cancelled.
+    fn reopen_cutoff(&self) -> Option<u64> {
+        self.attestations
+            .keys()
+            .filter(|s| s.is_reopened())
+            .filter_map(CheckboxState::timestamp)
+            .max()
+    }
+
+    /// Claims and completions not cancelled by a later reopen.
+    fn live_states(&self) -> impl Iterator<Item = &CheckboxState> {
+        let cutoff = self.reopen_cutoff();
+        self.attestations.keys().filter(move |s| {
+            (s.is_claimed() || s.is_done())
+                && cutoff.is_none_or(|cutoff| s.timestamp().is_some_and(|ts| ts > cutoff))
+        })
+    }
+
     /// Update the task title.
     ///
     /// Uses LWW semantics - the update with the highest vector clock wins.
@@ -461,16 +574,18 @@ impl TaskItem {
     /// Get the current checkbox state.
     ///
     /// Resolves the OR-Set to a single state by taking the maximum
-    /// (most progressed state wins: Done > Claimed > Empty).
+    /// (most progressed state wins: Done > Claimed > Empty). The latest
+    /// `Reopened` element wins over every claim and completion stamped at
+    /// or before it: those are ignored here.
     ///
     /// # Returns
     ///
-    /// - `Empty` if the OR-Set is empty
+    /// - `Empty` if the OR-Set is empty, or nothing follows the latest reopen
     /// - `Done` if any Done state exists (task completed)
     /// - `Claimed` if any Claimed state exists (task in progress)
     ///
     /// When multiple states of the same variant exist, the earliest
-    /// timestamp wins.
+    /// timestamp wins. Never returns `Reopened`.
     ///
     /// # Example
     ///
@@ -492,7 +607,7 @@ impl TaskItem {
         // tombstones may hide elements from it but cannot censor them here.
         // After admission (purge), every entry in attestations is
         // cryptographically valid for this list's scope.
-        let states: Vec<&CheckboxState> = self.attestations.keys().collect();
+        let states: Vec<&CheckboxState> = self.live_states().collect();
 
         if states.is_empty() {
             return CheckboxState::Empty;
@@ -531,12 +646,11 @@ impl TaskItem {
     /// winner (earliest timestamp, `CheckboxState` ordering as tiebreaker) —
     /// the same resolution [`TaskItem::current_state`] uses. Unlike
     /// `current_state`, the claim record remains available after the task
-    /// transitions to Done.
+    /// transitions to Done. Claims cancelled by a reopen are not considered.
     ///
     /// # Returns
     pub fn claim_record(&self) -> Option<(AgentId, u64)> {
-        self.attestations
-            .keys()
+        self.live_states()
             .filter(|s| s.is_claimed())
             .min()
             .and_then(|s| match s {
@@ -559,11 +673,10 @@ impl TaskItem {
     /// # Returns
     ///
     /// A `Vec` of `(agent_id, unix_ms_timestamp)` for every observed claim
-    /// candidate. Order is unspecified.
+    /// candidate since the latest reopen. Order is unspecified.
     #[must_use]
     pub fn claims(&self) -> Vec<(AgentId, u64)> {
-        self.attestations
-            .keys()
+        self.live_states()
             .filter_map(|s| match s {
                 CheckboxState::Claimed {
                     agent_id,
@@ -574,10 +687,110 @@ impl TaskItem {
             .collect()
     }
 
+    /// Audit history of this task: creation, then every claim and
+    /// completion, oldest first.
+    ///
+    /// Derived from replicated state rather than a separate log. The
+    /// creation event comes from the immutable `created_by`/`created_at`
+    /// fields, and claim/completion events from the attestation map, which
+    /// already keeps every signed `Claimed`/`Done` element (not just the
+    /// winner) and is synced with the task in every delta. Each event is
+    /// therefore authenticated by its actor's ML-DSA-65 signature, and
+    /// replicas that have merged the same deltas report the same history.
+    ///
+    /// Storage cost: no extra bytes beyond what provenance already stores —
+    /// roughly 5.3 KiB per claim or completion (ML-DSA-65 public key plus
+    /// signature), growing with the number of operations, never compacted.
+    /// Metadata edits (title, description, priority, assignee) are LWW
+    /// registers that keep only the latest value and do not appear here.
+    /// Elements dropped at admission (unattested or from non-members) are
+    /// excluded.
+    #[must_use]
+    pub fn history(&self) -> Vec<TaskEvent> {
+        let mut events = vec![TaskEvent {
+            kind: TaskEventKind::Added,
+            agent_id: self.created_by,
+            timestamp: self.created_at,
+        }];
+        events.extend(self.attestations.keys().filter_map(|s| match s {
+            CheckboxState::Claimed {
+                agent_id,
+                timestamp,
+            } => Some(TaskEvent {
+                kind: TaskEventKind::Claimed,
+                agent_id: *agent_id,
+                timestamp: *timestamp,
+            }),
+            CheckboxState::Done {
+                agent_id,
+                timestamp,
+            } => Some(TaskEvent {
+                kind: TaskEventKind::Completed,
+                agent_id: *agent_id,
+                timestamp: *timestamp,
+            }),
+            CheckboxState::Reopened {
+                agent_id,
+                timestamp,
+            } => Some(TaskEvent {
+                kind: TaskEventKind::Reopened,
+                agent_id: *agent_id,
+                timestamp: *timestamp,
+            }),
+            CheckboxState::Empty => None,
+        }));
+        // Same-millisecond operations keep their causal order
+        // (add → claim → complete); agent id breaks remaining ties.
+        events.sort_by_key(|e| (e.timestamp, e.kind, e.agent_id.0));
+        events
+    }
+
+    /// Every recorded checkbox state, oldest first.
+    ///
+    /// [`TaskItem::current_state`] collapses the attestation map to one
+    /// winner; this returns all of it, so callers can show that agent A
+    /// claimed, agent B also claimed and agent A completed. Like
+    /// [`TaskItem::history`] it adds no storage, and it holds only
+    /// `Claimed`, `Done` and `Reopened` states (no creation event). Same-millisecond
+    /// states keep `CheckboxState` order, so a claim precedes a completion.
+    #[must_use]
+    pub fn state_history(&self) -> Vec<CheckboxState> {
+        let mut states: Vec<CheckboxState> = self
+            .attestations
+            .keys()
+            .filter(|s| !s.is_empty())
+            .cloned()
+            .collect();
+        states.sort_by(|a, b| a.timestamp().cmp(&b.timestamp()).then_with(|| a.cmp(b)));
+        states
+    }
+
+    /// The latest reopen record, if this task has ever been reopened.
+    ///
+    /// # Returns
+    ///
+    /// `Some((agent_id, timestamp_ms))` for the most recent reopen (ties
+    /// broken by `CheckboxState` ordering), or `None`.
+    #[must_use]
+    pub fn reopen_record(&self) -> Option<(AgentId, u64)> {
+        self.attestations
+            .keys()
+            .filter(|s| s.is_reopened())
+            .max()
+            .and_then(|s| match s {
+                CheckboxState::Reopened {
+                    agent_id,
+                    timestamp,
+                } => Some((*agent_id, *timestamp)),
+                _ => None,
+            })
+    }
+
     /// The winning completion record, if this task has been completed.
     ///
     /// Resolves the OR-Set's `Done` entries to a single deterministic winner
     /// (earliest timestamp, `CheckboxState` ordering as tiebreaker).
+    /// Completions cancelled by a reopen are not considered.
     ///
     /// # Returns
     ///
@@ -585,8 +798,7 @@ impl TaskItem {
     /// if the task is not done.
     #[must_use]
     pub fn completion_record(&self) -> Option<(AgentId, u64)> {
-        self.attestations
-            .keys()
+        self.live_states()
             .filter(|s| s.is_done())
             .min()
             .and_then(|s| match s {
@@ -631,7 +843,9 @@ impl TaskItem {
         lp(h, self.description().as_bytes());
         h.update(&[self.priority()]);
         match self.current_state() {
-            CheckboxState::Empty => {
+            // current_state resolves a reopen to Empty, so a reopened task
+            // hashes like one that was never claimed.
+            CheckboxState::Empty | CheckboxState::Reopened { .. } => {
                 h.update(&[0u8]);
             }
             CheckboxState::Claimed {
@@ -773,7 +987,7 @@ impl TaskItem {
     /// a remote peer who subscribes to the topic but is not a group member
     /// cannot inject claims/completions even with a valid signature.
     ///
-    /// No-op when the element is not a Claimed/Done (Empty is never in the
+    /// No-op when the element is not a Claimed/Done/Reopened (Empty is never in the
     /// OR-Set). Returns the count of nonmember elements dropped.
     #[must_use]
     pub fn filter_unauthorized(&mut self, authorized: &HashSet<AgentId>) -> usize {
@@ -783,9 +997,9 @@ impl TaskItem {
         let mut dropped = 0usize;
         for state in attested {
             let agent_id = match &state {
-                CheckboxState::Claimed { agent_id, .. } | CheckboxState::Done { agent_id, .. } => {
-                    *agent_id
-                }
+                CheckboxState::Claimed { agent_id, .. }
+                | CheckboxState::Done { agent_id, .. }
+                | CheckboxState::Reopened { agent_id, .. } => *agent_id,
                 CheckboxState::Empty => continue,
             };
             if !authorized.contains(&agent_id) {
@@ -946,6 +1160,62 @@ mod tests {
         assert!(task.current_state().is_done());
     }
 
+    #[test]
+    fn history_records_add_claim_complete_with_actors() {
+        let peer = peer(1);
+        let (claimer, claimer_signing) = signing_for(2);
+        let (completer, completer_signing) = signing_for(3);
+        let mut task = make_task(peer);
+
+        task.claim(item_scope(), claimer, peer, 1, &claimer_signing)
+            .unwrap();
+        task.complete(item_scope(), completer, peer, 2, &completer_signing)
+            .unwrap();
+
+        let history = task.history();
+        let actors: Vec<_> = history.iter().map(|e| (e.kind, e.agent_id)).collect();
+        assert_eq!(
+            actors,
+            vec![
+                (TaskEventKind::Added, agent(1)),
+                (TaskEventKind::Claimed, claimer),
+                (TaskEventKind::Completed, completer),
+            ]
+        );
+        assert_eq!(history[0].timestamp, 1000);
+        assert!(history.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+
+        // History travels with the task: a fresh replica that merges the
+        // state reports the same audit trail.
+        let mut replica = make_task(peer);
+        replica.merge(item_scope(), &task).unwrap();
+        assert_eq!(replica.history(), history);
+    }
+
+    #[test]
+    fn state_history_keeps_every_claim_and_completion_in_time_order() {
+        let peer = peer(1);
+        let (a, a_signing) = signing_for(2);
+        let (b, b_signing) = signing_for(3);
+        let mut task = make_task(peer);
+        assert!(task.state_history().is_empty());
+
+        task.claim(item_scope(), a, peer, 1, &a_signing).unwrap();
+        task.claim(item_scope(), b, peer, 2, &b_signing).unwrap();
+        task.complete(item_scope(), a, peer, 3, &a_signing).unwrap();
+
+        let states = task.state_history();
+        assert_eq!(states.len(), 3);
+        assert!(states[0].is_claimed() && states[0].claimed_by() == Some(&a));
+        assert!(states[1].is_claimed() && states[1].claimed_by() == Some(&b));
+        assert!(states[2].is_done());
+        assert!(states
+            .windows(2)
+            .all(|w| w[0].timestamp() <= w[1].timestamp()));
+        // current_state still reports only the winner.
+        assert_eq!(task.current_state(), states[2]);
+    }
+
     #[test]
     fn test_cannot_complete_empty_task() {
         let peer = peer(1);
@@ -960,6 +1230,84 @@ mod tests {
         }
     }
 
+    #[test]
+    fn reopen_after_done_returns_task_to_empty() {
+        let peer = peer(1);
+        let (a, a_signing) = signing_for(2);
+        let (b, b_signing) = signing_for(3);
+        let mut task = make_task(peer);
+
+        task.claim(item_scope(), a, peer, 1, &a_signing).unwrap();
+        task.complete(item_scope(), a, peer, 2, &a_signing).unwrap();
+        task.reopen(item_scope(), b, peer, 3, &b_signing).unwrap();
+
+        assert_eq!(task.current_state(), CheckboxState::Empty);
+        assert!(task.claim_record().is_none());
+        assert!(task.completion_record().is_none());
+        assert_eq!(task.reopen_record().map(|(agent, _)| agent), Some(b));
+        assert!(task.state_history().last().unwrap().is_reopened());
+
+        // A reopened task can be claimed and completed again.
+        task.claim(item_scope(), b, peer, 4, &b_signing).unwrap();
+        assert_eq!(task.claim_record().map(|(agent, _)| agent), Some(b));
+        task.complete(item_scope(), b, peer, 5, &b_signing).unwrap();
+        assert_eq!(task.completion_record().map(|(agent, _)| agent), Some(b));
+        assert_eq!(task.state_history().len(), 5);
+    }
+
+    #[test]
+    fn test_cannot_reopen_task_that_is_not_done() {
+        let peer = peer(1);
+        let (agent, signing) = signing_for(1);
+        let mut task = make_task(peer);
+
+        let result = task.reopen(item_scope(), agent, peer, 1, &signing);
+        assert!(matches!(
+            result,
+            Err(CrdtError::InvalidStateTransition { .. })
+        ));
+
+        task.claim(item_scope(), agent, peer, 2, &signing).unwrap();
+        let result = task.reopen(item_scope(), agent, peer, 3, &signing);
+        assert!(matches!(
+            result,
+            Err(CrdtError::InvalidStateTransition { .. })
+        ));
+    }
+
+    #[test]
+    fn concurrent_reopen_and_complete_converge() {
+        let peer_a = peer(1);
+        let peer_b = peer(2);
+        let (a, a_signing) = signing_for(3);
+        let (b, b_signing) = signing_for(4);
+        let mut base = make_task(peer_a);
+        base.claim(item_scope(), a, peer_a, 1, &a_signing).unwrap();
+
+        // Replica 1 completes and then reopens; replica 2 concurrently
+        // completes the claimed task itself.
+        let mut one = base.clone();
+        one.complete(item_scope(), a, peer_a, 2, &a_signing)
+            .unwrap();
+        one.reopen(item_scope(), a, peer_a, 3, &a_signing).unwrap();
+        let mut two = base.clone();
+        two.complete(item_scope(), b, peer_b, 1, &b_signing)
+            .unwrap();
+
+        let mut merged_one = one.clone();
+        merged_one.merge(item_scope(), &two).unwrap();
+        let mut merged_two = two.clone();
+        merged_two.merge(item_scope(), &one).unwrap();
+
+        assert_eq!(merged_one.current_state(), merged_two.current_state());
+        assert_eq!(merged_one.state_history(), merged_two.state_history());
+        // Replica 1's own completion is always cancelled by its reopen.
+        assert_ne!(
+            merged_one.completion_record().map(|(agent, _)| agent),
+            Some(a)
+        );
+    }
+
     #[test]
     fn test_cannot_complete_done_task() {
         let peer = peer(1);
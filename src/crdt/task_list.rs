@@ -13,6 +13,14 @@
 //! - Tasks in OR-Set but not in ordering vector are appended to the end
 //!
 //! This provides eventual consistency with deterministic conflict resolution.
+//!
+//! ## JSON Snapshots
+//!
+//! [`TaskList::to_json_snapshot`] renders the list for dashboards and
+//! exports to non-Rust tools: each task's id, title, description, state,
+//! assignee, priority and position, in display order. It is a read-only
+//! projection. The OR-Set tags, vector clocks and attestations are left
+//! out, so a snapshot cannot be merged or imported back into a replica.
 
 use crate::crdt::{CrdtError, Result, TaskId, TaskItem, TaskListDelta};
 use crate::identity::AgentId;
@@ -23,6 +31,57 @@ use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
+/// A disagreement between a [`TaskList`]'s OR-Set membership, task data
+/// and ordering vector, as reported by [`TaskList::validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum Inconsistency {
+    /// The task is an OR-Set member but has no entry in the task data, so
+    /// `tasks_ordered` silently hides it.
+    #[error("task {task_id} is a member but has no task data")]
+    MissingTaskData {
+        /// The member without data.
+        task_id: TaskId,
+    },
+
+    /// A task-data entry is stored under a key other than its own id.
+    #[error("task data under key {key} carries id {item_id}")]
+    MismatchedTaskId {
+        /// The map key.
+        key: TaskId,
+        /// The id of the task stored under it.
+        item_id: TaskId,
+    },
+
+    /// The ordering vector lists the same task more than once, so
+    /// `tasks_ordered` would show it twice.
+    #[error("task {task_id} appears more than once in the ordering")]
+    DuplicateOrderEntry {
+        /// The repeated task.
+        task_id: TaskId,
+    },
+}
+
+impl Inconsistency {
+    /// Short machine-readable name of the discrepancy.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::MissingTaskData { .. } => "missing_task_data",
+            Self::MismatchedTaskId { .. } => "mismatched_task_id",
+            Self::DuplicateOrderEntry { .. } => "duplicate_order_entry",
+        }
+    }
+
+    /// The task the discrepancy concerns.
+    #[must_use]
+    pub fn task_id(&self) -> &TaskId {
+        match self {
+            Self::MissingTaskData { task_id } | Self::DuplicateOrderEntry { task_id } => task_id,
+            Self::MismatchedTaskId { key, .. } => key,
+        }
+    }
+}
+
 /// Task list identifier.
 ///
 /// A 32-byte unique identifier for a task list, typically derived from
@@ -89,6 +148,32 @@ fn default_seq_counter() -> Arc<AtomicU64> {
     Arc::new(AtomicU64::new(0))
 }
 
+/// Format version carried in [`TaskList::to_json_snapshot`] output. Bumped
+/// only when an existing field changes meaning or is removed.
+pub const JSON_SNAPSHOT_FORMAT: u32 = 1;
+
+/// JSON projection of a [`TaskList`] (see [`TaskList::to_json_snapshot`]).
+#[derive(Serialize)]
+struct JsonSnapshot<'a> {
+    format: u32,
+    id: String,
+    name: &'a str,
+    version: u64,
+    tasks: Vec<JsonSnapshotTask<'a>>,
+}
+
+/// One task in a [`JsonSnapshot`].
+#[derive(Serialize)]
+struct JsonSnapshotTask<'a> {
+    id: String,
+    title: &'a str,
+    description: &'a str,
+    state: String,
+    assignee: Option<String>,
+    priority: u8,
+    order: usize,
+}
+
 /// Domain-separation tag for served-state digest hashing (issue #240).
 pub(crate) const SERVED_DIGEST_DOMAIN: &[u8] = b"x0x.tasklist.served.digest.v1";
 
@@ -320,6 +405,15 @@ impl TaskList {
         self.seq_counter.fetch_add(1, Ordering::Relaxed) + 1
     }
 
+    /// Raise the sequence counter to at least `floor`.
+    ///
+    /// The counter is not serialized, so a list loaded from storage must be
+    /// given a floor above every seq it minted before it was saved, or this
+    /// node could re-mint an OR-Set `(peer, seq)` tag it already used.
+    pub fn restore_seq_counter(&self, floor: u64) {
+        self.seq_counter.fetch_max(floor, Ordering::Relaxed);
+    }
+
     /// Get the task list ID.
     #[must_use]
     pub fn id(&self) -> &TaskListId {
@@ -574,6 +668,42 @@ impl TaskList {
         Ok(())
     }
 
+    /// Reopen a completed task in the list.
+    ///
+    /// Delegates to the TaskItem's reopen method.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - ID of the task to reopen
+    /// * `agent_id` - Agent reopening the task
+    /// * `peer_id` - Peer making this change
+    /// * `seq` - Sequence number
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) if successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the task doesn't exist or is not done.
+    pub fn reopen_task(
+        &mut self,
+        task_id: &TaskId,
+        agent_id: AgentId,
+        peer_id: PeerId,
+        seq: u64,
+        signing: &crate::gossip::SigningContext,
+    ) -> Result<()> {
+        let task = self
+            .task_data
+            .get_mut(task_id)
+            .ok_or(CrdtError::TaskNotFound(*task_id))?;
+
+        task.reopen(self.id, agent_id, peer_id, seq, signing)?;
+        self.version += 1;
+        Ok(())
+    }
+
     /// Reorder the tasks in the list.
     ///
     /// # Arguments
@@ -783,6 +913,66 @@ impl TaskList {
         total_dropped
     }
 
+    /// Check that membership, task data and ordering agree.
+    ///
+    /// Cheap enough for `debug_assert!(list.validate().is_ok())` after a
+    /// merge. Two kinds of disagreement are expected and NOT reported:
+    /// ordering entries with no task data (`remove_task` leaves them in
+    /// place, and `merge_ordering` may name tasks not yet synced), and task
+    /// data for tasks removed from the OR-Set by a remote replica (merges
+    /// never drop task data). `tasks_ordered` filters both at read time.
+    ///
+    /// # Errors
+    ///
+    /// Returns every [`Inconsistency`] found, members before task data
+    /// before ordering, each group sorted by task id.
+    pub fn validate(&self) -> std::result::Result<(), Vec<Inconsistency>> {
+        let mut found = Vec::new();
+
+        let mut members: Vec<TaskId> = self.tasks.elements().into_iter().copied().collect();
+        members.sort_unstable_by_key(|id| *id.as_bytes());
+        found.extend(
+            members
+                .into_iter()
+                .filter(|id| !self.task_data.contains_key(id))
+                .map(|task_id| Inconsistency::MissingTaskData { task_id }),
+        );
+
+        let mut mismatched: Vec<Inconsistency> = self
+            .task_data
+            .iter()
+            .filter(|(key, task)| task.id() != *key)
+            .map(|(key, task)| Inconsistency::MismatchedTaskId {
+                key: *key,
+                item_id: *task.id(),
+            })
+            .collect();
+        mismatched.sort_unstable_by_key(|i| *i.task_id().as_bytes());
+        found.extend(mismatched);
+
+        let mut seen = HashSet::new();
+        let mut duplicates: Vec<TaskId> = self
+            .ordering
+            .get()
+            .iter()
+            .filter(|id| !seen.insert(**id))
+            .copied()
+            .collect();
+        duplicates.sort_unstable_by_key(|id| *id.as_bytes());
+        duplicates.dedup();
+        found.extend(
+            duplicates
+                .into_iter()
+                .map(|task_id| Inconsistency::DuplicateOrderEntry { task_id }),
+        );
+
+        if found.is_empty() {
+            Ok(())
+        } else {
+            Err(found)
+        }
+    }
+
     /// Get the number of tasks in the list.
     #[must_use]
     pub fn task_count(&self) -> usize {
@@ -799,6 +989,46 @@ impl TaskList {
     pub fn get_task_mut(&mut self, task_id: &TaskId) -> Option<&mut TaskItem> {
         self.task_data.get_mut(task_id)
     }
+
+    /// Render the list as pretty-printed JSON for external tools.
+    ///
+    /// The output holds the list's `format` ([`JSON_SNAPSHOT_FORMAT`]),
+    /// `id`, `name` and `version`, and its `tasks` in display order. Each
+    /// task has `id`, `title`, `description`, `state` (`empty`,
+    /// `claimed:<agent>` or `done:<agent>`), `assignee`, `priority` and
+    /// `order`. Ids are hex.
+    ///
+    /// This is a snapshot format only. It carries no CRDT causal metadata,
+    /// so reading it back into a [`TaskList`] would be lossy and is not
+    /// supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CrdtError::Json`] if serialization fails.
+    pub fn to_json_snapshot(&self) -> Result<String> {
+        let tasks = self
+            .tasks_ordered()
+            .into_iter()
+            .enumerate()
+            .map(|(order, task)| JsonSnapshotTask {
+                id: task.id().to_string(),
+                title: task.title(),
+                description: task.description(),
+                state: task.current_state().to_string(),
+                assignee: task.assignee().map(|a| hex::encode(a.as_bytes())),
+                priority: task.priority(),
+                order,
+            })
+            .collect();
+        let snapshot = JsonSnapshot {
+            format: JSON_SNAPSHOT_FORMAT,
+            id: self.id.to_string(),
+            name: self.name(),
+            version: self.version,
+            tasks,
+        };
+        Ok(serde_json::to_string_pretty(&snapshot)?)
+    }
 }
 
 #[cfg(test)]
@@ -982,6 +1212,40 @@ mod tests {
         assert_eq!(tasks[2].id(), &id2);
     }
 
+    #[test]
+    fn json_snapshot_lists_tasks_in_display_order() {
+        let peer = peer(1);
+        let mut list = TaskList::new(list_id(1), "My List".to_string(), peer);
+        let task1 = make_task(1, peer);
+        let task2 = make_task(2, peer);
+        let (id1, id2) = (*task1.id(), *task2.id());
+        list.add_task(task1, peer, 1).expect("add");
+        list.add_task(task2, peer, 2).expect("add");
+        list.reorder(vec![id2, id1], peer).expect("reorder");
+        list.get_task_mut(&id1)
+            .expect("task")
+            .update_assignee(Some(agent(7)), peer);
+
+        let json = list.to_json_snapshot().expect("snapshot");
+        assert_eq!(json, list.to_json_snapshot().expect("snapshot"));
+        let value: serde_json::Value = serde_json::from_str(&json).expect("valid JSON");
+        assert_eq!(value["format"], JSON_SNAPSHOT_FORMAT);
+        assert_eq!(value["id"], list_id(1).to_string());
+        assert_eq!(value["name"], "My List");
+        let tasks = value["tasks"].as_array().expect("tasks");
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0]["id"], id2.to_string());
+        assert_eq!(tasks[0]["order"], 0);
+        assert_eq!(tasks[0]["assignee"], serde_json::Value::Null);
+        assert_eq!(tasks[1]["id"], id1.to_string());
+        assert_eq!(tasks[1]["order"], 1);
+        assert_eq!(tasks[1]["title"], "Task 1");
+        assert_eq!(tasks[1]["description"], "Description 1");
+        assert_eq!(tasks[1]["state"], "empty");
+        assert_eq!(tasks[1]["assignee"], hex::encode([7u8; 32]));
+        assert_eq!(tasks[1]["priority"], 128);
+    }
+
     #[test]
     fn test_reorder_with_invalid_task() {
         let peer = peer(1);
@@ -1362,6 +1626,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_restore_seq_counter_only_raises() {
+        let list = TaskList::new(list_id(99), "seq test".to_string(), peer(1));
+        list.restore_seq_counter(100);
+        assert_eq!(list.next_seq(), 101);
+        list.restore_seq_counter(5);
+        assert_eq!(list.next_seq(), 102, "a lower floor must not rewind");
+    }
+
     #[test]
     fn test_rapid_add_tasks_all_survive() {
         let p = peer(1);
@@ -1377,4 +1650,61 @@ mod tests {
             "all 50 tasks must survive; duplicate OR-Set tags would drop some"
         );
     }
+
+    #[test]
+    fn test_validate_accepts_removed_and_unsynced_order_entries() {
+        let p = peer(1);
+        let mut list = TaskList::new(list_id(1), "valid".to_string(), p);
+        for i in 1u8..=3 {
+            let seq = list.next_seq();
+            list.add_task(make_task(i, p), p, seq).ok().unwrap();
+        }
+        list.remove_task(&TaskId::from_bytes([2; 32])).ok().unwrap();
+
+        let mut ahead = list.ordering_register().clone();
+        let mut order = ahead.get().clone();
+        order.push(TaskId::from_bytes([9; 32]));
+        ahead.set(order, peer(2));
+        list.merge_ordering(&ahead);
+
+        assert_eq!(list.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_reports_inconsistent_list() {
+        let p = peer(1);
+        let mut list = TaskList::new(list_id(1), "broken".to_string(), p);
+        for i in 1u8..=3 {
+            let seq = list.next_seq();
+            list.add_task(make_task(i, p), p, seq).ok().unwrap();
+        }
+        let one = TaskId::from_bytes([1; 32]);
+        let two = TaskId::from_bytes([2; 32]);
+        let three = TaskId::from_bytes([3; 32]);
+
+        // Member whose data went missing.
+        list.task_data.remove(&two);
+        // Data filed under the wrong key.
+        let moved = list.task_data.remove(&three).unwrap();
+        let stray = TaskId::from_bytes([7; 32]);
+        list.task_data.insert(stray, moved);
+        // Ordering that repeats a task.
+        list.ordering.set(vec![one, one, three, one], p);
+
+        let found = list.validate().unwrap_err();
+        assert_eq!(
+            found,
+            vec![
+                Inconsistency::MissingTaskData { task_id: two },
+                Inconsistency::MissingTaskData { task_id: three },
+                Inconsistency::MismatchedTaskId {
+                    key: stray,
+                    item_id: three,
+                },
+                Inconsistency::DuplicateOrderEntry { task_id: one },
+            ]
+        );
+        assert_eq!(found[0].kind(), "missing_task_data");
+        assert_eq!(found[2].task_id(), &stray);
+    }
 }
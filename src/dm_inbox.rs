@@ -1052,12 +1052,14 @@ mod tests {
         envelope: &DmEnvelope,
     ) -> PubSubMessage {
         PubSubMessage {
+            id: [0; 32],
             topic: DmInboxService::inbox_topic_name(&harness.recipient_agent_id),
             payload: Bytes::from(envelope.to_wire_bytes().expect("encode envelope")),
             sender: Some(sender.agent_id()),
             sender_public_key: Some(sender.public_key().as_bytes().to_vec()),
             verified: true,
             trust_level: Some(TrustLevel::Trusted),
+            on_behalf_of: None,
         }
     }
 
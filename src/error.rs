@@ -12,6 +12,7 @@ use thiserror::Error;
 /// - Invalid key material
 /// - PeerId verification mismatches
 /// - Persistent storage I/O errors
+/// - Network, gossip and uninitialized-subsystem failures
 /// - Serialization/deserialization failures
 ///
 /// # Examples
@@ -48,11 +49,33 @@ pub enum IdentityError {
     #[error("key storage error: {0}")]
     Storage(#[from] std::io::Error),
 
+    /// A signing or key-handling operation failed.
+    #[error("cryptographic operation failed: {0}")]
+    Crypto(String),
+
+    /// A transport-level operation (peer hints, node startup) failed.
+    #[error("network error: {0}")]
+    Network(String),
+
+    /// A gossip-layer operation (publish, runtime/service startup) failed.
+    #[error("gossip error: {0}")]
+    Gossip(String),
+
+    /// The operation needs a subsystem the agent was built without
+    /// (typically the network / gossip runtime).
+    #[error("not initialized: {0}")]
+    NotInitialized(String),
+
     /// ADR-0023 history store failed to initialize (e.g. the database is
     /// exclusively locked by another process).
     #[error("history initialization failed: {0}")]
     HistoryInit(String),
 
+    /// [`crate::AgentBuilder::build`] did not finish within its build
+    /// timeout (e.g. a contended socket bind or a stalled transport init).
+    #[error("agent construction timed out after {0:?}")]
+    BuildTimeout(std::time::Duration),
+
     /// Serialization or deserialization of keypairs failed.
     #[error("serialization error: {0}")]
     Serialization(String),
@@ -141,6 +164,21 @@ mod tests {
         assert_eq!(err.to_string(), "serialization error: invalid bincode");
     }
 
+    #[test]
+    fn test_subsystem_error_variants_display() {
+        let err = IdentityError::NotInitialized("gossip runtime".to_string());
+        assert_eq!(err.to_string(), "not initialized: gossip runtime");
+        let err = IdentityError::Gossip("publish failed".to_string());
+        assert_eq!(err.to_string(), "gossip error: publish failed");
+        let err = IdentityError::Network("bind failed".to_string());
+        assert_eq!(err.to_string(), "network error: bind failed");
+        let err = IdentityError::Crypto("sign failed".to_string());
+        assert_eq!(
+            err.to_string(),
+            "cryptographic operation failed: sign failed"
+        );
+    }
+
     #[test]
     fn test_result_type_ok() {
         let result: Result<i32> = Ok(42);
@@ -372,6 +410,11 @@ pub enum NetworkError {
     #[error("channel closed: {0}")]
     ChannelClosed(String),
 
+    /// The gossip data plane is paused for maintenance and the operation was
+    /// refused rather than queued.
+    #[error("gossip paused: {0}")]
+    GossipPaused(String),
+
     /// Invalid bootstrap node address.
     #[error("invalid bootstrap node address: {0}")]
     InvalidBootstrapNode(String),
@@ -414,6 +457,33 @@ pub enum NetworkError {
 /// ```
 pub type NetworkResult<T> = std::result::Result<T, NetworkError>;
 
+/// Failure of [`crate::network::NetworkNode::send_with_retry`] once its
+/// attempts are used up, classified by the last attempt's outcome.
+#[derive(Error, Debug)]
+pub enum SendRetryError {
+    /// The peer was not connected and (re)connecting failed, or the
+    /// address answered as a different peer.
+    #[error("connect to {addr} failed after {attempts} attempt(s): {source}")]
+    ConnectFailed {
+        /// Address that was dialled.
+        addr: std::net::SocketAddr,
+        /// Attempts made, including the first.
+        attempts: u32,
+        /// The last connect error.
+        #[source]
+        source: NetworkError,
+    },
+
+    /// The peer was connected but the send itself failed.
+    #[error("send failed after connecting ({attempts} attempt(s)): {reason}")]
+    SendFailed {
+        /// Attempts made, including the first.
+        attempts: u32,
+        /// The last send error.
+        reason: String,
+    },
+}
+
 /// Errors that can occur during presence operations.
 ///
 /// Covers beacon broadcasting, FOAF discovery queries, event subscriptions,
@@ -1999,6 +1999,7 @@ mod tests {
                 cert_not_after: None,
                 agent_certificate: None,
                 agent_public_key: keypair.public_key().as_bytes().to_vec(),
+                status: None,
             },
         );
         Arc::new(tokio::sync::RwLock::new(cache))
@@ -2863,6 +2864,7 @@ mod tests {
                     cert_not_after: None,
                     agent_certificate: None,
                     agent_public_key: kp.public_key().as_bytes().to_vec(),
+                    status: None,
                 },
             );
         }
@@ -2951,6 +2953,7 @@ mod tests {
                 cert_not_after: None,
                 agent_certificate: None,
                 agent_public_key: Vec::new(), // empty — beacon case
+                status: None,
             },
         );
         let cache = Arc::new(tokio::sync::RwLock::new(cache_map));
@@ -4,16 +4,28 @@
 //! enabling pub/sub messaging and HyParView membership management.
 
 pub mod config;
+pub mod durable;
+pub mod ordering;
+pub mod pause;
 pub mod pubsub;
 pub mod runtime;
+pub mod sharding;
 pub(crate) mod wire;
 
 pub use config::GossipConfig;
+pub use durable::{DurableLogConfig, DurableMessage, DurableSubscription, DurableTopicLog};
+pub use ordering::{SenderOrder, SenderTicket};
+pub use pause::{
+    GossipPauseConfig, GossipPauseStatsSnapshot, PausedIncomingPolicy, PausedPublishPolicy,
+};
 pub use pubsub::{
-    PubSubManager, PubSubMessage, PubSubStats, PubSubStatsSnapshot, SigningContext, Subscription,
+    check_app_topic, PubSubManager, PubSubMessage, PubSubStats, PubSubStatsSnapshot,
+    SignerSelection, SigningContext, Subscription, SubscriptionOverflow, TopicLag,
+    DEFAULT_SEEN_FRAMES_CAPACITY, DEFAULT_SUBSCRIBER_CAPACITY,
 };
 pub use runtime::{
     DispatchQueueDepthSnapshot, DispatchQueueStatsSnapshot, DispatchStreamStats,
     DispatchStreamStatsSnapshot, GossipDispatchStats, GossipDispatchStatsSnapshot, GossipRuntime,
 };
 pub use saorsa_gossip_pubsub::{PubSubStageStatsSnapshot, StageTimingStatsSnapshot};
+pub use sharding::TopicShardingConfig;
@@ -1,5 +1,7 @@
 //! Configuration for the gossip overlay network.
 
+use super::pause::GossipPauseConfig;
+use super::sharding::TopicShardingConfig;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for the gossip overlay network.
@@ -39,10 +41,65 @@ pub struct GossipConfig {
     /// the active worker target up to 32 during overload or restart bursts.
     #[serde(default = "default_dispatch_workers")]
     pub dispatch_workers: usize,
+
+    /// Keep frames from the same peer in arrival order when more than one
+    /// dispatch worker runs (see [`crate::gossip::SenderOrder`]). Frames
+    /// from different peers are still handled concurrently. Turning this
+    /// off lets a worker overtake a slow frame from the same peer.
+    /// Default: true
+    #[serde(default = "default_preserve_sender_order")]
+    pub preserve_sender_order: bool,
+
+    /// Opt-in deterministic topic sharding for pass-through relay traffic.
+    /// Default: disabled (every node relays every topic to every peer).
+    #[serde(default)]
+    pub topic_sharding: TopicShardingConfig,
+
+    /// Policy and buffering bounds applied while the pub/sub data plane is
+    /// paused for maintenance (see [`crate::gossip::pause`]).
+    /// Default: buffer inbound, queue publishes, 1024 entries each.
+    #[serde(default)]
+    pub pause: GossipPauseConfig,
+
+    /// Subscriptions to a single topic above which a warning is logged, to
+    /// surface a subscribe leak (e.g. in a retry loop). 0 disables.
+    /// Default: 64
+    #[serde(default = "default_subscription_warn_threshold")]
+    pub subscription_warn_threshold: usize,
+
+    /// Distinct frames each subscription remembers to drop exact repeats
+    /// of a delivered payload (see
+    /// [`crate::gossip::PubSubManager::subscribe_topic_id`]).
+    /// 0 disables.
+    /// Default: 8192
+    #[serde(default = "default_seen_frames_capacity")]
+    pub seen_frames_capacity: usize,
+
+    /// Compress pub/sub payloads of at least `compression_threshold` bytes
+    /// with zstd (wire version 4). Peers older than v4 cannot decode
+    /// compressed frames, so this stays off until the mesh has upgraded.
+    /// Default: false
+    #[serde(default)]
+    pub compression: bool,
+
+    /// Payload size in bytes from which publishes are compressed when
+    /// `compression` is on. Smaller payloads are sent as-is.
+    /// Default: 1024
+    #[serde(default = "default_compression_threshold")]
+    pub compression_threshold: usize,
+
+    /// Largest pub/sub payload in bytes. Local publishes over it fail with
+    /// `PayloadTooLarge`; inbound frames over it (plus framing overhead) are
+    /// dropped before PlumTree, so they are never rebroadcast. At most 4 MiB.
+    /// Default: 1 MiB
+    #[serde(default = "default_max_message_bytes")]
+    pub max_message_bytes: usize,
 }
 
 const MAX_DISPATCH_WORKERS: usize = 32;
 
+const MAX_SEEN_FRAMES_CAPACITY: usize = 1 << 20;
+
 const fn default_active_view_size() -> usize {
     6
 }
@@ -63,6 +120,26 @@ const fn default_dispatch_workers() -> usize {
     1
 }
 
+const fn default_preserve_sender_order() -> bool {
+    true
+}
+
+const fn default_subscription_warn_threshold() -> usize {
+    64
+}
+
+const fn default_seen_frames_capacity() -> usize {
+    super::pubsub::DEFAULT_SEEN_FRAMES_CAPACITY
+}
+
+const fn default_max_message_bytes() -> usize {
+    super::pubsub::DEFAULT_MAX_MESSAGE_BYTES
+}
+
+const fn default_compression_threshold() -> usize {
+    super::pubsub::DEFAULT_COMPRESSION_THRESHOLD
+}
+
 impl Default for GossipConfig {
     fn default() -> Self {
         Self {
@@ -71,6 +148,14 @@ impl Default for GossipConfig {
             arwl: 6,
             prwl: 3,
             dispatch_workers: default_dispatch_workers(),
+            preserve_sender_order: default_preserve_sender_order(),
+            topic_sharding: TopicShardingConfig::default(),
+            pause: GossipPauseConfig::default(),
+            subscription_warn_threshold: default_subscription_warn_threshold(),
+            seen_frames_capacity: default_seen_frames_capacity(),
+            compression: false,
+            compression_threshold: default_compression_threshold(),
+            max_message_bytes: default_max_message_bytes(),
         }
     }
 }
@@ -98,6 +183,21 @@ impl GossipConfig {
                 "dispatch_workers must be <= {MAX_DISPATCH_WORKERS}"
             ));
         }
+        if self.seen_frames_capacity > MAX_SEEN_FRAMES_CAPACITY {
+            return Err(format!(
+                "seen_frames_capacity must be <= {MAX_SEEN_FRAMES_CAPACITY}"
+            ));
+        }
+        if self.max_message_bytes == 0
+            || self.max_message_bytes > super::pubsub::MAX_PUBLISH_PAYLOAD_BYTES
+        {
+            return Err(format!(
+                "max_message_bytes must be between 1 and {}",
+                super::pubsub::MAX_PUBLISH_PAYLOAD_BYTES
+            ));
+        }
+        self.topic_sharding.validate()?;
+        self.pause.validate()?;
         Ok(())
     }
 }
@@ -114,6 +214,13 @@ mod tests {
         assert_eq!(config.arwl, 6);
         assert_eq!(config.prwl, 3);
         assert_eq!(config.dispatch_workers, 1);
+        assert!(config.preserve_sender_order);
+        assert!(!config.topic_sharding.enabled);
+        assert_eq!(config.subscription_warn_threshold, 64);
+        assert_eq!(config.seen_frames_capacity, 8192);
+        assert!(!config.compression);
+        assert_eq!(config.compression_threshold, 1024);
+        assert_eq!(config.max_message_bytes, 1024 * 1024);
     }
 
     #[test]
@@ -138,6 +245,20 @@ mod tests {
             ..Default::default()
         };
         assert!(invalid.validate().is_err());
+
+        let invalid = GossipConfig {
+            seen_frames_capacity: MAX_SEEN_FRAMES_CAPACITY + 1,
+            ..Default::default()
+        };
+        assert!(invalid.validate().is_err());
+
+        for max_message_bytes in [0, super::super::pubsub::MAX_PUBLISH_PAYLOAD_BYTES + 1] {
+            let invalid = GossipConfig {
+                max_message_bytes,
+                ..Default::default()
+            };
+            assert!(invalid.validate().is_err());
+        }
     }
 
     #[test]
@@ -165,5 +286,24 @@ mod tests {
         assert_eq!(cfg.arwl, defaults.arwl);
         assert_eq!(cfg.prwl, defaults.prwl);
         assert_eq!(cfg.dispatch_workers, defaults.dispatch_workers);
+        assert_eq!(cfg.topic_sharding, defaults.topic_sharding);
+        assert_eq!(cfg.pause, defaults.pause);
+        assert_eq!(cfg.compression, defaults.compression);
+        assert_eq!(cfg.compression_threshold, defaults.compression_threshold);
+    }
+
+    #[test]
+    fn topic_sharding_section_parses_and_validates() {
+        let cfg: GossipConfig =
+            toml::from_str("[topic_sharding]\nenabled = true\nshard_count = 16")
+                .expect("sharding TOML");
+        assert!(cfg.topic_sharding.enabled);
+        assert_eq!(cfg.topic_sharding.shard_count, 16);
+        assert_eq!(
+            cfg.topic_sharding.shards_per_peer,
+            TopicShardingConfig::default().shards_per_peer
+        );
+        // Default shards_per_peer (32) exceeds 16 shards — must be rejected.
+        assert!(cfg.validate().is_err());
     }
 }
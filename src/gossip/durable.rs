@@ -0,0 +1,490 @@
+//! Opt-in per-topic persistence for local at-least-once delivery.
+//!
+//! [`crate::Agent::subscribe_durable`] appends every message delivered on a
+//! topic to a bounded, append-only log under the agent's data directory
+//! before handing it to the application. Each record carries a monotonic
+//! sequence number; an application that remembers the last sequence it
+//! processed can pass it back as the `since` cursor after a restart and
+//! replay everything it missed.
+//!
+//! This is a **local** guarantee only: messages the mesh never delivered to
+//! this node are not recovered. Records are JSON lines; a torn trailing line
+//! left by a crash is skipped on reopen. The log is bounded by
+//! [`DurableLogConfig::max_bytes`] and [`DurableLogConfig::max_age_secs`],
+//! oldest records first. The highest sequence number issued is kept in a
+//! `.seq` file beside the log, so numbering continues after eviction
+//! empties the log and a stale cursor never matches a new record.
+
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use base64::Engine as _;
+use serde::{Deserialize, Serialize};
+
+use super::pubsub::{PubSubMessage, Subscription};
+use crate::identity::AgentId;
+
+/// Directory (under the agent data dir) holding the per-topic logs.
+pub const DURABLE_TOPICS_DIR: &str = "durable_topics";
+
+/// Bounds for a durable topic log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DurableLogConfig {
+    /// Maximum on-disk size of one topic log in bytes.
+    /// Default: 16 MiB
+    #[serde(default = "default_max_bytes")]
+    pub max_bytes: u64,
+
+    /// Records older than this are dropped at compaction; 0 disables age
+    /// eviction.
+    /// Default: 7 days
+    #[serde(default = "default_max_age_secs")]
+    pub max_age_secs: u64,
+}
+
+const fn default_max_bytes() -> u64 {
+    16 * 1024 * 1024
+}
+
+const fn default_max_age_secs() -> u64 {
+    7 * 24 * 60 * 60
+}
+
+impl Default for DurableLogConfig {
+    fn default() -> Self {
+        Self {
+            max_bytes: default_max_bytes(),
+            max_age_secs: default_max_age_secs(),
+        }
+    }
+}
+
+/// A message read back from (or just written to) a durable topic log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DurableMessage {
+    /// Monotonic per-topic sequence number; pass it as `since` to resume.
+    pub seq: u64,
+    /// Unix milliseconds at which the record was appended.
+    pub received_at_ms: u64,
+    /// The topic this message was published on.
+    pub topic: String,
+    /// The message payload.
+    pub payload: bytes::Bytes,
+    /// Sender's AgentId (`None` for unsigned legacy v1 messages).
+    pub sender: Option<AgentId>,
+    /// Whether the ML-DSA-65 signature was verified at receive time.
+    pub verified: bool,
+}
+
+/// On-disk line format.
+#[derive(Serialize, Deserialize)]
+struct LogRecord {
+    seq: u64,
+    received_at_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sender: Option<AgentId>,
+    verified: bool,
+    payload: String,
+}
+
+impl LogRecord {
+    fn into_message(self, topic: &str) -> Option<DurableMessage> {
+        let payload = base64::engine::general_purpose::STANDARD
+            .decode(self.payload)
+            .ok()?;
+        Some(DurableMessage {
+            seq: self.seq,
+            received_at_ms: self.received_at_ms,
+            topic: topic.to_string(),
+            payload: bytes::Bytes::from(payload),
+            sender: self.sender,
+            verified: self.verified,
+        })
+    }
+}
+
+/// Bounded append-only log for a single topic.
+pub struct DurableTopicLog {
+    topic: String,
+    path: PathBuf,
+    /// High-water sequence file, rewritten at every compaction.
+    seq_path: PathBuf,
+    config: DurableLogConfig,
+    file: File,
+    size: u64,
+    next_seq: u64,
+}
+
+impl DurableTopicLog {
+    /// Open (or create) the log for `topic` inside `dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the directory or file cannot be created or read.
+    pub fn open(dir: &Path, topic: &str, config: DurableLogConfig) -> io::Result<Self> {
+        std::fs::create_dir_all(dir)?;
+        let name = hex::encode(blake3::hash(topic.as_bytes()).as_bytes());
+        let path = dir.join(format!("{name}.log"));
+        let seq_path = dir.join(format!("{name}.seq"));
+        let last_seq = read_records(&path)?
+            .back()
+            .map(|record| record.seq)
+            .unwrap_or(0)
+            .max(read_high_water(&seq_path)?);
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+        let mut log = Self {
+            topic: topic.to_string(),
+            path,
+            seq_path,
+            config,
+            file,
+            size,
+            next_seq: last_seq + 1,
+        };
+        log.compact()?;
+        Ok(log)
+    }
+
+    /// Path of the backing file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Sequence number of the most recently appended record (0 if none).
+    #[must_use]
+    pub fn last_seq(&self) -> u64 {
+        self.next_seq - 1
+    }
+
+    /// Append a delivered message and return its durable form.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the record cannot be written.
+    pub fn append(&mut self, msg: &PubSubMessage) -> io::Result<DurableMessage> {
+        let record = LogRecord {
+            seq: self.next_seq,
+            received_at_ms: now_ms(),
+            sender: msg.sender,
+            verified: msg.verified,
+            payload: base64::engine::general_purpose::STANDARD.encode(&msg.payload),
+        };
+        let mut line = serde_json::to_vec(&record).map_err(io::Error::other)?;
+        line.push(b'\n');
+        self.file.write_all(&line)?;
+        self.file.flush()?;
+        self.size += line.len() as u64;
+        self.next_seq += 1;
+        let durable = DurableMessage {
+            seq: record.seq,
+            received_at_ms: record.received_at_ms,
+            topic: msg.topic.clone(),
+            payload: msg.payload.clone(),
+            sender: msg.sender,
+            verified: msg.verified,
+        };
+        if self.size > self.config.max_bytes {
+            self.compact()?;
+        }
+        Ok(durable)
+    }
+
+    /// All retained records with `seq > since`, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the log cannot be read.
+    pub fn since(&self, since: u64) -> io::Result<Vec<DurableMessage>> {
+        Ok(read_records(&self.path)?
+            .into_iter()
+            .filter(|record| record.seq > since)
+            .filter_map(|record| record.into_message(&self.topic))
+            .collect())
+    }
+
+    /// Drop expired records and trim to half of `max_bytes` once the budget
+    /// is exceeded, so compaction amortises over many appends.
+    fn compact(&mut self) -> io::Result<()> {
+        let now = now_ms();
+        let max_age_ms = self.config.max_age_secs.saturating_mul(1000);
+        let expired = |record: &LogRecord| {
+            max_age_ms > 0 && now.saturating_sub(record.received_at_ms) > max_age_ms
+        };
+        let records = read_records(&self.path)?;
+        let has_expired = records.front().is_some_and(expired);
+        if !has_expired && self.size <= self.config.max_bytes {
+            return Ok(());
+        }
+
+        let mut lines: VecDeque<Vec<u8>> = VecDeque::with_capacity(records.len());
+        let mut total: u64 = 0;
+        for record in records.into_iter().filter(|record| !expired(record)) {
+            let mut line = serde_json::to_vec(&record).map_err(io::Error::other)?;
+            line.push(b'\n');
+            total += line.len() as u64;
+            lines.push_back(line);
+        }
+        let target = if total > self.config.max_bytes {
+            self.config.max_bytes / 2
+        } else {
+            self.config.max_bytes
+        };
+        // Always keep the newest record so sequence numbers survive a reopen.
+        while total > target && lines.len() > 1 {
+            match lines.pop_front() {
+                Some(line) => total -= line.len() as u64,
+                None => break,
+            }
+        }
+
+        // Record the high-water mark before any record leaves the log: age
+        // eviction can empty it, and numbering must not restart at 1.
+        let tmp = self.seq_path.with_extension("seq.tmp");
+        {
+            let mut out = File::create(&tmp)?;
+            out.write_all(self.last_seq().to_string().as_bytes())?;
+            out.sync_all()?;
+        }
+        std::fs::rename(&tmp, &self.seq_path)?;
+
+        let tmp = self.path.with_extension("log.tmp");
+        {
+            let mut out = File::create(&tmp)?;
+            for line in &lines {
+                out.write_all(line)?;
+            }
+            out.sync_all()?;
+        }
+        std::fs::rename(&tmp, &self.path)?;
+        self.file = OpenOptions::new().append(true).open(&self.path)?;
+        self.size = total;
+        Ok(())
+    }
+}
+
+/// A topic subscription whose deliveries are persisted before being
+/// returned, preceded by a replay of the log from the caller's cursor.
+pub struct DurableSubscription {
+    inner: Subscription,
+    log: DurableTopicLog,
+    replay: VecDeque<DurableMessage>,
+}
+
+impl DurableSubscription {
+    pub(crate) fn new(inner: Subscription, log: DurableTopicLog, since: u64) -> io::Result<Self> {
+        let replay = log.since(since)?.into();
+        Ok(Self { inner, log, replay })
+    }
+
+    /// Get the topic for this subscription.
+    #[must_use]
+    pub fn topic(&self) -> &str {
+        self.inner.topic()
+    }
+
+    /// Number of replayed messages not yet returned by [`Self::recv`].
+    #[must_use]
+    pub fn pending_replay(&self) -> usize {
+        self.replay.len()
+    }
+
+    /// Receive the next message: replayed records first, then live ones.
+    ///
+    /// Live messages are appended to the log before being returned. If the
+    /// append fails the message is still delivered (with `seq == 0`) and the
+    /// failure is logged, so a full disk degrades to plain subscription
+    /// semantics rather than stalling delivery.
+    pub async fn recv(&mut self) -> Option<DurableMessage> {
+        if let Some(msg) = self.replay.pop_front() {
+            return Some(msg);
+        }
+        let msg = self.inner.recv().await?;
+        match self.log.append(&msg) {
+            Ok(durable) => Some(durable),
+            Err(e) => {
+                tracing::warn!(topic = %msg.topic, "durable topic log append failed: {e}");
+                Some(DurableMessage {
+                    seq: 0,
+                    received_at_ms: now_ms(),
+                    topic: msg.topic,
+                    payload: msg.payload,
+                    sender: msg.sender,
+                    verified: msg.verified,
+                })
+            }
+        }
+    }
+}
+
+fn read_records(path: &Path) -> io::Result<VecDeque<LogRecord>> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(VecDeque::new()),
+        Err(e) => return Err(e),
+    };
+    let mut records = VecDeque::new();
+    for line in BufReader::new(file).lines() {
+        // A torn final line from a crash mid-append is skipped, not fatal.
+        if let Ok(record) = serde_json::from_str::<LogRecord>(&line?) {
+            records.push_back(record);
+        }
+    }
+    Ok(records)
+}
+
+/// Highest sequence number recorded in `path`, 0 if absent or unreadable.
+fn read_high_water(path: &Path) -> io::Result<u64> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => Ok(contents.trim().parse().unwrap_or(0)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(0),
+        Err(e) => Err(e),
+    }
+}
+
+fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(topic: &str, payload: &str) -> PubSubMessage {
+        PubSubMessage {
+            id: [0; 32],
+            topic: topic.to_string(),
+            payload: bytes::Bytes::from(payload.to_string()),
+            sender: Some(AgentId([7u8; 32])),
+            sender_public_key: None,
+            verified: true,
+            trust_level: None,
+            on_behalf_of: None,
+        }
+    }
+
+    #[test]
+    fn replays_from_cursor_after_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let topic = "app/events";
+        let mut log =
+            DurableTopicLog::open(dir.path(), topic, DurableLogConfig::default()).unwrap();
+        for i in 0..5 {
+            let stored = log.append(&message(topic, &format!("m{i}"))).unwrap();
+            assert_eq!(stored.seq, i + 1);
+        }
+        // The app processed up to seq 2, then the process "restarted".
+        drop(log);
+
+        let mut log =
+            DurableTopicLog::open(dir.path(), topic, DurableLogConfig::default()).unwrap();
+        let replay = log.since(2).unwrap();
+        let payloads: Vec<_> = replay.iter().map(|m| m.payload.clone()).collect();
+        assert_eq!(payloads, vec!["m2", "m3", "m4"]);
+        assert!(replay.iter().all(|m| m.sender == Some(AgentId([7u8; 32]))));
+        assert!(replay.iter().all(|m| m.topic == topic));
+
+        // Sequence numbers continue across restarts so cursors stay valid.
+        assert_eq!(log.append(&message(topic, "m5")).unwrap().seq, 6);
+    }
+
+    #[test]
+    fn torn_trailing_line_is_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut log = DurableTopicLog::open(dir.path(), "t", DurableLogConfig::default()).unwrap();
+        log.append(&message("t", "ok")).unwrap();
+        let path = log.path().to_path_buf();
+        drop(log);
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"seq\":2,\"rece").unwrap();
+
+        let log = DurableTopicLog::open(dir.path(), "t", DurableLogConfig::default()).unwrap();
+        assert_eq!(log.last_seq(), 1);
+        assert_eq!(log.since(0).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn size_bound_evicts_oldest_records() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = DurableLogConfig {
+            max_bytes: 1024,
+            max_age_secs: 0,
+        };
+        let mut log = DurableTopicLog::open(dir.path(), "t", config).unwrap();
+        for i in 0..100 {
+            log.append(&message("t", &format!("payload-{i:04}")))
+                .unwrap();
+        }
+        assert!(std::fs::metadata(log.path()).unwrap().len() <= 1024);
+        let retained = log.since(0).unwrap();
+        assert!(!retained.is_empty());
+        // Newest survives, oldest is evicted.
+        assert_eq!(retained.last().unwrap().seq, 100);
+        assert!(retained.first().unwrap().seq > 1);
+    }
+
+    #[test]
+    fn age_bound_drops_expired_records_on_open() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut log = DurableTopicLog::open(dir.path(), "t", DurableLogConfig::default()).unwrap();
+        log.append(&message("t", "fresh")).unwrap();
+        let path = log.path().to_path_buf();
+        drop(log);
+        let stale = LogRecord {
+            seq: 0,
+            received_at_ms: 1,
+            sender: None,
+            verified: false,
+            payload: String::new(),
+        };
+        let mut contents = serde_json::to_vec(&stale).unwrap();
+        contents.push(b'\n');
+        contents.extend(std::fs::read(&path).unwrap());
+        std::fs::write(&path, contents).unwrap();
+
+        let log = DurableTopicLog::open(dir.path(), "t", DurableLogConfig::default()).unwrap();
+        let retained = log.since(0).unwrap();
+        assert_eq!(retained.len(), 1);
+        assert_eq!(retained[0].payload, "fresh");
+    }
+
+    #[test]
+    fn sequence_continues_after_every_record_expires() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut log = DurableTopicLog::open(dir.path(), "t", DurableLogConfig::default()).unwrap();
+        for i in 0..3 {
+            log.append(&message("t", &format!("m{i}"))).unwrap();
+        }
+        let path = log.path().to_path_buf();
+        drop(log);
+        // Age every record past the bound.
+        let aged: Vec<u8> = read_records(&path)
+            .unwrap()
+            .into_iter()
+            .flat_map(|mut record| {
+                record.received_at_ms = 1;
+                let mut line = serde_json::to_vec(&record).unwrap();
+                line.push(b'\n');
+                line
+            })
+            .collect();
+        std::fs::write(&path, aged).unwrap();
+
+        // The first reopen evicts everything; the next must still not reuse
+        // a sequence number an app may hold as its cursor.
+        let log = DurableTopicLog::open(dir.path(), "t", DurableLogConfig::default()).unwrap();
+        assert!(log.since(0).unwrap().is_empty());
+        assert_eq!(log.last_seq(), 3);
+        drop(log);
+        let mut log = DurableTopicLog::open(dir.path(), "t", DurableLogConfig::default()).unwrap();
+        assert_eq!(log.last_seq(), 3);
+        assert_eq!(log.append(&message("t", "m3")).unwrap().seq, 4);
+        assert_eq!(log.since(3).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,225 @@
+//! Per-sender ordering for concurrent PubSub dispatch.
+//!
+//! With more than one PubSub dispatch worker (see
+//! [`super::GossipConfig::dispatch_workers`]), frames are verified and
+//! decoded in parallel. [`SenderOrder`] keeps frames from the same peer in
+//! arrival order while frames from different peers still run concurrently:
+//! each worker takes a ticket for the frame's sender in the same critical
+//! section as the dequeue, then waits until every earlier frame from that
+//! sender has been handled.
+
+use std::collections::{BTreeSet, HashMap};
+use std::future::Future;
+use std::sync::Mutex;
+
+use saorsa_gossip_types::PeerId;
+use tokio::sync::Notify;
+
+/// Progress of one sender's tickets.
+#[derive(Default)]
+struct Turn {
+    /// Tickets handed out so far.
+    issued: u64,
+    /// Tickets below this have been handled.
+    served: u64,
+    /// Tickets above `served` that finished (or were abandoned) early.
+    finished: BTreeSet<u64>,
+}
+
+/// Ticket dispenser preserving per-sender order across dispatch workers.
+#[derive(Default)]
+pub struct SenderOrder {
+    /// Serializes dequeue + ticket so tickets follow queue order.
+    dequeue: tokio::sync::Mutex<()>,
+    turns: Mutex<HashMap<PeerId, Turn>>,
+    advanced: Notify,
+}
+
+/// A frame's place in its sender's order. Dropping it (after handling, or
+/// on cancellation) lets the sender's next frame proceed.
+#[must_use = "dropping a ticket immediately gives up the frame's turn"]
+pub struct SenderTicket<'a> {
+    order: &'a SenderOrder,
+    peer: PeerId,
+    seq: u64,
+}
+
+impl SenderOrder {
+    /// Create an empty dispenser.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Await `recv` and ticket the frame it yields, so tickets for a sender
+    /// are issued in the order its frames left the queue.
+    ///
+    /// # Errors
+    ///
+    /// Passes through the error from `recv`.
+    pub async fn dequeue<T, E>(
+        &self,
+        recv: impl Future<Output = Result<(PeerId, T), E>>,
+    ) -> Result<(PeerId, T, SenderTicket<'_>), E> {
+        let _turn = self.dequeue.lock().await;
+        let (peer, frame) = recv.await?;
+        let seq = {
+            let mut turns = self.turns.lock().unwrap_or_else(|p| p.into_inner());
+            let turn = turns.entry(peer).or_default();
+            let seq = turn.issued;
+            turn.issued += 1;
+            seq
+        };
+        Ok((
+            peer,
+            frame,
+            SenderTicket {
+                order: self,
+                peer,
+                seq,
+            },
+        ))
+    }
+
+    /// Senders with frames ticketed but not yet handled.
+    #[must_use]
+    pub fn senders_in_flight(&self) -> usize {
+        self.turns.lock().unwrap_or_else(|p| p.into_inner()).len()
+    }
+
+    fn is_turn(&self, peer: &PeerId, seq: u64) -> bool {
+        self.turns
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .get(peer)
+            .is_none_or(|turn| turn.served == seq)
+    }
+
+    fn finish(&self, peer: PeerId, seq: u64) {
+        {
+            let mut turns = self.turns.lock().unwrap_or_else(|p| p.into_inner());
+            let Some(turn) = turns.get_mut(&peer) else {
+                return;
+            };
+            if seq != turn.served {
+                turn.finished.insert(seq);
+                return;
+            }
+            turn.served += 1;
+            while turn.finished.remove(&turn.served) {
+                turn.served += 1;
+            }
+            if turn.served == turn.issued {
+                turns.remove(&peer);
+            }
+        }
+        self.advanced.notify_waiters();
+    }
+}
+
+impl SenderTicket<'_> {
+    /// Wait until every earlier frame from this sender has been handled.
+    pub async fn wait_turn(&self) {
+        loop {
+            let notified = self.order.advanced.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            if self.order.is_turn(&self.peer, self.seq) {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+impl Drop for SenderTicket<'_> {
+    fn drop(&mut self) {
+        self.order.finish(self.peer, self.seq);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_workers_preserve_per_sender_order() {
+        const SENDERS: u8 = 4;
+        const PER_SENDER: u32 = 50;
+
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        for seq in 0..PER_SENDER {
+            for sender in 0..SENDERS {
+                tx.send((PeerId::new([sender; 32]), seq)).expect("queue");
+            }
+        }
+        drop(tx);
+
+        let rx = Arc::new(tokio::sync::Mutex::new(rx));
+        let order = Arc::new(SenderOrder::new());
+        let handled = Arc::new(Mutex::new(HashMap::<PeerId, Vec<u32>>::new()));
+        let mut workers = Vec::new();
+        for _ in 0..8 {
+            let (rx, order, handled) = (Arc::clone(&rx), Arc::clone(&order), Arc::clone(&handled));
+            workers.push(tokio::spawn(async move {
+                loop {
+                    let recv = async { rx.lock().await.recv().await.ok_or(()) };
+                    let Ok((peer, seq, ticket)) = order.dequeue(recv).await else {
+                        break;
+                    };
+                    ticket.wait_turn().await;
+                    // Uneven work so a later frame would overtake an earlier
+                    // one without the ticket.
+                    let delay = u64::from((seq * 7 + u32::from(peer.as_bytes()[0])) % 5);
+                    tokio::time::sleep(Duration::from_millis(delay)).await;
+                    handled
+                        .lock()
+                        .expect("lock")
+                        .entry(peer)
+                        .or_default()
+                        .push(seq);
+                }
+            }));
+        }
+        for worker in workers {
+            worker.await.expect("worker");
+        }
+
+        let handled = handled.lock().expect("lock");
+        assert_eq!(handled.len(), usize::from(SENDERS));
+        for seqs in handled.values() {
+            assert_eq!(*seqs, (0..PER_SENDER).collect::<Vec<_>>());
+        }
+        assert_eq!(order.senders_in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn abandoned_ticket_releases_later_frames() {
+        let order = SenderOrder::new();
+        let peer = PeerId::new([9; 32]);
+        let (_, (), first) = order
+            .dequeue(async { Ok::<_, ()>((peer, ())) })
+            .await
+            .expect("first");
+        let (_, (), second) = order
+            .dequeue(async { Ok::<_, ()>((peer, ())) })
+            .await
+            .expect("second");
+
+        // The second frame waits for the first, which is cancelled (e.g. a
+        // worker timed out) rather than handled.
+        assert!(
+            tokio::time::timeout(Duration::from_millis(20), second.wait_turn())
+                .await
+                .is_err()
+        );
+        drop(first);
+        tokio::time::timeout(Duration::from_secs(1), second.wait_turn())
+            .await
+            .expect("second frame's turn");
+        drop(second);
+        assert_eq!(order.senders_in_flight(), 0);
+    }
+}
@@ -0,0 +1,279 @@
+//! Maintenance pause for the pub/sub data plane.
+//!
+//! [`crate::gossip::GossipRuntime::pause`] stops pub/sub processing without
+//! dropping connections: peers stay connected and HyParView/SWIM keep
+//! running, but inbound PubSub frames and publishes are held or refused
+//! according to [`GossipPauseConfig`] until
+//! [`crate::gossip::GossipRuntime::resume`].
+//!
+//! # Buffering bounds
+//!
+//! Held inbound frames and queued publishes live in two separate queues,
+//! each capped at [`GossipPauseConfig::max_buffered`] entries (default
+//! 1024, at most [`MAX_PAUSE_BUFFERED`]). Once a queue is full, further
+//! inbound frames are dropped and further publishes are rejected with
+//! [`NetworkError::GossipPaused`] even under
+//! [`PausedPublishPolicy::Queue`], so a pause that is never lifted cannot
+//! grow memory without limit. Every entry is bounded by
+//! [`super::GossipConfig::max_message_bytes`] (plus framing overhead for
+//! inbound frames).
+//!
+//! On resume, held inbound frames are replayed first, then queued
+//! publishes, each in arrival order. Traffic arriving while the replay runs
+//! is processed immediately and may interleave with it.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use bytes::Bytes;
+use saorsa_gossip_types::{PeerId, TopicId};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{NetworkError, NetworkResult};
+use crate::identity::AgentCertificate;
+
+/// Upper bound accepted for [`GossipPauseConfig::max_buffered`].
+pub const MAX_PAUSE_BUFFERED: usize = 65_536;
+
+/// What happens to inbound PubSub frames while paused.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PausedIncomingPolicy {
+    /// Hold frames (up to the bound) and process them on resume.
+    #[default]
+    Buffer,
+    /// Discard frames; PlumTree anti-entropy may still repair the gap later.
+    Drop,
+}
+
+/// What happens to publishes while paused.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PausedPublishPolicy {
+    /// Accept the publish (returns `Ok`) and send it on resume.
+    #[default]
+    Queue,
+    /// Fail the publish with [`NetworkError::GossipPaused`].
+    Reject,
+}
+
+/// Policy applied while the pub/sub data plane is paused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GossipPauseConfig {
+    /// Handling of inbound PubSub frames.
+    /// Default: buffer
+    #[serde(default)]
+    pub incoming: PausedIncomingPolicy,
+
+    /// Handling of publishes.
+    /// Default: queue
+    #[serde(default)]
+    pub publish: PausedPublishPolicy,
+
+    /// Capacity of each of the inbound and publish queues.
+    /// Default: 1024
+    #[serde(default = "default_max_buffered")]
+    pub max_buffered: usize,
+}
+
+const fn default_max_buffered() -> usize {
+    1024
+}
+
+impl Default for GossipPauseConfig {
+    fn default() -> Self {
+        Self {
+            incoming: PausedIncomingPolicy::default(),
+            publish: PausedPublishPolicy::default(),
+            max_buffered: default_max_buffered(),
+        }
+    }
+}
+
+impl GossipPauseConfig {
+    /// Validate configuration parameters.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_buffered > MAX_PAUSE_BUFFERED {
+            return Err(format!(
+                "pause.max_buffered must be <= {MAX_PAUSE_BUFFERED}"
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Counters describing what happened while the data plane was paused.
+#[derive(Debug, Default)]
+pub struct GossipPauseStats {
+    /// Number of pause → resume cycles started.
+    pub pauses: AtomicU64,
+    /// Inbound frames held for replay.
+    pub incoming_buffered: AtomicU64,
+    /// Inbound frames discarded (drop policy or full buffer).
+    pub incoming_dropped: AtomicU64,
+    /// Held inbound frames processed on resume.
+    pub incoming_replayed: AtomicU64,
+    /// Publishes accepted into the queue.
+    pub publish_queued: AtomicU64,
+    /// Publishes refused (reject policy or full queue).
+    pub publish_rejected: AtomicU64,
+    /// Queued publishes sent on resume.
+    pub publish_replayed: AtomicU64,
+}
+
+/// Snapshot of [`GossipPauseStats`] for JSON serialization.
+#[derive(Debug, Clone, Serialize)]
+pub struct GossipPauseStatsSnapshot {
+    pub paused: bool,
+    pub pauses: u64,
+    pub incoming_buffered: u64,
+    pub incoming_dropped: u64,
+    pub incoming_replayed: u64,
+    pub publish_queued: u64,
+    pub publish_rejected: u64,
+    pub publish_replayed: u64,
+}
+
+/// A publish accepted while paused.
+pub(crate) struct QueuedPublish {
+    pub(crate) topic: String,
+    pub(crate) topic_id: TopicId,
+    pub(crate) payload: Bytes,
+    /// Certificate to embed for a publish on behalf of a user.
+    pub(crate) certificate: Option<AgentCertificate>,
+}
+
+/// Frames and publishes released by [`PauseGate::resume`].
+pub(crate) struct HeldTraffic {
+    pub(crate) incoming: VecDeque<(PeerId, Bytes)>,
+    pub(crate) publishes: VecDeque<QueuedPublish>,
+}
+
+/// Pause flag plus the bounded queues it fills.
+///
+/// The flag is re-checked under each queue's lock and cleared under both,
+/// so nothing can be queued after [`Self::resume`] has drained the queues.
+#[derive(Default)]
+pub(crate) struct PauseGate {
+    paused: AtomicBool,
+    incoming: Mutex<VecDeque<(PeerId, Bytes)>>,
+    publishes: Mutex<VecDeque<QueuedPublish>>,
+    stats: GossipPauseStats,
+}
+
+impl PauseGate {
+    pub(crate) fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Acquire)
+    }
+
+    /// Start holding traffic. Returns `false` if already paused.
+    pub(crate) fn pause(&self) -> bool {
+        let started = !self.paused.swap(true, Ordering::AcqRel);
+        if started {
+            self.stats.pauses.fetch_add(1, Ordering::Relaxed);
+        }
+        started
+    }
+
+    /// Stop holding traffic and hand back everything held, or `None` if the
+    /// gate was not paused.
+    pub(crate) fn resume(&self) -> Option<HeldTraffic> {
+        let mut incoming = self
+            .incoming
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut publishes = self
+            .publishes
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if !self.paused.swap(false, Ordering::AcqRel) {
+            return None;
+        }
+        Some(HeldTraffic {
+            incoming: std::mem::take(&mut *incoming),
+            publishes: std::mem::take(&mut *publishes),
+        })
+    }
+
+    /// Apply the inbound policy. Returns the frame back when it should be
+    /// processed now (the gate is open).
+    pub(crate) fn admit_incoming(
+        &self,
+        config: &GossipPauseConfig,
+        peer: PeerId,
+        data: Bytes,
+    ) -> Option<(PeerId, Bytes)> {
+        if !self.is_paused() {
+            return Some((peer, data));
+        }
+        let mut queue = self
+            .incoming
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if !self.is_paused() {
+            return Some((peer, data));
+        }
+        if config.incoming == PausedIncomingPolicy::Buffer && queue.len() < config.max_buffered {
+            queue.push_back((peer, data));
+            self.stats.incoming_buffered.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.stats.incoming_dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        None
+    }
+
+    /// Apply the publish policy. `Ok(Some(_))` hands the publish back for
+    /// immediate sending; `Ok(None)` means it was queued.
+    pub(crate) fn admit_publish(
+        &self,
+        config: &GossipPauseConfig,
+        publish: QueuedPublish,
+    ) -> NetworkResult<Option<QueuedPublish>> {
+        if !self.is_paused() {
+            return Ok(Some(publish));
+        }
+        let mut queue = self
+            .publishes
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if !self.is_paused() {
+            return Ok(Some(publish));
+        }
+        let reason = match config.publish {
+            PausedPublishPolicy::Reject => "publishes are rejected while paused",
+            PausedPublishPolicy::Queue if queue.len() < config.max_buffered => {
+                queue.push_back(publish);
+                self.stats.publish_queued.fetch_add(1, Ordering::Relaxed);
+                return Ok(None);
+            }
+            PausedPublishPolicy::Queue => "publish queue is full",
+        };
+        self.stats.publish_rejected.fetch_add(1, Ordering::Relaxed);
+        Err(NetworkError::GossipPaused(format!(
+            "{reason} (topic '{}')",
+            publish.topic
+        )))
+    }
+
+    pub(crate) fn record_incoming_replayed(&self) {
+        self.stats.incoming_replayed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_publish_replayed(&self) {
+        self.stats.publish_replayed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> GossipPauseStatsSnapshot {
+        GossipPauseStatsSnapshot {
+            paused: self.is_paused(),
+            pauses: self.stats.pauses.load(Ordering::Relaxed),
+            incoming_buffered: self.stats.incoming_buffered.load(Ordering::Relaxed),
+            incoming_dropped: self.stats.incoming_dropped.load(Ordering::Relaxed),
+            incoming_replayed: self.stats.incoming_replayed.load(Ordering::Relaxed),
+            publish_queued: self.stats.publish_queued.load(Ordering::Relaxed),
+            publish_rejected: self.stats.publish_rejected.load(Ordering::Relaxed),
+            publish_replayed: self.stats.publish_replayed.load(Ordering::Relaxed),
+        }
+    }
+}
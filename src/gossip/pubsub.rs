@@ -10,7 +10,9 @@
 
 use crate::contacts::{ContactStore, TrustLevel};
 use crate::error::{NetworkError, NetworkResult};
-use crate::identity::AgentId;
+use crate::gossip::pause::{GossipPauseConfig, GossipPauseStatsSnapshot, PauseGate, QueuedPublish};
+use crate::gossip::sharding::TopicShardingConfig;
+use crate::identity::{AgentCertificate, AgentId, UserId};
 use crate::network::NetworkNode;
 use bytes::Bytes;
 use saorsa_gossip_pubsub::{PlumtreePubSub, PubSub};
@@ -19,6 +21,7 @@ use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::sync::{mpsc, RwLock};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Drop-detection counters for the pub/sub pipeline.
 ///
@@ -47,6 +50,19 @@ pub struct PubSubStats {
     /// message not delivered, but accounted for so decode→delivery deltas stay
     /// meaningful.
     pub subscriber_channel_closed: AtomicU64,
+    /// Subscriptions that pushed a topic past the subscription warning
+    /// threshold (a likely subscribe leak).
+    pub subscription_threshold_exceeded: AtomicU64,
+    /// Decoded messages withheld from a subscriber whose minimum trust level
+    /// the sender did not meet (see [`PubSubManager::subscribe_filtered`]).
+    pub filtered_by_trust: AtomicU64,
+    /// Frames not delivered because the same bytes already reached that
+    /// subscription (see [`PubSubManager::subscribe_topic_id`]).
+    pub incoming_duplicate_dropped: AtomicU64,
+    /// Inbound frames dropped for exceeding the message size limit (see
+    /// [`PubSubManager::set_max_message_bytes`]): before PlumTree, or before
+    /// delivery for a compressed frame whose decompressed size would.
+    pub incoming_oversize_dropped: AtomicU64,
 }
 
 /// Snapshot of [`PubSubStats`] for JSON serialization.
@@ -60,12 +76,17 @@ pub struct PubSubStatsSnapshot {
     pub delivered_to_subscriber: u64,
     pub slow_subscriber_dropped: u64,
     pub subscriber_channel_closed: u64,
+    pub subscription_threshold_exceeded: u64,
+    pub filtered_by_trust: u64,
+    pub incoming_duplicate_dropped: u64,
+    pub incoming_oversize_dropped: u64,
     /// `incoming_total - incoming_decoded - incoming_decode_failed` — messages
     /// that entered the pipeline but did not reach a decision yet (usually 0,
     /// non-zero means a worker panicked or the decode task is blocked).
     pub in_flight_decode: i64,
-    /// `incoming_decoded - delivered_to_subscriber - subscriber_channel_closed`
-    /// — messages decoded but never handed off (drop signal).
+    /// `incoming_decoded - delivered_to_subscriber - subscriber_channel_closed
+    /// - filtered_by_trust` — messages decoded but never handed off (drop
+    /// signal).
     pub decode_to_delivery_drops: i64,
 }
 
@@ -80,11 +101,17 @@ impl PubSubStats {
         let delivered_to_subscriber = self.delivered_to_subscriber.load(Ordering::Relaxed);
         let slow_subscriber_dropped = self.slow_subscriber_dropped.load(Ordering::Relaxed);
         let subscriber_channel_closed = self.subscriber_channel_closed.load(Ordering::Relaxed);
+        let subscription_threshold_exceeded =
+            self.subscription_threshold_exceeded.load(Ordering::Relaxed);
+        let filtered_by_trust = self.filtered_by_trust.load(Ordering::Relaxed);
+        let incoming_duplicate_dropped = self.incoming_duplicate_dropped.load(Ordering::Relaxed);
+        let incoming_oversize_dropped = self.incoming_oversize_dropped.load(Ordering::Relaxed);
         let in_flight_decode =
             incoming_total as i64 - incoming_decoded as i64 - incoming_decode_failed as i64;
         let decode_to_delivery_drops = incoming_decoded as i64
             - delivered_to_subscriber as i64
-            - subscriber_channel_closed as i64;
+            - subscriber_channel_closed as i64
+            - filtered_by_trust as i64;
         PubSubStatsSnapshot {
             publish_total,
             publish_failed,
@@ -94,6 +121,10 @@ impl PubSubStats {
             delivered_to_subscriber,
             slow_subscriber_dropped,
             subscriber_channel_closed,
+            subscription_threshold_exceeded,
+            filtered_by_trust,
+            incoming_duplicate_dropped,
+            incoming_oversize_dropped,
             in_flight_decode,
             decode_to_delivery_drops,
         }
@@ -106,11 +137,64 @@ const MSG_V2_PREFIX: &[u8] = b"x0x-msg-v2";
 /// Version byte for signed messages.
 const VERSION_V2: u8 = 0x02;
 
+/// Domain separation prefix for user-attested message payloads.
+const MSG_V3_PREFIX: &[u8] = b"x0x-msg-v3";
+
+/// Version byte for signed messages carrying a user certificate.
+const VERSION_V3: u8 = 0x03;
+
+/// Largest certificate a v3 frame may embed. A v2 (expiry-carrying)
+/// certificate is about 7.3 KiB of ML-DSA-65 keys and signature; anything
+/// bigger is refused before it is parsed or verified.
+const MAX_V3_CERTIFICATE_BYTES: usize = 8 * 1024;
+
+/// Version byte for a zstd-compressed v1, v2 or v3 frame.
+const VERSION_COMPRESSED: u8 = 0x04;
+
+/// zstd level for compressed frames (zstd's own default).
+const COMPRESSION_LEVEL: i32 = 3;
+
+/// Largest inner frame a compressed frame may declare: the largest payload
+/// plus headroom for the length-prefixed header fields.
+const MAX_DECOMPRESSED_FRAME_BYTES: usize = MAX_PUBLISH_PAYLOAD_BYTES + 512 * 1024;
+
+/// Legacy unsigned wire format (no version byte on the wire).
+pub const WIRE_VERSION_V1: u8 = 0x01;
+
+/// Signed wire format.
+pub const WIRE_VERSION_V2: u8 = VERSION_V2;
+
+/// Signed wire format carrying the user certificate a message is published
+/// on behalf of. Only emitted by [`PubSubManager::publish_on_behalf`].
+pub const WIRE_VERSION_V3: u8 = VERSION_V3;
+
+/// A v1, v2 or v3 frame compressed with zstd. Only emitted once
+/// compression is enabled (see [`PubSubManager::set_compression_threshold`]).
+pub const WIRE_VERSION_V4: u8 = VERSION_COMPRESSED;
+
+/// Newest wire format this build emits and accepts.
+pub const LATEST_WIRE_VERSION: u8 = WIRE_VERSION_V4;
+
+/// Default payload size in bytes from which publishes are compressed.
+pub const DEFAULT_COMPRESSION_THRESHOLD: usize = 1024;
+
+/// Which identity signs a publish (see [`crate::Agent::publish_as`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignerSelection {
+    /// The agent alone — what [`PubSubManager::publish`] does.
+    Agent,
+    /// The agent on behalf of this user. The agent must hold a certificate
+    /// from the user; it is embedded so recipients can attribute the
+    /// message via [`PubSubMessage::on_behalf_of`].
+    User(UserId),
+}
+
 /// Signing context for message authentication.
 ///
 /// Holds the agent identity and key material needed to sign outgoing
 /// pub/sub messages. Created from an [`crate::identity::AgentKeypair`]
-/// and shared via `Arc` across the pub/sub manager.
+/// and shared via `Arc` across the pub/sub manager. The secret key bytes
+/// are zeroed when the context is dropped.
 pub struct SigningContext {
     /// The agent's 32-byte identifier.
     pub agent_id: AgentId,
@@ -130,6 +214,21 @@ impl std::fmt::Debug for SigningContext {
     }
 }
 
+impl Zeroize for SigningContext {
+    /// Scrub the secret key bytes; the public half is left intact.
+    fn zeroize(&mut self) {
+        self.secret_key_bytes.zeroize();
+    }
+}
+
+impl Drop for SigningContext {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SigningContext {}
+
 impl SigningContext {
     /// Create a signing context from an agent keypair.
     pub fn from_keypair(kp: &crate::identity::AgentKeypair) -> Self {
@@ -143,25 +242,33 @@ impl SigningContext {
 
     /// Sign a message using the agent's ML-DSA-65 secret key.
     pub fn sign(&self, message: &[u8]) -> NetworkResult<Vec<u8>> {
-        let secret_key =
+        let mut secret_key =
             ant_quic::MlDsaSecretKey::from_bytes(&self.secret_key_bytes).map_err(|e| {
                 NetworkError::SerializationError(format!("invalid secret key: {:?}", e))
             })?;
         let signature =
-            ant_quic::crypto::raw_public_keys::pqc::sign_with_ml_dsa(&secret_key, message)
-                .map_err(|e| {
-                    NetworkError::SerializationError(format!("signing failed: {:?}", e))
-                })?;
+            ant_quic::crypto::raw_public_keys::pqc::sign_with_ml_dsa(&secret_key, message);
+        secret_key.zeroize();
+        let signature = signature
+            .map_err(|e| NetworkError::SerializationError(format!("signing failed: {:?}", e)))?;
         Ok(signature.as_bytes().to_vec())
     }
 }
 
 /// Message published to the pub/sub system.
 ///
-/// Messages may be signed (v2) or unsigned (v1 legacy). The `sender` and
-/// `verified` fields indicate the authentication state.
+/// Messages may be signed (v2), signed on behalf of a user (v3), or
+/// unsigned (v1 legacy). The `sender`, `verified` and `on_behalf_of` fields
+/// indicate the authentication state.
+///
+/// For a storable envelope with an id and timestamp, convert to
+/// [`crate::network::Message`]; see its docs for when to use which.
 #[derive(Debug, Clone)]
 pub struct PubSubMessage {
+    /// Content-addressed id; see [`PubSubMessage::content_id`]. Copies of
+    /// one message arriving over different gossip paths share it, so
+    /// subscribers can dedup on it.
+    pub id: [u8; 32],
     /// The topic this message was published on.
     pub topic: String,
     /// The message payload.
@@ -174,13 +281,84 @@ pub struct PubSubMessage {
     pub verified: bool,
     /// Trust level from the local contact store (populated during incoming handling).
     pub trust_level: Option<TrustLevel>,
+    /// User the sender published on behalf of: set only for a v3 message
+    /// whose embedded certificate verifies and binds the sender's key.
+    pub on_behalf_of: Option<UserId>,
+}
+
+impl PubSubMessage {
+    /// BLAKE3 id of a message from `sender` on `topic` carrying `payload`.
+    ///
+    /// Only the logical content is hashed — not the wire version, signature
+    /// or certificate — so a message has the same id whether it travelled
+    /// as v1, v2 or v3. Re-publishing identical bytes from the same sender
+    /// on the same topic yields the same id by design.
+    #[must_use]
+    pub fn content_id(sender: Option<&AgentId>, topic: &str, payload: &[u8]) -> [u8; 32] {
+        let mut hasher = blake3::Hasher::new_derive_key("x0x pubsub message id v1");
+        match sender {
+            Some(sender) => hasher.update(&[1]).update(&sender.0),
+            None => hasher.update(&[0]),
+        };
+        hasher.update(&(topic.len() as u64).to_be_bytes());
+        hasher.update(topic.as_bytes());
+        hasher.update(payload);
+        *hasher.finalize().as_bytes()
+    }
+}
+
+/// Backpressure counters for a single [`Subscription`].
+///
+/// Shared between the delivery side and the subscription holder, so the
+/// counters stay readable after the `Subscription` itself has been moved
+/// into a forwarding task (e.g. the daemon's SSE forwarder).
+#[derive(Debug, Default)]
+pub struct SubscriptionOverflow {
+    /// Messages discarded because the subscriber channel was full.
+    dropped: AtomicU64,
+    /// Messages queued in the subscriber channel and not yet received.
+    buffered: AtomicU64,
+}
+
+impl SubscriptionOverflow {
+    /// Messages dropped because this subscriber did not keep up.
+    #[must_use]
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Current queue depth: delivered to the channel but not yet received.
+    #[must_use]
+    pub fn buffered(&self) -> u64 {
+        self.buffered.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn record_queued(&self) {
+        self.buffered.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count a dropped message. Returns `true` for this subscriber's first
+    /// drop, so callers can warn once instead of once per message.
+    pub(crate) fn record_dropped(&self) -> bool {
+        self.dropped.fetch_add(1, Ordering::Relaxed) == 0
+    }
+
+    fn record_received(&self) {
+        let _ = self
+            .buffered
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| {
+                Some(n.saturating_sub(1))
+            });
+    }
 }
 
 /// Subscription to a topic.
 ///
-/// Receives messages published to its topic through a channel receiver.
-/// The subscription is canceled when dropped, automatically decrementing
-/// topic subscriber counts in the PubSubManager.
+/// Receives messages published to its topic through a channel receiver,
+/// either with [`recv`](Self::recv) or as a [`futures::Stream`] of
+/// [`PubSubMessage`]s. The subscription is canceled when dropped, including
+/// when it is dropped inside a stream combinator, automatically
+/// decrementing topic subscriber counts in the PubSubManager.
 pub struct Subscription {
     /// The topic this subscription is for.
     topic: String,
@@ -188,6 +366,8 @@ pub struct Subscription {
     receiver: mpsc::Receiver<PubSubMessage>,
     /// Reference to per-topic subscriber counts for cleanup on drop.
     topic_ref_counts: Arc<RwLock<HashMap<String, usize>>>,
+    /// Drop and queue-depth counters for this subscriber.
+    overflow: Arc<SubscriptionOverflow>,
 }
 
 impl Subscription {
@@ -203,7 +383,52 @@ impl Subscription {
     ///
     /// The next message, or `None` if the subscription has been canceled.
     pub async fn recv(&mut self) -> Option<PubSubMessage> {
-        self.receiver.recv().await
+        let message = self.receiver.recv().await;
+        if message.is_some() {
+            self.overflow.record_received();
+        }
+        message
+    }
+
+    /// Receive the next message and deserialize its payload as JSON.
+    ///
+    /// Returns `Ok(None)` once the subscription has been canceled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::IdentityError::Storage`] if the payload is
+    /// not valid JSON for `T`. The message is consumed either way.
+    pub async fn recv_json<T: serde::de::DeserializeOwned>(
+        &mut self,
+    ) -> crate::error::Result<Option<T>> {
+        let Some(message) = self.recv().await else {
+            return Ok(None);
+        };
+        serde_json::from_slice(&message.payload)
+            .map(Some)
+            .map_err(|e| crate::error::IdentityError::Storage(e.into()))
+    }
+
+    /// Backpressure counters for this subscription.
+    #[must_use]
+    pub fn overflow(&self) -> Arc<SubscriptionOverflow> {
+        Arc::clone(&self.overflow)
+    }
+}
+
+impl futures::Stream for Subscription {
+    type Item = PubSubMessage;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<PubSubMessage>> {
+        let this = self.get_mut();
+        let polled = this.receiver.poll_recv(cx);
+        if let std::task::Poll::Ready(Some(_)) = &polled {
+            this.overflow.record_received();
+        }
+        polled
     }
 }
 
@@ -240,7 +465,7 @@ impl Drop for Subscription {
 ///     └─> Dispatch to PlumTree handler (EAGER/IHAVE/IWANT/AntiEntropy)
 ///
 /// Local subscription delivery path:
-///     PlumTree topic receiver → decode x0x payload (v1/v2) → trust filter → subscriber channel
+///     PlumTree topic receiver → decode x0x payload (v1/v2/v3) → trust filter → subscriber channel
 /// ```
 pub struct PubSubManager {
     /// Network node used by PlumTree transport and topic peer initialization.
@@ -265,7 +490,46 @@ pub struct PubSubManager {
     /// Subscriber channels for `local:` topics (issue #89). These topics
     /// are same-daemon IPC: delivered only to local subscribers, never
     /// handed to PlumTree, never gossipped to remote peers.
-    local_topics: Arc<RwLock<HashMap<String, Vec<mpsc::Sender<PubSubMessage>>>>>,
+    local_topics: Arc<RwLock<HashMap<String, Vec<LocalSubscriber>>>>,
+    /// Opt-in topic sharding for pass-through relay traffic. Set via
+    /// `set_topic_sharding()`; unset means every topic is relayed to every
+    /// plane-cleared peer.
+    topic_sharding: std::sync::OnceLock<TopicShardingConfig>,
+    /// Maintenance pause policy. Set via `set_pause_config()`; unset means
+    /// [`GossipPauseConfig::default`].
+    pause_config: std::sync::OnceLock<GossipPauseConfig>,
+    /// Maintenance pause gate for inbound frames and publishes.
+    pause: PauseGate,
+    /// Pinned wire version. Set via `set_wire_version()`; unset means
+    /// [`LATEST_WIRE_VERSION`].
+    wire_version: std::sync::OnceLock<u8>,
+    /// Backpressure counters of every subscription, by topic, for
+    /// [`Self::subscriber_stats`]. An entry dies with its subscription and
+    /// delivery path and is pruned when read.
+    subscriber_overflows:
+        std::sync::Mutex<HashMap<String, Vec<std::sync::Weak<SubscriptionOverflow>>>>,
+    /// Prefix subscriptions (see [`Self::subscribe_prefix`]).
+    prefix_taps: Arc<PrefixTaps>,
+    /// Live prefix subscriptions by prefix, decremented on drop like
+    /// `topic_ref_counts`.
+    prefix_ref_counts: Arc<RwLock<HashMap<String, usize>>>,
+    /// Topics held open for prefix subscribers (see
+    /// [`Self::route_prefix_topic`]).
+    prefix_routes: Arc<std::sync::Mutex<std::collections::HashSet<String>>>,
+    /// Per-topic subscription count above which subscribing warns. Set via
+    /// `set_subscription_warn_threshold()`; unset or 0 disables.
+    subscription_warn_threshold: std::sync::OnceLock<usize>,
+    /// Distinct delivered frames each subscription remembers to drop
+    /// repeats. Set via `set_seen_frames_capacity()`; unset means
+    /// [`DEFAULT_SEEN_FRAMES_CAPACITY`], 0 disables.
+    seen_frames_capacity: std::sync::OnceLock<usize>,
+    /// Payload size from which publishes are compressed. Set via
+    /// `set_compression_threshold()`; unset means compression is off.
+    compression_threshold: std::sync::OnceLock<usize>,
+    /// Largest payload published or (plus [`FRAME_OVERHEAD_BYTES`]) frame
+    /// accepted. Set via `set_max_message_bytes()`; unset means
+    /// [`DEFAULT_MAX_MESSAGE_BYTES`].
+    max_message_bytes: std::sync::OnceLock<usize>,
 }
 
 /// Topic-name prefix marking a topic as local-only (issue #89).
@@ -277,12 +541,229 @@ pub struct PubSubManager {
 /// referencing one has no subscription to deliver into and is dropped.
 pub const LOCAL_TOPIC_PREFIX: &str = "local:";
 
+/// Sender half of a `local:` subscription plus its backpressure counters.
+type LocalSubscriber = (mpsc::Sender<PubSubMessage>, Arc<SubscriptionOverflow>);
+
+/// Subscriber channel capacity used by [`PubSubManager::subscribe`].
+pub const DEFAULT_SUBSCRIBER_CAPACITY: usize = 10_000;
+
+/// Delivered frames each subscription remembers by default to drop
+/// duplicates (see [`PubSubManager::subscribe_topic_id`]).
+pub const DEFAULT_SEEN_FRAMES_CAPACITY: usize = 8192;
+
+/// BLAKE3 hashes of the most recent distinct delivered frames, oldest first.
+#[derive(Default)]
+struct SeenFrames {
+    order: std::collections::VecDeque<blake3::Hash>,
+    hashes: std::collections::HashSet<blake3::Hash>,
+}
+
+impl SeenFrames {
+    /// Record `hash`, evicting the oldest entries beyond `capacity`.
+    /// Returns `false` if it was already present.
+    fn insert(&mut self, hash: blake3::Hash, capacity: usize) -> bool {
+        if !self.hashes.insert(hash) {
+            return false;
+        }
+        self.order.push_back(hash);
+        while self.order.len() > capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.hashes.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
+/// Frames a prefix subscriber remembers to suppress duplicates.
+const PREFIX_RECENT_FRAMES: usize = 1024;
+
+/// How often a prefix route re-checks that some prefix subscription still
+/// matches its topic, on a topic too quiet to notice on delivery.
+const PREFIX_ROUTE_RECHECK: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// One [`PubSubManager::subscribe_prefix`] subscription.
+struct PrefixSubscriber {
+    prefix: String,
+    tx: mpsc::Sender<PubSubMessage>,
+    overflow: Arc<SubscriptionOverflow>,
+    /// Hashes of recently delivered frames, oldest first. The same frame
+    /// reaches the tap once per exact subscription to its topic plus once
+    /// from the publish path, and must be delivered once.
+    recent: std::collections::VecDeque<blake3::Hash>,
+}
+
+/// Prefix subscribers, fed from every delivery path of the manager.
+#[derive(Default)]
+struct PrefixTaps {
+    subscribers: std::sync::Mutex<Vec<PrefixSubscriber>>,
+}
+
+impl PrefixTaps {
+    fn is_empty(&self) -> bool {
+        self.subscribers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .is_empty()
+    }
+
+    /// True if a live prefix subscription matches `topic`.
+    fn matches(&self, topic: &str) -> bool {
+        let mut subscribers = self
+            .subscribers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        subscribers.retain(|sub| !sub.tx.is_closed());
+        subscribers.iter().any(|sub| topic.starts_with(&sub.prefix))
+    }
+
+    fn add(&self, subscriber: PrefixSubscriber) {
+        self.subscribers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(subscriber);
+    }
+
+    /// Hand `message` to every prefix subscriber whose prefix matches its
+    /// topic. `frame` identifies the encoded frame for de-duplication;
+    /// `None` for paths that see each message exactly once.
+    fn deliver(&self, message: &PubSubMessage, frame: Option<blake3::Hash>, stats: &PubSubStats) {
+        let mut subscribers = self
+            .subscribers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        subscribers.retain_mut(|sub| {
+            if sub.tx.is_closed() {
+                return false;
+            }
+            if !message.topic.starts_with(&sub.prefix) {
+                return true;
+            }
+            if let Some(frame) = frame {
+                if sub.recent.contains(&frame) {
+                    return true;
+                }
+                if sub.recent.len() == PREFIX_RECENT_FRAMES {
+                    sub.recent.pop_front();
+                }
+                sub.recent.push_back(frame);
+            }
+            match sub.tx.try_send(message.clone()) {
+                Ok(()) => {
+                    sub.overflow.record_queued();
+                    stats.delivered_to_subscriber.fetch_add(1, Ordering::Relaxed);
+                    true
+                }
+                Err(mpsc::error::TrySendError::Full(_)) => {
+                    if sub.overflow.record_dropped() {
+                        tracing::warn!(
+                            prefix = %sub.prefix,
+                            "[4/6 pubsub] prefix subscriber channel full — dropping messages until it catches up"
+                        );
+                    }
+                    stats.slow_subscriber_dropped.fetch_add(1, Ordering::Relaxed);
+                    true
+                }
+                Err(mpsc::error::TrySendError::Closed(_)) => false,
+            }
+        });
+    }
+}
+
+/// Dropped deliveries on one topic, summed over its live subscriptions.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct TopicLag {
+    /// Topic name.
+    pub topic: String,
+    /// Live subscriptions to the topic.
+    pub subscribers: usize,
+    /// Messages dropped because a subscriber channel was full.
+    pub dropped: u64,
+    /// Messages queued in subscriber channels and not yet received.
+    pub buffered: u64,
+}
+
 /// True when `topic` is a same-daemon-only topic (issue #89).
 #[must_use]
 pub fn is_local_topic(topic: &str) -> bool {
     topic.starts_with(LOCAL_TOPIC_PREFIX)
 }
 
+/// Upper bound on the configurable message size limit. Matches the inbound
+/// decode bound ([`crate::network::MAX_MESSAGE_DESERIALIZE_SIZE`]) so a
+/// publish that succeeds locally is never one that peers refuse to decode.
+pub const MAX_PUBLISH_PAYLOAD_BYTES: usize = 4 * 1024 * 1024;
+
+/// Default for [`crate::gossip::GossipConfig::max_message_bytes`].
+pub const DEFAULT_MAX_MESSAGE_BYTES: usize = 1024 * 1024;
+
+/// Allowance on top of the payload limit for the rest of an inbound frame:
+/// topic, sender key, signature, user certificate and PlumTree envelope.
+const FRAME_OVERHEAD_BYTES: usize = 128 * 1024;
+
+/// Topic prefixes owned by x0x's own services (announcements, DM bus,
+/// directory shards, release manifests, ...).
+///
+/// `publish` does not refuse them — x0x's services publish through the same
+/// path — but application traffic there is misread by every peer, so
+/// [`PubSubManager::validate_publish`] rejects them.
+pub const RESERVED_TOPIC_PREFIXES: &[&str] = &["x0x.", "x0x/"];
+
+/// True when `topic` lies in an x0x-owned namespace.
+#[must_use]
+pub fn is_reserved_topic(topic: &str) -> bool {
+    RESERVED_TOPIC_PREFIXES
+        .iter()
+        .any(|prefix| topic.starts_with(prefix))
+}
+
+/// Reject `topic` if an application may not publish to it: task-list sync
+/// traffic ([`crate::crdt::TASK_LIST_SYNC_TOPIC_PREFIX`], also under
+/// `local:`), whose frames every replica applies to its CRDT. Shared by
+/// `Agent::publish`, `Agent::publish_as`, `POST /publish` and WebSocket
+/// publishes.
+///
+/// Narrower than [`is_reserved_topic`] on purpose: applications and the
+/// GUI publish on other x0x-named topics such as `x0x.group.<id>.chat`, so
+/// only [`PubSubManager::validate_publish`] flags those.
+///
+/// # Errors
+///
+/// [`NetworkError::InvalidMessage`] for a task-list sync topic.
+pub fn check_app_topic(topic: &str) -> NetworkResult<()> {
+    let unscoped = topic.strip_prefix(LOCAL_TOPIC_PREFIX).unwrap_or(topic);
+    if unscoped.starts_with(crate::crdt::TASK_LIST_SYNC_TOPIC_PREFIX) {
+        return Err(NetworkError::InvalidMessage(format!(
+            "topic {topic:?} carries task-list sync traffic"
+        )));
+    }
+    Ok(())
+}
+
+/// Checks every publish enforces: non-empty topic that fits the wire
+/// format's u16 length prefix, and a payload of at most `max_payload` bytes.
+fn check_publish(topic: &str, payload_len: usize, max_payload: usize) -> NetworkResult<()> {
+    if topic.is_empty() {
+        return Err(NetworkError::InvalidMessage(
+            "topic must not be empty".to_string(),
+        ));
+    }
+    if u16::try_from(topic.len()).is_err() {
+        return Err(NetworkError::InvalidMessage(format!(
+            "topic is {} bytes; limit is {}",
+            topic.len(),
+            u16::MAX
+        )));
+    }
+    if payload_len > max_payload {
+        return Err(NetworkError::PayloadTooLarge {
+            size: payload_len,
+            max: max_payload,
+        });
+    }
+    Ok(())
+}
+
 impl std::fmt::Debug for PubSubManager {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("PubSubManager")
@@ -351,6 +832,18 @@ impl PubSubManager {
             revocation_set: std::sync::OnceLock::new(),
             stats: Arc::new(PubSubStats::default()),
             local_topics: Arc::new(RwLock::new(HashMap::new())),
+            topic_sharding: std::sync::OnceLock::new(),
+            pause_config: std::sync::OnceLock::new(),
+            pause: PauseGate::default(),
+            wire_version: std::sync::OnceLock::new(),
+            subscriber_overflows: std::sync::Mutex::new(HashMap::new()),
+            prefix_taps: Arc::new(PrefixTaps::default()),
+            prefix_ref_counts: Arc::new(RwLock::new(HashMap::new())),
+            prefix_routes: Arc::default(),
+            subscription_warn_threshold: std::sync::OnceLock::new(),
+            seen_frames_capacity: std::sync::OnceLock::new(),
+            compression_threshold: std::sync::OnceLock::new(),
+            max_message_bytes: std::sync::OnceLock::new(),
         })
     }
 
@@ -395,132 +888,612 @@ impl PubSubManager {
         let _ = self.revocation_set.set(set);
     }
 
-    /// Subscribe to a topic.
+    /// Enable deterministic topic sharding for pass-through topics.
     ///
-    /// Creates a new subscription to receive messages published to the
-    /// given topic. The subscription is canceled when the returned
-    /// `Subscription` is dropped.
-    pub async fn subscribe(&self, topic: String) -> Subscription {
-        let topic_id = TopicId::from_entity(topic.as_bytes());
-        self.subscribe_topic_id(topic, topic_id).await
+    /// Only topics without a local subscriber are affected: their eager set
+    /// narrows to the connected peers that own the topic's shard (see
+    /// [`crate::gossip::sharding`]). A disabled config is ignored. Call once
+    /// after construction; a second call is a no-op, matching `set_contacts`.
+    pub fn set_topic_sharding(&self, config: TopicShardingConfig) {
+        if config.enabled {
+            let _ = self.topic_sharding.set(config);
+        }
     }
 
-    /// Subscribe to a topic with an explicit transport `TopicId`.
+    /// Pin the pub/sub wire version for interop testing and staged rollouts.
     ///
-    /// Most callers use [`Self::subscribe`], which derives the transport id
-    /// from the topic string. DM inbox topics are specified as raw
-    /// domain-separated `TopicId`s, while still carrying a stable string topic
-    /// name inside the signed x0x payload.
-    pub async fn subscribe_topic_id(&self, topic: String, topic_id: TopicId) -> Subscription {
-        // `local:` topics never touch PlumTree — same-daemon delivery only
-        // (issue #89).
-        if is_local_topic(&topic) {
-            let (tx, rx) = mpsc::channel(10_000);
-            self.local_topics
-                .write()
-                .await
-                .entry(topic.clone())
-                .or_default()
-                .push(tx);
+    /// Publishes are encoded in at most this version (a v1 pin publishes
+    /// unsigned even with a signing context) and subscriptions created
+    /// afterwards drop inbound frames above it. Call once after
+    /// construction, before subscribing; a second call is a no-op, matching
+    /// `set_contacts`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NetworkError::ConfigError`] for a version this build does
+    /// not implement.
+    pub fn set_wire_version(&self, version: u8) -> NetworkResult<()> {
+        if !(WIRE_VERSION_V1..=LATEST_WIRE_VERSION).contains(&version) {
+            return Err(NetworkError::ConfigError(format!(
+                "unsupported pub/sub wire version {version} (supported: \
+                 {WIRE_VERSION_V1}..={LATEST_WIRE_VERSION})"
+            )));
+        }
+        let _ = self.wire_version.set(version);
+        Ok(())
+    }
+
+    /// The wire version publishes are capped at.
+    #[must_use]
+    pub fn wire_version(&self) -> u8 {
+        self.wire_version
+            .get()
+            .copied()
+            .unwrap_or(LATEST_WIRE_VERSION)
+    }
+
+    /// Set the policy applied while paused (see [`crate::gossip::pause`]).
+    /// Call once after construction; a second call is a no-op, matching
+    /// `set_contacts`.
+    pub fn set_pause_config(&self, config: GossipPauseConfig) {
+        let _ = self.pause_config.set(config);
+    }
+
+    /// Warn when a topic gains more than `threshold` subscriptions (0
+    /// disables). Call once after construction; a second call is a no-op,
+    /// matching `set_contacts`.
+    pub fn set_subscription_warn_threshold(&self, threshold: usize) {
+        let _ = self.subscription_warn_threshold.set(threshold);
+    }
+
+    /// Remember the last `capacity` distinct frames delivered to each
+    /// subscription and drop repeats (0 disables). Call once after construction; a second call is a no-op,
+    /// matching `set_contacts`.
+    pub fn set_seen_frames_capacity(&self, capacity: usize) {
+        let _ = self.seen_frames_capacity.set(capacity);
+    }
+
+    /// Compress publishes whose payload is at least `threshold` bytes into
+    /// v4 frames, unless the wire version is pinned below
+    /// [`WIRE_VERSION_V4`]. Unset means no compression. Call once after
+    /// construction; a second call is a no-op, matching `set_contacts`.
+    pub fn set_compression_threshold(&self, threshold: usize) {
+        let _ = self.compression_threshold.set(threshold);
+    }
+
+    /// `frame` wrapped in a compressed v4 frame when compression is on, the
+    /// payload reaches the threshold and compressing actually saves bytes.
+    fn maybe_compress(&self, frame: Bytes, payload_len: usize) -> Bytes {
+        match self.compression_threshold.get() {
+            Some(&threshold)
+                if payload_len >= threshold && self.wire_version() >= WIRE_VERSION_V4 =>
             {
-                let mut counts = self.topic_ref_counts.write().await;
-                *counts.entry(topic.clone()).or_insert(0) += 1;
+                compress_frame(&frame).unwrap_or(frame)
             }
-            return Subscription {
-                topic,
-                receiver: rx,
-                topic_ref_counts: Arc::clone(&self.topic_ref_counts),
-            };
+            _ => frame,
         }
+    }
 
-        self.register_dynamic_topic_priority(&topic, topic_id);
-        self.initialize_topic_peers(topic_id).await;
+    /// Refuse publishes with a payload over `max_bytes` and drop inbound
+    /// frames over `max_bytes` plus framing overhead (capped at
+    /// [`MAX_PUBLISH_PAYLOAD_BYTES`]). Call once after construction; a
+    /// second call is a no-op, matching `set_contacts`.
+    pub fn set_max_message_bytes(&self, max_bytes: usize) {
+        let _ = self.max_message_bytes.set(max_bytes);
+    }
 
-        let mut plumtree_rx = self.plumtree.subscribe(topic_id);
-        // Plumtree registers subscribers on a spawned task; yield once so
-        // immediate local publishes in the same task see this subscriber.
-        tokio::task::yield_now().await;
-        let (tx, rx) = mpsc::channel(10_000);
-        let contacts = self.contacts.get().cloned();
-        let revocation_set = self.revocation_set.get().cloned();
+    /// The payload size limit publishes are held to.
+    #[must_use]
+    pub fn max_message_bytes(&self) -> usize {
+        self.max_message_bytes
+            .get()
+            .copied()
+            .unwrap_or(DEFAULT_MAX_MESSAGE_BYTES)
+            .min(MAX_PUBLISH_PAYLOAD_BYTES)
+    }
 
-        {
+    /// Distinct frames each subscription remembers to drop repeats.
+    fn seen_frames_capacity(&self) -> usize {
+        self.seen_frames_capacity
+            .get()
+            .copied()
+            .unwrap_or(DEFAULT_SEEN_FRAMES_CAPACITY)
+    }
+
+    /// Count a new subscription to `topic`, warning when it exceeds the
+    /// threshold. Warns on the first subscription past it and again each
+    /// time the count grows by another threshold, so a leak stays visible
+    /// without logging on every call.
+    async fn count_subscription(&self, topic: &str) {
+        let count = {
             let mut counts = self.topic_ref_counts.write().await;
-            *counts.entry(topic.clone()).or_insert(0) += 1;
+            let count = counts.entry(topic.to_string()).or_insert(0);
+            *count += 1;
+            *count
+        };
+        let threshold = self.subscription_warn_threshold.get().copied().unwrap_or(0);
+        if threshold > 0 && count > threshold && (count - 1) % threshold == 0 {
+            self.stats
+                .subscription_threshold_exceeded
+                .fetch_add(1, Ordering::Relaxed);
+            tracing::warn!(
+                topic = %topic,
+                subscriptions = count,
+                threshold,
+                "topic has more subscriptions than expected; every message is delivered to each — check for a subscribe leak"
+            );
         }
+    }
 
-        let sub_topic = topic.clone();
-        let stats = Arc::clone(&self.stats);
-        tokio::spawn(async move {
-            loop {
-                let received = tokio::select! {
-                    // The subscriber dropping its receiver must end this
-                    // forwarding task PROMPTLY, even on a forever-quiet
-                    // topic — parking on recv() alone only notices the
-                    // closed downstream when the next message arrives, so
-                    // every discarded subscription would pin a task and its
-                    // PlumTree registration indefinitely (issue #238
-                    // round-5 review: registration rollbacks on unique
-                    // topics leaked these unboundedly). Both arms are
-                    // cancel-safe.
-                    () = tx.closed() => {
-                        stats
-                            .subscriber_channel_closed
-                            .fetch_add(1, Ordering::Relaxed);
-                        tracing::debug!(
-                            topic = %sub_topic,
-                            "[4/6 pubsub] subscriber receiver dropped — ending forwarding task"
-                        );
-                        return;
-                    }
-                    received = plumtree_rx.recv() => received,
-                };
-                let Some((_peer, encoded_payload)) = received else {
-                    return;
-                };
-                stats.incoming_total.fetch_add(1, Ordering::Relaxed);
-                tracing::debug!(
-                    topic = %sub_topic,
-                    payload_len = encoded_payload.len(),
-                    "[4/6 pubsub] received from PlumTree, decoding"
-                );
-                let Some(message) = decode_for_delivery(
-                    encoded_payload,
-                    contacts.as_ref(),
-                    revocation_set.as_ref(),
+    fn pause_config(&self) -> GossipPauseConfig {
+        self.pause_config.get().copied().unwrap_or_default()
+    }
+
+    /// Stop processing inbound frames and publishes until [`Self::resume`].
+    ///
+    /// Connections are untouched. Returns `false` if already paused.
+    pub fn pause(&self) -> bool {
+        self.pause.pause()
+    }
+
+    /// Whether the data plane is currently paused.
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.pause.is_paused()
+    }
+
+    /// Lift a pause and replay whatever the policy held, inbound frames
+    /// first. Returns `false` if not paused.
+    pub async fn resume(&self) -> bool {
+        let Some(held) = self.pause.resume() else {
+            return false;
+        };
+        for (peer, data) in held.incoming {
+            self.pause.record_incoming_replayed();
+            self.dispatch_incoming(peer, data).await;
+        }
+        for queued in held.publishes {
+            self.pause.record_publish_replayed();
+            if let Err(e) = self
+                .publish_admitted(
+                    queued.topic.clone(),
+                    queued.topic_id,
+                    queued.payload,
+                    queued.certificate.as_ref(),
                 )
                 .await
-                else {
-                    stats.incoming_decode_failed.fetch_add(1, Ordering::Relaxed);
-                    tracing::warn!(
-                        topic = %sub_topic,
-                        "[4/6 pubsub] decode_for_delivery returned None, skipping"
-                    );
-                    continue;
-                };
-                stats.incoming_decoded.fetch_add(1, Ordering::Relaxed);
-                tracing::debug!(
-                    topic = %sub_topic,
-                    msg_topic = %message.topic,
-                    "[4/6 pubsub] decoded, forwarding to subscriber channel"
-                );
-                match tx.try_send(message) {
+            {
+                tracing::warn!(topic = %queued.topic, "queued publish failed on resume: {e}");
+            }
+        }
+        true
+    }
+
+    /// Counters for traffic held, dropped, rejected and replayed by pauses.
+    pub fn pause_stats(&self) -> GossipPauseStatsSnapshot {
+        self.pause.snapshot()
+    }
+
+    /// Per-topic lag of live subscriptions, sorted by topic.
+    ///
+    /// A non-zero `dropped` means a consumer did not keep up and lost
+    /// messages; deliveries use `try_send`, so a slow consumer never stalls
+    /// reception or rebroadcast.
+    pub fn subscriber_stats(&self) -> Vec<TopicLag> {
+        let mut registry = self
+            .subscriber_overflows
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        registry.retain(|_, overflows| {
+            overflows.retain(|overflow| overflow.strong_count() > 0);
+            !overflows.is_empty()
+        });
+        let mut lags: Vec<TopicLag> = registry
+            .iter()
+            .map(|(topic, overflows)| {
+                let live: Vec<_> = overflows.iter().filter_map(|o| o.upgrade()).collect();
+                TopicLag {
+                    topic: topic.clone(),
+                    subscribers: live.len(),
+                    dropped: live.iter().map(|o| o.dropped()).sum(),
+                    buffered: live.iter().map(|o| o.buffered()).sum(),
+                }
+            })
+            .collect();
+        lags.sort_by(|a, b| a.topic.cmp(&b.topic));
+        lags
+    }
+
+    fn register_overflow(&self, topic: &str, overflow: &Arc<SubscriptionOverflow>) {
+        self.subscriber_overflows
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry(topic.to_string())
+            .or_default()
+            .push(Arc::downgrade(overflow));
+    }
+
+    /// Subscribe to a topic.
+    ///
+    /// Creates a new subscription to receive messages published to the
+    /// given topic. The subscription is canceled when the returned
+    /// `Subscription` is dropped. Its channel holds
+    /// [`DEFAULT_SUBSCRIBER_CAPACITY`] messages.
+    pub async fn subscribe(&self, topic: String) -> Subscription {
+        self.subscribe_with_capacity(topic, DEFAULT_SUBSCRIBER_CAPACITY)
+            .await
+    }
+
+    /// Subscribe to a topic with a subscriber channel of `capacity` messages.
+    ///
+    /// Size it to the topic: bursty topics need room to absorb bursts
+    /// without drops, rare control topics need little. `capacity` must be
+    /// at least 1; 0 is treated as 1.
+    pub async fn subscribe_with_capacity(&self, topic: String, capacity: usize) -> Subscription {
+        let topic_id = TopicId::from_entity(topic.as_bytes());
+        self.subscribe_topic_id_with_capacity(topic, topic_id, capacity)
+            .await
+    }
+
+    /// Subscribe to a topic, delivering only messages whose sender's trust
+    /// level is at least `min_trust`.
+    ///
+    /// The sender's level comes from the contact store (see
+    /// [`Self::set_contacts`]); unsigned messages, senders without a contact
+    /// entry, and every sender when no contact store is set count as
+    /// [`TrustLevel::Unknown`]. Messages published by this agent are always
+    /// delivered. Withheld messages never enter the channel and are counted
+    /// in [`PubSubStats::filtered_by_trust`]. `local:` topics carry only
+    /// same-daemon traffic and are not filtered.
+    pub async fn subscribe_filtered(&self, topic: String, min_trust: TrustLevel) -> Subscription {
+        let topic_id = TopicId::from_entity(topic.as_bytes());
+        self.subscribe_topic_id_inner(
+            topic,
+            topic_id,
+            DEFAULT_SUBSCRIBER_CAPACITY,
+            Some(min_trust),
+            false,
+        )
+        .await
+    }
+
+    /// Subscribe to every topic starting with `prefix`.
+    ///
+    /// Gossip routes by exact topic and carries only a hash of the name, so
+    /// a topic is routed once this manager has seen its name: every topic
+    /// with a live exact subscription when the prefix subscription starts,
+    /// and every matching topic subscribed to or published on afterwards.
+    /// Each routed topic stays subscribed until no prefix subscription
+    /// matches it, so remote traffic keeps arriving after the subscription
+    /// or publish that revealed it is gone. The route is not a subscriber of
+    /// its own: it is left out of [`Self::subscription_count`],
+    /// [`Self::subscriber_stats`] and the subscription warning. A topic only remote peers use, and
+    /// which this manager has never seen named, is not received.
+    ///
+    /// Each message is delivered once per prefix subscription, even when
+    /// several exact subscriptions to its topic exist. Dropping the
+    /// subscription cancels it.
+    pub async fn subscribe_prefix(&self, prefix: String) -> Subscription {
+        let (tx, rx) = mpsc::channel(DEFAULT_SUBSCRIBER_CAPACITY);
+        let overflow = Arc::new(SubscriptionOverflow::default());
+        self.prefix_taps.add(PrefixSubscriber {
+            prefix: prefix.clone(),
+            tx,
+            overflow: Arc::clone(&overflow),
+            recent: std::collections::VecDeque::new(),
+        });
+        *self
+            .prefix_ref_counts
+            .write()
+            .await
+            .entry(prefix.clone())
+            .or_insert(0) += 1;
+        let known: Vec<String> = self
+            .topic_ref_counts
+            .read()
+            .await
+            .keys()
+            .filter(|topic| topic.starts_with(&prefix))
+            .cloned()
+            .collect();
+        for topic in known {
+            self.route_prefix_topic(&topic).await;
+        }
+        Subscription {
+            topic: prefix,
+            receiver: rx,
+            topic_ref_counts: Arc::clone(&self.prefix_ref_counts),
+            overflow,
+        }
+    }
+
+    /// Hold a subscription to `topic` open while a prefix subscription
+    /// matches it; its forwarding task feeds the prefix subscribers like any
+    /// exact subscription's. No-op for `local:` topics, which every local
+    /// publish already taps, for topics no prefix subscription matches, and
+    /// for topics already routed.
+    async fn route_prefix_topic(&self, topic: &str) {
+        if is_local_topic(topic) || !self.prefix_taps.matches(topic) {
+            return;
+        }
+        if !self
+            .prefix_routes
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(topic.to_string())
+        {
+            return;
+        }
+        let topic_id = TopicId::from_entity(topic.as_bytes());
+        // Boxed to break the async recursion through the subscribe path.
+        let subscribe: std::pin::Pin<
+            Box<dyn std::future::Future<Output = Subscription> + Send + '_>,
+        > = Box::pin(self.subscribe_topic_id_inner(
+            topic.to_string(),
+            topic_id,
+            DEFAULT_SUBSCRIBER_CAPACITY,
+            None,
+            true,
+        ));
+        let mut route = subscribe.await;
+        let prefix_taps = Arc::clone(&self.prefix_taps);
+        let prefix_routes = Arc::clone(&self.prefix_routes);
+        let topic = topic.to_string();
+        tokio::spawn(async move {
+            let mut recheck = tokio::time::interval(PREFIX_ROUTE_RECHECK);
+            loop {
+                // The forwarding task already handed each message to the
+                // prefix subscribers; this copy is discarded.
+                tokio::select! {
+                    received = route.recv() => {
+                        if received.is_none() {
+                            break;
+                        }
+                    }
+                    _ = recheck.tick() => {}
+                }
+                if !prefix_taps.matches(&topic) {
+                    break;
+                }
+            }
+            prefix_routes
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .remove(&topic);
+            tracing::debug!(topic = %topic, "[4/6 pubsub] prefix route closed");
+        });
+    }
+
+    /// Get the number of distinct prefixes with at least one live prefix
+    /// subscription.
+    pub async fn prefix_subscription_count(&self) -> usize {
+        self.prefix_ref_counts.read().await.len()
+    }
+
+    /// Subscribe to a topic with an explicit transport `TopicId`.
+    ///
+    /// Most callers use [`Self::subscribe`], which derives the transport id
+    /// from the topic string. DM inbox topics are specified as raw
+    /// domain-separated `TopicId`s, while still carrying a stable string topic
+    /// name inside the signed x0x payload.
+    ///
+    /// A frame whose exact bytes were among the last
+    /// [`DEFAULT_SEEN_FRAMES_CAPACITY`] (configurable) distinct frames
+    /// delivered to this subscription is dropped, so a payload circling a
+    /// mesh with cycles reaches the subscriber once.
+    pub async fn subscribe_topic_id(&self, topic: String, topic_id: TopicId) -> Subscription {
+        self.subscribe_topic_id_with_capacity(topic, topic_id, DEFAULT_SUBSCRIBER_CAPACITY)
+            .await
+    }
+
+    /// [`Self::subscribe_topic_id`] with a subscriber channel of `capacity`
+    /// messages (0 is treated as 1).
+    pub async fn subscribe_topic_id_with_capacity(
+        &self,
+        topic: String,
+        topic_id: TopicId,
+        capacity: usize,
+    ) -> Subscription {
+        self.subscribe_topic_id_inner(topic, topic_id, capacity, None, false)
+            .await
+    }
+
+    /// `prefix_route` marks the subscription [`Self::route_prefix_topic`]
+    /// holds open. It is bookkeeping, not a subscriber: it takes no topic
+    /// ref-count, so it neither trips the subscription warning nor shows in
+    /// [`Self::subscription_count`] and [`Self::subscriber_stats`].
+    async fn subscribe_topic_id_inner(
+        &self,
+        topic: String,
+        topic_id: TopicId,
+        capacity: usize,
+        min_trust: Option<TrustLevel>,
+        prefix_route: bool,
+    ) -> Subscription {
+        // `mpsc::channel` panics on a zero capacity.
+        let capacity = capacity.max(1);
+        // `local:` topics never touch PlumTree — same-daemon delivery only
+        // (issue #89).
+        if is_local_topic(&topic) {
+            let (tx, rx) = mpsc::channel(capacity);
+            let overflow = Arc::new(SubscriptionOverflow::default());
+            self.register_overflow(&topic, &overflow);
+            self.local_topics
+                .write()
+                .await
+                .entry(topic.clone())
+                .or_default()
+                .push((tx, Arc::clone(&overflow)));
+            self.count_subscription(&topic).await;
+            return Subscription {
+                topic,
+                receiver: rx,
+                topic_ref_counts: Arc::clone(&self.topic_ref_counts),
+                overflow,
+            };
+        }
+
+        self.register_dynamic_topic_priority(&topic, topic_id);
+        self.initialize_topic_peers(topic_id).await;
+
+        let mut plumtree_rx = self.plumtree.subscribe(topic_id);
+        // Plumtree registers subscribers on a spawned task; yield once so
+        // immediate local publishes in the same task see this subscriber.
+        tokio::task::yield_now().await;
+        let (tx, rx) = mpsc::channel(capacity);
+        let contacts = self.contacts.get().cloned();
+        let revocation_set = self.revocation_set.get().cloned();
+        let max_wire_version = self.wire_version();
+        let local_agent = self.signing.as_ref().map(|signing| signing.agent_id);
+        let seen_capacity = self.seen_frames_capacity();
+        let max_frame = self.max_message_bytes() + FRAME_OVERHEAD_BYTES;
+
+        let overflow = Arc::new(SubscriptionOverflow::default());
+        let topic_ref_counts = if prefix_route {
+            Arc::default()
+        } else {
+            self.count_subscription(&topic).await;
+            if topic_id == TopicId::from_entity(topic.as_bytes()) {
+                self.route_prefix_topic(&topic).await;
+            }
+            self.register_overflow(&topic, &overflow);
+            Arc::clone(&self.topic_ref_counts)
+        };
+
+        let sub_topic = topic.clone();
+        let stats = Arc::clone(&self.stats);
+        let prefix_taps = Arc::clone(&self.prefix_taps);
+        let task_overflow = Arc::clone(&overflow);
+        tokio::spawn(async move {
+            // Per subscription: a cache shared across subscriptions to one
+            // topic would let only the first of them see each frame.
+            let mut seen = SeenFrames::default();
+            loop {
+                let received = tokio::select! {
+                    // The subscriber dropping its receiver must end this
+                    // forwarding task PROMPTLY, even on a forever-quiet
+                    // topic — parking on recv() alone only notices the
+                    // closed downstream when the next message arrives, so
+                    // every discarded subscription would pin a task and its
+                    // PlumTree registration indefinitely (issue #238
+                    // round-5 review: registration rollbacks on unique
+                    // topics leaked these unboundedly). Both arms are
+                    // cancel-safe.
+                    () = tx.closed() => {
+                        stats
+                            .subscriber_channel_closed
+                            .fetch_add(1, Ordering::Relaxed);
+                        tracing::debug!(
+                            topic = %sub_topic,
+                            "[4/6 pubsub] subscriber receiver dropped — ending forwarding task"
+                        );
+                        return;
+                    }
+                    received = plumtree_rx.recv() => received,
+                };
+                let Some((_peer, encoded_payload)) = received else {
+                    return;
+                };
+                stats.incoming_total.fetch_add(1, Ordering::Relaxed);
+                tracing::debug!(
+                    topic = %sub_topic,
+                    payload_len = encoded_payload.len(),
+                    "[4/6 pubsub] received from PlumTree, decoding"
+                );
+                let frame_version = wire_version_of(&encoded_payload);
+                if frame_version > max_wire_version {
+                    stats.incoming_decode_failed.fetch_add(1, Ordering::Relaxed);
+                    tracing::debug!(
+                        topic = %sub_topic,
+                        frame_version,
+                        max_wire_version,
+                        "[4/6 pubsub] frame above pinned wire version, skipping"
+                    );
+                    continue;
+                }
+                // A compressed frame passes the size check in
+                // `handle_incoming`; hold its decompressed size to the same
+                // limit before inflating it.
+                if let Some(inner_len) = compressed_inner_len(&encoded_payload) {
+                    if inner_len > max_frame {
+                        stats
+                            .incoming_oversize_dropped
+                            .fetch_add(1, Ordering::Relaxed);
+                        tracing::warn!(
+                            topic = %sub_topic,
+                            inner_len,
+                            max_frame,
+                            "[4/6 pubsub] compressed frame inflates past the size limit, skipping"
+                        );
+                        continue;
+                    }
+                }
+                let frame = blake3::hash(&encoded_payload);
+                if seen_capacity > 0 && !seen.insert(frame, seen_capacity) {
+                    stats
+                        .incoming_duplicate_dropped
+                        .fetch_add(1, Ordering::Relaxed);
+                    tracing::trace!(
+                        topic = %sub_topic,
+                        "[4/6 pubsub] duplicate frame, skipping"
+                    );
+                    continue;
+                }
+                let Some(message) = decode_for_delivery(
+                    encoded_payload,
+                    contacts.as_ref(),
+                    revocation_set.as_ref(),
+                )
+                .await
+                else {
+                    stats.incoming_decode_failed.fetch_add(1, Ordering::Relaxed);
+                    tracing::warn!(
+                        topic = %sub_topic,
+                        "[4/6 pubsub] decode_for_delivery returned None, skipping"
+                    );
+                    continue;
+                };
+                stats.incoming_decoded.fetch_add(1, Ordering::Relaxed);
+                tracing::debug!(
+                    topic = %sub_topic,
+                    msg_topic = %message.topic,
+                    "[4/6 pubsub] decoded, forwarding to subscriber channel"
+                );
+                if !prefix_taps.is_empty() {
+                    prefix_taps.deliver(&message, Some(frame), &stats);
+                }
+                if let Some(min_trust) = min_trust {
+                    if !meets_trust(&message, min_trust, local_agent.as_ref()) {
+                        stats.filtered_by_trust.fetch_add(1, Ordering::Relaxed);
+                        continue;
+                    }
+                }
+                match tx.try_send(message) {
                     Ok(()) => {
+                        task_overflow.record_queued();
                         stats
                             .delivered_to_subscriber
                             .fetch_add(1, Ordering::Relaxed);
                     }
                     Err(tokio::sync::mpsc::error::TrySendError::Full(_)) => {
+                        let first_drop = task_overflow.record_dropped();
                         stats
                             .slow_subscriber_dropped
                             .fetch_add(1, Ordering::Relaxed);
                         stats
                             .subscriber_channel_closed
                             .fetch_add(1, Ordering::Relaxed);
-                        tracing::warn!(
-                            topic = %sub_topic,
-                            "[4/6 pubsub] subscriber channel full — dropping slow subscriber"
-                        );
+                        if first_drop {
+                            tracing::warn!(
+                                topic = %sub_topic,
+                                "[4/6 pubsub] subscriber channel full — dropping slow subscriber"
+                            );
+                        } else {
+                            tracing::debug!(
+                                topic = %sub_topic,
+                                "[4/6 pubsub] subscriber channel full again — dropping slow subscriber"
+                            );
+                        }
                         break;
                     }
                     Err(tokio::sync::mpsc::error::TrySendError::Closed(_)) => {
@@ -537,14 +1510,44 @@ impl PubSubManager {
         Subscription {
             topic,
             receiver: rx,
-            topic_ref_counts: self.topic_ref_counts.clone(),
+            topic_ref_counts,
+            overflow,
         }
     }
 
+    /// Run every check [`Self::publish`] would apply to an application
+    /// publish, without sending anything.
+    ///
+    /// Returns the first failure of: topic validity, payload size limit,
+    /// reserved x0x namespace, and signing readiness (a manager without a
+    /// [`SigningContext`] would send unsigned v1 messages that peers report
+    /// as unverified).
+    ///
+    /// # Errors
+    ///
+    /// [`NetworkError::InvalidMessage`] for topic problems or missing
+    /// signing context; [`NetworkError::PayloadTooLarge`] for oversize
+    /// payloads.
+    pub fn validate_publish(&self, topic: &str, payload: &[u8]) -> NetworkResult<()> {
+        check_publish(topic, payload.len(), self.max_message_bytes())?;
+        if is_reserved_topic(topic) {
+            return Err(NetworkError::InvalidMessage(format!(
+                "topic {topic:?} is in a reserved x0x namespace"
+            )));
+        }
+        if self.signing.is_none() {
+            return Err(NetworkError::InvalidMessage(
+                "no signing context: publishes would be unsigned".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     /// Publish a message to a topic.
     ///
     /// When a signing context is present, the message is signed with
-    /// ML-DSA-65 and encoded in v2 format. Otherwise, v1 (unsigned).
+    /// ML-DSA-65 and encoded in v2 format. Otherwise, or when pinned to v1
+    /// via [`Self::set_wire_version`], v1 (unsigned).
     ///
     /// # Errors
     ///
@@ -563,31 +1566,136 @@ impl PubSubManager {
         topic: String,
         topic_id: TopicId,
         payload: Bytes,
+    ) -> NetworkResult<()> {
+        if let Err(err) = check_publish(&topic, payload.len(), self.max_message_bytes()) {
+            self.stats.publish_failed.fetch_add(1, Ordering::Relaxed);
+            return Err(err);
+        }
+
+        let publish = QueuedPublish {
+            topic,
+            topic_id,
+            payload,
+            certificate: None,
+        };
+        self.admit_and_publish(publish).await
+    }
+
+    /// Publish on behalf of the user who issued `certificate` for this
+    /// agent.
+    ///
+    /// The message is signed by the agent as usual and encoded in v3 format
+    /// with the certificate embedded and covered by the signature, so
+    /// recipients see the user in [`PubSubMessage::on_behalf_of`]. Peers
+    /// older than v3 (or pinned below it) cannot decode these messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NetworkError::InvalidMessage`] without a signing context or
+    /// if `certificate` does not verify or binds another agent, and
+    /// [`NetworkError::ConfigError`] when pinned below [`WIRE_VERSION_V3`];
+    /// otherwise as [`Self::publish`].
+    pub async fn publish_on_behalf(
+        &self,
+        topic: String,
+        payload: Bytes,
+        certificate: AgentCertificate,
+    ) -> NetworkResult<()> {
+        if let Err(err) = check_publish(&topic, payload.len(), self.max_message_bytes())
+            .and_then(|()| self.check_attestation(&certificate))
+        {
+            self.stats.publish_failed.fetch_add(1, Ordering::Relaxed);
+            return Err(err);
+        }
+
+        let topic_id = TopicId::from_entity(topic.as_bytes());
+        let publish = QueuedPublish {
+            topic,
+            topic_id,
+            payload,
+            certificate: Some(certificate),
+        };
+        self.admit_and_publish(publish).await
+    }
+
+    /// A certificate can be embedded only if this manager signs as the agent
+    /// it binds and the wire version allows v3.
+    fn check_attestation(&self, certificate: &AgentCertificate) -> NetworkResult<()> {
+        let Some(ctx) = self.signing.as_ref() else {
+            return Err(NetworkError::InvalidMessage(
+                "no signing context: cannot publish on behalf of a user".to_string(),
+            ));
+        };
+        if self.wire_version() < WIRE_VERSION_V3 {
+            return Err(NetworkError::ConfigError(format!(
+                "publishing on behalf of a user needs wire version {WIRE_VERSION_V3} \
+                 (pinned to {})",
+                self.wire_version()
+            )));
+        }
+        certificate
+            .verify()
+            .map_err(|e| NetworkError::InvalidMessage(format!("user certificate: {e}")))?;
+        if certificate.agent_public_key() != ctx.public_key_bytes.as_slice() {
+            return Err(NetworkError::InvalidMessage(
+                "user certificate binds a different agent".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn admit_and_publish(&self, publish: QueuedPublish) -> NetworkResult<()> {
+        match self.pause.admit_publish(&self.pause_config(), publish)? {
+            Some(publish) => {
+                self.publish_admitted(
+                    publish.topic,
+                    publish.topic_id,
+                    publish.payload,
+                    publish.certificate.as_ref(),
+                )
+                .await
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Encode and hand a validated publish to PlumTree (or local fan-out).
+    async fn publish_admitted(
+        &self,
+        topic: String,
+        topic_id: TopicId,
+        payload: Bytes,
+        certificate: Option<&AgentCertificate>,
     ) -> NetworkResult<()> {
         // `local:` topics fan out to same-daemon subscribers only — the
         // payload never reaches PlumTree or any remote peer (issue #89).
         if is_local_topic(&topic) {
-            return self.publish_local(topic, payload).await;
+            return self.publish_local(topic, payload, certificate).await;
         }
 
-        let encoded_result = if let Some(ref ctx) = self.signing {
-            let signing_payload =
-                build_signing_payload(ctx.agent_id.as_bytes(), topic.as_bytes(), &payload);
-            ctx.sign(&signing_payload).and_then(|signature| {
-                encode_v2(
-                    &ctx.agent_id,
-                    &ctx.public_key_bytes,
-                    &signature,
-                    &topic,
-                    &payload,
-                )
-            })
-        } else {
-            encode_v1(&topic, &payload)
+        let encoded_result = match (self.signing.as_ref(), certificate) {
+            (Some(ctx), Some(cert)) => encode_signed_v3(ctx, cert, &topic, &payload),
+            (None, Some(_)) => Err(NetworkError::InvalidMessage(
+                "no signing context: cannot publish on behalf of a user".to_string(),
+            )),
+            (Some(ctx), None) if self.wire_version() >= WIRE_VERSION_V2 => {
+                let signing_payload =
+                    build_signing_payload(ctx.agent_id.as_bytes(), topic.as_bytes(), &payload);
+                ctx.sign(&signing_payload).and_then(|signature| {
+                    encode_v2(
+                        &ctx.agent_id,
+                        &ctx.public_key_bytes,
+                        &signature,
+                        &topic,
+                        &payload,
+                    )
+                })
+            }
+            (_, None) => encode_v1(&topic, &payload),
         };
 
         let encoded = match encoded_result {
-            Ok(e) => e,
+            Ok(e) => self.maybe_compress(e, payload.len()),
             Err(err) => {
                 self.stats.publish_failed.fetch_add(1, Ordering::Relaxed);
                 return Err(err);
@@ -597,9 +1705,17 @@ impl PubSubManager {
         self.register_dynamic_topic_priority(&topic, topic_id);
         self.initialize_topic_peers(topic_id).await;
 
+        let frame = (!self.prefix_taps.is_empty()).then(|| blake3::hash(&encoded));
         match self.plumtree.publish(topic_id, encoded).await {
             Ok(()) => {
                 self.stats.publish_total.fetch_add(1, Ordering::Relaxed);
+                if let Some(frame) = frame {
+                    if topic_id == TopicId::from_entity(topic.as_bytes()) {
+                        self.route_prefix_topic(&topic).await;
+                    }
+                    let message = self.local_message(topic, payload, certificate);
+                    self.prefix_taps.deliver(&message, Some(frame), &self.stats);
+                }
                 Ok(())
             }
             Err(e) => {
@@ -617,30 +1733,30 @@ impl PubSubManager {
     /// `Full` vs `Closed` arms encode the slow-subscriber-drop behaviour:
     /// `Full` keeps the subscriber (the message is dropped, not the queue),
     /// `Closed` evicts it.
-    async fn publish_local(&self, topic: String, payload: Bytes) -> NetworkResult<()> {
-        let message = PubSubMessage {
-            topic: topic.clone(),
-            payload,
-            sender: self.signing.as_ref().map(|ctx| ctx.agent_id),
-            sender_public_key: self
-                .signing
-                .as_ref()
-                .map(|ctx| ctx.public_key_bytes.clone()),
-            // Local publishes come from a bearer-token-authenticated
-            // API caller on this daemon — trusted by construction.
-            verified: true,
-            trust_level: None,
-        };
+    async fn publish_local(
+        &self,
+        topic: String,
+        payload: Bytes,
+        certificate: Option<&AgentCertificate>,
+    ) -> NetworkResult<()> {
+        let message = self.local_message(topic.clone(), payload, certificate);
         let mut topics = self.local_topics.write().await;
         if let Some(senders) = topics.get_mut(&topic) {
-            senders.retain(|tx| match tx.try_send(message.clone()) {
+            senders.retain(|(tx, overflow)| match tx.try_send(message.clone()) {
                 Ok(()) => {
+                    overflow.record_queued();
                     self.stats
                         .delivered_to_subscriber
                         .fetch_add(1, Ordering::Relaxed);
                     true
                 }
                 Err(mpsc::error::TrySendError::Full(_)) => {
+                    if overflow.record_dropped() {
+                        tracing::warn!(
+                            topic = %topic,
+                            "[4/6 pubsub] local subscriber channel full — dropping messages until it catches up"
+                        );
+                    }
                     self.stats
                         .slow_subscriber_dropped
                         .fetch_add(1, Ordering::Relaxed);
@@ -649,15 +1765,80 @@ impl PubSubManager {
                 Err(mpsc::error::TrySendError::Closed(_)) => false,
             });
         }
+        drop(topics);
+        self.prefix_taps.deliver(&message, None, &self.stats);
         self.stats.publish_total.fetch_add(1, Ordering::Relaxed);
         Ok(())
     }
 
+    /// The message local subscribers see for a publish from this manager.
+    fn local_message(
+        &self,
+        topic: String,
+        payload: Bytes,
+        certificate: Option<&AgentCertificate>,
+    ) -> PubSubMessage {
+        let sender = self.signing.as_ref().map(|ctx| ctx.agent_id);
+        PubSubMessage {
+            id: PubSubMessage::content_id(sender.as_ref(), &topic, &payload),
+            topic,
+            payload,
+            sender,
+            sender_public_key: self
+                .signing
+                .as_ref()
+                .map(|ctx| ctx.public_key_bytes.clone()),
+            // Local publishes come from a bearer-token-authenticated
+            // API caller on this daemon — trusted by construction.
+            verified: true,
+            trust_level: None,
+            on_behalf_of: certificate.and_then(|cert| cert.user_id().ok()),
+        }
+    }
+
     /// Handle an incoming message from a peer.
     ///
     /// This delegates to the PlumTree implementation for protocol-level
-    /// processing (EAGER/IHAVE/IWANT/AntiEntropy).
+    /// processing (EAGER/IHAVE/IWANT/AntiEntropy). While paused, the frame
+    /// is held or dropped per the pause policy instead.
+    ///
+    /// Never waits on a local subscriber: each subscription's forwarding
+    /// task hands messages over with `try_send`, counting and isolating a
+    /// subscriber whose channel is full (see [`Self::subscribe_topic_id`]),
+    /// so one stalled consumer cannot back up reception or rebroadcast for
+    /// other topics.
+    ///
+    /// Every frame reaches PlumTree, whose message-id cache stops a message
+    /// from being re-broadcast twice; IHAVE/IWANT/GRAFT control frames
+    /// legitimately repeat and must not be dropped here. Exact repeats of a
+    /// delivered payload are dropped per subscription instead (see
+    /// [`Self::subscribe_topic_id`]).
+    ///
+    /// Signatures are not checked here. A frame is decoded and verified only
+    /// when it is delivered to a local subscriber (`decode_for_delivery`);
+    /// frames on topics with no local subscriber are relayed verbatim,
+    /// unverified, and the receivers that deliver them do the check. A relay
+    /// can therefore forward a frame with an invalid signature, but no
+    /// subscriber ever sees it.
     pub async fn handle_incoming(&self, peer: PeerId, data: Bytes) {
+        let max_frame = self.max_message_bytes() + FRAME_OVERHEAD_BYTES;
+        if data.len() > max_frame {
+            self.stats
+                .incoming_oversize_dropped
+                .fetch_add(1, Ordering::Relaxed);
+            tracing::warn!(
+                "Dropping {}-byte pubsub frame from {} (limit {max_frame})",
+                data.len(),
+                crate::logging::LogPeerId::from(peer)
+            );
+            return;
+        }
+        if let Some((peer, data)) = self.pause.admit_incoming(&self.pause_config(), peer, data) {
+            self.dispatch_incoming(peer, data).await;
+        }
+    }
+
+    async fn dispatch_incoming(&self, peer: PeerId, data: Bytes) {
         if let Err(e) = self.plumtree.handle_message(peer, data).await {
             tracing::warn!(
                 "Failed to handle PlumTree pubsub message from {}: {e}",
@@ -700,8 +1881,19 @@ impl PubSubManager {
             .map(|peer| PeerId::new(peer.0))
             .collect();
 
-        // Refresh locally subscribed topics.
-        let subscribed: Vec<String> = self.topic_ref_counts.read().await.keys().cloned().collect();
+        // Refresh locally subscribed topics, including those held open for
+        // prefix subscribers.
+        let mut subscribed: Vec<String> =
+            self.topic_ref_counts.read().await.keys().cloned().collect();
+        subscribed.extend(
+            self.prefix_routes
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .iter()
+                .cloned(),
+        );
+        subscribed.sort();
+        subscribed.dedup();
         if !peers.is_empty() && !subscribed.is_empty() {
             tracing::debug!(
                 "[4/6 pubsub] refresh_topic_peers: {} connected peers, {} subscribed topics",
@@ -717,15 +1909,22 @@ impl PubSubManager {
         // Also refresh pass-through topics (known to PlumTree but without local
         // subscribers). Without this, nodes that relay gossip messages for topics
         // they don't subscribe to would have empty eager sets and drop messages
-        // instead of forwarding them.
+        // instead of forwarding them. With topic sharding enabled, a node that
+        // does not own a pass-through topic's shard relays only towards owners.
         let all_plumtree_topics = self.plumtree.all_topic_ids().await;
         let subscribed_ids: std::collections::HashSet<TopicId> = subscribed
             .iter()
             .map(|t| TopicId::from_entity(t.as_bytes()))
             .collect();
+        let local_peer =
+            saorsa_gossip_transport::GossipTransport::local_peer_id(self.network.as_ref());
         for topic_id in all_plumtree_topics {
             if !subscribed_ids.contains(&topic_id) {
-                self.plumtree.set_topic_peers(topic_id, peers.clone()).await;
+                let relay_peers = match self.topic_sharding.get() {
+                    Some(sharding) => sharding.relay_peers(&local_peer, &topic_id, &peers),
+                    None => peers.clone(),
+                };
+                self.plumtree.set_topic_peers(topic_id, relay_peers).await;
             }
         }
     }
@@ -762,12 +1961,27 @@ impl PubSubManager {
     }
 }
 
+/// Whether `message` passes a [`PubSubManager::subscribe_filtered`]
+/// threshold: own messages always do; otherwise the sender's trust level,
+/// [`TrustLevel::Unknown`] when none was resolved, must reach `min_trust`.
+fn meets_trust(
+    message: &PubSubMessage,
+    min_trust: TrustLevel,
+    local_agent: Option<&AgentId>,
+) -> bool {
+    if message.verified && message.sender.is_some() && message.sender.as_ref() == local_agent {
+        return true;
+    }
+    message.trust_level.unwrap_or(TrustLevel::Unknown) >= min_trust
+}
+
 /// Decode and filter a delivered payload before exposing it to x0x subscribers.
 ///
 /// Revocation is checked against the authoritative gossiped `RevocationSet`
 /// (issue #191) before the operator-local `ContactStore`, so a gossiped
 /// issuer/agent revocation closes delivery even before the eviction loop sets
-/// `trust = Blocked`.
+/// `trust = Blocked`. Verified messages from `Unknown` senders also feed
+/// the contact store's opt-in auto-promotion.
 async fn decode_for_delivery(
     encoded_payload: Bytes,
     contacts: Option<&Arc<RwLock<ContactStore>>>,
@@ -810,12 +2024,24 @@ async fn decode_for_delivery(
             tracing::debug!("Dropping delivered payload from revoked sender {}", sender);
             return None;
         }
-        let trust = guard.trust_level(&sender);
+        let mut trust = guard.trust_level(&sender);
+        let auto_promote = guard.auto_promote_after().is_some();
         drop(guard);
         if trust == TrustLevel::Blocked {
             tracing::debug!("Dropping delivered payload from blocked sender {}", sender);
             return None;
         }
+        // Opt-in auto-promotion counts only signature-verified messages.
+        if auto_promote
+            && trust == TrustLevel::Unknown
+            && message.verified
+            && store
+                .write()
+                .await
+                .record_verified_message(&sender, message.id)
+        {
+            trust = TrustLevel::Known;
+        }
         message.trust_level = Some(trust);
     }
 
@@ -977,7 +2203,9 @@ fn encode_v1(topic: &str, payload: &Bytes) -> NetworkResult<Bytes> {
 }
 
 /// Decode a v1 (unsigned) pub/sub message.
-fn decode_v1(data: &[u8]) -> NetworkResult<PubSubMessage> {
+///
+/// The payload is a zero-copy slice of `data`.
+fn decode_v1(data: &Bytes) -> NetworkResult<PubSubMessage> {
     if data.len() < 2 {
         return Err(NetworkError::SerializationError(
             "Message too short".to_string(),
@@ -994,15 +2222,17 @@ fn decode_v1(data: &[u8]) -> NetworkResult<PubSubMessage> {
     let topic = String::from_utf8(data[2..2 + topic_len].to_vec())
         .map_err(|e| NetworkError::SerializationError(format!("Invalid UTF-8: {}", e)))?;
 
-    let payload = Bytes::copy_from_slice(&data[2 + topic_len..]);
+    let payload = data.slice(2 + topic_len..);
 
     Ok(PubSubMessage {
+        id: PubSubMessage::content_id(None, &topic, &payload),
         topic,
         payload,
         sender: None,
         sender_public_key: None,
         verified: false,
         trust_level: None,
+        on_behalf_of: None,
     })
 }
 
@@ -1079,70 +2309,290 @@ fn take_lp<'a>(data: &'a [u8], pos: &mut usize, what: &str) -> NetworkResult<&'a
     Ok(field)
 }
 
-/// Decode a v2 (signed) message, verifying the ML-DSA-65 signature.
-fn decode_v2(data: &[u8]) -> NetworkResult<PubSubMessage> {
-    // Minimum: 1 (version) + 32 (agent_id) + 2 (pk_len) + 2 (sig_len) + 2 (topic_len)
-    if data.len() < 39 {
+/// Decode a v2 (signed) message, verifying the ML-DSA-65 signature.
+///
+/// The payload is a zero-copy slice of `data`.
+fn decode_v2(data: &Bytes) -> NetworkResult<PubSubMessage> {
+    // Minimum: 1 (version) + 32 (agent_id) + 2 (pk_len) + 2 (sig_len) + 2 (topic_len)
+    if data.len() < 39 {
+        return Err(NetworkError::SerializationError(
+            "V2 message too short".to_string(),
+        ));
+    }
+
+    let mut pos = 1; // skip version byte
+
+    // Agent ID (32 bytes)
+    let mut agent_id_bytes = [0u8; 32];
+    agent_id_bytes.copy_from_slice(&data[pos..pos + 32]);
+    let agent_id = AgentId(agent_id_bytes);
+    pos += 32;
+
+    let public_key_bytes = take_lp(data, &mut pos, "public key").map_err(|e| match e {
+        NetworkError::SerializationError(msg) if msg == "Truncated public key length" => {
+            NetworkError::SerializationError("Truncated pubkey length".to_string())
+        }
+        other => other,
+    })?;
+    let public_key_bytes = public_key_bytes.to_vec();
+
+    let signature_bytes = take_lp(data, &mut pos, "signature")?;
+
+    let topic_bytes = take_lp(data, &mut pos, "topic")?;
+    let topic = String::from_utf8(topic_bytes.to_vec())
+        .map_err(|e| NetworkError::SerializationError(format!("Invalid UTF-8: {}", e)))?;
+
+    // Payload (remaining bytes)
+    let payload = data.slice(pos..);
+
+    // Verify: reconstruct the public key and check the signature
+    let verified = verify_signature(
+        &public_key_bytes,
+        &agent_id_bytes,
+        topic.as_bytes(),
+        &payload,
+        signature_bytes,
+    );
+
+    if !verified {
+        tracing::warn!(
+            "ML-DSA-65 signature verification failed for sender {}",
+            agent_id
+        );
+    }
+
+    Ok(PubSubMessage {
+        id: PubSubMessage::content_id(Some(&agent_id), &topic, &payload),
+        topic,
+        payload,
+        sender: Some(agent_id),
+        sender_public_key: Some(public_key_bytes),
+        verified,
+        trust_level: None,
+        on_behalf_of: None,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Wire format: V3 (signed, on behalf of a user)
+// ---------------------------------------------------------------------------
+
+/// Sign and encode a v3 message carrying `certificate`.
+///
+/// Format: v2 with the certificate inserted before the topic:
+/// ```text
+/// [version: 0x03]
+/// [sender_agent_id: 32 bytes]
+/// [pubkey_len: u16_be] [sender_public_key: pubkey_len bytes]
+/// [sig_len: u16_be]    [signature: sig_len bytes]
+/// [cert_len: u16_be]   [certificate: cert_len bytes, storage encoding]
+/// [topic_len: u16_be]  [topic_bytes: topic_len bytes]
+/// [payload: remaining bytes]
+/// ```
+fn encode_signed_v3(
+    ctx: &SigningContext,
+    certificate: &AgentCertificate,
+    topic: &str,
+    payload: &Bytes,
+) -> NetworkResult<Bytes> {
+    let cert_bytes = certificate
+        .to_storage_bytes()
+        .map_err(|e| NetworkError::SerializationError(format!("certificate: {e}")))?;
+    if cert_bytes.len() > MAX_V3_CERTIFICATE_BYTES {
+        return Err(NetworkError::SerializationError(format!(
+            "Certificate exceeds {MAX_V3_CERTIFICATE_BYTES} bytes"
+        )));
+    }
+    let signing_payload = build_signing_payload_v3(
+        ctx.agent_id.as_bytes(),
+        &cert_bytes,
+        topic.as_bytes(),
+        payload,
+    )?;
+    let signature = ctx.sign(&signing_payload)?;
+
+    let lp = |field: &[u8], what: &str| {
+        u16::try_from(field.len())
+            .map(u16::to_be_bytes)
+            .map_err(|_| NetworkError::SerializationError(format!("{what} too long")))
+    };
+    let pk_len = lp(&ctx.public_key_bytes, "Public key")?;
+    let sig_len = lp(&signature, "Signature")?;
+    let cert_len = lp(&cert_bytes, "Certificate")?;
+    let topic_len = lp(topic.as_bytes(), "Topic")?;
+
+    let mut buf = Vec::with_capacity(
+        1 + 32
+            + 8
+            + ctx.public_key_bytes.len()
+            + signature.len()
+            + cert_bytes.len()
+            + topic.len()
+            + payload.len(),
+    );
+    buf.push(VERSION_V3);
+    buf.extend_from_slice(ctx.agent_id.as_bytes());
+    buf.extend_from_slice(&pk_len);
+    buf.extend_from_slice(&ctx.public_key_bytes);
+    buf.extend_from_slice(&sig_len);
+    buf.extend_from_slice(&signature);
+    buf.extend_from_slice(&cert_len);
+    buf.extend_from_slice(&cert_bytes);
+    buf.extend_from_slice(&topic_len);
+    buf.extend_from_slice(topic.as_bytes());
+    buf.extend_from_slice(payload);
+
+    Ok(Bytes::from(buf))
+}
+
+/// Decode a v3 message, verifying both the agent's signature and the
+/// embedded user certificate. `verified` requires both; `on_behalf_of` is
+/// set only when the certificate verifies, is unexpired, and binds the
+/// sender's public key.
+fn decode_v3(data: &Bytes) -> NetworkResult<PubSubMessage> {
+    // Minimum: 1 (version) + 32 (agent_id) + 4 × 2 (length prefixes)
+    if data.len() < 41 {
         return Err(NetworkError::SerializationError(
-            "V2 message too short".to_string(),
+            "V3 message too short".to_string(),
         ));
     }
 
-    let mut pos = 1; // skip version byte
-
-    // Agent ID (32 bytes)
+    let mut pos = 1;
     let mut agent_id_bytes = [0u8; 32];
     agent_id_bytes.copy_from_slice(&data[pos..pos + 32]);
     let agent_id = AgentId(agent_id_bytes);
     pos += 32;
 
-    let public_key_bytes = take_lp(data, &mut pos, "public key").map_err(|e| match e {
-        NetworkError::SerializationError(msg) if msg == "Truncated public key length" => {
-            NetworkError::SerializationError("Truncated pubkey length".to_string())
-        }
-        other => other,
-    })?;
-    let public_key_bytes = public_key_bytes.to_vec();
-
+    let public_key_bytes = take_lp(data, &mut pos, "public key")?.to_vec();
     let signature_bytes = take_lp(data, &mut pos, "signature")?;
-
+    let cert_bytes = take_lp(data, &mut pos, "certificate")?;
+    if cert_bytes.len() > MAX_V3_CERTIFICATE_BYTES {
+        return Err(NetworkError::SerializationError(format!(
+            "V3 certificate exceeds {MAX_V3_CERTIFICATE_BYTES} bytes"
+        )));
+    }
     let topic_bytes = take_lp(data, &mut pos, "topic")?;
     let topic = String::from_utf8(topic_bytes.to_vec())
         .map_err(|e| NetworkError::SerializationError(format!("Invalid UTF-8: {}", e)))?;
+    let payload = data.slice(pos..);
+
+    let signature_ok = build_signing_payload_v3(&agent_id_bytes, cert_bytes, topic_bytes, &payload)
+        .is_ok_and(|signing_payload| {
+            verify_signed_payload(
+                &public_key_bytes,
+                &agent_id_bytes,
+                &signing_payload,
+                signature_bytes,
+            )
+        });
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let on_behalf_of = AgentCertificate::from_storage_bytes(cert_bytes)
+        .ok()
+        .filter(|cert| {
+            cert.agent_public_key() == public_key_bytes.as_slice()
+                && !cert.is_expired(now)
+                && cert.verify().is_ok()
+        })
+        .and_then(|cert| cert.user_id().ok());
 
-    // Payload (remaining bytes)
-    let payload = Bytes::copy_from_slice(&data[pos..]);
-
-    // Verify: reconstruct the public key and check the signature
-    let verified = verify_signature(
-        &public_key_bytes,
-        &agent_id_bytes,
-        topic.as_bytes(),
-        &payload,
-        signature_bytes,
-    );
-
-    if !verified {
+    if !signature_ok || on_behalf_of.is_none() {
         tracing::warn!(
-            "ML-DSA-65 signature verification failed for sender {}",
+            signature_ok,
+            certificate_ok = on_behalf_of.is_some(),
+            "v3 message verification failed for sender {}",
             agent_id
         );
     }
 
     Ok(PubSubMessage {
+        id: PubSubMessage::content_id(Some(&agent_id), &topic, &payload),
         topic,
         payload,
         sender: Some(agent_id),
         sender_public_key: Some(public_key_bytes),
-        verified,
+        verified: signature_ok && on_behalf_of.is_some(),
         trust_level: None,
+        on_behalf_of: on_behalf_of.filter(|_| signature_ok),
     })
 }
 
-/// Auto-detect and decode a pub/sub message (v1 or v2).
+/// Wire version of an encoded frame, using the same first-byte rule as
+/// [`decode_auto`].
+fn wire_version_of(data: &[u8]) -> u8 {
+    match data.first() {
+        Some(&VERSION_V2) => WIRE_VERSION_V2,
+        Some(&VERSION_V3) => WIRE_VERSION_V3,
+        Some(&VERSION_COMPRESSED) => WIRE_VERSION_V4,
+        _ => WIRE_VERSION_V1,
+    }
+}
+
+/// Wrap an encoded frame in a compressed v4 frame, or `None` when that
+/// would not make it smaller.
+///
+/// Wire format: `[0x04][inner_len: u32_be][zstd(inner frame)]`
+fn compress_frame(frame: &[u8]) -> Option<Bytes> {
+    let inner_len = u32::try_from(frame.len()).ok()?;
+    let compressed = zstd::bulk::compress(frame, COMPRESSION_LEVEL).ok()?;
+    if 5 + compressed.len() >= frame.len() {
+        return None;
+    }
+    let mut buf = Vec::with_capacity(5 + compressed.len());
+    buf.push(VERSION_COMPRESSED);
+    buf.extend_from_slice(&inner_len.to_be_bytes());
+    buf.extend_from_slice(&compressed);
+    Some(Bytes::from(buf))
+}
+
+/// Inner frame length a compressed v4 frame declares, or `None` for any
+/// other frame. [`decompress_frame`] rejects a frame that inflates to any
+/// other length.
+fn compressed_inner_len(data: &[u8]) -> Option<usize> {
+    match data {
+        [VERSION_COMPRESSED, a, b, c, d, ..] => Some(u32::from_be_bytes([*a, *b, *c, *d]) as usize),
+        _ => None,
+    }
+}
+
+/// Unwrap a compressed v4 frame. The declared inner length bounds the
+/// allocation, so a small frame cannot inflate past
+/// `MAX_DECOMPRESSED_FRAME_BYTES`; nested compression is rejected.
+fn decompress_frame(data: &[u8]) -> NetworkResult<Bytes> {
+    if data.len() < 5 {
+        return Err(NetworkError::SerializationError(
+            "Compressed message too short".to_string(),
+        ));
+    }
+    let inner_len = u32::from_be_bytes([data[1], data[2], data[3], data[4]]) as usize;
+    if inner_len == 0 || inner_len > MAX_DECOMPRESSED_FRAME_BYTES {
+        return Err(NetworkError::SerializationError(format!(
+            "Compressed message declares invalid length {inner_len}"
+        )));
+    }
+    let inner = zstd::bulk::decompress(&data[5..], inner_len).map_err(|e| {
+        NetworkError::SerializationError(format!("Failed to decompress message: {e}"))
+    })?;
+    if inner.len() != inner_len {
+        return Err(NetworkError::SerializationError(
+            "Compressed message length mismatch".to_string(),
+        ));
+    }
+    if inner[0] == VERSION_COMPRESSED {
+        return Err(NetworkError::SerializationError(
+            "Nested compressed message".to_string(),
+        ));
+    }
+    Ok(Bytes::from(inner))
+}
+
+/// Auto-detect and decode a pub/sub message (v1, v2, v3 or compressed v4).
 ///
 /// The first byte distinguishes the format:
 /// - `0x02` → v2 (signed)
+/// - `0x03` → v3 (signed, on behalf of a user)
+/// - `0x04` → v4 (zstd-compressed v1/v2/v3 frame, decompressed first)
 /// - Anything else → v1 (legacy unsigned, where byte is high byte of topic_len)
 fn decode_auto(data: Bytes) -> NetworkResult<PubSubMessage> {
     if data.is_empty() {
@@ -1150,11 +2600,16 @@ fn decode_auto(data: Bytes) -> NetworkResult<PubSubMessage> {
             "Empty message".to_string(),
         ));
     }
-
-    if data[0] == VERSION_V2 {
-        decode_v2(&data)
+    let data = if data[0] == VERSION_COMPRESSED {
+        decompress_frame(&data)?
     } else {
-        decode_v1(&data)
+        data
+    };
+
+    match data[0] {
+        VERSION_V2 => decode_v2(&data),
+        VERSION_V3 => decode_v3(&data),
+        _ => decode_v1(&data),
     }
 }
 
@@ -1170,6 +2625,33 @@ fn build_signing_payload(agent_id: &[u8; 32], topic: &[u8], payload: &[u8]) -> V
     buf
 }
 
+/// Build the v3 signing payload with domain separation. The certificate
+/// and topic are length-prefixed so neither can be shifted into the other.
+///
+/// `b"x0x-msg-v3" || sender_agent_id(32) || cert_len(u16_be) || cert ||
+/// topic_len(u16_be) || topic_bytes || payload`
+fn build_signing_payload_v3(
+    agent_id: &[u8; 32],
+    cert: &[u8],
+    topic: &[u8],
+    payload: &[u8],
+) -> NetworkResult<Vec<u8>> {
+    let cert_len = u16::try_from(cert.len())
+        .map_err(|_| NetworkError::SerializationError("Certificate too long".to_string()))?;
+    let topic_len = u16::try_from(topic.len())
+        .map_err(|_| NetworkError::SerializationError("Topic too long".to_string()))?;
+    let mut buf =
+        Vec::with_capacity(MSG_V3_PREFIX.len() + 32 + 4 + cert.len() + topic.len() + payload.len());
+    buf.extend_from_slice(MSG_V3_PREFIX);
+    buf.extend_from_slice(agent_id);
+    buf.extend_from_slice(&cert_len.to_be_bytes());
+    buf.extend_from_slice(cert);
+    buf.extend_from_slice(&topic_len.to_be_bytes());
+    buf.extend_from_slice(topic);
+    buf.extend_from_slice(payload);
+    Ok(buf)
+}
+
 /// Verify an ML-DSA-65 signature against the reconstructed signing payload.
 fn verify_signature(
     public_key_bytes: &[u8],
@@ -1177,6 +2659,23 @@ fn verify_signature(
     topic: &[u8],
     payload: &[u8],
     signature_bytes: &[u8],
+) -> bool {
+    let signing_payload = build_signing_payload(agent_id, topic, payload);
+    verify_signed_payload(
+        public_key_bytes,
+        agent_id,
+        &signing_payload,
+        signature_bytes,
+    )
+}
+
+/// Verify an ML-DSA-65 signature over a prebuilt signing payload, checking
+/// that `agent_id` derives from the public key.
+fn verify_signed_payload(
+    public_key_bytes: &[u8],
+    agent_id: &[u8; 32],
+    signing_payload: &[u8],
+    signature_bytes: &[u8],
 ) -> bool {
     let public_key = match ant_quic::MlDsaPublicKey::from_bytes(public_key_bytes) {
         Ok(pk) => pk,
@@ -1196,11 +2695,9 @@ fn verify_signature(
             Err(_) => return false,
         };
 
-    let signing_payload = build_signing_payload(agent_id, topic, payload);
-
     ant_quic::crypto::raw_public_keys::pqc::verify_with_ml_dsa(
         &public_key,
-        &signing_payload,
+        signing_payload,
         &signature,
     )
     .is_ok()
@@ -1406,12 +2903,12 @@ mod tests {
 
     #[test]
     fn test_v1_too_short() {
-        assert!(decode_v1(&[0x12]).is_err());
+        assert!(decode_v1(&Bytes::from_static(&[0x12])).is_err());
     }
 
     #[test]
     fn test_v1_invalid_utf8() {
-        let data = vec![0, 3, 0xFF, 0xFF, 0xFF];
+        let data = Bytes::from(vec![0, 3, 0xFF, 0xFF, 0xFF]);
         assert!(decode_v1(&data).is_err());
     }
 
@@ -1446,6 +2943,59 @@ mod tests {
         assert!(msg.verified);
     }
 
+    /// The id depends only on sender, topic and payload, so v2 and v3 copies
+    /// of one message agree, and changing any of the three changes it.
+    #[test]
+    fn message_id_is_content_addressed_across_wire_versions() {
+        use crate::identity::UserKeypair;
+
+        let kp = AgentKeypair::generate().expect("keygen");
+        let ctx = SigningContext::from_keypair(&kp);
+        let payload = Bytes::from("same words");
+        let signature = ctx
+            .sign(&build_signing_payload(
+                ctx.agent_id.as_bytes(),
+                b"chat",
+                &payload,
+            ))
+            .expect("sign");
+        let v2 = decode_auto(
+            encode_v2(
+                &ctx.agent_id,
+                &ctx.public_key_bytes,
+                &signature,
+                "chat",
+                &payload,
+            )
+            .expect("encode v2"),
+        )
+        .expect("decode v2");
+        let user = UserKeypair::generate().expect("user");
+        let cert = AgentCertificate::issue(&user, &kp).expect("cert");
+        let v3 = decode_auto(encode_signed_v3(&ctx, &cert, "chat", &payload).expect("encode v3"))
+            .expect("decode v3");
+
+        assert_eq!(v2.id, v3.id);
+        assert_eq!(
+            v2.id,
+            PubSubMessage::content_id(Some(&ctx.agent_id), "chat", &payload)
+        );
+        assert_ne!(
+            v2.id,
+            PubSubMessage::content_id(Some(&ctx.agent_id), "chat2", &payload)
+        );
+        assert_ne!(
+            v2.id,
+            PubSubMessage::content_id(Some(&ctx.agent_id), "chat", b"other words")
+        );
+        let v1 = decode_auto(encode_v1("chat", &payload).expect("encode v1")).expect("decode v1");
+        assert_eq!(v1.id, PubSubMessage::content_id(None, "chat", &payload));
+        assert_ne!(
+            v1.id, v2.id,
+            "unsigned copies never collide with signed ones"
+        );
+    }
+
     /// Issue #191 gap 3: pubsub delivery must consult the authoritative
     /// gossiped `RevocationSet`, not just the operator-local ContactStore.
     /// Pre-fix `decode_for_delivery` never checked `RevocationSet`, so a
@@ -1522,6 +3072,52 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn decode_for_delivery_auto_promotes_after_distinct_verified_messages() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let mut store = ContactStore::new(dir.path().join("contacts.json"));
+        store.set_auto_promote_after(Some(2));
+        let store = Arc::new(RwLock::new(store));
+
+        let kp = AgentKeypair::generate().expect("keygen");
+        let ctx = SigningContext::from_keypair(&kp);
+        let frame = |text: &'static str| {
+            let payload = Bytes::from(text);
+            let signing_payload = build_signing_payload(ctx.agent_id.as_bytes(), b"chat", &payload);
+            let signature = ctx.sign(&signing_payload).expect("sign message");
+            encode_v2(
+                &ctx.agent_id,
+                &ctx.public_key_bytes,
+                &signature,
+                "chat",
+                &payload,
+            )
+            .expect("encode")
+        };
+        let deliver = |encoded: Bytes| {
+            let store = Arc::clone(&store);
+            async move {
+                decode_for_delivery(encoded, Some(&store), None)
+                    .await
+                    .expect("delivered")
+                    .trust_level
+            }
+        };
+
+        // The same message delivered twice (e.g. to two subscriptions)
+        // counts once.
+        let first = frame("one");
+        assert_eq!(deliver(first.clone()).await, Some(TrustLevel::Unknown));
+        assert_eq!(deliver(first).await, Some(TrustLevel::Unknown));
+        assert_eq!(deliver(frame("two")).await, Some(TrustLevel::Known));
+        assert_eq!(deliver(frame("three")).await, Some(TrustLevel::Known));
+
+        let store = store.read().await;
+        assert_eq!(store.trust_level(&ctx.agent_id), TrustLevel::Known);
+        let last = store.audit_entries().last().expect("audit entry");
+        assert_eq!(last.source, crate::contacts::AuditSource::AutoPromote);
+    }
+
     #[test]
     fn test_v2_tampered_payload_fails_verification() {
         let kp = AgentKeypair::generate().expect("keygen");
@@ -1575,6 +3171,32 @@ mod tests {
         assert!(!msg.verified); // Wrong key for signature
     }
 
+    /// Best-effort: drop runs [`Zeroize::zeroize`], which scrubs the secret
+    /// buffer in place. Freed memory can't be inspected soundly, so check the
+    /// still-owned buffer right after the scrub the destructor performs.
+    #[test]
+    fn test_signing_context_zeroizes_secret_key() {
+        let kp = AgentKeypair::generate().expect("keygen");
+        let mut ctx = SigningContext::from_keypair(&kp);
+        assert!(ctx.secret_key_bytes.iter().any(|&b| b != 0));
+        let ptr = ctx.secret_key_bytes.as_ptr();
+        let capacity = ctx.secret_key_bytes.capacity();
+
+        ctx.zeroize();
+
+        assert!(ctx.secret_key_bytes.is_empty());
+        assert_eq!(
+            ctx.secret_key_bytes.as_ptr(),
+            ptr,
+            "buffer must be scrubbed in place"
+        );
+        // SAFETY: `ctx` still owns the allocation, and `Vec::zeroize` wrote
+        // every byte of its capacity.
+        let scrubbed = unsafe { std::slice::from_raw_parts(ptr, capacity) };
+        assert!(scrubbed.iter().all(|&b| b == 0));
+        assert!(!ctx.public_key_bytes.is_empty(), "public half is kept");
+    }
+
     #[test]
     fn test_v2_empty_payload() {
         let kp = AgentKeypair::generate().expect("keygen");
@@ -1603,7 +3225,7 @@ mod tests {
     #[test]
     fn test_v2_truncated_data() {
         // Just version byte + a few bytes — should fail
-        assert!(decode_v2(&[VERSION_V2, 0, 0, 0]).is_err());
+        assert!(decode_v2(&Bytes::from_static(&[VERSION_V2, 0, 0, 0])).is_err());
     }
 
     // -----------------------------------------------------------------------
@@ -1662,7 +3284,7 @@ mod tests {
         let payload_start = v2_payload_start(&buf);
         for cut in 1..payload_start {
             assert!(
-                decode_v2(&buf[..cut]).is_err(),
+                decode_v2(&buf.slice(..cut)).is_err(),
                 "truncating to {cut} of {} bytes (payload_start={payload_start}) must be an error",
                 buf.len()
             );
@@ -1679,7 +3301,7 @@ mod tests {
         let payload_start = v2_payload_start(&buf);
         for cut in payload_start..=buf.len() {
             assert!(
-                decode_v2(&buf[..cut]).is_ok(),
+                decode_v2(&buf.slice(..cut)).is_ok(),
                 "truncating to {cut} (>= payload_start {payload_start}) must still decode"
             );
         }
@@ -1692,7 +3314,7 @@ mod tests {
         let mut buf = valid_v2_buffer().to_vec();
         buf[33] = 0xFF;
         buf[34] = 0xFF;
-        assert!(decode_v2(&buf).is_err());
+        assert!(decode_v2(&Bytes::from(buf)).is_err());
     }
 
     #[test]
@@ -1704,7 +3326,7 @@ mod tests {
         let sig_len_pos = 35 + pk_len;
         buf[sig_len_pos] = 0xFF;
         buf[sig_len_pos + 1] = 0xFF;
-        assert!(decode_v2(&buf).is_err());
+        assert!(decode_v2(&Bytes::from(buf)).is_err());
     }
 
     #[test]
@@ -1717,7 +3339,7 @@ mod tests {
         let topic_len_pos = sig_len_pos + 2 + sig_len;
         buf[topic_len_pos] = 0xFF;
         buf[topic_len_pos + 1] = 0xFF;
-        assert!(decode_v2(&buf).is_err());
+        assert!(decode_v2(&Bytes::from(buf)).is_err());
     }
 
     // -----------------------------------------------------------------------
@@ -1851,6 +3473,61 @@ mod tests {
         assert!(!msg.verified);
     }
 
+    #[tokio::test]
+    async fn test_recv_json_decodes_payload() {
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        let mut sub = manager.subscribe("json".to_string()).await;
+
+        for payload in [r#"{"n":7}"#, "not json"] {
+            manager
+                .publish("json".to_string(), Bytes::from(payload))
+                .await
+                .expect("Publish failed");
+        }
+
+        let value: serde_json::Value = sub.recv_json().await.expect("decode").expect("message");
+        assert_eq!(value["n"], 7);
+        assert!(sub.recv_json::<serde_json::Value>().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn subscription_is_a_stream_and_cleans_up_through_combinators() {
+        use futures::StreamExt;
+
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        let sub = manager.subscribe("stream".to_string()).await;
+        assert_eq!(manager.subscription_count().await, 1);
+
+        for payload in ["a", "skip", "b", "c"] {
+            manager
+                .publish("stream".to_string(), Bytes::from(payload))
+                .await
+                .expect("Publish failed");
+        }
+
+        let payloads: Vec<Bytes> = sub
+            .filter(|msg| futures::future::ready(msg.payload != "skip"))
+            .map(|msg| msg.payload)
+            .take(2)
+            .collect()
+            .await;
+        assert_eq!(payloads, [Bytes::from("a"), Bytes::from("b")]);
+
+        // `collect` dropped the combinator chain, and with it the
+        // subscription.
+        let mut released = false;
+        for _ in 0..200 {
+            if manager.subscription_count().await == 0 {
+                released = true;
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert!(released, "dropping the stream must release the topic");
+    }
+
     #[tokio::test]
     async fn test_publish_local_delivery_signed() {
         let node = test_node().await;
@@ -1872,6 +3549,129 @@ mod tests {
         assert!(msg.verified);
     }
 
+    #[test]
+    fn check_app_topic_refuses_only_task_list_sync() {
+        for topic in [
+            "app/chat",
+            "x0x.space.abc.feed",
+            "x0x.group.abc.chat",
+            "local:x0x.space.abc.feed",
+        ] {
+            assert!(check_app_topic(topic).is_ok(), "{topic}");
+        }
+        for topic in ["x0x/tasklist/v1/shared", "local:x0x/tasklist/v1/shared"] {
+            assert!(matches!(
+                check_app_topic(topic),
+                Err(NetworkError::InvalidMessage(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn validate_publish_rejects_bad_input_without_sending() {
+        let node = test_node().await;
+        let kp = AgentKeypair::generate().expect("keygen");
+        let ctx = Arc::new(SigningContext::from_keypair(&kp));
+        let manager = PubSubManager::new(node.clone(), Some(ctx)).expect("manager");
+
+        assert!(manager.validate_publish("app/chat", b"hello").is_ok());
+        assert!(matches!(
+            manager.validate_publish("app/chat", &vec![0u8; MAX_PUBLISH_PAYLOAD_BYTES + 1]),
+            Err(NetworkError::PayloadTooLarge { .. })
+        ));
+        assert!(matches!(
+            manager.validate_publish("x0x.identity.announce.v2", b"hello"),
+            Err(NetworkError::InvalidMessage(_))
+        ));
+        assert!(manager.validate_publish("", b"hello").is_err());
+        // Validation is a dry run: nothing was counted as published.
+        assert_eq!(manager.stats().publish_total, 0);
+
+        let unsigned = PubSubManager::new(node, None).expect("manager");
+        assert!(unsigned.validate_publish("app/chat", b"hello").is_err());
+    }
+
+    #[tokio::test]
+    async fn publish_enforces_payload_limit() {
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        let oversized = Bytes::from(vec![0u8; MAX_PUBLISH_PAYLOAD_BYTES + 1]);
+        assert!(matches!(
+            manager.publish("chat".to_string(), oversized).await,
+            Err(NetworkError::PayloadTooLarge { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn max_message_bytes_limits_publish_and_inbound_frames() {
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        assert_eq!(manager.max_message_bytes(), DEFAULT_MAX_MESSAGE_BYTES);
+        manager.set_max_message_bytes(1024);
+
+        let mut sub = manager.subscribe("chat".to_string()).await;
+        assert!(matches!(
+            manager
+                .publish("chat".to_string(), Bytes::from(vec![0u8; 1025]))
+                .await,
+            Err(NetworkError::PayloadTooLarge {
+                size: 1025,
+                max: 1024
+            })
+        ));
+        assert_eq!(manager.stats().publish_failed, 1);
+        manager
+            .publish("chat".to_string(), Bytes::from(vec![0u8; 1024]))
+            .await
+            .expect("publish at the limit");
+        assert_eq!(sub.recv().await.expect("delivery").payload.len(), 1024);
+
+        let peer = PeerId::new([1; 32]);
+        let oversize = Bytes::from(vec![0x12; 1024 + FRAME_OVERHEAD_BYTES + 1]);
+        manager.handle_incoming(peer, oversize.clone()).await;
+        assert_eq!(manager.stats().incoming_oversize_dropped, 1);
+        // Nothing is remembered about a dropped frame, so a retry is
+        // dropped again.
+        manager.handle_incoming(peer, oversize).await;
+        assert_eq!(manager.stats().incoming_oversize_dropped, 2);
+        assert_eq!(manager.stats().incoming_duplicate_dropped, 0);
+    }
+
+    #[tokio::test]
+    async fn compressed_frame_inflating_past_the_limit_is_dropped() {
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        manager.set_max_message_bytes(1024);
+        let topic = "chat";
+        let mut sub = manager.subscribe(topic.to_string()).await;
+
+        // Zeros compress to a few dozen bytes, far under the inbound frame
+        // limit, but inflate past it.
+        let payload = Bytes::from(vec![0u8; 1024 + FRAME_OVERHEAD_BYTES + 1]);
+        let bomb =
+            compress_frame(&encode_v1(topic, &payload).expect("encode")).expect("compressible");
+        assert!(bomb.len() < 1024);
+        manager
+            .plumtree
+            .publish(TopicId::from_entity(topic.as_bytes()), bomb)
+            .await
+            .expect("inject frame");
+        let small =
+            compress_frame(&encode_v1(topic, &Bytes::from(vec![0u8; 1024])).expect("encode"))
+                .expect("compressible");
+        manager
+            .plumtree
+            .publish(TopicId::from_entity(topic.as_bytes()), small)
+            .await
+            .expect("inject frame");
+
+        let message = sub.recv().await.expect("frame within the limit delivered");
+        assert_eq!(message.payload.len(), 1024);
+        let stats = manager.stats();
+        assert_eq!(stats.incoming_oversize_dropped, 1);
+        assert_eq!(stats.incoming_decoded, 1);
+    }
+
     #[tokio::test]
     async fn test_multiple_subscribers() {
         let node = test_node().await;
@@ -1937,16 +3737,184 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_handle_incoming_invalid() {
+    async fn test_handle_incoming_invalid() {
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        let _sub = manager.subscribe("test".to_string()).await;
+
+        let peer = PeerId::new([1; 32]);
+        // Should not panic on invalid data
+        manager
+            .handle_incoming(peer, Bytes::from(&[0x12][..]))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn subscribe_filtered_withholds_senders_below_min_trust() {
+        let node = test_node().await;
+        let own = AgentKeypair::generate().expect("own keygen");
+        let manager = PubSubManager::new(node, Some(Arc::new(SigningContext::from_keypair(&own))))
+            .expect("manager");
+        let known = SigningContext::from_keypair(&AgentKeypair::generate().expect("keygen"));
+        let stranger = SigningContext::from_keypair(&AgentKeypair::generate().expect("keygen"));
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut store = ContactStore::new(dir.path().join("contacts.json"));
+        store.set_trust(&known.agent_id, TrustLevel::Known);
+        manager.set_contacts(Arc::new(RwLock::new(store)));
+
+        let topic = "vetted";
+        let mut sub = manager
+            .subscribe_filtered(topic.to_string(), TrustLevel::Known)
+            .await;
+        for (ctx, body) in [(&stranger, "stranger"), (&known, "known")] {
+            let payload = Bytes::from(body);
+            let signing_payload =
+                build_signing_payload(ctx.agent_id.as_bytes(), topic.as_bytes(), &payload);
+            let signature = ctx.sign(&signing_payload).expect("sign");
+            let frame = encode_v2(
+                &ctx.agent_id,
+                &ctx.public_key_bytes,
+                &signature,
+                topic,
+                &payload,
+            )
+            .expect("encode");
+            manager
+                .plumtree
+                .publish(TopicId::from_entity(topic.as_bytes()), frame)
+                .await
+                .expect("inject frame");
+        }
+        manager
+            .publish(topic.to_string(), Bytes::from("own"))
+            .await
+            .expect("publish");
+
+        let first = sub.recv().await.expect("known sender delivered");
+        assert_eq!(first.payload, Bytes::from("known"));
+        let second = sub.recv().await.expect("own message delivered");
+        assert_eq!(second.payload, Bytes::from("own"));
+        assert_eq!(manager.stats().filtered_by_trust, 1);
+    }
+
+    #[tokio::test]
+    async fn relay_only_frames_skip_signature_verification() {
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        let ctx = SigningContext::from_keypair(&AgentKeypair::generate().expect("keygen"));
+        let frame = |topic: &str, body: &'static str, signed_body: &str| {
+            let signing_payload = build_signing_payload(
+                ctx.agent_id.as_bytes(),
+                topic.as_bytes(),
+                signed_body.as_bytes(),
+            );
+            let signature = ctx.sign(&signing_payload).expect("sign");
+            encode_v2(
+                &ctx.agent_id,
+                &ctx.public_key_bytes,
+                &signature,
+                topic,
+                &Bytes::from(body),
+            )
+            .expect("encode")
+        };
+        let mut sub = manager.subscribe("delivered".to_string()).await;
+
+        // Bad signatures on both topics; only the subscribed one is decoded.
+        for topic in ["relay-only", "delivered"] {
+            manager
+                .plumtree
+                .publish(
+                    TopicId::from_entity(topic.as_bytes()),
+                    frame(topic, "forged", "signed"),
+                )
+                .await
+                .expect("inject frame");
+        }
+        manager
+            .plumtree
+            .publish(
+                TopicId::from_entity("delivered".as_bytes()),
+                frame("delivered", "good", "good"),
+            )
+            .await
+            .expect("inject frame");
+
+        let message = sub.recv().await.expect("valid frame delivered");
+        assert_eq!(message.payload, Bytes::from("good"));
+        assert!(message.verified);
+        let stats = manager.stats();
+        assert_eq!(stats.incoming_total, 2);
+        assert_eq!(stats.incoming_decode_failed, 1);
+        assert_eq!(stats.incoming_decoded, 1);
+    }
+
+    #[tokio::test]
+    async fn repeated_frame_is_delivered_once() {
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        let topic = "dedupe";
+        let mut sub = manager.subscribe(topic.to_string()).await;
+        let mut other = manager.subscribe(topic.to_string()).await;
+        let frame = encode_v1(topic, &Bytes::from("once")).expect("encode");
+
+        // The same bytes injected twice, as when a payload comes back
+        // around a cycle under a fresh PlumTree message id.
+        for _ in 0..2 {
+            manager
+                .plumtree
+                .publish(TopicId::from_entity(topic.as_bytes()), frame.clone())
+                .await
+                .expect("inject frame");
+        }
+        manager
+            .plumtree
+            .publish(
+                TopicId::from_entity(topic.as_bytes()),
+                encode_v1(topic, &Bytes::from("next")).expect("encode"),
+            )
+            .await
+            .expect("inject frame");
+
+        // Each subscription sees the frame once, then the next one.
+        for sub in [&mut sub, &mut other] {
+            let first = sub.recv().await.expect("first frame delivered");
+            assert_eq!(first.payload, Bytes::from("once"));
+            let next = sub.recv().await.expect("next frame delivered");
+            assert_eq!(next.payload, Bytes::from("next"));
+        }
+        assert_eq!(manager.stats().incoming_duplicate_dropped, 2);
+    }
+
+    #[tokio::test]
+    async fn handle_incoming_passes_repeated_frames_to_plumtree() {
         let node = test_node().await;
         let manager = PubSubManager::new(node, None).expect("manager");
-        let _sub = manager.subscribe("test".to_string()).await;
+        let frame = Bytes::from(vec![0x12; 64]);
 
-        let peer = PeerId::new([1; 32]);
-        // Should not panic on invalid data
+        // Control frames (IHAVE/IWANT/GRAFT) repeat legitimately; only
+        // PlumTree can tell them apart from a replayed EAGER message.
         manager
-            .handle_incoming(peer, Bytes::from(&[0x12][..]))
+            .handle_incoming(PeerId::new([1; 32]), frame.clone())
             .await;
+        manager.handle_incoming(PeerId::new([2; 32]), frame).await;
+        assert_eq!(manager.stats().incoming_duplicate_dropped, 0);
+    }
+
+    #[test]
+    fn seen_frames_evicts_oldest_beyond_capacity() {
+        let mut seen = SeenFrames::default();
+        let a = blake3::hash(b"a");
+        let b = blake3::hash(b"b");
+        let c = blake3::hash(b"c");
+        assert!(seen.insert(a, 2));
+        assert!(seen.insert(b, 2));
+        assert!(!seen.insert(a, 2));
+        assert!(seen.insert(c, 2));
+        // `a` was evicted by `c` and counts as new again.
+        assert!(seen.insert(a, 2));
+        assert!(!seen.insert(c, 2));
     }
 
     // -----------------------------------------------------------------------
@@ -2079,6 +4047,618 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn prefix_subscription_matches_topics_by_prefix() {
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        let mut team = manager.subscribe_prefix("local:team/".to_string()).await;
+
+        for topic in ["local:team/tasks", "local:other", "local:team/chat"] {
+            manager
+                .publish(topic.to_string(), Bytes::from(topic))
+                .await
+                .expect("local publish");
+        }
+
+        assert_eq!(team.recv().await.expect("first").topic, "local:team/tasks");
+        assert_eq!(team.recv().await.expect("second").topic, "local:team/chat");
+        assert!(
+            team.receiver.try_recv().is_err(),
+            "non-matching topic leaked"
+        );
+
+        assert_eq!(manager.prefix_subscription_count().await, 1);
+        drop(team);
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert_eq!(manager.prefix_subscription_count().await, 0);
+        assert!(!manager.prefix_taps.is_empty(), "pruned lazily");
+        manager
+            .publish("local:team/tasks".to_string(), Bytes::from_static(b"x"))
+            .await
+            .expect("local publish");
+        assert!(
+            manager.prefix_taps.is_empty(),
+            "closed tap pruned on delivery"
+        );
+    }
+
+    #[tokio::test]
+    async fn prefix_subscription_delivers_each_message_once() {
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        let mut prefix = manager.subscribe_prefix("team/".to_string()).await;
+        // Two exact subscriptions on the topic each forward the frame, and
+        // the publish path taps it too; the prefix subscriber gets one copy.
+        let mut exact_a = manager.subscribe("team/tasks".to_string()).await;
+        let mut exact_b = manager.subscribe("team/tasks".to_string()).await;
+
+        manager
+            .publish("team/tasks".to_string(), Bytes::from_static(b"delta"))
+            .await
+            .expect("publish");
+
+        assert_eq!(exact_a.recv().await.expect("exact a").payload, "delta");
+        assert_eq!(exact_b.recv().await.expect("exact b").payload, "delta");
+        let msg = prefix.recv().await.expect("prefix");
+        assert_eq!(msg.topic, "team/tasks");
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert!(prefix.receiver.try_recv().is_err(), "duplicate delivery");
+    }
+
+    #[tokio::test]
+    async fn prefix_subscription_keeps_receiving_a_topic_once_seen() {
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        let mut prefix = manager.subscribe_prefix("team/".to_string()).await;
+
+        // An exact subscription reveals the topic name, then goes away.
+        drop(manager.subscribe("team/chat".to_string()).await);
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert!(manager.prefix_routes.lock().unwrap().contains("team/chat"));
+
+        // Traffic on the topic, as from a remote peer, still arrives.
+        manager
+            .plumtree
+            .publish(
+                TopicId::from_entity("team/chat".as_bytes()),
+                encode_v1("team/chat", &Bytes::from("remote")).expect("encode"),
+            )
+            .await
+            .expect("inject frame");
+        let msg = prefix.recv().await.expect("routed delivery");
+        assert_eq!(msg.topic, "team/chat");
+        assert_eq!(msg.payload, Bytes::from("remote"));
+
+        // Without a matching prefix subscription the route closes.
+        drop(prefix);
+        manager
+            .plumtree
+            .publish(
+                TopicId::from_entity("team/chat".as_bytes()),
+                encode_v1("team/chat", &Bytes::from("late")).expect("encode"),
+            )
+            .await
+            .expect("inject frame");
+        let mut closed = false;
+        for _ in 0..200 {
+            if manager.prefix_routes.lock().unwrap().is_empty() {
+                closed = true;
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert!(closed, "route must close with its last prefix subscription");
+    }
+
+    #[tokio::test]
+    async fn subscribing_past_the_threshold_warns() {
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        manager.set_subscription_warn_threshold(2);
+
+        let mut subs = Vec::new();
+        for _ in 0..2 {
+            subs.push(manager.subscribe("local:leak".to_string()).await);
+        }
+        assert_eq!(manager.stats().subscription_threshold_exceeded, 0);
+
+        subs.push(manager.subscribe("local:leak".to_string()).await);
+        assert_eq!(manager.stats().subscription_threshold_exceeded, 1);
+        // Rate-limited: the next warning waits for another `threshold`.
+        subs.push(manager.subscribe("local:leak".to_string()).await);
+        assert_eq!(manager.stats().subscription_threshold_exceeded, 1);
+        subs.push(manager.subscribe("local:leak".to_string()).await);
+        assert_eq!(manager.stats().subscription_threshold_exceeded, 2);
+    }
+
+    /// The exact subscription a prefix route holds open is not a subscriber:
+    /// a wildcard over a busy namespace must not look like a leak.
+    #[tokio::test]
+    async fn prefix_routes_do_not_count_as_subscriptions() {
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        manager.set_subscription_warn_threshold(1);
+
+        let _feed = manager.subscribe("x0x.space.abc.feed".to_string()).await;
+        let _prefix = manager.subscribe_prefix("x0x.".to_string()).await;
+        assert!(manager
+            .prefix_routes
+            .lock()
+            .unwrap()
+            .contains("x0x.space.abc.feed"));
+        let _chat = manager.subscribe("x0x.group.abc.chat".to_string()).await;
+        assert!(manager
+            .prefix_routes
+            .lock()
+            .unwrap()
+            .contains("x0x.group.abc.chat"));
+
+        assert_eq!(manager.stats().subscription_threshold_exceeded, 0);
+        assert_eq!(manager.subscription_count().await, 2);
+        let lags = manager.subscriber_stats();
+        assert_eq!(lags.len(), 2);
+        assert!(lags.iter().all(|lag| lag.subscribers == 1), "{lags:?}");
+    }
+
+    #[tokio::test]
+    async fn subscribe_with_capacity_bounds_the_channel() {
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        let small = manager
+            .subscribe_with_capacity("local:small".to_string(), 2)
+            .await;
+        // Zero is clamped rather than panicking in `mpsc::channel`.
+        let clamped = manager
+            .subscribe_with_capacity("local:small".to_string(), 0)
+            .await;
+
+        for i in 0..3u8 {
+            manager
+                .publish("local:small".to_string(), Bytes::from(vec![i]))
+                .await
+                .expect("local publish");
+        }
+
+        assert_eq!(small.overflow().buffered(), 2);
+        assert_eq!(small.overflow().dropped(), 1);
+        assert_eq!(clamped.overflow().buffered(), 1);
+        assert_eq!(clamped.overflow().dropped(), 2);
+    }
+
+    /// The global `slow_subscriber_dropped` counter can't tell operators
+    /// *which* consumer is losing messages; the per-subscription counters
+    /// must attribute drops and queue depth to the stalled subscriber only.
+    #[tokio::test]
+    async fn subscription_overflow_attributes_drops_to_the_slow_subscriber() {
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        let mut slow = manager.subscribe("local:overflow".to_string()).await;
+        let mut fast = manager.subscribe("local:overflow".to_string()).await;
+        let slow_overflow = slow.overflow();
+
+        for i in 0..10_005u32 {
+            manager
+                .publish(
+                    "local:overflow".to_string(),
+                    Bytes::from(i.to_be_bytes().to_vec()),
+                )
+                .await
+                .expect("local publish");
+            fast.recv().await.expect("fast subscriber keeps up");
+        }
+
+        assert_eq!(slow_overflow.dropped(), 5);
+        assert_eq!(slow_overflow.buffered(), 10_000);
+        assert_eq!(fast.overflow().dropped(), 0);
+        assert_eq!(fast.overflow().buffered(), 0);
+        assert_eq!(
+            manager.subscriber_stats(),
+            vec![TopicLag {
+                topic: "local:overflow".to_string(),
+                subscribers: 2,
+                dropped: 5,
+                buffered: 10_000,
+            }]
+        );
+
+        slow.recv().await.expect("buffered message");
+        assert_eq!(slow_overflow.buffered(), 9_999);
+
+        // Closed local subscribers are pruned on the next publish.
+        drop((slow, fast, slow_overflow));
+        manager
+            .publish("local:overflow".to_string(), Bytes::from_static(b"x"))
+            .await
+            .expect("local publish");
+        assert!(manager.subscriber_stats().is_empty());
+    }
+
+    /// A PlumTree subscriber that never reads must not stall delivery: once
+    /// its channel is full it is isolated and counted, and both publishes and
+    /// inbound frames keep flowing to everyone else without waiting on it.
+    #[tokio::test]
+    async fn saturated_subscriber_does_not_stall_incoming_delivery() {
+        use tokio::time::{timeout, Duration};
+
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        let slow = manager.subscribe("saturated".to_string()).await;
+        let mut fast = manager.subscribe("saturated".to_string()).await;
+        let slow_overflow = slow.overflow();
+
+        for i in 0..10_010u32 {
+            manager
+                .publish(
+                    "saturated".to_string(),
+                    Bytes::from(i.to_be_bytes().to_vec()),
+                )
+                .await
+                .expect("publish");
+            let msg = timeout(Duration::from_secs(5), fast.recv())
+                .await
+                .expect("fast subscriber must not wait behind the slow one")
+                .expect("fast subscriber open");
+            assert_eq!(msg.payload, Bytes::from(i.to_be_bytes().to_vec()));
+        }
+
+        assert_eq!(slow_overflow.dropped(), 1);
+        assert_eq!(manager.stats().slow_subscriber_dropped, 1);
+        timeout(
+            Duration::from_secs(1),
+            manager.handle_incoming(PeerId::new([9u8; 32]), Bytes::from_static(b"frame")),
+        )
+        .await
+        .expect("handle_incoming must return promptly with a saturated subscriber");
+    }
+
+    /// A v1-pinned manager never emits a signed v2 frame, even with a
+    /// signing context, and drops inbound v2 frames while still delivering v1.
+    #[tokio::test]
+    async fn pinned_wire_version_caps_outgoing_and_drops_newer_inbound() {
+        let node = test_node().await;
+        let kp = AgentKeypair::generate().expect("keygen");
+        let ctx = Arc::new(SigningContext::from_keypair(&kp));
+        let manager = PubSubManager::new(node, Some(Arc::clone(&ctx))).expect("manager");
+        assert!(manager.set_wire_version(0).is_err());
+        assert!(manager.set_wire_version(LATEST_WIRE_VERSION + 1).is_err());
+        manager.set_wire_version(WIRE_VERSION_V1).expect("pin v1");
+        assert_eq!(manager.wire_version(), WIRE_VERSION_V1);
+
+        let mut sub = manager.subscribe("chat".to_string()).await;
+        manager
+            .publish("chat".to_string(), Bytes::from("pinned"))
+            .await
+            .expect("publish");
+        let msg = sub.recv().await.expect("v1 delivery");
+        assert_eq!(msg.payload, Bytes::from("pinned"));
+        assert!(msg.sender.is_none(), "pinned publish must be unsigned v1");
+        assert!(!msg.verified);
+
+        let payload = Bytes::from("from a v2 peer");
+        let signing_payload = build_signing_payload(ctx.agent_id.as_bytes(), b"chat", &payload);
+        let signature = ctx.sign(&signing_payload).expect("sign");
+        let v2_frame = encode_v2(
+            &ctx.agent_id,
+            &ctx.public_key_bytes,
+            &signature,
+            "chat",
+            &payload,
+        )
+        .expect("encode");
+        assert_eq!(wire_version_of(&v2_frame), WIRE_VERSION_V2);
+        let decode_failed_before = manager.stats().incoming_decode_failed;
+        manager
+            .plumtree
+            .publish(TopicId::from_entity(b"chat"), v2_frame)
+            .await
+            .expect("inject v2 frame");
+        manager
+            .publish("chat".to_string(), Bytes::from("after"))
+            .await
+            .expect("publish");
+
+        let msg = sub.recv().await.expect("v1 delivery after dropped v2");
+        assert_eq!(msg.payload, Bytes::from("after"));
+        assert_eq!(
+            manager.stats().incoming_decode_failed,
+            decode_failed_before + 1
+        );
+    }
+
+    /// A JSON-like payload the size of a CRDT snapshot.
+    fn snapshot_payload(len: usize) -> Bytes {
+        let mut payload = Vec::with_capacity(len);
+        let mut i = 0u32;
+        while payload.len() < len {
+            payload.extend_from_slice(format!("{{\"task\":{i},\"done\":false}},").as_bytes());
+            i += 1;
+        }
+        payload.truncate(len);
+        Bytes::from(payload)
+    }
+
+    /// A 10 KiB publish goes out as a smaller v4 frame and reaches the
+    /// subscriber as the original, still-verified plaintext.
+    #[tokio::test]
+    async fn large_payload_is_compressed_and_roundtrips() {
+        let node = test_node().await;
+        let kp = AgentKeypair::generate().expect("keygen");
+        let ctx = Arc::new(SigningContext::from_keypair(&kp));
+        let manager = PubSubManager::new(node, Some(Arc::clone(&ctx))).expect("manager");
+        manager.set_compression_threshold(DEFAULT_COMPRESSION_THRESHOLD);
+
+        let payload = snapshot_payload(10 * 1024);
+        let signing_payload = build_signing_payload(ctx.agent_id.as_bytes(), b"crdt", &payload);
+        let signature = ctx.sign(&signing_payload).expect("sign");
+        let frame = encode_v2(
+            &ctx.agent_id,
+            &ctx.public_key_bytes,
+            &signature,
+            "crdt",
+            &payload,
+        )
+        .expect("encode");
+        let compressed = manager.maybe_compress(frame.clone(), payload.len());
+        assert_eq!(wire_version_of(&compressed), WIRE_VERSION_V4);
+        assert!(compressed.len() < frame.len());
+        let decoded = decode_auto(compressed).expect("decode compressed");
+        assert_eq!(decoded.payload, payload);
+        assert!(decoded.verified);
+
+        let mut sub = manager.subscribe("crdt".to_string()).await;
+        manager
+            .publish("crdt".to_string(), payload.clone())
+            .await
+            .expect("publish");
+        let msg = sub.recv().await.expect("delivery");
+        assert_eq!(msg.payload, payload);
+        assert!(msg.verified);
+        assert_eq!(msg.sender, Some(kp.agent_id()));
+    }
+
+    /// Payloads below the threshold, managers without compression and
+    /// managers pinned below v4 all send the frame unchanged.
+    #[tokio::test]
+    async fn small_or_disabled_payloads_stay_uncompressed() {
+        let small = snapshot_payload(DEFAULT_COMPRESSION_THRESHOLD - 1);
+        let small_frame = encode_v1("crdt", &small).expect("encode");
+        let large = snapshot_payload(10 * 1024);
+        let large_frame = encode_v1("crdt", &large).expect("encode");
+
+        let enabled = PubSubManager::new(test_node().await, None).expect("manager");
+        enabled.set_compression_threshold(DEFAULT_COMPRESSION_THRESHOLD);
+        assert_eq!(
+            enabled.maybe_compress(small_frame.clone(), small.len()),
+            small_frame
+        );
+
+        let disabled = PubSubManager::new(test_node().await, None).expect("manager");
+        assert_eq!(
+            disabled.maybe_compress(large_frame.clone(), large.len()),
+            large_frame
+        );
+
+        let pinned = PubSubManager::new(test_node().await, None).expect("manager");
+        pinned.set_compression_threshold(DEFAULT_COMPRESSION_THRESHOLD);
+        pinned.set_wire_version(WIRE_VERSION_V3).expect("pin v3");
+        assert_eq!(
+            pinned.maybe_compress(large_frame.clone(), large.len()),
+            large_frame
+        );
+    }
+
+    #[test]
+    fn decompress_rejects_oversized_and_nested_frames() {
+        let frame = encode_v1("crdt", &snapshot_payload(10 * 1024)).expect("encode");
+        let compressed = compress_frame(&frame).expect("compressible");
+
+        let mut oversized = compressed.to_vec();
+        oversized[1..5].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(decode_auto(Bytes::from(oversized)).is_err());
+
+        let nested = compress_frame(&compressed.repeat(4)).expect("compressible");
+        assert!(decode_auto(nested).is_err());
+
+        assert!(decode_auto(Bytes::from_static(&[VERSION_COMPRESSED, 0, 0])).is_err());
+    }
+
+    /// Publishing as the agent carries no user attribution; publishing on
+    /// behalf of each certifying user attributes the message to that user.
+    #[tokio::test]
+    async fn publish_on_behalf_attributes_message_to_selected_user() {
+        use crate::identity::UserKeypair;
+
+        let node = test_node().await;
+        let kp = AgentKeypair::generate().expect("keygen");
+        let ctx = Arc::new(SigningContext::from_keypair(&kp));
+        let manager = PubSubManager::new(node, Some(ctx)).expect("manager");
+        let alice = UserKeypair::generate().expect("alice");
+        let bob = UserKeypair::generate().expect("bob");
+        let alice_cert = AgentCertificate::issue(&alice, &kp).expect("alice cert");
+        let bob_cert = AgentCertificate::issue(&bob, &kp).expect("bob cert");
+
+        let mut sub = manager.subscribe("chat".to_string()).await;
+        manager
+            .publish("chat".to_string(), Bytes::from("agent"))
+            .await
+            .expect("publish");
+        let msg = sub.recv().await.expect("agent delivery");
+        assert!(msg.verified);
+        assert_eq!(msg.sender, Some(kp.agent_id()));
+        assert_eq!(msg.on_behalf_of, None);
+
+        for (cert, user) in [(alice_cert, alice.user_id()), (bob_cert, bob.user_id())] {
+            manager
+                .publish_on_behalf("chat".to_string(), Bytes::from("user"), cert)
+                .await
+                .expect("publish on behalf");
+            let msg = sub.recv().await.expect("attested delivery");
+            assert!(msg.verified);
+            assert_eq!(msg.sender, Some(kp.agent_id()));
+            assert_eq!(msg.on_behalf_of, Some(user));
+        }
+    }
+
+    /// A certificate for another agent is refused at publish time, and a
+    /// v3 frame whose certificate binds another key is not attributed.
+    #[tokio::test]
+    async fn publish_on_behalf_rejects_foreign_certificate() {
+        use crate::identity::UserKeypair;
+
+        let node = test_node().await;
+        let kp = AgentKeypair::generate().expect("keygen");
+        let other = AgentKeypair::generate().expect("other keygen");
+        let ctx = SigningContext::from_keypair(&kp);
+        let manager = PubSubManager::new(node, Some(Arc::new(SigningContext::from_keypair(&kp))))
+            .expect("manager");
+        let user = UserKeypair::generate().expect("user");
+        let foreign = AgentCertificate::issue(&user, &other).expect("foreign cert");
+
+        assert!(manager
+            .publish_on_behalf("chat".to_string(), Bytes::from("x"), foreign.clone())
+            .await
+            .is_err());
+
+        let frame = encode_signed_v3(&ctx, &foreign, "chat", &Bytes::from("x")).expect("encode");
+        assert_eq!(wire_version_of(&frame), WIRE_VERSION_V3);
+        let msg = decode_auto(frame).expect("decode");
+        assert!(!msg.verified);
+        assert_eq!(msg.on_behalf_of, None);
+
+        let own = AgentCertificate::issue(&user, &kp).expect("own cert");
+        let frame = encode_signed_v3(&ctx, &own, "chat", &Bytes::from("x")).expect("encode");
+        let msg = decode_auto(frame).expect("decode");
+        assert!(msg.verified);
+        assert_eq!(msg.on_behalf_of, Some(user.user_id()));
+
+        manager.set_wire_version(WIRE_VERSION_V2).expect("pin v2");
+        assert!(matches!(
+            manager
+                .publish_on_behalf("chat".to_string(), Bytes::from("x"), own)
+                .await,
+            Err(NetworkError::ConfigError(_))
+        ));
+    }
+
+    /// Real certificates fit the v3 bound with room to spare; a frame
+    /// embedding anything larger is rejected outright.
+    #[test]
+    fn v3_certificate_size_is_bounded() {
+        use crate::identity::UserKeypair;
+
+        let kp = AgentKeypair::generate().expect("keygen");
+        let user = UserKeypair::generate().expect("user");
+        let cert = AgentCertificate::issue_with_expiry(&user, &kp, Some(u64::MAX))
+            .expect("cert")
+            .to_storage_bytes()
+            .expect("cert bytes");
+        assert!(cert.len() <= MAX_V3_CERTIFICATE_BYTES);
+
+        let ctx = SigningContext::from_keypair(&kp);
+        let oversized = vec![0u8; MAX_V3_CERTIFICATE_BYTES + 1];
+        let mut frame = vec![VERSION_V3];
+        frame.extend_from_slice(ctx.agent_id.as_bytes());
+        let fields: [&[u8]; 4] = [&ctx.public_key_bytes, &[0u8; 16], &oversized, b"chat"];
+        for field in fields {
+            frame.extend_from_slice(&u16::try_from(field.len()).expect("len").to_be_bytes());
+            frame.extend_from_slice(field);
+        }
+        frame.extend_from_slice(b"payload");
+        assert!(matches!(
+            decode_auto(Bytes::from(frame)),
+            Err(NetworkError::SerializationError(e)) if e.contains("certificate exceeds")
+        ));
+    }
+
+    /// Publishes made during a maintenance pause are queued up to the bound,
+    /// refused beyond it, and delivered in order once resumed.
+    #[tokio::test]
+    async fn paused_publishes_are_queued_and_delivered_on_resume() {
+        use crate::gossip::pause::{GossipPauseConfig, PausedIncomingPolicy, PausedPublishPolicy};
+
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        manager.set_pause_config(GossipPauseConfig {
+            incoming: PausedIncomingPolicy::Buffer,
+            publish: PausedPublishPolicy::Queue,
+            max_buffered: 3,
+        });
+        let mut sub = manager.subscribe("local:maint".to_string()).await;
+
+        assert!(manager.pause());
+        assert!(!manager.pause(), "second pause is a no-op");
+        for i in 0..3u8 {
+            manager
+                .publish("local:maint".to_string(), Bytes::from(vec![i]))
+                .await
+                .expect("queued while paused");
+        }
+        let overflow = manager
+            .publish("local:maint".to_string(), Bytes::from_static(b"x"))
+            .await;
+        assert!(matches!(overflow, Err(NetworkError::GossipPaused(_))));
+        manager
+            .handle_incoming(PeerId::new([9u8; 32]), Bytes::from_static(b"frame"))
+            .await;
+        assert!(
+            tokio::time::timeout(std::time::Duration::from_millis(50), sub.recv())
+                .await
+                .is_err(),
+            "nothing is delivered while paused"
+        );
+
+        assert!(manager.resume().await);
+        for i in 0..3u8 {
+            let msg = sub.recv().await.expect("replayed publish");
+            assert_eq!(msg.payload, Bytes::from(vec![i]));
+        }
+        let stats = manager.pause_stats();
+        assert!(!stats.paused);
+        assert_eq!(stats.pauses, 1);
+        assert_eq!(stats.publish_queued, 3);
+        assert_eq!(stats.publish_rejected, 1);
+        assert_eq!(stats.publish_replayed, 3);
+        assert_eq!(stats.incoming_buffered, 1);
+        assert_eq!(stats.incoming_replayed, 1);
+        assert!(!manager.resume().await, "resume when not paused is a no-op");
+    }
+
+    #[tokio::test]
+    async fn paused_reject_policy_refuses_publishes_and_drops_incoming() {
+        use crate::gossip::pause::{GossipPauseConfig, PausedIncomingPolicy, PausedPublishPolicy};
+
+        let node = test_node().await;
+        let manager = PubSubManager::new(node, None).expect("manager");
+        manager.set_pause_config(GossipPauseConfig {
+            incoming: PausedIncomingPolicy::Drop,
+            publish: PausedPublishPolicy::Reject,
+            ..GossipPauseConfig::default()
+        });
+        let mut sub = manager.subscribe("local:maint".to_string()).await;
+
+        manager.pause();
+        let refused = manager
+            .publish("local:maint".to_string(), Bytes::from_static(b"early"))
+            .await;
+        assert!(matches!(refused, Err(NetworkError::GossipPaused(_))));
+        manager
+            .handle_incoming(PeerId::new([9u8; 32]), Bytes::from_static(b"frame"))
+            .await;
+        manager.resume().await;
+
+        manager
+            .publish("local:maint".to_string(), Bytes::from_static(b"late"))
+            .await
+            .expect("publish after resume");
+        let msg = sub.recv().await.expect("post-resume publish");
+        assert_eq!(msg.payload, Bytes::from_static(b"late"));
+        let stats = manager.pause_stats();
+        assert_eq!(stats.publish_rejected, 1);
+        assert_eq!(stats.publish_replayed, 0);
+        assert_eq!(stats.incoming_dropped, 1);
+        assert_eq!(stats.incoming_replayed, 0);
+    }
+
     // ── PubSubStats ────────────────────────────────────────────────────
 
     #[test]
@@ -2093,6 +4673,9 @@ mod tests {
         assert_eq!(snap.delivered_to_subscriber, 0);
         assert_eq!(snap.slow_subscriber_dropped, 0);
         assert_eq!(snap.subscriber_channel_closed, 0);
+        assert_eq!(snap.incoming_duplicate_dropped, 0);
+        assert_eq!(snap.incoming_oversize_dropped, 0);
+        assert_eq!(snap.filtered_by_trust, 0);
     }
 
     #[test]
@@ -1,6 +1,8 @@
 //! Gossip runtime orchestration.
 
 use super::config::GossipConfig;
+use super::ordering::SenderOrder;
+use super::pause::GossipPauseStatsSnapshot;
 use super::pubsub::{PubSubManager, SigningContext};
 use crate::error::NetworkResult;
 use crate::network::NetworkNode;
@@ -319,6 +321,7 @@ async fn run_pubsub_dispatcher(
     network: Arc<NetworkNode>,
     pubsub: Arc<PubSubManager>,
     dispatch_stats: Arc<GossipDispatchStats>,
+    sender_order: Option<Arc<SenderOrder>>,
 ) {
     loop {
         // X0X-0009: every worker slot is spawned once and tracked for
@@ -339,8 +342,23 @@ async fn run_pubsub_dispatcher(
             }
             continue;
         }
-        match network.receive_pubsub_message().await {
-            Ok((peer, data)) => {
+        let received = match sender_order.as_deref() {
+            Some(order) => order
+                .dequeue(network.receive_pubsub_message())
+                .await
+                .map(|(peer, data, ticket)| (peer, data, Some(ticket))),
+            None => network
+                .receive_pubsub_message()
+                .await
+                .map(|(peer, data)| (peer, data, None)),
+        };
+        match received {
+            Ok((peer, data, ticket)) => {
+                // Holding the ticket until the end of this arm keeps the
+                // peer's next frame waiting until this one is handled.
+                if let Some(ticket) = &ticket {
+                    ticket.wait_turn().await;
+                }
                 let (recv_depth, recv_capacity) =
                     network.gossip_recv_queue_depth(GossipStreamType::PubSub);
                 dispatch_stats.record_dequeue(GossipStreamType::PubSub, recv_depth, recv_capacity);
@@ -875,6 +893,14 @@ impl GossipRuntime {
             signing,
             Some(oracle),
         )?);
+        pubsub.set_topic_sharding(config.topic_sharding);
+        pubsub.set_pause_config(config.pause);
+        pubsub.set_subscription_warn_threshold(config.subscription_warn_threshold);
+        pubsub.set_seen_frames_capacity(config.seen_frames_capacity);
+        pubsub.set_max_message_bytes(config.max_message_bytes);
+        if config.compression {
+            pubsub.set_compression_threshold(config.compression_threshold);
+        }
         let dispatch_workers = config.dispatch_workers;
 
         Ok(Self {
@@ -943,6 +969,35 @@ impl GossipRuntime {
         self.dispatch_stats.snapshot()
     }
 
+    /// Pause pub/sub processing for a maintenance window.
+    ///
+    /// Connections, membership and SWIM keep running; inbound PubSub frames
+    /// are buffered or dropped and publishes queued or rejected according
+    /// to [`GossipConfig::pause`]. Returns `false` if already paused.
+    pub fn pause(&self) -> bool {
+        let started = self.pubsub.pause();
+        if started {
+            tracing::info!("Gossip pub/sub paused for maintenance");
+        }
+        started
+    }
+
+    /// Resume pub/sub processing, replaying held frames and queued
+    /// publishes. Returns `false` if not paused.
+    pub async fn resume(&self) -> bool {
+        let resumed = self.pubsub.resume().await;
+        if resumed {
+            tracing::info!(stats = ?self.pubsub.pause_stats(), "Gossip pub/sub resumed");
+        }
+        resumed
+    }
+
+    /// Snapshot of what pauses have held, dropped, rejected and replayed.
+    #[must_use]
+    pub fn pause_stats(&self) -> GossipPauseStatsSnapshot {
+        self.pubsub.pause_stats()
+    }
+
     /// Start the gossip runtime.
     ///
     /// This initializes all gossip components and begins protocol operations.
@@ -970,6 +1025,10 @@ impl GossipRuntime {
             .store(usize_to_u64(pubsub_worker_count), Ordering::Relaxed);
         let target_count = Arc::new(AtomicUsize::new(pubsub_worker_count));
         let worker_notify = Arc::new(Notify::new());
+        let sender_order = self
+            .config
+            .preserve_sender_order
+            .then(|| Arc::new(SenderOrder::new()));
         let mut pubsub_handles = Vec::with_capacity(PUBSUB_WORKER_MAX + 1);
         for worker_id in 0..PUBSUB_WORKER_MAX {
             pubsub_handles.push(tokio::spawn(run_pubsub_dispatcher(
@@ -979,6 +1038,7 @@ impl GossipRuntime {
                 Arc::clone(&network),
                 Arc::clone(&pubsub),
                 Arc::clone(&dispatch_stats),
+                sender_order.clone(),
             )));
         }
         let supervisor_handle = tokio::spawn(run_pubsub_worker_supervisor(
@@ -1004,15 +1064,34 @@ impl GossipRuntime {
             Arc::clone(&dispatch_stats),
         ));
 
-        // Periodically refresh PlumTree topic peers with current connections.
-        // This ensures newly connected peers (discovered via HyParView or
-        // direct connection) are added to the eager set for existing topics.
-        // Using 1-second interval to minimize the window where a newly-connected
-        // peer could miss a published message (e.g. release manifest broadcast).
+        // Refresh PlumTree topic peers with current connections: once now,
+        // on every new connection, and every second. This ensures newly
+        // connected peers (discovered via HyParView or direct connection) are
+        // added to the eager set for existing topics — including topics
+        // subscribed before the runtime started — so a newly-connected peer
+        // does not miss a published message (e.g. release manifest
+        // broadcast). The periodic tick also covers peers that only become
+        // gossip-eligible after the plane hello (issue #206), which emits no
+        // connection event of its own.
         let pubsub_refresh = Arc::clone(&self.pubsub);
+        let mut network_events = self.network.subscribe();
         let peer_sync_handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(1));
+            let mut events_open = true;
             loop {
-                tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+                tokio::select! {
+                    _ = interval.tick() => {}
+                    event = network_events.recv(), if events_open => match event {
+                        Ok(crate::network::NetworkEvent::PeerConnected { .. })
+                        | Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {}
+                        Ok(_) => continue,
+                        // Keep the periodic refresh alone.
+                        Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                            events_open = false;
+                            continue;
+                        }
+                    },
+                }
                 pubsub_refresh.refresh_topic_peers().await;
             }
         });
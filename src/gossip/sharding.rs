@@ -0,0 +1,364 @@
+//! Opt-in deterministic topic sharding for relay traffic.
+//!
+//! By default every node relays every topic it sees to every plane-cleared
+//! peer. On very large meshes that flood does not scale, so x0x can instead
+//! partition the topic-hash space into `shard_count` shards and make each
+//! peer responsible for a contiguous run of `shards_per_peer` of them. The
+//! partition is consistent hashing over BLAKE3: both the topic's shard and a
+//! peer's range are pure functions of their 32-byte ids, so every node
+//! computes the same answer for any `(peer, topic)` pair without
+//! coordination.
+//!
+//! # Relay rule
+//!
+//! Only *pass-through* topics (known to PlumTree but with no local
+//! subscriber) are affected. For such a topic a node:
+//!
+//! - relays to every peer when it is responsible for the topic's shard;
+//! - otherwise relays only to connected peers that *are* responsible (the
+//!   rendezvous hop), falling back to every peer when none of its
+//!   neighbours is responsible, so sharding can never partition a topic.
+//!
+//! # Subscribing to a topic you don't shard
+//!
+//! Locally subscribed and locally published topics always keep the full
+//! eager set, whether or not this node owns their shard. An out-of-shard
+//! subscriber is therefore reached through the rendezvous hop: publishers
+//! push to their neighbours, non-owning relays funnel the message into the
+//! topic's owners, and the owners flood it to all of their neighbours —
+//! including the subscriber as long as it is connected to at least one
+//! owner. [`TopicShardingConfig::rendezvous_peer`] names the canonical owner
+//! among a set of known peers so callers that want that guarantee can dial
+//! it explicitly.
+
+use saorsa_gossip_types::{PeerId, TopicId};
+use serde::{Deserialize, Serialize};
+
+/// Domain separation for topic shard placement.
+const TOPIC_SHARD_DOMAIN: &[u8] = b"x0x-topic-shard-v1";
+
+/// Configuration for opt-in topic sharding.
+///
+/// Disabled by default; a disabled config leaves relay behaviour unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TopicShardingConfig {
+    /// Enable shard-restricted relaying of pass-through topics.
+    /// Default: false
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Number of shards the topic-hash space is divided into.
+    /// Default: 256
+    #[serde(default = "default_shard_count")]
+    pub shard_count: u16,
+
+    /// Number of consecutive shards each peer is responsible for. Higher
+    /// values trade relay load for more owners (redundancy) per topic.
+    /// Default: 32
+    #[serde(default = "default_shards_per_peer")]
+    pub shards_per_peer: u16,
+}
+
+const fn default_shard_count() -> u16 {
+    256
+}
+
+const fn default_shards_per_peer() -> u16 {
+    32
+}
+
+impl Default for TopicShardingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            shard_count: default_shard_count(),
+            shards_per_peer: default_shards_per_peer(),
+        }
+    }
+}
+
+impl TopicShardingConfig {
+    /// Validate the sharding parameters.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.shard_count == 0 {
+            return Err("topic_sharding.shard_count must be > 0".to_string());
+        }
+        if self.shards_per_peer == 0 {
+            return Err("topic_sharding.shards_per_peer must be > 0".to_string());
+        }
+        if self.shards_per_peer > self.shard_count {
+            return Err("topic_sharding.shards_per_peer must be <= shard_count".to_string());
+        }
+        Ok(())
+    }
+
+    /// The shard a topic hashes into.
+    #[must_use]
+    pub fn topic_shard(&self, topic: &TopicId) -> u16 {
+        shard_of(b"topic", &topic.to_bytes(), self.shard_count)
+    }
+
+    /// The first shard of the run `peer` is responsible for.
+    #[must_use]
+    pub fn peer_range_start(&self, peer: &PeerId) -> u16 {
+        shard_of(b"peer", &peer.to_bytes(), self.shard_count)
+    }
+
+    /// Whether `peer` is responsible for relaying `topic`.
+    #[must_use]
+    pub fn is_responsible(&self, peer: &PeerId, topic: &TopicId) -> bool {
+        let count = u32::from(self.shard_count.max(1));
+        let start = u32::from(self.peer_range_start(peer));
+        let shard = u32::from(self.topic_shard(topic));
+        // Distance walking forward around the ring from the peer's start.
+        let offset = (shard + count - start) % count;
+        offset < u32::from(self.shards_per_peer)
+    }
+
+    /// Select the peers a node should relay a pass-through `topic` to.
+    ///
+    /// Returns `connected` unchanged when sharding is disabled, when the
+    /// local node owns the topic's shard, or when no connected peer owns it.
+    #[must_use]
+    pub fn relay_peers(
+        &self,
+        local: &PeerId,
+        topic: &TopicId,
+        connected: &[PeerId],
+    ) -> Vec<PeerId> {
+        if !self.enabled || self.is_responsible(local, topic) {
+            return connected.to_vec();
+        }
+        let owners: Vec<PeerId> = connected
+            .iter()
+            .copied()
+            .filter(|peer| self.is_responsible(peer, topic))
+            .collect();
+        if owners.is_empty() {
+            connected.to_vec()
+        } else {
+            owners
+        }
+    }
+
+    /// Canonical rendezvous owner for `topic` among `known` peers: the owner
+    /// whose range starts closest before the topic's shard. `None` when no
+    /// known peer owns the shard.
+    #[must_use]
+    pub fn rendezvous_peer(&self, topic: &TopicId, known: &[PeerId]) -> Option<PeerId> {
+        let count = u32::from(self.shard_count.max(1));
+        let shard = u32::from(self.topic_shard(topic));
+        known
+            .iter()
+            .copied()
+            .filter(|peer| self.is_responsible(peer, topic))
+            .min_by_key(|peer| {
+                let start = u32::from(self.peer_range_start(peer));
+                ((shard + count - start) % count, peer.to_bytes())
+            })
+    }
+}
+
+/// Map a 32-byte id into `[0, shard_count)` with BLAKE3 domain separation.
+fn shard_of(kind: &[u8], id: &[u8; 32], shard_count: u16) -> u16 {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(TOPIC_SHARD_DOMAIN);
+    hasher.update(kind);
+    hasher.update(id);
+    let digest = hasher.finalize();
+    let bytes = digest.as_bytes();
+    let value = u16::from_be_bytes([bytes[0], bytes[1]]);
+    value % shard_count.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{HashSet, VecDeque};
+
+    fn peer(n: u16) -> PeerId {
+        let mut bytes = [0u8; 32];
+        bytes[..2].copy_from_slice(&n.to_be_bytes());
+        PeerId::new(bytes)
+    }
+
+    fn enabled() -> TopicShardingConfig {
+        TopicShardingConfig {
+            enabled: true,
+            shard_count: 64,
+            shards_per_peer: 8,
+        }
+    }
+
+    #[test]
+    fn default_is_disabled_and_valid() {
+        let cfg = TopicShardingConfig::default();
+        assert!(!cfg.enabled);
+        assert!(cfg.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_degenerate_ranges() {
+        let zero = TopicShardingConfig {
+            shard_count: 0,
+            ..Default::default()
+        };
+        assert!(zero.validate().is_err());
+        let too_wide = TopicShardingConfig {
+            shard_count: 4,
+            shards_per_peer: 5,
+            ..Default::default()
+        };
+        assert!(too_wide.validate().is_err());
+    }
+
+    #[test]
+    fn disabled_config_relays_to_everyone() {
+        let cfg = TopicShardingConfig::default();
+        let connected: Vec<PeerId> = (1..10).map(peer).collect();
+        let topic = TopicId::from_entity(b"any-topic");
+        assert_eq!(cfg.relay_peers(&peer(0), &topic, &connected), connected);
+    }
+
+    #[test]
+    fn placement_is_deterministic_across_instances() {
+        // Every node must reach the same verdict for the same (peer, topic)
+        // pair, otherwise relays would funnel into peers that don't forward.
+        let a = enabled();
+        let b = enabled();
+        let topic = TopicId::from_entity(b"x0x.shard.test");
+        for n in 0..100 {
+            assert_eq!(
+                a.is_responsible(&peer(n), &topic),
+                b.is_responsible(&peer(n), &topic)
+            );
+        }
+    }
+
+    #[test]
+    fn responsibility_covers_roughly_the_configured_fraction() {
+        let cfg = enabled();
+        let peers: Vec<PeerId> = (0..2_000).map(peer).collect();
+        let topic = TopicId::from_entity(b"fraction");
+        let owners = peers
+            .iter()
+            .filter(|p| cfg.is_responsible(p, &topic))
+            .count();
+        // 8 / 64 = 12.5 % expected; allow generous slack for hash variance.
+        assert!((150..=350).contains(&owners), "owners = {owners}");
+    }
+
+    /// Flood `topic` from `publisher` over `edges` using the shard relay
+    /// rule and return every node that received it.
+    fn simulate(
+        cfg: &TopicShardingConfig,
+        topic: &TopicId,
+        nodes: &[PeerId],
+        edges: &[(usize, usize)],
+        interested: &HashSet<usize>,
+        publisher: usize,
+    ) -> HashSet<usize> {
+        let mut adjacency = vec![Vec::new(); nodes.len()];
+        for &(a, b) in edges {
+            if !adjacency[a].contains(&b) {
+                adjacency[a].push(b);
+                adjacency[b].push(a);
+            }
+        }
+        let mut reached = HashSet::from([publisher]);
+        let mut queue = VecDeque::from([publisher]);
+        while let Some(node) = queue.pop_front() {
+            let neighbours: Vec<PeerId> = adjacency[node].iter().map(|&i| nodes[i]).collect();
+            let targets = if interested.contains(&node) {
+                neighbours
+            } else {
+                cfg.relay_peers(&nodes[node], topic, &neighbours)
+            };
+            for target in targets {
+                let idx = nodes.iter().position(|p| *p == target).expect("known node");
+                if reached.insert(idx) {
+                    queue.push_back(idx);
+                }
+            }
+        }
+        reached
+    }
+
+    #[test]
+    fn messages_reach_out_of_shard_subscribers_via_rendezvous() {
+        let cfg = enabled();
+        let nodes: Vec<PeerId> = (0..60).map(peer).collect();
+        // Sparse ring with chords: every node has a handful of neighbours,
+        // far fewer than the mesh size.
+        let mut edges = Vec::new();
+        for i in 0..nodes.len() {
+            edges.push((i, (i + 1) % nodes.len()));
+            edges.push((i, (i * 7 + 3) % nodes.len()));
+        }
+
+        for t in 0..32u8 {
+            let topic = TopicId::from_entity(&[b't', t]);
+            let rendezvous = cfg
+                .rendezvous_peer(&topic, &nodes)
+                .expect("an owner exists");
+            let rendezvous_idx = nodes.iter().position(|p| *p == rendezvous).expect("idx");
+
+            // Pick publisher + subscribers that do NOT own the shard.
+            let outsiders: Vec<usize> = (0..nodes.len())
+                .filter(|&i| !cfg.is_responsible(&nodes[i], &topic))
+                .collect();
+            let publisher = outsiders[0];
+            let subscribers: Vec<usize> = outsiders.iter().copied().skip(1).step_by(9).collect();
+
+            // Interested nodes keep one link to the canonical rendezvous owner.
+            let mut topic_edges = edges.clone();
+            for &node in std::iter::once(&publisher).chain(subscribers.iter()) {
+                if node != rendezvous_idx {
+                    topic_edges.push((node, rendezvous_idx));
+                }
+            }
+            let interested: HashSet<usize> = std::iter::once(publisher)
+                .chain(subscribers.iter().copied())
+                .collect();
+
+            let reached = simulate(&cfg, &topic, &nodes, &topic_edges, &interested, publisher);
+            for sub in &subscribers {
+                assert!(
+                    reached.contains(sub),
+                    "topic {t}: out-of-shard subscriber {sub} missed the message"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn non_owning_relays_forward_only_to_owners() {
+        let cfg = enabled();
+        let topic = TopicId::from_entity(b"relay-narrowing");
+        let local = (0..1_000)
+            .map(peer)
+            .find(|p| !cfg.is_responsible(p, &topic))
+            .expect("a non-owner");
+        let connected: Vec<PeerId> = (1_000..1_200).map(peer).collect();
+        let relays = cfg.relay_peers(&local, &topic, &connected);
+        assert!(!relays.is_empty());
+        assert!(relays.len() < connected.len());
+        assert!(relays.iter().all(|p| cfg.is_responsible(p, &topic)));
+    }
+
+    #[test]
+    fn non_owner_without_owner_neighbours_falls_back_to_flood() {
+        let cfg = enabled();
+        let topic = TopicId::from_entity(b"fallback");
+        let local = (0..1_000)
+            .map(peer)
+            .find(|p| !cfg.is_responsible(p, &topic))
+            .expect("a non-owner");
+        let connected: Vec<PeerId> = (1_000..2_000)
+            .map(peer)
+            .filter(|p| !cfg.is_responsible(p, &topic))
+            .take(5)
+            .collect();
+        assert_eq!(cfg.relay_peers(&local, &topic, &connected), connected);
+    }
+}
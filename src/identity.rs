@@ -16,7 +16,7 @@ use ant_quic::{
 };
 use hex;
 use serde::{Deserialize, Serialize};
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Length of a PeerId in bytes (SHA-256 hash output).
 pub const PEER_ID_LENGTH: usize = 32;
@@ -99,6 +99,24 @@ impl AgentId {
             Err(crate::error::IdentityError::PeerIdMismatch)
         }
     }
+    /// Four speakable words for this id, or `hex:<id>` when words are
+    /// unavailable (see [`crate::words`]).
+    ///
+    /// The words encode a prefix of the id, not all 32 bytes; turn them back
+    /// into an id with [`AgentId::from_words`].
+    #[must_use]
+    pub fn to_words(&self) -> String {
+        crate::words::render_identity(self, None)
+    }
+    /// Resolve words from [`AgentId::to_words`] to the one agent in
+    /// `candidates` (e.g. discovered agents or contacts) whose id they
+    /// prefix. A `hex:<id>` rendering resolves exactly without candidates.
+    pub fn from_words<'a>(
+        words: &str,
+        candidates: impl IntoIterator<Item = &'a AgentId>,
+    ) -> Result<Self, crate::words::WordsError> {
+        crate::words::resolve_identity(words, candidates.into_iter().map(|id| &id.0)).map(Self)
+    }
 }
 
 impl UserId {
@@ -129,6 +147,12 @@ impl UserId {
             Err(crate::error::IdentityError::PeerIdMismatch)
         }
     }
+    /// Four speakable words for this id (a prefix, as for
+    /// [`AgentId::to_words`]), or `hex:<id>` when words are unavailable.
+    #[must_use]
+    pub fn to_words(&self) -> String {
+        crate::words::render_hex_id(&hex::encode(self.0))
+    }
 }
 
 impl std::fmt::Display for UserId {
@@ -137,18 +161,179 @@ impl std::fmt::Display for UserId {
     }
 }
 
-impl std::fmt::Display for MachineId {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "MachineId(0x{})", hex::encode(&self.0[..8]))
+/// Error parsing a [`MachineId`], [`AgentId`] or [`UserId`] from text.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseIdError {
+    /// Neither 64 hex characters nor 52 Crockford Base32 symbols.
+    #[error("expected 64 hex characters or 52 Base32 symbols, got {0} characters")]
+    Length(usize),
+    /// A character outside the expected alphabet.
+    #[error("invalid character {0:?}")]
+    InvalidChar(char),
+    /// Base32 whose final symbol carries nonzero padding bits, so it is not
+    /// the encoding of any id.
+    #[error("non-canonical Base32: padding bits must be zero")]
+    NonCanonical,
+}
+
+/// Crockford Base32 alphabet: digits and uppercase letters minus I, L, O, U.
+const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Base32 symbols needed for a 32-byte id (256 bits, 5 per symbol).
+const BASE32_ID_LEN: usize = (PEER_ID_LENGTH * 8).div_ceil(5);
+
+fn encode_crockford(bytes: &[u8; PEER_ID_LENGTH]) -> String {
+    let mut out = String::with_capacity(BASE32_ID_LEN);
+    let (mut buffer, mut bits) = (0u16, 0u32);
+    for &byte in bytes {
+        buffer = (buffer << 8) | u16::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(char::from(
+                CROCKFORD_ALPHABET[usize::from((buffer >> bits) & 0x1f)],
+            ));
+        }
+    }
+    if bits > 0 {
+        out.push(char::from(
+            CROCKFORD_ALPHABET[usize::from((buffer << (5 - bits)) & 0x1f)],
+        ));
     }
+    out
 }
 
-impl std::fmt::Display for AgentId {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "AgentId(0x{})", hex::encode(&self.0[..8]))
+/// Decode Crockford Base32: case-insensitive, `O` reads as `0`, `I` and `L`
+/// as `1`, and `-` separators are ignored.
+fn decode_crockford(s: &str) -> Result<[u8; PEER_ID_LENGTH], ParseIdError> {
+    let symbols: Vec<char> = s.chars().filter(|&c| c != '-').collect();
+    if symbols.len() != BASE32_ID_LEN {
+        return Err(ParseIdError::Length(symbols.len()));
+    }
+    let mut out = [0u8; PEER_ID_LENGTH];
+    let (mut buffer, mut bits, mut filled) = (0u16, 0u32, 0usize);
+    for c in symbols {
+        let value = match c.to_ascii_uppercase() {
+            'O' => 0,
+            'I' | 'L' => 1,
+            upper => CROCKFORD_ALPHABET
+                .iter()
+                .position(|&a| char::from(a) == upper)
+                .ok_or(ParseIdError::InvalidChar(c))?,
+        };
+        buffer = (buffer << 5) | value as u16;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            if let Some(slot) = out.get_mut(filled) {
+                *slot = (buffer >> bits) as u8;
+            }
+            filled += 1;
+        }
+    }
+    if buffer & ((1 << bits) - 1) != 0 {
+        return Err(ParseIdError::NonCanonical);
     }
+    Ok(out)
 }
 
+/// Parse the full hex form (optionally `0x`-prefixed) or the Base32 form.
+fn parse_id(s: &str) -> Result<[u8; PEER_ID_LENGTH], ParseIdError> {
+    let hex_str = s.strip_prefix("0x").unwrap_or(s);
+    if hex_str.len() == PEER_ID_LENGTH * 2 {
+        let mut out = [0u8; PEER_ID_LENGTH];
+        return match hex::decode_to_slice(hex_str, &mut out) {
+            Ok(()) => Ok(out),
+            Err(hex::FromHexError::InvalidHexCharacter { c, .. }) => {
+                Err(ParseIdError::InvalidChar(c))
+            }
+            Err(_) => Err(ParseIdError::Length(s.len())),
+        };
+    }
+    decode_crockford(s).map_err(|e| match e {
+        // Report the input length, not the separator-stripped one.
+        ParseIdError::Length(_) => ParseIdError::Length(s.chars().count()),
+        other => other,
+    })
+}
+
+/// Lossless text forms shared by every id type: full hex, Crockford Base32,
+/// and a `FromStr` accepting either.
+macro_rules! impl_id_text {
+    ($ty:ident) => {
+        impl $ty {
+            /// The full id as 64 lowercase hex characters.
+            #[must_use]
+            pub fn to_hex(&self) -> String {
+                hex::encode(self.0)
+            }
+
+            /// The id as 52 Crockford Base32 symbols: shorter than hex and
+            /// free of easily confused characters.
+            #[must_use]
+            pub fn to_base32(&self) -> String {
+                encode_crockford(&self.0)
+            }
+
+            /// Parse the form produced by [`to_base32`](Self::to_base32).
+            /// Case-insensitive; `O`, `I` and `L` read as `0`, `1` and `1`, and
+            /// `-` separators are ignored.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`ParseIdError`] if `s` is not 52 Base32 symbols or
+            /// does not encode a 32-byte id.
+            pub fn from_base32(s: &str) -> Result<Self, ParseIdError> {
+                decode_crockford(s).map(Self)
+            }
+        }
+
+        #[doc = concat!("Accepts the [`", stringify!($ty), "::to_hex`] form (optionally")]
+        #[doc = concat!("`0x`-prefixed) or the [`", stringify!($ty), "::to_base32`] form.")]
+        impl std::str::FromStr for $ty {
+            type Err = ParseIdError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_id(s).map(Self)
+            }
+        }
+    };
+}
+
+/// Full-hex `Display` for [`MachineId`] and [`AgentId`], with the truncated
+/// form kept as `short()`.
+macro_rules! impl_id_display {
+    ($ty:ident) => {
+        impl $ty {
+            /// Truncated form for logs, e.g.
+            #[doc = concat!("`", stringify!($ty), "(0x0123456789abcdef)`:")]
+            /// the first 8 bytes only, so it cannot be parsed back.
+            #[must_use]
+            pub fn short(&self) -> String {
+                format!(
+                    concat!(stringify!($ty), "(0x{})"),
+                    hex::encode(&self.0[..8])
+                )
+            }
+        }
+
+        /// The full id as 64 lowercase hex characters; parses back with
+        /// [`FromStr`](std::str::FromStr). See also
+        #[doc = concat!("[`", stringify!($ty), "::short`].")]
+        impl std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.to_hex())
+            }
+        }
+    };
+}
+
+impl_id_text!(MachineId);
+impl_id_text!(AgentId);
+impl_id_text!(UserId);
+impl_id_display!(MachineId);
+impl_id_display!(AgentId);
+
 /// Machine-pinned ML-DSA-65 keypair.
 pub struct MachineKeypair {
     public_key: MlDsaPublicKey,
@@ -170,6 +355,8 @@ impl Drop for MachineKeypair {
     }
 }
 
+impl ZeroizeOnDrop for MachineKeypair {}
+
 impl MachineKeypair {
     /// Generate a new random MachineKeypair.
     pub fn generate() -> Result<Self, crate::error::IdentityError> {
@@ -245,6 +432,8 @@ impl Drop for AgentKeypair {
     }
 }
 
+impl ZeroizeOnDrop for AgentKeypair {}
+
 impl AgentKeypair {
     /// Generate a new random AgentKeypair.
     pub fn generate() -> Result<Self, crate::error::IdentityError> {
@@ -324,6 +513,8 @@ impl Drop for UserKeypair {
     }
 }
 
+impl ZeroizeOnDrop for UserKeypair {}
+
 impl UserKeypair {
     /// Generate a new random UserKeypair.
     pub fn generate() -> Result<Self, crate::error::IdentityError> {
@@ -759,11 +950,35 @@ impl AgentCertificate {
 ///   └─ Agent (portable, runs on many machines)
 ///        └─ Machine (hardware-pinned)
 /// ```
+///
+/// # Multiple user certificates
+///
+/// A shared agent (one acting for a team) may be attested by several users.
+/// The *primary* certificate is the one bound to this identity's own user
+/// keypair, or the first certificate added when there is no user keypair;
+/// [`user_id`](Self::user_id) reports its user. Further certificates are
+/// attached with [`add_agent_certificate`](Self::add_agent_certificate) and
+/// listed, primary first, by [`agent_certificates`](Self::agent_certificates)
+/// and [`user_ids`](Self::user_ids).
+///
+/// Trust implications:
+/// - Each certificate is an independent attestation by its user. Holding
+///   one says nothing about the others, and revoking or letting one expire
+///   leaves the rest valid.
+/// - Only the primary certificate is carried in identity announcements,
+///   heartbeats and introduction cards, so peers key contact and trust
+///   decisions on the primary user. Additional certificates must be
+///   presented separately and verified by the recipient.
+/// - Anyone trusting "agents of user U" now also trusts an agent U shares
+///   with other users; the other co-signers can steer the agent's behaviour
+///   through whatever control channel they have over it.
 pub struct Identity {
     machine_keypair: MachineKeypair,
     agent_keypair: AgentKeypair,
     user_keypair: Option<UserKeypair>,
     agent_certificate: Option<AgentCertificate>,
+    /// Certificates from users other than the primary, in insertion order.
+    additional_certificates: Vec<AgentCertificate>,
 }
 
 impl std::fmt::Debug for Identity {
@@ -773,6 +988,7 @@ impl std::fmt::Debug for Identity {
             .field("agent_keypair", &self.agent_keypair)
             .field("user_keypair", &self.user_keypair)
             .field("agent_certificate", &self.agent_certificate)
+            .field("additional_certificates", &self.additional_certificates)
             .finish()
     }
 }
@@ -786,6 +1002,7 @@ impl Identity {
             agent_keypair,
             user_keypair: None,
             agent_certificate: None,
+            additional_certificates: Vec::new(),
         }
     }
     /// Create a new Identity with all three layers.
@@ -801,6 +1018,7 @@ impl Identity {
             agent_keypair,
             user_keypair: Some(user_keypair),
             agent_certificate: Some(agent_certificate),
+            additional_certificates: Vec::new(),
         }
     }
     /// Generate a new Identity with fresh machine and agent keypairs.
@@ -813,6 +1031,7 @@ impl Identity {
             agent_keypair: AgentKeypair::generate()?,
             user_keypair: None,
             agent_certificate: None,
+            additional_certificates: Vec::new(),
         })
     }
     /// Get the machine ID.
@@ -827,11 +1046,32 @@ impl Identity {
     pub fn agent_id(&self) -> AgentId {
         self.agent_keypair.agent_id()
     }
-    /// Get the user ID, if a user keypair is present.
+    /// Get the primary user ID: the user keypair's, or else the user of the
+    /// primary certificate.
     #[inline]
     #[must_use]
     pub fn user_id(&self) -> Option<UserId> {
-        self.user_keypair.as_ref().map(|kp| kp.user_id())
+        self.user_keypair
+            .as_ref()
+            .map(|kp| kp.user_id())
+            .or_else(|| {
+                self.agent_certificate
+                    .as_ref()
+                    .and_then(|cert| cert.user_id().ok())
+            })
+    }
+    /// Get every user attesting this agent, primary first.
+    #[must_use]
+    pub fn user_ids(&self) -> Vec<UserId> {
+        let mut ids: Vec<UserId> = self.user_id().into_iter().collect();
+        for cert in &self.additional_certificates {
+            if let Ok(user_id) = cert.user_id() {
+                if !ids.contains(&user_id) {
+                    ids.push(user_id);
+                }
+            }
+        }
+        ids
     }
     /// Get a reference to the machine keypair.
     #[inline]
@@ -857,6 +1097,215 @@ impl Identity {
     pub fn agent_certificate(&self) -> Option<&AgentCertificate> {
         self.agent_certificate.as_ref()
     }
+    /// Get every agent certificate, primary first.
+    #[must_use]
+    pub fn agent_certificates(&self) -> Vec<&AgentCertificate> {
+        self.agent_certificate
+            .iter()
+            .chain(&self.additional_certificates)
+            .collect()
+    }
+    /// Get the certificates other than the primary.
+    #[inline]
+    #[must_use]
+    pub fn additional_certificates(&self) -> &[AgentCertificate] {
+        &self.additional_certificates
+    }
+    /// Attach a certificate from another user attesting this agent.
+    ///
+    /// The certificate must verify, bind this agent, and not be expired. It
+    /// becomes the primary when there is none yet; a certificate for a user
+    /// that already attests this agent replaces that user's certificate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::IdentityError::CertificateVerification`] if the
+    /// certificate fails any of those checks.
+    pub fn add_agent_certificate(
+        &mut self,
+        cert: AgentCertificate,
+    ) -> Result<(), crate::error::IdentityError> {
+        cert.verify()?;
+        if cert.agent_id()? != self.agent_id() {
+            return Err(crate::error::IdentityError::CertificateVerification(
+                "certificate binds a different agent".to_string(),
+            ));
+        }
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        if cert.is_expired(now) {
+            return Err(crate::error::IdentityError::CertificateVerification(
+                "certificate has expired".to_string(),
+            ));
+        }
+
+        let user_id = cert.user_id()?;
+        if self.user_id() == Some(user_id) || self.agent_certificate.is_none() {
+            self.agent_certificate = Some(cert);
+        } else if let Some(existing) = self
+            .additional_certificates
+            .iter_mut()
+            .find(|c| c.user_id().ok() == Some(user_id))
+        {
+            *existing = cert;
+        } else {
+            self.additional_certificates.push(cert);
+        }
+        Ok(())
+    }
+
+    /// Sign arbitrary application data with the agent key.
+    ///
+    /// Signs `b"x0x-agent-sig-v1\0" || msg`, so the signature can never be
+    /// mistaken for a pubsub, certificate or machine-key signature. Check it
+    /// with [`verify_agent`](Self::verify_agent).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::IdentityError::Crypto`] if signing fails.
+    pub fn sign_with_agent(&self, msg: &[u8]) -> Result<Vec<u8>, crate::error::IdentityError> {
+        sign_in_domain(self.agent_keypair.secret_key(), AGENT_SIGNATURE_DOMAIN, msg)
+    }
+
+    /// Verify a [`sign_with_agent`](Self::sign_with_agent) signature.
+    ///
+    /// Returns `false` unless `agent_id` derives from `pubkey` and `sig` is a
+    /// valid signature by it over `msg`.
+    #[must_use]
+    pub fn verify_agent(
+        agent_id: &AgentId,
+        pubkey: &MlDsaPublicKey,
+        msg: &[u8],
+        sig: &[u8],
+    ) -> bool {
+        AgentId::from_public_key(pubkey) == *agent_id
+            && verify_in_domain(pubkey, AGENT_SIGNATURE_DOMAIN, msg, sig)
+    }
+
+    /// Sign arbitrary application data with the machine key, under its own
+    /// domain (`b"x0x-machine-sig-v1\0"`). Check it with
+    /// [`verify_machine`](Self::verify_machine).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::IdentityError::Crypto`] if signing fails.
+    pub fn sign_with_machine(&self, msg: &[u8]) -> Result<Vec<u8>, crate::error::IdentityError> {
+        sign_in_domain(
+            self.machine_keypair.secret_key(),
+            MACHINE_SIGNATURE_DOMAIN,
+            msg,
+        )
+    }
+
+    /// Verify a [`sign_with_machine`](Self::sign_with_machine) signature.
+    ///
+    /// Returns `false` unless `machine_id` derives from `pubkey` and `sig` is
+    /// a valid signature by it over `msg`.
+    #[must_use]
+    pub fn verify_machine(
+        machine_id: &MachineId,
+        pubkey: &MlDsaPublicKey,
+        msg: &[u8],
+        sig: &[u8],
+    ) -> bool {
+        MachineId::from_public_key(pubkey) == *machine_id
+            && verify_in_domain(pubkey, MACHINE_SIGNATURE_DOMAIN, msg, sig)
+    }
+
+    /// Verify that the User → Agent binding of this identity is consistent.
+    ///
+    /// Checks the primary certificate's signature, that it binds
+    /// [`agent_id`](Self::agent_id), and, when a user keypair is held, that
+    /// it was issued by that user. Additional certificates must also verify
+    /// and bind this agent. An identity without a certificate has no chain
+    /// and passes. Expiry is not checked; see
+    /// [`AgentCertificate::is_expired`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::IdentityError::CertificateVerification`] on
+    /// the first broken link.
+    pub fn verify_chain(&self) -> Result<(), crate::error::IdentityError> {
+        let Some(cert) = &self.agent_certificate else {
+            return Ok(());
+        };
+        let user_id = match &self.user_keypair {
+            Some(kp) => kp.user_id(),
+            None => cert.user_id()?,
+        };
+        verify_remote_identity(&self.agent_id(), &user_id, cert)?;
+        for cert in &self.additional_certificates {
+            verify_remote_identity(&self.agent_id(), &cert.user_id()?, cert)?;
+        }
+        Ok(())
+    }
+}
+
+/// Verify an identity received over the wire: `cert` must carry a valid
+/// user signature and bind exactly `agent_id` to `user_id`.
+///
+/// Expiry is not checked; see [`AgentCertificate::is_expired`].
+///
+/// # Errors
+///
+/// Returns [`crate::error::IdentityError::CertificateVerification`] if the
+/// signature is invalid or either id does not match the certificate.
+pub fn verify_remote_identity(
+    agent_id: &AgentId,
+    user_id: &UserId,
+    cert: &AgentCertificate,
+) -> Result<(), crate::error::IdentityError> {
+    cert.verify()?;
+    if cert.agent_id()? != *agent_id {
+        return Err(crate::error::IdentityError::CertificateVerification(
+            "agent certificate agent_id mismatch".to_string(),
+        ));
+    }
+    if cert.user_id()? != *user_id {
+        return Err(crate::error::IdentityError::CertificateVerification(
+            "agent certificate user_id mismatch".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Domain prefix for [`Identity::sign_with_agent`] signatures.
+const AGENT_SIGNATURE_DOMAIN: &[u8] = b"x0x-agent-sig-v1\0";
+
+/// Domain prefix for [`Identity::sign_with_machine`] signatures.
+const MACHINE_SIGNATURE_DOMAIN: &[u8] = b"x0x-machine-sig-v1\0";
+
+fn domain_message(domain: &[u8], msg: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(domain.len() + msg.len());
+    buf.extend_from_slice(domain);
+    buf.extend_from_slice(msg);
+    buf
+}
+
+fn sign_in_domain(
+    secret_key: &MlDsaSecretKey,
+    domain: &[u8],
+    msg: &[u8],
+) -> Result<Vec<u8>, crate::error::IdentityError> {
+    ant_quic::crypto::raw_public_keys::pqc::sign_with_ml_dsa(
+        secret_key,
+        &domain_message(domain, msg),
+    )
+    .map(|sig| sig.as_bytes().to_vec())
+    .map_err(|e| crate::error::IdentityError::Crypto(format!("signing failed: {e:?}")))
+}
+
+fn verify_in_domain(pubkey: &MlDsaPublicKey, domain: &[u8], msg: &[u8], sig: &[u8]) -> bool {
+    let Ok(sig) = ant_quic::crypto::raw_public_keys::pqc::MlDsaSignature::from_bytes(sig) else {
+        return false;
+    };
+    ant_quic::crypto::raw_public_keys::pqc::verify_with_ml_dsa(
+        pubkey,
+        &domain_message(domain, msg),
+        &sig,
+    )
+    .is_ok()
 }
 
 // ---------------------------------------------------------------------------
@@ -912,7 +1361,8 @@ pub struct IntroductionCard {
     /// Optional human-readable display name.
     pub display_name: Option<String>,
     /// Four-word speakable identity (e.g. "bodily example dismiss galaxy").
-    /// For human-backed agents: "agent words @ user words".
+    /// For human-backed agents: "agent words @ user words". Falls back to
+    /// `hex:<id>` when words are unavailable (see [`crate::words`]).
     pub identity_words: String,
     /// Trust-gated services offered by this agent.
     pub services: Vec<ServiceEntry>,
@@ -973,18 +1423,7 @@ impl IntroductionCard {
         let user_id = identity.user_id();
         let certificate = identity.agent_certificate().cloned();
 
-        let encoder = four_word_networking::IdentityEncoder::new();
-        let identity_words = if let Some(uid) = user_id {
-            encoder
-                .encode_full(agent_id.as_bytes(), uid.as_bytes())
-                .map(|w| w.to_string())
-                .unwrap_or_default()
-        } else {
-            encoder
-                .encode_agent(agent_id.as_bytes())
-                .map(|w| w.to_string())
-                .unwrap_or_default()
-        };
+        let identity_words = crate::words::render_identity(&agent_id, user_id.as_ref());
 
         let machine_public_key = identity.machine_keypair().public_key().as_bytes().to_vec();
         let unsigned = IntroductionCardUnsigned {
@@ -1184,6 +1623,77 @@ mod tests {
         let restored = UserKeypair::from_bytes(&pub_bytes, &sec_bytes).unwrap();
         assert_eq!(keypair.user_id(), restored.user_id());
     }
+    #[test]
+    fn agent_id_text_forms_round_trip() {
+        let id = AgentKeypair::generate().unwrap().agent_id();
+
+        let hex_form = id.to_string();
+        assert_eq!(hex_form, hex::encode(id.as_bytes()));
+        assert_eq!(hex_form.parse::<AgentId>().unwrap(), id);
+        assert_eq!(format!("0x{hex_form}").parse::<AgentId>().unwrap(), id);
+
+        let base32 = id.to_base32();
+        assert_eq!(base32.len(), 52);
+        assert_eq!(AgentId::from_base32(&base32).unwrap(), id);
+        assert_eq!(base32.parse::<AgentId>().unwrap(), id);
+        // Case-insensitive and tolerant of separators.
+        let loose = base32.to_lowercase();
+        let loose = format!("{}-{}", &loose[..26], &loose[26..]);
+        assert_eq!(AgentId::from_base32(&loose).unwrap(), id);
+
+        assert_eq!(id.short(), format!("AgentId(0x{})", &hex_form[..16]));
+    }
+
+    #[test]
+    fn machine_id_base32_matches_known_vectors() {
+        let zero = MachineId([0; PEER_ID_LENGTH]);
+        assert_eq!(zero.to_base32(), "0".repeat(52));
+        let ones = MachineId([0xff; PEER_ID_LENGTH]);
+        // 256 one bits then 4 zero padding bits.
+        assert_eq!(ones.to_base32(), format!("{}G", "Z".repeat(51)));
+        // Ambiguous letters read as the digits they resemble.
+        let aliased = format!("{}{}", "o".repeat(26), "O".repeat(26));
+        assert_eq!(MachineId::from_base32(&aliased).unwrap(), zero);
+        assert_eq!(ones.short(), "MachineId(0xffffffffffffffff)");
+    }
+
+    #[test]
+    fn id_parsing_rejects_malformed_input() {
+        assert_eq!(
+            "abc".parse::<AgentId>().unwrap_err(),
+            ParseIdError::Length(3)
+        );
+        assert_eq!(
+            AgentId::from_base32(&"U".repeat(52)).unwrap_err(),
+            ParseIdError::InvalidChar('U')
+        );
+        assert_eq!(
+            format!("{}g", "0".repeat(63))
+                .parse::<AgentId>()
+                .unwrap_err(),
+            ParseIdError::InvalidChar('g')
+        );
+        // The last symbol's low 4 bits are padding and must be zero.
+        assert_eq!(
+            AgentId::from_base32(&format!("{}1", "0".repeat(51))).unwrap_err(),
+            ParseIdError::NonCanonical
+        );
+    }
+
+    #[test]
+    fn user_id_parses_its_full_hex_form() {
+        let id = UserId([0xa5; PEER_ID_LENGTH]);
+        assert_eq!(id.to_hex(), "a5".repeat(32));
+        assert_eq!(id.to_hex().parse::<UserId>().unwrap(), id);
+        assert_eq!(id.to_base32().parse::<UserId>().unwrap(), id);
+        assert_eq!(
+            "a5".repeat(31).parse::<UserId>().unwrap_err(),
+            ParseIdError::Length(62)
+        );
+        // Display stays truncated for UserId; to_hex() is the lossless form.
+        assert!(id.to_string().starts_with("UserId(0x"));
+    }
+
     #[test]
     fn test_user_id_display() {
         let keypair = UserKeypair::generate().unwrap();
@@ -1382,6 +1892,94 @@ mod tests {
         // Verification should fail because message changed
         assert!(cert.verify().is_err());
     }
+    #[test]
+    fn application_signatures_are_domain_separated() {
+        let identity = Identity::generate().unwrap();
+        let agent_pk = identity.agent_keypair().public_key();
+        let machine_pk = identity.machine_keypair().public_key();
+        let msg = b"authenticate me";
+
+        let agent_sig = identity.sign_with_agent(msg).unwrap();
+        assert!(Identity::verify_agent(
+            &identity.agent_id(),
+            agent_pk,
+            msg,
+            &agent_sig
+        ));
+        assert!(!Identity::verify_agent(
+            &identity.agent_id(),
+            agent_pk,
+            b"something else",
+            &agent_sig
+        ));
+        // The id must derive from the key it is checked against.
+        assert!(!Identity::verify_agent(
+            &AgentId([0; PEER_ID_LENGTH]),
+            agent_pk,
+            msg,
+            &agent_sig
+        ));
+        assert!(!Identity::verify_agent(
+            &identity.agent_id(),
+            agent_pk,
+            msg,
+            b"not a signature"
+        ));
+
+        let machine_sig = identity.sign_with_machine(msg).unwrap();
+        assert!(Identity::verify_machine(
+            &identity.machine_id(),
+            machine_pk,
+            msg,
+            &machine_sig
+        ));
+
+        // A raw signature over the same bytes is not an application
+        // signature: the domain prefix is part of what gets signed.
+        let raw = ant_quic::crypto::raw_public_keys::pqc::sign_with_ml_dsa(
+            identity.agent_keypair().secret_key(),
+            msg,
+        )
+        .unwrap();
+        assert!(!Identity::verify_agent(
+            &identity.agent_id(),
+            agent_pk,
+            msg,
+            raw.as_bytes()
+        ));
+    }
+
+    #[test]
+    fn verify_chain_checks_user_agent_binding() {
+        let user_kp = UserKeypair::generate().unwrap();
+        let agent_kp = AgentKeypair::generate().unwrap();
+        let cert = AgentCertificate::issue(&user_kp, &agent_kp).unwrap();
+        let (agent_id, user_id) = (agent_kp.agent_id(), user_kp.user_id());
+
+        assert!(verify_remote_identity(&agent_id, &user_id, &cert).is_ok());
+        let other_agent = AgentKeypair::generate().unwrap().agent_id();
+        assert!(verify_remote_identity(&other_agent, &user_id, &cert).is_err());
+        let other_user = UserKeypair::generate().unwrap().user_id();
+        assert!(verify_remote_identity(&agent_id, &other_user, &cert).is_err());
+
+        let identity =
+            Identity::new_with_user(MachineKeypair::generate().unwrap(), agent_kp, user_kp, cert);
+        assert!(identity.verify_chain().is_ok());
+        assert!(Identity::generate().unwrap().verify_chain().is_ok());
+
+        // A certificate for a different agent breaks the chain.
+        let stray_user = UserKeypair::generate().unwrap();
+        let stray_agent = AgentKeypair::generate().unwrap();
+        let stray = AgentCertificate::issue(&stray_user, &stray_agent).unwrap();
+        let broken = Identity::new_with_user(
+            MachineKeypair::generate().unwrap(),
+            AgentKeypair::generate().unwrap(),
+            stray_user,
+            stray,
+        );
+        assert!(broken.verify_chain().is_err());
+    }
+
     #[test]
     fn test_identity_with_user() {
         let machine_kp = MachineKeypair::generate().unwrap();
@@ -1397,6 +1995,60 @@ mod tests {
         assert!(identity.agent_certificate().is_some());
     }
 
+    #[test]
+    fn test_identity_with_two_user_certificates() {
+        let machine_kp = MachineKeypair::generate().unwrap();
+        let agent_kp = AgentKeypair::generate().unwrap();
+        let alice = UserKeypair::generate().unwrap();
+        let bob = UserKeypair::generate().unwrap();
+        let alice_cert = AgentCertificate::issue(&alice, &agent_kp).unwrap();
+        let bob_cert = AgentCertificate::issue(&bob, &agent_kp).unwrap();
+        let (alice_id, bob_id) = (alice.user_id(), bob.user_id());
+
+        let mut identity = Identity::new_with_user(machine_kp, agent_kp, alice, alice_cert);
+        identity.add_agent_certificate(bob_cert.clone()).unwrap();
+        // Re-adding the same user's certificate replaces rather than duplicates.
+        identity.add_agent_certificate(bob_cert).unwrap();
+
+        assert_eq!(identity.user_id(), Some(alice_id));
+        assert_eq!(identity.user_ids(), vec![alice_id, bob_id]);
+        let certs = identity.agent_certificates();
+        assert_eq!(certs.len(), 2);
+        for (cert, user_id) in certs.iter().zip([alice_id, bob_id]) {
+            cert.verify().unwrap();
+            assert_eq!(cert.user_id().unwrap(), user_id);
+            assert_eq!(cert.agent_id().unwrap(), identity.agent_id());
+        }
+    }
+
+    #[test]
+    fn test_first_added_certificate_becomes_primary() {
+        let mut identity = Identity::generate().unwrap();
+        let carol = UserKeypair::generate().unwrap();
+        let dave = UserKeypair::generate().unwrap();
+        let carol_cert = AgentCertificate::issue(&carol, identity.agent_keypair()).unwrap();
+        let dave_cert = AgentCertificate::issue(&dave, identity.agent_keypair()).unwrap();
+
+        identity.add_agent_certificate(carol_cert).unwrap();
+        identity.add_agent_certificate(dave_cert).unwrap();
+
+        assert_eq!(identity.user_id(), Some(carol.user_id()));
+        assert_eq!(identity.user_ids(), vec![carol.user_id(), dave.user_id()]);
+        assert_eq!(identity.additional_certificates().len(), 1);
+    }
+
+    #[test]
+    fn test_add_agent_certificate_rejects_foreign_agent() {
+        let mut identity = Identity::generate().unwrap();
+        let user_kp = UserKeypair::generate().unwrap();
+        let other_agent = AgentKeypair::generate().unwrap();
+        let cert = AgentCertificate::issue(&user_kp, &other_agent).unwrap();
+
+        assert!(identity.add_agent_certificate(cert).is_err());
+        assert!(identity.agent_certificates().is_empty());
+        assert!(identity.user_ids().is_empty());
+    }
+
     #[test]
     fn test_introduction_card_signature_round_trip() {
         let identity = Identity::generate().unwrap();
@@ -182,6 +182,9 @@ pub mod exec;
 /// The x0x Constitution — The Four Laws of Intelligent Coexistence — embedded at compile time.
 pub mod constitution;
 
+/// Four-word speakable renderings of ids and addresses, with hex fallback.
+pub mod words;
+
 /// Privacy-preserving log identifier wrappers (salted-hash redaction).
 pub mod logging;
 
@@ -302,6 +305,9 @@ pub struct Agent {
     /// announcements.  Set by `announce_identity(true, true)` and respected
     /// by the heartbeat so it doesn't erase a consented disclosure.
     user_identity_consented: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    /// Application-set status carried on identity announcements (see
+    /// [`Agent::set_status`]).
+    status: std::sync::Arc<std::sync::RwLock<Option<String>>>,
     /// Capability store populated by the advert service and consulted by
     /// `send_direct` to choose between gossip and raw-QUIC paths.
     capability_store: std::sync::Arc<dm_capability::CapabilityStore>,
@@ -326,6 +332,14 @@ pub struct Agent {
     /// Identity-scoped directory.  When `Some`, revocations.bin is saved here
     /// instead of `~/.x0x/`.
     identity_dir: Option<std::path::PathBuf>,
+    /// On-disk task-list store, when a directory was configured.
+    task_list_storage: Option<crdt::TaskListStorage>,
+    /// Save activity of the task-list savers; see
+    /// [`Agent::task_list_persistence_stats`].
+    task_list_persistence: std::sync::Arc<crdt::persistence::PersistenceCounters>,
+    /// Save pacing shared with every task-list saver; see
+    /// [`Agent::set_persistence_policy`].
+    persistence_policy: tokio::sync::watch::Sender<crdt::PersistencePolicy>,
     /// Cancellation token driving deterministic teardown of all long-lived
     /// Agent background loops (identity/network-event/direct listeners and the
     /// presence broadcast-peer refresh). Cancelling it makes every token-aware
@@ -363,6 +377,19 @@ pub struct Agent {
     /// [`Agent::set_connect_policy`]. `std` RwLock: gate reads are a brief
     /// clone of the inner `Arc`, never held across an await.
     connect_policy: std::sync::Arc<std::sync::RwLock<std::sync::Arc<connect::ConnectPolicy>>>,
+    /// Task lists created or joined through this agent, by canonical id, for
+    /// [`Agent::task_list_by_id`]. Entries are weak so the registry never
+    /// keeps a discarded list's sync loops alive.
+    task_list_registry:
+        std::sync::Mutex<std::collections::HashMap<crdt::TaskListId, RegisteredTaskList>>,
+}
+
+/// A [`TaskListHandle`] held weakly by the agent's task-list registry.
+struct RegisteredTaskList {
+    sync: std::sync::Weak<crdt::TaskListSync>,
+    peer_id: saorsa_gossip_types::PeerId,
+    replica_epoch: u64,
+    signing: std::sync::Arc<gossip::SigningContext>,
 }
 
 /// Closed-flag task registry for deterministic Agent teardown.
@@ -776,6 +803,17 @@ pub const IDENTITY_HEARTBEAT_INTERVAL_SECS: u64 = 300;
 /// [`Agent::presence`] and [`Agent::discovered_agents`].
 pub const IDENTITY_TTL_SECS: u64 = 900;
 
+/// Default upper bound on [`AgentBuilder::build`] (seconds).
+///
+/// Building binds sockets and starts the QUIC endpoint and gossip runtime;
+/// a contended port or a stalled transport init would otherwise hang the
+/// caller forever. Override with [`AgentBuilder::with_build_timeout`].
+pub const AGENT_BUILD_TIMEOUT_SECS: u64 = 60;
+
+/// Maximum length, in bytes, of the status string set with
+/// [`Agent::set_status`].
+pub const MAX_AGENT_STATUS_BYTES: usize = 256;
+
 const DISCOVERY_REBROADCAST_STATE_CAP: usize = 1024;
 const DISCOVERY_REBROADCAST_STATE_TTL: std::time::Duration = std::time::Duration::from_secs(3600);
 
@@ -911,6 +949,13 @@ pub struct IdentityAnnouncement {
     /// checks the binding rejects a swapped key. Introduced in the v2 gossip
     /// envelope (`X0A2` magic prefix).
     pub agent_public_key: Vec<u8>,
+    /// Application-set status (e.g. "busy", "working on task X"), at most
+    /// [`MAX_AGENT_STATUS_BYTES`] bytes.
+    ///
+    /// Covered by `machine_signature` when present. Only carried in the v3
+    /// gossip envelope (`X0A3` magic prefix), which is used only when a
+    /// status is set, so agents without a status stay readable by v2 peers.
+    pub status: Option<String>,
 }
 
 impl IdentityAnnouncement {
@@ -947,11 +992,24 @@ impl IdentityAnnouncement {
             ));
         }
 
-        let unsigned_bytes = bincode::serialize(&self.to_unsigned()).map_err(|e| {
-            error::IdentityError::Serialization(format!(
-                "failed to serialize announcement for verification: {e}"
-            ))
-        })?;
+        if self
+            .status
+            .as_ref()
+            .is_some_and(|status| status.len() > MAX_AGENT_STATUS_BYTES)
+        {
+            return Err(error::IdentityError::CertificateVerification(format!(
+                "announcement status exceeds {MAX_AGENT_STATUS_BYTES} bytes"
+            )));
+        }
+
+        let unsigned_bytes =
+            announcement_signing_bytes(&self.to_unsigned(), self.status.as_deref()).map_err(
+                |e| {
+                    error::IdentityError::Serialization(format!(
+                        "failed to serialize announcement for verification: {e}"
+                    ))
+                },
+            )?;
         let signature = ant_quic::crypto::raw_public_keys::pqc::MlDsaSignature::from_bytes(
             &self.machine_signature,
         )
@@ -975,20 +1033,7 @@ impl IdentityAnnouncement {
 
         match (self.user_id, self.agent_certificate.as_ref()) {
             (Some(user_id), Some(cert)) => {
-                cert.verify()?;
-                let cert_agent_id = cert.agent_id()?;
-                if cert_agent_id != self.agent_id {
-                    return Err(error::IdentityError::CertificateVerification(
-                        "agent certificate agent_id mismatch".to_string(),
-                    ));
-                }
-                let cert_user_id = cert.user_id()?;
-                if cert_user_id != user_id {
-                    return Err(error::IdentityError::CertificateVerification(
-                        "agent certificate user_id mismatch".to_string(),
-                    ));
-                }
-                Ok(())
+                identity::verify_remote_identity(&self.agent_id, &user_id, cert)
             }
             (None, None) => Ok(()),
             _ => Err(error::IdentityError::CertificateVerification(
@@ -998,6 +1043,21 @@ impl IdentityAnnouncement {
     }
 }
 
+/// Bytes covered by an identity announcement's machine signature.
+///
+/// Without a status this is exactly the bincode of the unsigned
+/// announcement, as v2 peers expect; a status is appended after it.
+fn announcement_signing_bytes(
+    unsigned: &IdentityAnnouncementUnsigned,
+    status: Option<&str>,
+) -> bincode::Result<Vec<u8>> {
+    let mut bytes = bincode::serialize(unsigned)?;
+    if let Some(status) = status {
+        bytes.extend(bincode::serialize(status)?);
+    }
+    Ok(bytes)
+}
+
 /// Maximum accepted positive clock skew for identity announcements retained as
 /// security bindings. Mirrors the direct-message freshness policy.
 const IDENTITY_ANNOUNCEMENT_CLOCK_SKEW_SECS: u64 = dm::CLOCK_SKEW_TOLERANCE_MS / 1_000;
@@ -1299,9 +1359,9 @@ impl UserAnnouncement {
             &unsigned_bytes,
         )
         .map_err(|e| {
-            error::IdentityError::Storage(std::io::Error::other(format!(
+            error::IdentityError::Crypto(format!(
                 "failed to sign user announcement with user key: {e:?}"
-            )))
+            ))
         })?
         .as_bytes()
         .to_vec();
@@ -1473,6 +1533,9 @@ pub struct DiscoveredAgent {
     /// propagated — the agent cannot be attested and a `ForwardV2` stream
     /// is denied fail-closed.
     pub agent_public_key: Vec<u8>,
+    /// Status the agent set with [`Agent::set_status`], from its latest
+    /// identity announcement.
+    pub status: Option<String>,
 }
 
 /// Cached machine endpoint data derived from signed machine announcements.
@@ -1606,6 +1669,43 @@ struct IdentityAnnouncementBuildOptions<'a> {
     allow_local_scope: bool,
 }
 
+/// Run `connect` for every item with at most `limit` attempts in flight
+/// (`0` is treated as 1), returning the outcomes in input order. A panicked
+/// attempt is logged and omitted.
+pub(crate) async fn connect_bounded<I, F, Fut>(
+    items: I,
+    limit: usize,
+    connect: F,
+) -> Vec<Fut::Output>
+where
+    I: IntoIterator,
+    F: Fn(I::Item) -> Fut,
+    Fut: std::future::Future + Send + 'static,
+    Fut::Output: Send + 'static,
+{
+    let permits = std::sync::Arc::new(tokio::sync::Semaphore::new(limit.max(1)));
+    let handles: Vec<_> = items
+        .into_iter()
+        .map(|item| {
+            let permits = std::sync::Arc::clone(&permits);
+            let attempt = connect(item);
+            tokio::spawn(async move {
+                let _permit = permits.acquire_owned().await;
+                attempt.await
+            })
+        })
+        .collect();
+
+    let mut results = Vec::with_capacity(handles.len());
+    for handle in handles {
+        match handle.await {
+            Ok(result) => results.push(result),
+            Err(e) => tracing::error!("Connection task panicked: {}", e),
+        }
+    }
+    results
+}
+
 fn push_unique<T: Copy + PartialEq>(items: &mut Vec<T>, item: T) {
     if !items.contains(&item) {
         items.push(item);
@@ -1682,6 +1782,9 @@ async fn upsert_discovered_agent(
                 if !incoming.agent_public_key.is_empty() {
                     existing.agent_public_key = incoming.agent_public_key;
                 }
+                // Each announcement carries the agent's current status, so
+                // one without a status clears it.
+                existing.status = incoming.status;
             }
             existing.last_seen = incoming.last_seen;
         }
@@ -1776,22 +1879,102 @@ const MAX_MACHINE_ANNOUNCEMENT_DECODE_BYTES: u64 = 64 * 1024;
 /// same way the on-disk cert `X0C2` marker is.
 const IDENTITY_ANNOUNCEMENT_V2_MAGIC: &[u8; 4] = b"X0A2";
 
-/// Serialize an identity announcement in the v2 envelope (magic prefix +
-/// bincode). The magic lets a new peer distinguish v2 (carries
-/// `agent_public_key`) from legacy payloads.
+/// Magic prefix marking an identity announcement that also carries a
+/// status (the v3 envelope). Only used when a status is set.
+const IDENTITY_ANNOUNCEMENT_V3_MAGIC: &[u8; 4] = b"X0A3";
+
+/// Serialize an identity announcement: the v3 envelope when it carries a
+/// status, otherwise the v2 envelope (magic prefix + bincode). The magic
+/// lets a new peer distinguish v3, v2 (carries `agent_public_key`) and
+/// legacy payloads.
 fn serialize_identity_announcement(
     announcement: &IdentityAnnouncement,
 ) -> Result<Vec<u8>, Box<bincode::ErrorKind>> {
     use bincode::Options;
-    let body = bincode::DefaultOptions::new()
-        .with_fixint_encoding()
-        .serialize(announcement)?;
-    let mut out = Vec::with_capacity(IDENTITY_ANNOUNCEMENT_V2_MAGIC.len() + body.len());
-    out.extend_from_slice(IDENTITY_ANNOUNCEMENT_V2_MAGIC);
+    let opts = bincode::DefaultOptions::new().with_fixint_encoding();
+    let (magic, body) = if announcement.status.is_some() {
+        (
+            IDENTITY_ANNOUNCEMENT_V3_MAGIC,
+            opts.serialize(announcement)?,
+        )
+    } else {
+        (
+            IDENTITY_ANNOUNCEMENT_V2_MAGIC,
+            opts.serialize(&IdentityAnnouncementV2::from(announcement))?,
+        )
+    };
+    let mut out = Vec::with_capacity(magic.len() + body.len());
+    out.extend_from_slice(magic);
     out.extend_from_slice(&body);
     Ok(out)
 }
 
+/// v2 identity-announcement wire shape: [`IdentityAnnouncement`] minus the
+/// trailing `status` field.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct IdentityAnnouncementV2 {
+    agent_id: identity::AgentId,
+    machine_id: identity::MachineId,
+    user_id: Option<identity::UserId>,
+    agent_certificate: Option<identity::AgentCertificate>,
+    machine_public_key: Vec<u8>,
+    machine_signature: Vec<u8>,
+    addresses: Vec<std::net::SocketAddr>,
+    announced_at: u64,
+    nat_type: Option<String>,
+    can_receive_direct: Option<bool>,
+    is_relay: Option<bool>,
+    is_coordinator: Option<bool>,
+    reachable_via: Vec<identity::MachineId>,
+    relay_candidates: Vec<identity::MachineId>,
+    agent_public_key: Vec<u8>,
+}
+
+impl From<&IdentityAnnouncement> for IdentityAnnouncementV2 {
+    fn from(announcement: &IdentityAnnouncement) -> Self {
+        Self {
+            agent_id: announcement.agent_id,
+            machine_id: announcement.machine_id,
+            user_id: announcement.user_id,
+            agent_certificate: announcement.agent_certificate.clone(),
+            machine_public_key: announcement.machine_public_key.clone(),
+            machine_signature: announcement.machine_signature.clone(),
+            addresses: announcement.addresses.clone(),
+            announced_at: announcement.announced_at,
+            nat_type: announcement.nat_type.clone(),
+            can_receive_direct: announcement.can_receive_direct,
+            is_relay: announcement.is_relay,
+            is_coordinator: announcement.is_coordinator,
+            reachable_via: announcement.reachable_via.clone(),
+            relay_candidates: announcement.relay_candidates.clone(),
+            agent_public_key: announcement.agent_public_key.clone(),
+        }
+    }
+}
+
+impl From<IdentityAnnouncementV2> for IdentityAnnouncement {
+    fn from(v2: IdentityAnnouncementV2) -> Self {
+        Self {
+            agent_id: v2.agent_id,
+            machine_id: v2.machine_id,
+            user_id: v2.user_id,
+            agent_certificate: v2.agent_certificate,
+            machine_public_key: v2.machine_public_key,
+            machine_signature: v2.machine_signature,
+            addresses: v2.addresses,
+            announced_at: v2.announced_at,
+            nat_type: v2.nat_type,
+            can_receive_direct: v2.can_receive_direct,
+            is_relay: v2.is_relay,
+            is_coordinator: v2.is_coordinator,
+            reachable_via: v2.reachable_via,
+            relay_candidates: v2.relay_candidates,
+            agent_public_key: v2.agent_public_key,
+            status: None,
+        }
+    }
+}
+
 /// Legacy identity-announcement wire shape (pre-#204): identical to
 /// [`IdentityAnnouncement`] minus the trailing `agent_public_key` field.
 /// Used only to deserialize payloads from old peers so they still populate
@@ -1842,6 +2025,7 @@ impl IdentityAnnouncementLegacy {
             reachable_via: self.reachable_via,
             relay_candidates: self.relay_candidates,
             agent_public_key,
+            status: None,
         }
     }
 }
@@ -1856,11 +2040,14 @@ fn deserialize_identity_announcement(
             .with_limit(crate::network::MAX_MESSAGE_DESERIALIZE_SIZE)
             .reject_trailing_bytes()
     };
-    // v2 envelope: magic prefix + bincode(IdentityAnnouncement).
-    if payload.len() >= IDENTITY_ANNOUNCEMENT_V2_MAGIC.len()
-        && &payload[..IDENTITY_ANNOUNCEMENT_V2_MAGIC.len()] == IDENTITY_ANNOUNCEMENT_V2_MAGIC
-    {
-        return opts().deserialize(&payload[IDENTITY_ANNOUNCEMENT_V2_MAGIC.len()..]);
+    // v3 envelope: magic prefix + bincode(IdentityAnnouncement).
+    if let Some(body) = payload.strip_prefix(IDENTITY_ANNOUNCEMENT_V3_MAGIC) {
+        return opts().deserialize(body);
+    }
+    // v2 envelope: magic prefix + bincode(IdentityAnnouncementV2).
+    if let Some(body) = payload.strip_prefix(IDENTITY_ANNOUNCEMENT_V2_MAGIC) {
+        let v2: IdentityAnnouncementV2 = opts().deserialize(body)?;
+        return Ok(v2.into());
     }
     // Legacy envelope: bincode(IdentityAnnouncementLegacy) — recover the key
     // from the cert when present.
@@ -1979,10 +2166,10 @@ fn build_machine_announcement_for_identity(
         &unsigned_bytes,
     )
     .map_err(|e| {
-        error::IdentityError::Storage(std::io::Error::other(format!(
+        error::IdentityError::Crypto(format!(
             "failed to sign machine announcement with machine key: {:?}",
             e
-        )))
+        ))
     })?
     .as_bytes()
     .to_vec();
@@ -2050,11 +2237,27 @@ pub struct AgentBuilder {
     /// paired with another daemon's cert, and peers would reject as
     /// "agent certificate agent_id mismatch".
     agent_cert_path: Option<std::path::PathBuf>,
+    /// Certificates from other users attesting this agent, persisted beside
+    /// the primary certificate on build.
+    additional_certificates: Vec<identity::AgentCertificate>,
     user_keypair: Option<identity::UserKeypair>,
     user_key_path: Option<std::path::PathBuf>,
     #[allow(dead_code)]
     network_config: Option<network::NetworkConfig>,
+    /// Override for [`network::NetworkConfig::max_connections`], applied on
+    /// build whichever order it was set relative to `with_network_config`.
+    max_connections: Option<u32>,
+    /// Replacement for [`network::NetworkConfig::bootstrap_nodes`], applied
+    /// on build like `max_connections`.
+    bootstrap_peers: Option<Vec<std::net::SocketAddr>>,
+    /// Peers appended to the bootstrap set on build.
+    additional_bootstrap_peers: Vec<std::net::SocketAddr>,
+    /// Network without bootstrap nodes, port mapping or cached peers (see
+    /// [`AgentBuilder::offline`]).
+    offline: bool,
     gossip_config: Option<gossip::GossipConfig>,
+    /// Pinned pub/sub wire version (`None` = latest).
+    wire_version: Option<u8>,
     peer_cache_dir: Option<std::path::PathBuf>,
     /// When true, skip opening the bootstrap peer cache entirely.
     /// Useful for fully isolated embedders and test harnesses.
@@ -2070,9 +2273,25 @@ pub struct AgentBuilder {
     /// revocations.bin).  When set, revocations are loaded/saved there
     /// instead of the default `~/.x0x/` directory.
     identity_dir: Option<std::path::PathBuf>,
+    /// Directory of persisted task lists (`None` = no task-list storage).
+    task_list_storage_dir: Option<std::path::PathBuf>,
     /// ADR-0023 durable history. `None` (library default) means no history
     /// service is started; the daemon passes its `[history]` config here.
     history_config: Option<history::HistoryConfig>,
+    /// Overall limit on [`AgentBuilder::build`] (`None` =
+    /// [`AGENT_BUILD_TIMEOUT_SECS`]).
+    build_timeout: Option<std::time::Duration>,
+}
+
+/// Run an agent build, giving up with
+/// [`error::IdentityError::BuildTimeout`] once `limit` elapses.
+async fn bounded_build<T>(
+    limit: std::time::Duration,
+    build: impl std::future::Future<Output = error::Result<T>>,
+) -> error::Result<T> {
+    tokio::time::timeout(limit, build)
+        .await
+        .map_err(|_| error::IdentityError::BuildTimeout(limit))?
 }
 
 /// Context captured by the background identity heartbeat task.
@@ -2091,6 +2310,8 @@ struct HeartbeatContext {
     /// heartbeats include `user_id` and `agent_certificate` so they don't
     /// erase a consented disclosure.
     user_identity_consented: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    /// Status set with [`Agent::set_status`], carried on each heartbeat.
+    status: std::sync::Arc<std::sync::RwLock<Option<String>>>,
     allow_local_discovery_addrs: bool,
     /// Local revocation set — piggybacked on each heartbeat for partition-
     /// tolerant eventual propagation.
@@ -2225,20 +2446,23 @@ impl HeartbeatContext {
             reachable_via: reachable_via.clone(),
             relay_candidates: relay_candidates.clone(),
         };
-        let unsigned_bytes = bincode::serialize(&unsigned).map_err(|e| {
-            error::IdentityError::Serialization(format!(
-                "heartbeat: failed to serialize announcement: {e}"
-            ))
-        })?;
+        let status = self
+            .status
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+        let unsigned_bytes =
+            announcement_signing_bytes(&unsigned, status.as_deref()).map_err(|e| {
+                error::IdentityError::Serialization(format!(
+                    "heartbeat: failed to serialize announcement: {e}"
+                ))
+            })?;
         let machine_signature = ant_quic::crypto::raw_public_keys::pqc::sign_with_ml_dsa(
             self.identity.machine_keypair().secret_key(),
             &unsigned_bytes,
         )
         .map_err(|e| {
-            error::IdentityError::Storage(std::io::Error::other(format!(
-                "heartbeat: failed to sign announcement: {:?}",
-                e
-            )))
+            error::IdentityError::Crypto(format!("heartbeat: failed to sign announcement: {:?}", e))
         })?
         .as_bytes()
         .to_vec();
@@ -2265,6 +2489,7 @@ impl HeartbeatContext {
             reachable_via: reachable_via.clone(),
             relay_candidates: relay_candidates.clone(),
             agent_public_key,
+            status,
         };
         tracing::debug!(
             target: "x0x::discovery",
@@ -2316,18 +2541,16 @@ impl HeartbeatContext {
             )
             .await
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
+                error::IdentityError::Gossip(format!(
                     "heartbeat: machine shard publish failed: {e}"
-                )))
+                ))
             })?;
         self.runtime
             .pubsub()
             .publish(MACHINE_ANNOUNCE_TOPIC.to_string(), machine_payload)
             .await
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
-                    "heartbeat: machine publish failed: {e}"
-                )))
+                error::IdentityError::Gossip(format!("heartbeat: machine publish failed: {e}"))
             })?;
 
         let encoded = serialize_identity_announcement(&announcement).map_err(|e| {
@@ -2342,11 +2565,7 @@ impl HeartbeatContext {
                 bytes::Bytes::from(encoded),
             )
             .await
-            .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
-                    "heartbeat: publish failed: {e}"
-                )))
-            })?;
+            .map_err(|e| error::IdentityError::Gossip(format!("heartbeat: publish failed: {e}")))?;
         let now = Agent::unix_timestamp_secs();
         upsert_discovered_machine(
             &self.machine_cache,
@@ -2374,6 +2593,7 @@ impl HeartbeatContext {
             cert_not_after: None,
             agent_certificate: None,
             agent_public_key: announcement.agent_public_key.clone(),
+            status: announcement.status.clone(),
         };
         upsert_discovered_machine_from_agent(&self.machine_cache, &discovered_agent).await;
         upsert_discovered_agent(&self.cache, discovered_agent).await;
@@ -2413,6 +2633,11 @@ impl Agent {
     /// Network and gossip runtime setup is opt-in via
     /// [`Agent::builder()`] and [`AgentBuilder::with_network_config()`].
     ///
+    /// The agent has no network node at all, so pub/sub, task lists and
+    /// `connect_addr` fail with `NotInitialized`. For a networked agent that
+    /// still never contacts the global bootstrap nodes, build with
+    /// [`AgentBuilder::offline`].
+    ///
     /// For more control, use [`Agent::builder()`].
     pub async fn new() -> error::Result<Self> {
         Agent::builder().build().await
@@ -2431,10 +2656,16 @@ impl Agent {
             agent_keypair: None,
             agent_key_path: None,
             agent_cert_path: None,
+            additional_certificates: Vec::new(),
             user_keypair: None,
             user_key_path: None,
             network_config: None,
+            max_connections: None,
+            bootstrap_peers: None,
+            additional_bootstrap_peers: Vec::new(),
+            offline: false,
             gossip_config: None,
+            wire_version: None,
             peer_cache_dir: None,
             disable_peer_cache: false,
             heartbeat_interval_secs: None,
@@ -2444,7 +2675,9 @@ impl Agent {
             presence_offline_timeout_secs: None,
             contact_store_path: None,
             identity_dir: None,
+            task_list_storage_dir: None,
             history_config: None,
+            build_timeout: None,
         }
     }
 
@@ -2498,6 +2731,19 @@ impl Agent {
         self.identity.user_id()
     }
 
+    /// Sign arbitrary application data with the agent key, e.g. to
+    /// authenticate a call to another service.
+    ///
+    /// Verify with [`identity::Identity::verify_agent`] against this agent's
+    /// id and public key. See [`identity::Identity::sign_with_agent`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::IdentityError::Crypto`] if signing fails.
+    pub fn sign(&self, msg: &[u8]) -> error::Result<Vec<u8>> {
+        self.identity.sign_with_agent(msg)
+    }
+
     /// Get the agent certificate, if one exists.
     ///
     /// The certificate cryptographically binds this agent to a user identity.
@@ -2507,6 +2753,21 @@ impl Agent {
         self.identity.agent_certificate()
     }
 
+    /// Get every user attesting this agent, primary first.
+    ///
+    /// See [`identity::Identity`] for the trust implications of shared
+    /// agents.
+    #[must_use]
+    pub fn user_ids(&self) -> Vec<identity::UserId> {
+        self.identity.user_ids()
+    }
+
+    /// Get every agent certificate, primary first.
+    #[must_use]
+    pub fn agent_certificates(&self) -> Vec<&identity::AgentCertificate> {
+        self.identity.agent_certificates()
+    }
+
     /// Get the network node, if initialized.
     #[must_use]
     pub fn network(&self) -> Option<&std::sync::Arc<network::NetworkNode>> {
@@ -2542,6 +2803,16 @@ impl Agent {
         self.gossip_runtime.as_ref().map(|rt| rt.dispatch_stats())
     }
 
+    /// Snapshot of maintenance-pause counters for the gossip data plane.
+    ///
+    /// Returns `None` when the agent has no gossip runtime. Exposed through
+    /// `GET /diagnostics/gossip` so operators can see what a maintenance
+    /// window buffered, dropped or rejected.
+    #[must_use]
+    pub fn gossip_pause_stats(&self) -> Option<gossip::GossipPauseStatsSnapshot> {
+        self.gossip_runtime.as_ref().map(|rt| rt.pause_stats())
+    }
+
     /// Snapshot of per-stage PubSub handling timings.
     ///
     /// Returns `None` when the agent has no gossip runtime. This is the
@@ -2644,9 +2915,9 @@ impl Agent {
                 .upsert_peer_hints(target_peer_id, target.addresses.clone(), None)
                 .await
                 .map_err(|e| {
-                    error::IdentityError::Storage(std::io::Error::other(format!(
+                    error::IdentityError::Network(format!(
                         "failed to upsert target peer hints: {e}"
-                    )))
+                    ))
                 })?;
             tracing::debug!(
                 target: "x0x::connect",
@@ -2782,9 +3053,9 @@ impl Agent {
                 .upsert_peer_hints(peer_id, addrs, Some(caps))
                 .await
                 .map_err(|e| {
-                    error::IdentityError::Storage(std::io::Error::other(format!(
+                    error::IdentityError::Network(format!(
                         "failed to upsert helper peer hints: {e}"
-                    )))
+                    ))
                 })?;
         }
 
@@ -3165,9 +3436,9 @@ impl Agent {
             self.seed_transport_peer_hints_for_target(network, &agent)
                 .await
                 .map_err(|e| {
-                    error::IdentityError::Storage(std::io::Error::other(format!(
+                    error::IdentityError::Network(format!(
                         "failed to seed transport peer hints: {e}"
-                    )))
+                    ))
                 })?;
 
             match tokio::time::timeout(
@@ -3338,9 +3609,9 @@ impl Agent {
             self.seed_transport_peer_hints_for_target(network, &agent)
                 .await
                 .map_err(|e| {
-                    error::IdentityError::Storage(std::io::Error::other(format!(
+                    error::IdentityError::Network(format!(
                         "failed to seed transport peer hints: {e}"
-                    )))
+                    ))
                 })?;
             let coordinated_result = tokio::time::timeout(
                 dial_timeout,
@@ -3647,9 +3918,7 @@ impl Agent {
             .upsert_peer_hints(peer_id, info.addresses.clone(), None)
             .await
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
-                    "failed to upsert machine peer hints: {e}"
-                )))
+                error::IdentityError::Network(format!("failed to upsert machine peer hints: {e}"))
             })?;
 
         match tokio::time::timeout(
@@ -3787,6 +4056,33 @@ impl Agent {
         Ok(connectivity::ConnectOutcome::Unreachable)
     }
 
+    /// Dial a peer at a known address, outside the bootstrap flow.
+    ///
+    /// For private meshes and [`AgentBuilder::offline`] agents whose peers
+    /// are learned out-of-band. Returns the remote machine's id (its QUIC
+    /// peer id); the agent behind it becomes known once its identity
+    /// announcement arrives.
+    ///
+    /// # Errors
+    ///
+    /// [`error::IdentityError::NotInitialized`] if the agent has no network,
+    /// [`error::IdentityError::Network`] if the dial fails.
+    pub async fn connect_to(
+        &self,
+        addr: std::net::SocketAddr,
+    ) -> error::Result<identity::MachineId> {
+        let network = self.network.as_ref().ok_or_else(|| {
+            error::IdentityError::NotInitialized(
+                "network not initialized - configure agent with network first".to_string(),
+            )
+        })?;
+        let peer_id = network
+            .connect_addr(addr)
+            .await
+            .map_err(|e| error::IdentityError::Network(format!("connect to {addr} failed: {e}")))?;
+        Ok(identity::MachineId(peer_id.0))
+    }
+
     /// Spawn a background task whose handle is tracked for deterministic
     /// teardown. Returns without spawning once the registry is `closed` (i.e.
     /// `shutdown()` has begun) — this is what closes the join_network race:
@@ -3840,10 +4136,17 @@ impl Agent {
     /// a listener blocks on a transport that is concurrently shutting down.
     /// Idempotent: `cancel()` is idempotent, the registry drains empty on a
     /// second call, and the `stop_*` helpers use `Option::take`.
+    ///
+    /// Task lists are part of the same ordering: their sync loops are
+    /// cancelled up front so each finishes the delta it is merging and exits
+    /// within the tracked-task grace window, rather than being aborted when
+    /// the window expires.
     pub async fn shutdown(&self) {
         // 1. Signal every token-aware loop to break. Inert until now, so this
         //    is the first thing that changes steady-state behavior.
         self.shutdown_token.cancel();
+        // 1b. Task-list sync loops listen on their own token, not ours.
+        self.cancel_task_list_syncs();
 
         // 2. Stop the simple Option<JoinHandle> background tasks.
         self.stop_identity_heartbeat().await;
@@ -5361,6 +5664,32 @@ impl Agent {
         }
     }
 
+    /// Check whether an agent can be reached right now, without sending
+    /// application traffic.
+    ///
+    /// Reports [`connectivity::ReachabilityStatus::Connected`] when a
+    /// connection to the agent's machine is already open. Otherwise makes
+    /// one connection attempt from the discovery cache (the addresses
+    /// [`Agent::find_agent`] and identity announcements record, see
+    /// [`Agent::connect_to_agent`]) and reports whether it went direct, was
+    /// relayed through a coordinated hole-punch, or failed. An agent missing
+    /// from the cache is reported as not found rather than searched for;
+    /// call [`Agent::find_agent`] first to look it up on the network.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only for internal failures, as
+    /// [`Agent::connect_to_agent`] does.
+    pub async fn verify_contact_reachability(
+        &self,
+        agent_id: &identity::AgentId,
+    ) -> error::Result<connectivity::ReachabilityReport> {
+        if self.is_agent_connected(agent_id).await {
+            return Ok(connectivity::ConnectOutcome::AlreadyConnected.into());
+        }
+        Ok(self.connect_to_agent(agent_id).await?.into())
+    }
+
     /// Get list of currently connected agents.
     ///
     /// Returns agents that have been discovered and are currently connected
@@ -5403,6 +5732,41 @@ impl Agent {
         }
     }
 
+    /// Set the status string carried on this agent's identity announcements
+    /// (e.g. "busy", "idle", "working on task X").
+    ///
+    /// Peers see it in [`DiscoveredAgent::status`] and the daemon's
+    /// `/presence` output after the next heartbeat or
+    /// [`Agent::announce_identity`]. An empty string clears the status.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `status` is longer than
+    /// [`MAX_AGENT_STATUS_BYTES`] bytes.
+    pub fn set_status(&self, status: String) -> error::Result<()> {
+        if status.len() > MAX_AGENT_STATUS_BYTES {
+            return Err(error::IdentityError::Storage(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("status must be at most {MAX_AGENT_STATUS_BYTES} bytes"),
+            )));
+        }
+        *self
+            .status
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) =
+            (!status.is_empty()).then_some(status);
+        Ok(())
+    }
+
+    /// The status set with [`Agent::set_status`], if any.
+    #[must_use]
+    pub fn status(&self) -> Option<String> {
+        self.status
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
     /// Announce this agent's identity on the network discovery topic.
     ///
     /// By default, announcements include agent + machine identity only.
@@ -5426,9 +5790,9 @@ impl Agent {
         human_consent: bool,
     ) -> error::Result<()> {
         let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
-            error::IdentityError::Storage(std::io::Error::other(
-                "gossip runtime not initialized - configure agent with network first",
-            ))
+            error::IdentityError::NotInitialized(
+                "gossip runtime not initialized - configure agent with network first".to_string(),
+            )
         })?;
 
         self.start_identity_listener().await?;
@@ -5581,18 +5945,16 @@ impl Agent {
             )
             .await
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
+                error::IdentityError::Gossip(format!(
                     "failed to publish machine announcement to shard topic: {e}"
-                )))
+                ))
             })?;
         runtime
             .pubsub()
             .publish(MACHINE_ANNOUNCE_TOPIC.to_string(), machine_payload)
             .await
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
-                    "failed to publish machine announcement: {e}"
-                )))
+                error::IdentityError::Gossip(format!("failed to publish machine announcement: {e}"))
             })?;
 
         let encoded = serialize_identity_announcement(&announcement).map_err(|e| {
@@ -5610,9 +5972,9 @@ impl Agent {
             .publish(shard_topic, payload.clone())
             .await
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
+                error::IdentityError::Gossip(format!(
                     "failed to publish identity announcement to shard topic: {e}"
-                )))
+                ))
             })?;
 
         // Also publish to legacy broadcast topic for backward compatibility.
@@ -5621,9 +5983,9 @@ impl Agent {
             .publish(IDENTITY_ANNOUNCE_TOPIC.to_string(), payload)
             .await
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
+                error::IdentityError::Gossip(format!(
                     "failed to publish identity announcement: {e}"
-                )))
+                ))
             })?;
 
         let now = Self::unix_timestamp_secs();
@@ -5653,6 +6015,7 @@ impl Agent {
             cert_not_after: None,
             agent_certificate: None,
             agent_public_key: announcement.agent_public_key.clone(),
+            status: announcement.status.clone(),
         };
         upsert_discovered_machine_from_agent(&self.machine_discovery_cache, &discovered_agent)
             .await;
@@ -5921,9 +6284,9 @@ impl Agent {
             ))
         })?;
         let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
-            error::IdentityError::Storage(std::io::Error::other(
-                "gossip runtime not initialized - configure agent with network first",
-            ))
+            error::IdentityError::NotInitialized(
+                "gossip runtime not initialized - configure agent with network first".to_string(),
+            )
         })?;
         self.start_identity_listener().await?;
 
@@ -5939,18 +6302,16 @@ impl Agent {
             .publish(shard_topic_for_user(&announcement.user_id), payload.clone())
             .await
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
+                error::IdentityError::Gossip(format!(
                     "failed to publish user announcement to shard topic: {e}"
-                )))
+                ))
             })?;
         runtime
             .pubsub()
             .publish(USER_ANNOUNCE_TOPIC.to_string(), payload)
             .await
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
-                    "failed to publish user announcement: {e}"
-                )))
+                error::IdentityError::Gossip(format!("failed to publish user announcement: {e}"))
             })?;
 
         let now = Self::unix_timestamp_secs();
@@ -6021,9 +6382,9 @@ impl Agent {
 
     async fn start_identity_listener(&self) -> error::Result<()> {
         let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
-            error::IdentityError::Storage(std::io::Error::other(
-                "gossip runtime not initialized - configure agent with network first",
-            ))
+            error::IdentityError::NotInitialized(
+                "gossip runtime not initialized - configure agent with network first".to_string(),
+            )
         })?;
 
         if self
@@ -6614,6 +6975,7 @@ impl Agent {
                     cert_not_after,
                     agent_certificate: announcement.agent_certificate.clone(),
                     agent_public_key: announcement.agent_public_key.clone(),
+                    status: announcement.status.clone(),
                 };
                 record_authenticated_machine_binding_from_message(
                     &authenticated_machine_bindings,
@@ -6843,20 +7205,22 @@ impl Agent {
             reachable_via,
             relay_candidates,
         };
-        let unsigned_bytes = bincode::serialize(&unsigned).map_err(|e| {
-            error::IdentityError::Serialization(format!(
-                "failed to serialize unsigned identity announcement: {e}"
-            ))
-        })?;
+        let status = self.status();
+        let unsigned_bytes =
+            announcement_signing_bytes(&unsigned, status.as_deref()).map_err(|e| {
+                error::IdentityError::Serialization(format!(
+                    "failed to serialize unsigned identity announcement: {e}"
+                ))
+            })?;
         let machine_signature = ant_quic::crypto::raw_public_keys::pqc::sign_with_ml_dsa(
             self.identity.machine_keypair().secret_key(),
             &unsigned_bytes,
         )
         .map_err(|e| {
-            error::IdentityError::Storage(std::io::Error::other(format!(
+            error::IdentityError::Crypto(format!(
                 "failed to sign identity announcement with machine key: {:?}",
                 e
-            )))
+            ))
         })?
         .as_bytes()
         .to_vec();
@@ -6882,6 +7246,7 @@ impl Agent {
                 .public_key()
                 .as_bytes()
                 .to_vec(),
+            status,
         })
     }
 
@@ -6889,7 +7254,10 @@ impl Agent {
     ///
     /// Connects to bootstrap peers in parallel with automatic retries.
     /// Failed connections are retried after a delay to allow stale
-    /// connections on remote nodes to expire.
+    /// connections on remote nodes to expire. When no peer is connected and
+    /// none of the bootstrap peers answers a quick [`bootstrap::probe`], the
+    /// bootstrap rounds are skipped so an offline start returns promptly;
+    /// peers that do answer are kept rather than dialled a second time.
     ///
     /// If the agent was not configured with a network, this method
     /// succeeds gracefully (nothing to join).
@@ -6901,9 +7269,7 @@ impl Agent {
 
         if let Some(ref runtime) = self.gossip_runtime {
             runtime.start().await.map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
-                    "failed to start gossip runtime: {e}"
-                )))
+                error::IdentityError::Gossip(format!("failed to start gossip runtime: {e}"))
             })?;
             tracing::info!("Gossip runtime started");
         }
@@ -6985,27 +7351,60 @@ impl Agent {
                 .copied()
                 .collect();
 
-            // Round 1: Connect to all bootstrap peers in parallel
-            let (succeeded, mut failed) = self
-                .connect_peers_parallel_tracked(network, &remaining)
-                .await;
+            // Pre-flight: with nothing connected, probe the bootstrap peers
+            // quickly. The probe dials, so peers that answer are already
+            // connected; none answering means the machine is offline, and
+            // the rounds below are skipped rather than sleeping through
+            // every retry.
+            let (probed, unprobed): (Vec<std::net::SocketAddr>, Vec<std::net::SocketAddr>) =
+                if all_connected.is_empty() {
+                    let health =
+                        bootstrap::probe(network, &remaining, bootstrap::DEFAULT_PROBE_TIMEOUT)
+                            .await;
+                    let (up, down): (Vec<_>, Vec<_>) =
+                        health.into_iter().partition(|health| health.reachable);
+                    (
+                        up.into_iter().map(|health| health.addr).collect(),
+                        down.into_iter().map(|health| health.addr).collect(),
+                    )
+                } else {
+                    (Vec::new(), remaining.clone())
+                };
+            let offline = all_connected.is_empty() && probed.is_empty();
+            if offline {
+                tracing::warn!(
+                    "No bootstrap peer answered a reachability probe; skipping connection rounds"
+                );
+            }
+            all_connected.extend(&probed);
+
+            // Round 1: Connect in parallel to the bootstrap peers the probe
+            // did not already reach, with the full connect timeout.
+            let (succeeded, mut failed) = if offline || unprobed.is_empty() {
+                (Vec::new(), unprobed)
+            } else {
+                self.connect_peers_parallel_tracked(network, &unprobed)
+                    .await
+            };
             all_connected.extend(&succeeded);
             tracing::info!(
                 "Phase 2 round 1: {}/{} bootstrap peers connected",
-                succeeded.len(),
+                probed.len() + succeeded.len(),
                 remaining.len()
             );
 
-            // Retry rounds for failed peers
-            for round in 2..=3 {
-                if failed.is_empty() {
+            // Retry rounds for failed peers, on the configured jittered
+            // schedule so agents restarted together spread their redials.
+            let retry = &network.config().bootstrap_retry;
+            for round in 2..=retry.max_rounds {
+                if failed.is_empty() || offline {
                     break;
                 }
-                let delay = std::time::Duration::from_secs(if round == 2 { 10 } else { 15 });
+                let delay = retry.jittered_delay(round);
                 tracing::info!(
-                    "Retrying {} failed peers in {}s (round {})",
+                    "Retrying {} failed peers in {:.1}s (round {})",
                     failed.len(),
-                    delay.as_secs(),
+                    delay.as_secs_f64(),
                     round
                 );
                 tokio::time::sleep(delay).await;
@@ -7201,6 +7600,7 @@ impl Agent {
                 cache: std::sync::Arc::clone(&self.identity_discovery_cache),
                 machine_cache: std::sync::Arc::clone(&self.machine_discovery_cache),
                 user_identity_consented: std::sync::Arc::clone(&self.user_identity_consented),
+                status: std::sync::Arc::clone(&self.status),
                 allow_local_discovery_addrs: allow_local_discovery_addresses(network.config()),
                 revocation_set: std::sync::Arc::clone(&self.revocation_set),
             };
@@ -7245,9 +7645,9 @@ impl Agent {
     /// service cannot subscribe/publish on its topic.
     pub async fn start_capability_advert_service(&self) -> error::Result<()> {
         let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
-            error::IdentityError::Storage(std::io::Error::other(
-                "cannot start capability advert service: no gossip runtime configured",
-            ))
+            error::IdentityError::NotInitialized(
+                "cannot start capability advert service: no gossip runtime configured".to_string(),
+            )
         })?;
 
         let signing = std::sync::Arc::new(gossip::SigningContext::from_keypair(
@@ -7264,9 +7664,7 @@ impl Agent {
         )
         .await
         .map_err(|e| {
-            error::IdentityError::Storage(std::io::Error::other(format!(
-                "capability advert service spawn failed: {e}"
-            )))
+            error::IdentityError::Gossip(format!("capability advert service spawn failed: {e}"))
         })?;
 
         let mut guard = self.capability_advert_service.lock().await;
@@ -7309,9 +7707,9 @@ impl Agent {
         config: dm_inbox::DmInboxConfig,
     ) -> error::Result<()> {
         let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
-            error::IdentityError::Storage(std::io::Error::other(
-                "cannot start DM inbox: no gossip runtime configured",
-            ))
+            error::IdentityError::NotInitialized(
+                "cannot start DM inbox: no gossip runtime configured".to_string(),
+            )
         })?;
         let signing = std::sync::Arc::new(gossip::SigningContext::from_keypair(
             self.identity.agent_keypair(),
@@ -7323,11 +7721,7 @@ impl Agent {
         let (machine_pub_bytes, machine_sec_bytes) = self.identity.machine_keypair().to_bytes();
         let machine_keypair = std::sync::Arc::new(
             identity::MachineKeypair::from_bytes(&machine_pub_bytes, &machine_sec_bytes).map_err(
-                |e| {
-                    error::IdentityError::Storage(std::io::Error::other(format!(
-                        "DM inbox machine key copy: {e}"
-                    )))
-                },
+                |e| error::IdentityError::Crypto(format!("DM inbox machine key copy: {e}")),
             )?,
         );
         let service = dm_inbox::DmInboxService::spawn(
@@ -7347,11 +7741,7 @@ impl Agent {
             self.history_handle.clone(),
         )
         .await
-        .map_err(|e| {
-            error::IdentityError::Storage(std::io::Error::other(format!(
-                "DM inbox spawn failed: {e}"
-            )))
-        })?;
+        .map_err(|e| error::IdentityError::Gossip(format!("DM inbox spawn failed: {e}")))?;
         let mut guard = self.dm_inbox_service.lock().await;
         // Shutdown race (issue #116): if shutdown began while we were spawning,
         // abort the freshly-spawned service instead of storing it (and skip the
@@ -7390,6 +7780,9 @@ impl Agent {
     }
 
     /// Connect to cached peers in parallel, returning (succeeded, failed) peer lists.
+    ///
+    /// At most [`network::NetworkConfig::max_concurrent_connects`] attempts
+    /// are in flight at once.
     async fn connect_cached_peers_parallel_tracked(
         &self,
         network: &std::sync::Arc<network::NetworkNode>,
@@ -7398,12 +7791,12 @@ impl Agent {
         use tokio::time::{timeout, Duration};
         const CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
 
-        let handles: Vec<_> = peers
-            .iter()
-            .map(|peer| {
+        let results = connect_bounded(
+            peers.iter().map(|peer| peer.peer_id),
+            network.config().max_concurrent_connects,
+            |peer_id| {
                 let net = network.clone();
-                let peer_id = peer.peer_id;
-                tokio::spawn(async move {
+                async move {
                     tracing::debug!("Connecting to cached peer: {:?}", peer_id);
                     match timeout(CONNECT_TIMEOUT, net.connect_cached_peer(peer_id)).await {
                         Ok(Ok(addr)) => {
@@ -7423,23 +7816,26 @@ impl Agent {
                             Err(peer_id)
                         }
                     }
-                })
-            })
-            .collect();
-
-        let mut succeeded = Vec::new();
-        let mut failed = Vec::new();
-        for handle in handles {
-            match handle.await {
-                Ok(Ok(addr)) => succeeded.push(addr),
-                Ok(Err(peer_id)) => failed.push(peer_id),
-                Err(e) => tracing::error!("Connection task panicked: {}", e),
+                }
+            },
+        )
+        .await;
+
+        let mut succeeded = Vec::new();
+        let mut failed = Vec::new();
+        for result in results {
+            match result {
+                Ok(addr) => succeeded.push(addr),
+                Err(peer_id) => failed.push(peer_id),
             }
         }
         (succeeded, failed)
     }
 
     /// Connect to multiple peers in parallel, returning (succeeded, failed) address lists.
+    ///
+    /// At most [`network::NetworkConfig::max_concurrent_connects`] attempts
+    /// are in flight at once.
     async fn connect_peers_parallel_tracked(
         &self,
         network: &std::sync::Arc<network::NetworkNode>,
@@ -7451,12 +7847,12 @@ impl Agent {
         // ourselves or to unreachable addresses.
         const CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
 
-        let handles: Vec<_> = addrs
-            .iter()
-            .map(|addr| {
+        let results = connect_bounded(
+            addrs.iter().copied(),
+            network.config().max_concurrent_connects,
+            |addr| {
                 let net = network.clone();
-                let addr = *addr;
-                tokio::spawn(async move {
+                async move {
                     tracing::debug!("Connecting to peer: {}", addr);
                     match timeout(CONNECT_TIMEOUT, net.connect_addr(addr)).await {
                         Ok(Ok(_)) => {
@@ -7476,17 +7872,17 @@ impl Agent {
                             Err(addr)
                         }
                     }
-                })
-            })
-            .collect();
+                }
+            },
+        )
+        .await;
 
         let mut succeeded = Vec::new();
         let mut failed = Vec::new();
-        for handle in handles {
-            match handle.await {
-                Ok(Ok(addr)) => succeeded.push(addr),
-                Ok(Err(addr)) => failed.push(addr),
-                Err(e) => tracing::error!("Connection task panicked: {}", e),
+        for result in results {
+            match result {
+                Ok(addr) => succeeded.push(addr),
+                Err(addr) => failed.push(addr),
             }
         }
         (succeeded, failed)
@@ -7497,46 +7893,352 @@ impl Agent {
     /// Returns a [`gossip::Subscription`] that yields messages as they arrive
     /// through the gossip network.
     ///
+    /// The gossip runtime exists from [`AgentBuilder::build`], so this may be
+    /// called before [`Agent::join_network`]: the subscription registers with
+    /// the gossip layer immediately. Until peers connect it only sees this
+    /// agent's own publishes; each peer is added to the topic as soon as it
+    /// connects, so remote messages published after that are not missed.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - Gossip runtime is not initialized (configure agent with network first)
     pub async fn subscribe(&self, topic: &str) -> error::Result<Subscription> {
         let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
-            error::IdentityError::Storage(std::io::Error::other(
-                "gossip runtime not initialized - configure agent with network first",
-            ))
+            error::IdentityError::NotInitialized(
+                "gossip runtime not initialized - configure agent with network first".to_string(),
+            )
         })?;
         Ok(runtime.pubsub().subscribe(topic.to_string()).await)
     }
 
+    /// Subscribe to a topic with a subscriber channel of `capacity` messages.
+    ///
+    /// Same as [`Agent::subscribe`], which uses
+    /// [`gossip::DEFAULT_SUBSCRIBER_CAPACITY`]. Messages arriving while the
+    /// channel is full are dropped and counted in
+    /// [`Subscription::overflow`]. `capacity` must be at least 1; 0 is
+    /// treated as 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the gossip runtime is not initialized (configure
+    /// agent with network first).
+    pub async fn subscribe_with_capacity(
+        &self,
+        topic: &str,
+        capacity: usize,
+    ) -> error::Result<Subscription> {
+        let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
+            error::IdentityError::NotInitialized(
+                "gossip runtime not initialized - configure agent with network first".to_string(),
+            )
+        })?;
+        Ok(runtime
+            .pubsub()
+            .subscribe_with_capacity(topic.to_string(), capacity)
+            .await)
+    }
+
+    /// Subscribe to a topic, receiving only messages from senders trusted at
+    /// `min_trust` or above, e.g. [`contacts::TrustLevel::Known`] to hear
+    /// only from vetted contacts on a public topic.
+    ///
+    /// Trust comes from this agent's contact store; unsigned messages and
+    /// senders without a contact entry count as
+    /// [`contacts::TrustLevel::Unknown`]. This agent's own messages are
+    /// always delivered. Other messages are dropped before they reach the
+    /// channel. See [`gossip::PubSubManager::subscribe_filtered`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the gossip runtime is not initialized (configure
+    /// agent with network first).
+    pub async fn subscribe_filtered(
+        &self,
+        topic: &str,
+        min_trust: contacts::TrustLevel,
+    ) -> error::Result<Subscription> {
+        let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
+            error::IdentityError::NotInitialized(
+                "gossip runtime not initialized - configure agent with network first".to_string(),
+            )
+        })?;
+        Ok(runtime
+            .pubsub()
+            .subscribe_filtered(topic.to_string(), min_trust)
+            .await)
+    }
+
+    /// Subscribe to every topic starting with `prefix`, e.g. `team/sprint/`
+    /// for `team/sprint/tasks` and `team/sprint/chat`.
+    ///
+    /// Gossip carries only a hash of each topic name, so a matching topic is
+    /// received once this agent has seen its name — subscribed to it
+    /// exactly or published on it — and from then on while the prefix
+    /// subscription lives. A topic only remote peers use and this agent has
+    /// never named is not received. See
+    /// [`gossip::PubSubManager::subscribe_prefix`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the gossip runtime is not initialized (configure
+    /// agent with network first).
+    pub async fn subscribe_prefix(&self, prefix: &str) -> error::Result<Subscription> {
+        let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
+            error::IdentityError::NotInitialized(
+                "gossip runtime not initialized - configure agent with network first".to_string(),
+            )
+        })?;
+        Ok(runtime.pubsub().subscribe_prefix(prefix.to_string()).await)
+    }
+
+    /// Release every subscription to a topic.
+    ///
+    /// Use this when a [`Subscription`] is held somewhere its `Drop` timing
+    /// is hard to control, such as inside a collection. Outstanding
+    /// subscriptions to the topic stop receiving messages. Unsubscribing
+    /// from a topic with no subscribers is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the gossip runtime is not initialized (configure
+    /// agent with network first).
+    pub async fn unsubscribe(&self, topic: &str) -> error::Result<()> {
+        let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
+            error::IdentityError::NotInitialized(
+                "gossip runtime not initialized - configure agent with network first".to_string(),
+            )
+        })?;
+        runtime.pubsub().unsubscribe(topic).await;
+        Ok(())
+    }
+
+    /// Subscribe to a topic with local at-least-once delivery across restarts.
+    ///
+    /// Every delivered message is appended to a bounded per-topic log under
+    /// `<data_dir>/durable_topics/` before it is returned, tagged with a
+    /// monotonic sequence number. Pass the last sequence the application
+    /// finished processing as `since` (0 for everything retained) and the
+    /// returned [`gossip::durable::DurableSubscription`] first replays the
+    /// logged messages after that cursor, then continues with live ones.
+    ///
+    /// This only covers messages this agent actually received; it is not a
+    /// mesh-level delivery guarantee.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Gossip runtime is not initialized (configure agent with network first)
+    /// - The data directory or the topic log cannot be opened
+    pub async fn subscribe_durable(
+        &self,
+        topic: &str,
+        since: u64,
+    ) -> error::Result<gossip::durable::DurableSubscription> {
+        self.subscribe_durable_with_config(
+            topic,
+            since,
+            gossip::durable::DurableLogConfig::default(),
+        )
+        .await
+    }
+
+    /// Same as [`Agent::subscribe_durable`], with the topic log bounded by
+    /// `config` instead of [`gossip::DurableLogConfig::default`] (16 MiB,
+    /// 7 days).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Agent::subscribe_durable`].
+    pub async fn subscribe_durable_with_config(
+        &self,
+        topic: &str,
+        since: u64,
+        config: gossip::durable::DurableLogConfig,
+    ) -> error::Result<gossip::durable::DurableSubscription> {
+        let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
+            error::IdentityError::NotInitialized(
+                "gossip runtime not initialized - configure agent with network first".to_string(),
+            )
+        })?;
+        let data_dir = self
+            .identity_dir
+            .clone()
+            .or_else(|| dirs::home_dir().map(|h| h.join(".x0x")))
+            .ok_or_else(|| {
+                error::IdentityError::Storage(std::io::Error::other(
+                    "no identity_dir and no home directory for durable topic logs",
+                ))
+            })?;
+        let log = gossip::durable::DurableTopicLog::open(
+            &data_dir.join(gossip::durable::DURABLE_TOPICS_DIR),
+            topic,
+            config,
+        )?;
+        // Subscribe before reading the replay so nothing published in
+        // between is lost; at worst it is delivered twice.
+        let subscription = runtime.pubsub().subscribe(topic.to_string()).await;
+        Ok(gossip::durable::DurableSubscription::new(
+            subscription,
+            log,
+            since,
+        )?)
+    }
+
     /// Publish a message to a topic.
     ///
     /// The message will propagate through the gossip network via
     /// epidemic broadcast — every agent that receives it will
     /// relay it to its neighbours.
     ///
+    /// Task-list sync topics ([`gossip::check_app_topic`]) are refused:
+    /// every replica would apply the payload to its CRDT.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - Gossip runtime is not initialized (configure agent with network first)
+    /// - The topic carries task-list sync traffic
     /// - Message encoding or broadcast fails
     pub async fn publish(&self, topic: &str, payload: Vec<u8>) -> error::Result<()> {
+        gossip::check_app_topic(topic)
+            .map_err(|e| error::IdentityError::Gossip(format!("publish rejected: {e}")))?;
         let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
-            error::IdentityError::Storage(std::io::Error::other(
-                "gossip runtime not initialized - configure agent with network first",
-            ))
+            error::IdentityError::NotInitialized(
+                "gossip runtime not initialized - configure agent with network first".to_string(),
+            )
         })?;
         runtime
             .pubsub()
             .publish(topic.to_string(), bytes::Bytes::from(payload))
             .await
-            .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
-                    "publish failed: {}",
-                    e
-                )))
-            })
+            .map_err(|e| error::IdentityError::Gossip(format!("publish failed: {}", e)))
+    }
+
+    /// Encrypt `plaintext` for `group` and publish it to `topic`.
+    ///
+    /// The payload is an [`mls::MlsEpochMessage`] sealed with
+    /// [`mls::MlsGroup::encrypt_epoch_message`]; members at the same epoch
+    /// open it with [`mls::MlsGroup::decrypt_epoch_message`]. The key mixes
+    /// in the group's secret epoch material, so relays and non-members see
+    /// only the group id and epoch. This is the legacy GSS plane: a member
+    /// removed from `group` can keep deriving later keys, so use the
+    /// TreeKEM plane where removal must cut a member off.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::IdentityError::Crypto`] if encryption fails
+    /// (including an exhausted nonce space for the epoch), otherwise the
+    /// same errors as [`Agent::publish`].
+    pub async fn publish_encrypted(
+        &self,
+        group: &mls::MlsGroup,
+        topic: &str,
+        plaintext: &[u8],
+    ) -> error::Result<()> {
+        let payload = group
+            .encrypt_epoch_message(plaintext)
+            .map_err(|e| error::IdentityError::Crypto(e.to_string()))?;
+        self.publish(topic, payload).await
+    }
+
+    /// Publish a value to a topic as JSON.
+    ///
+    /// Serializes `value` with `serde_json` and forwards to
+    /// [`Agent::publish`]. Pair with [`Subscription::recv_json`] on the
+    /// receiving side.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::IdentityError::Storage`] if `value` cannot be
+    /// serialized, otherwise the same errors as [`Agent::publish`].
+    pub async fn publish_json<T: serde::Serialize>(
+        &self,
+        topic: &str,
+        value: &T,
+    ) -> error::Result<()> {
+        let payload =
+            serde_json::to_vec(value).map_err(|e| error::IdentityError::Storage(e.into()))?;
+        self.publish(topic, payload).await
+    }
+
+    /// Publish a message, choosing who signs for it.
+    ///
+    /// [`gossip::SignerSelection::Agent`] behaves exactly like
+    /// [`Agent::publish`]. [`gossip::SignerSelection::User`] publishes on
+    /// behalf of that user: the agent still signs, but the user's
+    /// [`identity::AgentCertificate`] for this agent travels with the
+    /// message so recipients can attribute it to the human
+    /// ([`gossip::PubSubMessage::on_behalf_of`]). The agent must hold a
+    /// certificate from that user (see [`Agent::agent_certificates`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::IdentityError::NotInitialized`] without a gossip
+    /// runtime, [`error::IdentityError::CertificateVerification`] if this
+    /// agent holds no certificate from the selected user, or
+    /// [`error::IdentityError::Gossip`] for a task-list sync topic or if
+    /// the publish fails.
+    pub async fn publish_as(
+        &self,
+        topic: &str,
+        payload: Vec<u8>,
+        signer: gossip::SignerSelection,
+    ) -> error::Result<()> {
+        let user_id = match signer {
+            gossip::SignerSelection::Agent => return self.publish(topic, payload).await,
+            gossip::SignerSelection::User(user_id) => user_id,
+        };
+        gossip::check_app_topic(topic)
+            .map_err(|e| error::IdentityError::Gossip(format!("publish rejected: {e}")))?;
+        let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
+            error::IdentityError::NotInitialized(
+                "gossip runtime not initialized - configure agent with network first".to_string(),
+            )
+        })?;
+        let certificate = self
+            .identity
+            .agent_certificates()
+            .into_iter()
+            .find(|cert| cert.user_id().ok() == Some(user_id))
+            .cloned()
+            .ok_or_else(|| {
+                error::IdentityError::CertificateVerification(format!(
+                    "no certificate from user {user_id} for this agent"
+                ))
+            })?;
+        runtime
+            .pubsub()
+            .publish_on_behalf(topic.to_string(), bytes::Bytes::from(payload), certificate)
+            .await
+            .map_err(|e| error::IdentityError::Gossip(format!("publish failed: {}", e)))
+    }
+
+    /// Check that [`Agent::publish`] would accept `topic` and `payload`,
+    /// without broadcasting anything.
+    ///
+    /// Runs the publish-path checks — topic validity, payload size limit
+    /// ([`gossip::GossipConfig::max_message_bytes`]), reserved x0x
+    /// namespaces, and signing readiness — and returns the first failure.
+    /// Intended for tooling that wants to catch usage errors before wiring
+    /// a publish into an automation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::IdentityError::NotInitialized`] without a gossip
+    /// runtime, or [`error::IdentityError::Gossip`] describing the first
+    /// failed check.
+    pub fn validate_publish(&self, topic: &str, payload: &[u8]) -> error::Result<()> {
+        let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
+            error::IdentityError::NotInitialized(
+                "gossip runtime not initialized - configure agent with network first".to_string(),
+            )
+        })?;
+        runtime
+            .pubsub()
+            .validate_publish(topic, payload)
+            .map_err(|e| error::IdentityError::Gossip(format!("publish rejected: {e}")))
     }
 
     /// Get connected peer IDs.
@@ -7548,9 +8250,9 @@ impl Agent {
     /// Returns an error if the network is not initialized.
     pub async fn peers(&self) -> error::Result<Vec<saorsa_gossip_types::PeerId>> {
         let network = self.network.as_ref().ok_or_else(|| {
-            error::IdentityError::Storage(std::io::Error::other(
-                "network not initialized - configure agent with network first",
-            ))
+            error::IdentityError::NotInitialized(
+                "network not initialized - configure agent with network first".to_string(),
+            )
         })?;
         let ant_peers = network.connected_peers().await;
         Ok(ant_peers
@@ -8024,6 +8726,7 @@ impl Agent {
                                     .and_then(|c| c.not_after()),
                                 agent_certificate: ann.agent_certificate.clone(),
                                 agent_public_key: ann.agent_public_key.clone(),
+                                status: ann.status.clone(),
                             };
                             upsert_discovered_machine_from_agent(&machine_cache, &discovered_agent)
                                 .await;
@@ -8061,6 +8764,7 @@ impl Agent {
                     cert_not_after: None,
                     agent_certificate: None,
                     agent_public_key: Vec::new(),
+                    status: None,
                 },
             )
             .await;
@@ -9026,14 +9730,14 @@ impl Agent {
             return Ok(());
         }
         let Some(runtime) = self.gossip_runtime.as_ref().map(std::sync::Arc::clone) else {
-            return Err(error::IdentityError::Storage(std::io::Error::other(
-                "gossip runtime not initialized — cannot start heartbeat",
-            )));
+            return Err(error::IdentityError::NotInitialized(
+                "gossip runtime not initialized — cannot start heartbeat".to_string(),
+            ));
         };
         let Some(network) = self.network.as_ref().map(std::sync::Arc::clone) else {
-            return Err(error::IdentityError::Storage(std::io::Error::other(
-                "network not initialized — cannot start heartbeat",
-            )));
+            return Err(error::IdentityError::NotInitialized(
+                "network not initialized — cannot start heartbeat".to_string(),
+            ));
         };
         let allow_local_discovery_addrs = allow_local_discovery_addresses(network.config());
         let ctx = HeartbeatContext {
@@ -9044,6 +9748,7 @@ impl Agent {
             cache: std::sync::Arc::clone(&self.identity_discovery_cache),
             machine_cache: std::sync::Arc::clone(&self.machine_discovery_cache),
             user_identity_consented: std::sync::Arc::clone(&self.user_identity_consented),
+            status: std::sync::Arc::clone(&self.status),
             allow_local_discovery_addrs,
             revocation_set: std::sync::Arc::clone(&self.revocation_set),
         };
@@ -9095,9 +9800,9 @@ impl Agent {
         use saorsa_gossip_rendezvous::{Capability, ProviderSummary};
 
         let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
-            error::IdentityError::Storage(std::io::Error::other(
-                "gossip runtime not initialized — cannot advertise identity",
-            ))
+            error::IdentityError::NotInitialized(
+                "gossip runtime not initialized — cannot advertise identity".to_string(),
+            )
         })?;
 
         let peer_id = runtime.peer_id();
@@ -9119,9 +9824,7 @@ impl Agent {
         summary
             .sign_raw(self.identity.machine_keypair().secret_key().as_bytes())
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
-                    "failed to sign rendezvous summary: {e}"
-                )))
+                error::IdentityError::Crypto(format!("failed to sign rendezvous summary: {e}"))
             })?;
 
         let cbor_bytes = summary.to_cbor().map_err(|e| {
@@ -9136,9 +9839,7 @@ impl Agent {
             .publish(topic, bytes::Bytes::from(cbor_bytes))
             .await
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
-                    "failed to publish rendezvous summary: {e}"
-                )))
+                error::IdentityError::Gossip(format!("failed to publish rendezvous summary: {e}"))
             })?;
 
         self.rendezvous_advertised
@@ -9370,10 +10071,40 @@ impl Agent {
     /// let list = agent.create_task_list("Sprint Planning", "team-sprint").await?;
     /// ```
     pub async fn create_task_list(&self, name: &str, topic: &str) -> error::Result<TaskListHandle> {
+        self.create_task_list_with_options(name, topic, crdt::SyncOptions::default())
+            .await
+    }
+
+    /// Create a new collaborative task list with explicit sync options.
+    ///
+    /// Like [`create_task_list`](Self::create_task_list), but `options`
+    /// set how often the replica re-requests state while it has not
+    /// converged with a holder (see [`crdt::SyncOptions`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the gossip runtime is not initialized,
+    /// `options` are out of bounds, or the list's snapshot in the
+    /// configured task-list storage is unreadable.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let options = SyncOptions { sync_interval_secs: 5 };
+    /// let list = agent
+    ///     .create_task_list_with_options("Standup", "team-standup", options)
+    ///     .await?;
+    /// ```
+    pub async fn create_task_list_with_options(
+        &self,
+        name: &str,
+        topic: &str,
+        options: crdt::SyncOptions,
+    ) -> error::Result<TaskListHandle> {
         let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
-            error::IdentityError::Storage(std::io::Error::other(
-                "gossip runtime not initialized - configure agent with network first",
-            ))
+            error::IdentityError::NotInitialized(
+                "gossip runtime not initialized - configure agent with network first".to_string(),
+            )
         })?;
 
         let peer_id = runtime.peer_id();
@@ -9381,32 +10112,30 @@ impl Agent {
         // every replica of this list agrees on it — it is the attestation scope
         // bound into claim/complete signatures. See TaskListId::from_topic.
         let list_id = crdt::TaskListId::from_topic(topic);
-        let task_list = crdt::TaskList::new(list_id, name.to_string(), peer_id);
+        let task_list = match self.load_persisted_task_list(&list_id).await? {
+            Some(saved) => saved,
+            None => crdt::TaskList::new(list_id, name.to_string(), peer_id),
+        };
 
-        let sync = crdt::TaskListSync::new(
+        let sync = crdt::TaskListSync::with_options(
             task_list,
             std::sync::Arc::clone(runtime.pubsub()),
             topic.to_string(),
             peer_id,
+            options,
         )
         .map_err(|e| {
-            error::IdentityError::Storage(std::io::Error::other(format!(
-                "task list sync creation failed: {}",
-                e
-            )))
+            error::IdentityError::Gossip(format!("task list sync creation failed: {}", e))
         })?;
 
         let sync = std::sync::Arc::new(sync);
         sync.start_with_spawner(|fut| self.spawn_tracked(fut))
             .await
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
-                    "task list sync start failed: {}",
-                    e
-                )))
+                error::IdentityError::Gossip(format!("task list sync start failed: {}", e))
             })?;
 
-        Ok(TaskListHandle {
+        let handle = TaskListHandle {
             sync,
             agent_id: self.agent_id(),
             peer_id,
@@ -9414,7 +10143,11 @@ impl Agent {
             signing: std::sync::Arc::new(gossip::SigningContext::from_keypair(
                 self.identity.agent_keypair(),
             )),
-        })
+            owners: self.task_owners(),
+        };
+        self.register_task_list(&handle);
+        self.spawn_task_list_saver(&handle);
+        Ok(handle)
     }
 
     /// Join an existing task list by topic.
@@ -9441,9 +10174,9 @@ impl Agent {
     /// ```
     pub async fn join_task_list(&self, topic: &str) -> error::Result<TaskListHandle> {
         let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
-            error::IdentityError::Storage(std::io::Error::other(
-                "gossip runtime not initialized - configure agent with network first",
-            ))
+            error::IdentityError::NotInitialized(
+                "gossip runtime not initialized - configure agent with network first".to_string(),
+            )
         })?;
 
         let peer_id = runtime.peer_id();
@@ -9452,7 +10185,10 @@ impl Agent {
         // create_task_list / TaskListId::from_topic), otherwise the scope bound
         // into remote claim attestations won't verify and claims never converge.
         let list_id = crdt::TaskListId::from_topic(topic);
-        let task_list = crdt::TaskList::new(list_id, String::new(), peer_id);
+        let task_list = match self.load_persisted_task_list(&list_id).await? {
+            Some(saved) => saved,
+            None => crdt::TaskList::new(list_id, String::new(), peer_id),
+        };
 
         let sync = crdt::TaskListSync::new(
             task_list,
@@ -9461,23 +10197,17 @@ impl Agent {
             peer_id,
         )
         .map_err(|e| {
-            error::IdentityError::Storage(std::io::Error::other(format!(
-                "task list sync creation failed: {}",
-                e
-            )))
+            error::IdentityError::Gossip(format!("task list sync creation failed: {}", e))
         })?;
 
         let sync = std::sync::Arc::new(sync);
         sync.start_with_spawner(|fut| self.spawn_tracked(fut))
             .await
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
-                    "task list sync start failed: {}",
-                    e
-                )))
+                error::IdentityError::Gossip(format!("task list sync start failed: {}", e))
             })?;
 
-        Ok(TaskListHandle {
+        let handle = TaskListHandle {
             sync,
             agent_id: self.agent_id(),
             peer_id,
@@ -9485,44 +10215,353 @@ impl Agent {
             signing: std::sync::Arc::new(gossip::SigningContext::from_keypair(
                 self.identity.agent_keypair(),
             )),
-        })
+            owners: self.task_owners(),
+        };
+        self.register_task_list(&handle);
+        self.spawn_task_list_saver(&handle);
+        Ok(handle)
     }
 }
 
-// ─── Proactive peer reconnect (post-disconnect / post-restart) ──────────────
+impl Agent {
+    /// Look up a task list this agent created or joined by its canonical id.
+    ///
+    /// Task lists are addressed by gossip topic, but every list also has a
+    /// [`crdt::TaskListId`] derived from that topic
+    /// ([`crdt::TaskListId::from_topic`]), so the id identifies the same
+    /// logical list on every replica. Returns `None` if no live handle for
+    /// the id exists: never opened here, or every handle was dropped or
+    /// [cancelled](TaskListHandle::cancel_sync). If a topic was joined more
+    /// than once, the most recent handle's replica is returned.
+    #[must_use]
+    pub fn task_list_by_id(&self, id: &crdt::TaskListId) -> Option<TaskListHandle> {
+        let mut registry = self
+            .task_list_registry
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let entry = registry.get(id)?;
+        match entry.sync.upgrade().filter(|sync| !sync.is_cancelled()) {
+            Some(sync) => Some(TaskListHandle {
+                sync,
+                agent_id: self.agent_id(),
+                peer_id: entry.peer_id,
+                replica_epoch: entry.replica_epoch,
+                signing: std::sync::Arc::clone(&entry.signing),
+                owners: self.task_owners(),
+            }),
+            None => {
+                registry.remove(id);
+                None
+            }
+        }
+    }
 
-/// Exponential backoff delays for proactive reconnect attempts after a known
-/// peer disconnects. Each delay is the wait *before* the attempt, so the first
-/// attempt fires after 1 s — fast enough for a daemon restart on the same host
-/// while avoiding a tight retry loop on a genuinely-down peer.
-const RECONNECT_BACKOFF_DELAYS: &[std::time::Duration] = &[
-    std::time::Duration::from_secs(1),
-    std::time::Duration::from_secs(2),
-    std::time::Duration::from_secs(4),
-    std::time::Duration::from_secs(8),
-    std::time::Duration::from_secs(16),
-];
+    /// The task-list store configured with
+    /// [`AgentBuilder::with_task_list_storage_dir`], if any.
+    #[must_use]
+    pub fn task_list_storage(&self) -> Option<&crdt::TaskListStorage> {
+        self.task_list_storage.as_ref()
+    }
 
-/// After a reconnect sequence exhausts all attempts WITHOUT reconnecting, hold
-/// the single-flight tracker slot for this long before releasing it. A
-/// genuinely dead/unreachable peer keeps emitting transport-close lifecycle
-/// events; without this cooldown each one immediately re-triggers a new
-/// reconnect sequence, and every attempt drives ant-quic NAT
-/// traversal/hole-punch to the dead endpoint — a tight loop that accumulates
-/// connection state and leaks memory (~1 MB/s observed on a churning mesh).
-/// Holding the slot means the existing single-flight guard suppresses
-/// re-scheduling for the cooldown window. A real recovery (PeerConnected)
-/// removes+aborts the entry, ending the cooldown immediately.
-const RECONNECT_FAILURE_COOLDOWN: std::time::Duration = std::time::Duration::from_secs(60);
+    /// Save activity of this agent's task-list snapshots: when the last
+    /// save happened, how many saves succeeded or failed, and how many
+    /// lists are currently behind on disk. All zero when no storage
+    /// directory is configured.
+    #[must_use]
+    pub fn task_list_persistence_stats(&self) -> crdt::TaskListPersistenceStats {
+        self.task_list_persistence.snapshot()
+    }
 
-/// Whether a discovered peer's identity announcement should trigger an
-/// auto-connect dial.
-///
-/// Returns `false` when the peer's reconnect is suppressed — it was revoked,
-/// policy-rejected, admin-disconnected, or capacity-evicted — so that a
-/// suppressed peer which keeps broadcasting identity announcements is not
-/// silently reconnected (which would undo the security/eviction decision), and
-/// `false` when the peer is already connected. Extracted as a pure predicate so
+    /// The task-list persistence policy in force.
+    #[must_use]
+    pub fn persistence_policy(&self) -> crdt::PersistencePolicy {
+        *self.persistence_policy.borrow()
+    }
+
+    /// Change how often open task lists are saved, effective immediately
+    /// for every list, including ones already open.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crdt::CrdtError::InvalidPersistencePolicy`] if `policy`
+    /// fails [`crdt::PersistencePolicy::validate`]; the policy in force is
+    /// left unchanged.
+    pub fn set_persistence_policy(
+        &self,
+        policy: crdt::PersistencePolicy,
+    ) -> std::result::Result<(), crdt::CrdtError> {
+        policy.validate()?;
+        self.persistence_policy.send_replace(policy);
+        Ok(())
+    }
+
+    /// Save `handle`'s list to the configured task-list storage now.
+    ///
+    /// The background saver already writes each list within a few seconds
+    /// of a change; this is for callers that need the list on disk at a
+    /// known point, e.g. just before a planned restart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`std::io::ErrorKind::Unsupported`] storage error if no
+    /// storage directory is configured, or another error if the save fails.
+    pub async fn checkpoint_task_list(
+        &self,
+        handle: &TaskListHandle,
+    ) -> error::Result<crdt::TaskListCheckpoint> {
+        let Some(storage) = &self.task_list_storage else {
+            return Err(error::IdentityError::Storage(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "task-list persistence is disabled: no storage directory configured",
+            )));
+        };
+        let list_id = handle.id();
+        let list = handle.sync.read().await.clone();
+        if let Err(e) = storage.save_task_list(&list_id, &list).await {
+            self.task_list_persistence.record_failure(&list_id);
+            return Err(error::IdentityError::Storage(std::io::Error::other(
+                format!("failed to save task list {list_id}: {e}"),
+            )));
+        }
+        self.task_list_persistence.record_save(&list_id);
+        Ok(crdt::TaskListCheckpoint {
+            list_id,
+            version: list.current_version(),
+            task_count: list.task_count(),
+            saved_at: dm::now_unix_ms(),
+        })
+    }
+
+    /// Ids of the task lists persisted in the configured storage directory.
+    ///
+    /// Includes lists not opened in this process, e.g. ones saved before a
+    /// restart; compare with [`task_list_by_id`](Self::task_list_by_id) to
+    /// find those not yet rejoined. Files whose name is not a list id are
+    /// skipped. Empty when no storage directory is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage directory cannot be read.
+    pub async fn persisted_task_lists(&self) -> error::Result<Vec<crdt::TaskListId>> {
+        let Some(storage) = &self.task_list_storage else {
+            return Ok(Vec::new());
+        };
+        let names = storage.list_task_lists().await.map_err(|e| {
+            error::IdentityError::Storage(std::io::Error::other(format!(
+                "failed to list persisted task lists: {e}"
+            )))
+        })?;
+        let mut ids: Vec<crdt::TaskListId> = names
+            .iter()
+            .filter_map(|name| {
+                let bytes: [u8; 32] = hex::decode(name).ok()?.try_into().ok()?;
+                Some(crdt::TaskListId::new(bytes))
+            })
+            .collect();
+        ids.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
+        Ok(ids)
+    }
+
+    /// Gossip topic of a task list persisted in the configured storage.
+    ///
+    /// Recorded when the list is opened, so a persisted list's group scope
+    /// can be checked before it is rejoined. `None` when no storage is
+    /// configured or no topic was recorded, e.g. for a snapshot saved by an
+    /// older version that has not been reopened since.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the recorded topic cannot be read.
+    pub async fn persisted_task_list_topic(
+        &self,
+        list_id: &crdt::TaskListId,
+    ) -> error::Result<Option<String>> {
+        let Some(storage) = &self.task_list_storage else {
+            return Ok(None);
+        };
+        storage.load_task_list_topic(list_id).await.map_err(|e| {
+            error::IdentityError::Storage(std::io::Error::other(format!(
+                "failed to read the topic of task list {list_id}: {e}"
+            )))
+        })
+    }
+
+    /// Load `list_id`'s snapshot from the configured task-list storage.
+    ///
+    /// `Ok(None)` when no storage is configured or nothing was saved for the
+    /// list yet. An unreadable snapshot is an error rather than a fresh
+    /// start: the saver would otherwise overwrite it with an empty list.
+    async fn load_persisted_task_list(
+        &self,
+        list_id: &crdt::TaskListId,
+    ) -> error::Result<Option<crdt::TaskList>> {
+        let Some(storage) = &self.task_list_storage else {
+            return Ok(None);
+        };
+        let list = match storage.load_task_list(list_id).await {
+            Ok(list) => list,
+            Err(crdt::CrdtError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(None);
+            }
+            Err(e) => {
+                return Err(error::IdentityError::Storage(std::io::Error::other(
+                    format!(
+                        "task list snapshot {list_id} is unreadable ({e}); remove it to start the list empty"
+                    ),
+                )));
+            }
+        };
+        if list.id() != list_id {
+            return Err(error::IdentityError::Storage(std::io::Error::other(
+                format!("task list snapshot {list_id} holds list {}", list.id()),
+            )));
+        }
+        // The seq counter is not saved. Tags minted before the restart came
+        // from a counter started at zero in an earlier process, so the
+        // current time in microseconds is a floor above all of them.
+        let floor = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| u64::try_from(d.as_micros()).unwrap_or(u64::MAX));
+        list.restore_seq_counter(floor);
+        Ok(Some(list))
+    }
+
+    /// Keep `handle`'s snapshot in the configured task-list storage current.
+    ///
+    /// Saves whenever the list's version has moved since the last save,
+    /// checked on open, then every
+    /// [`save_interval_secs`](crdt::PersistencePolicy::save_interval_secs)
+    /// and whenever the policy changes, plus once more on shutdown. Exits
+    /// when the list is dropped or its sync is cancelled. The list's topic
+    /// is recorded once on open (see [`Self::persisted_task_list_topic`]).
+    fn spawn_task_list_saver(&self, handle: &TaskListHandle) {
+        let Some(storage) = self.task_list_storage.clone() else {
+            return;
+        };
+        let sync = std::sync::Arc::downgrade(&handle.sync);
+        let list_id = handle.id();
+        let topic = handle.topic().to_string();
+        let token = self.shutdown_token.clone();
+        let counters = std::sync::Arc::clone(&self.task_list_persistence);
+        let mut policy = self.persistence_policy.subscribe();
+        self.spawn_tracked(async move {
+            if let Err(e) = storage.save_task_list_topic(&list_id, &topic).await {
+                tracing::warn!("failed to record the topic of task list {list_id}: {e}");
+            }
+            let mut saved_version = None;
+            loop {
+                let Some(sync) = sync.upgrade() else {
+                    break;
+                };
+                let stopping = token.is_cancelled() || sync.is_cancelled();
+                let list = {
+                    let list = sync.read().await;
+                    (saved_version != Some(list.current_version())).then(|| list.clone())
+                };
+                drop(sync);
+                if let Some(list) = list {
+                    match storage.save_task_list(&list_id, &list).await {
+                        Ok(()) => {
+                            saved_version = Some(list.current_version());
+                            counters.record_save(&list_id);
+                        }
+                        Err(e) => {
+                            counters.record_failure(&list_id);
+                            tracing::warn!("failed to save task list {list_id}: {e}");
+                        }
+                    }
+                }
+                if stopping {
+                    break;
+                }
+                let wait = std::time::Duration::from_secs(policy.borrow().save_interval_secs);
+                tokio::select! {
+                    _ = tokio::time::sleep(wait) => {}
+                    changed = policy.changed() => {
+                        // The agent is gone; nothing will save again.
+                        if changed.is_err() {
+                            break;
+                        }
+                    }
+                    _ = token.cancelled() => {}
+                }
+            }
+        });
+    }
+
+    fn task_owners(&self) -> TaskOwners {
+        TaskOwners {
+            local: self.user_id().map(|user_id| (self.agent_id(), user_id)),
+            discovered: std::sync::Arc::clone(&self.identity_discovery_cache),
+        }
+    }
+
+    /// Cancel the sync loops of every live registered task list.
+    fn cancel_task_list_syncs(&self) {
+        let syncs: Vec<_> = self
+            .task_list_registry
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .values()
+            .filter_map(|entry| entry.sync.upgrade())
+            .collect();
+        for sync in syncs {
+            sync.cancel_sync();
+        }
+    }
+
+    fn register_task_list(&self, handle: &TaskListHandle) {
+        let mut registry = self
+            .task_list_registry
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        registry.retain(|_, entry| entry.sync.strong_count() > 0);
+        registry.insert(
+            handle.id(),
+            RegisteredTaskList {
+                sync: std::sync::Arc::downgrade(&handle.sync),
+                peer_id: handle.peer_id,
+                replica_epoch: handle.replica_epoch,
+                signing: std::sync::Arc::clone(&handle.signing),
+            },
+        );
+    }
+}
+
+// ─── Proactive peer reconnect (post-disconnect / post-restart) ──────────────
+
+/// Exponential backoff delays for proactive reconnect attempts after a known
+/// peer disconnects. Each delay is the wait *before* the attempt, so the first
+/// attempt fires after 1 s — fast enough for a daemon restart on the same host
+/// while avoiding a tight retry loop on a genuinely-down peer.
+const RECONNECT_BACKOFF_DELAYS: &[std::time::Duration] = &[
+    std::time::Duration::from_secs(1),
+    std::time::Duration::from_secs(2),
+    std::time::Duration::from_secs(4),
+    std::time::Duration::from_secs(8),
+    std::time::Duration::from_secs(16),
+];
+
+/// After a reconnect sequence exhausts all attempts WITHOUT reconnecting, hold
+/// the single-flight tracker slot for this long before releasing it. A
+/// genuinely dead/unreachable peer keeps emitting transport-close lifecycle
+/// events; without this cooldown each one immediately re-triggers a new
+/// reconnect sequence, and every attempt drives ant-quic NAT
+/// traversal/hole-punch to the dead endpoint — a tight loop that accumulates
+/// connection state and leaks memory (~1 MB/s observed on a churning mesh).
+/// Holding the slot means the existing single-flight guard suppresses
+/// re-scheduling for the cooldown window. A real recovery (PeerConnected)
+/// removes+aborts the entry, ending the cooldown immediately.
+const RECONNECT_FAILURE_COOLDOWN: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Whether a discovered peer's identity announcement should trigger an
+/// auto-connect dial.
+///
+/// Returns `false` when the peer's reconnect is suppressed — it was revoked,
+/// policy-rejected, admin-disconnected, or capacity-evicted — so that a
+/// suppressed peer which keeps broadcasting identity announcements is not
+/// silently reconnected (which would undo the security/eviction decision), and
+/// `false` when the peer is already connected. Extracted as a pure predicate so
 /// the security invariant is unit-testable independently of the gossip stack.
 fn announcement_should_auto_connect(reconnect_suppressed: bool, already_connected: bool) -> bool {
     !reconnect_suppressed && !already_connected
@@ -9832,6 +10871,30 @@ fn schedule_reconnect(
     tracker.insert(peer_id_bytes, handle);
 }
 
+/// True when a key load failed only because the key file does not exist —
+/// the one case where `AgentBuilder::build` may generate a replacement.
+fn is_key_file_missing(err: &error::IdentityError) -> bool {
+    matches!(err, error::IdentityError::Storage(e) if e.kind() == std::io::ErrorKind::NotFound)
+}
+
+/// Wrap a failure to load an existing key file, naming the file so the
+/// operator knows which key to repair instead of seeing a new identity.
+fn unreadable_key_error(
+    kind: &str,
+    path: &std::path::Path,
+    err: error::IdentityError,
+) -> error::IdentityError {
+    tracing::error!(
+        path = %path.display(),
+        error = %err,
+        "{kind} key file exists but could not be loaded; refusing to overwrite it"
+    );
+    error::IdentityError::Storage(std::io::Error::other(format!(
+        "{kind} key file {} exists but could not be loaded (not overwriting): {err}",
+        path.display()
+    )))
+}
+
 impl AgentBuilder {
     /// Set a custom path for the machine keypair.
     ///
@@ -9911,6 +10974,27 @@ impl AgentBuilder {
         self
     }
 
+    /// Attach a certificate from another user attesting this agent.
+    ///
+    /// For shared agents acting on behalf of several users. The certificate
+    /// is checked on build (it must verify and bind this agent) and saved,
+    /// with any previously saved ones, to the additional-certificates file
+    /// beside `agent.cert` (see [`storage::additional_certificates_path`]).
+    /// The primary user binding is unchanged; see [`identity::Identity`].
+    ///
+    /// # Arguments
+    ///
+    /// * `cert` - A certificate issued for this agent by another user.
+    ///
+    /// # Returns
+    ///
+    /// Self for chaining.
+    #[must_use]
+    pub fn with_additional_certificate(mut self, cert: identity::AgentCertificate) -> Self {
+        self.additional_certificates.push(cert);
+        self
+    }
+
     /// Set network configuration for P2P communication.
     ///
     /// If not set, the agent is built without a network node or gossip
@@ -9929,6 +11013,105 @@ impl AgentBuilder {
         self
     }
 
+    /// Cap the number of simultaneous peer connections.
+    ///
+    /// Overrides [`network::NetworkConfig::max_connections`] on the
+    /// configuration given to [`Self::with_network_config`], in either call
+    /// order. Without one, networking is enabled with
+    /// `NetworkConfig::default()` and this cap applied.
+    #[must_use]
+    pub fn with_max_connections(mut self, max_connections: u32) -> Self {
+        self.max_connections = Some(max_connections);
+        self
+    }
+
+    /// Bootstrap from exactly `peers` instead of the default bootstrap
+    /// nodes.
+    ///
+    /// Replaces [`network::NetworkConfig::bootstrap_nodes`] on the
+    /// configuration given to [`Self::with_network_config`], in either call
+    /// order. Without one, networking is enabled with
+    /// `NetworkConfig::default()` and its bootstrap set replaced.
+    #[must_use]
+    pub fn with_bootstrap_peers(mut self, peers: Vec<std::net::SocketAddr>) -> Self {
+        self.bootstrap_peers = Some(peers);
+        self
+    }
+
+    /// Bootstrap from `peers` in addition to the configured bootstrap nodes
+    /// (the defaults unless replaced).
+    ///
+    /// Applied after [`Self::with_bootstrap_peers`]; addresses already in
+    /// the set are not added twice.
+    #[must_use]
+    pub fn with_additional_bootstrap_peers(mut self, peers: Vec<std::net::SocketAddr>) -> Self {
+        self.additional_bootstrap_peers.extend(peers);
+        self
+    }
+
+    /// Run with networking but without reaching out to anyone.
+    ///
+    /// The agent gets a network node and gossip runtime, so pub/sub and task
+    /// lists work, but has no bootstrap nodes, no port mapping and an
+    /// in-memory peer cache (no previously seen peers are dialled).
+    /// `join_network` therefore returns without dialling anyone; peers are
+    /// added by hand with [`Agent::connect_to`]. Unless the network
+    /// configuration names one, the node binds an ephemeral loopback port so
+    /// several offline agents can share a host — bind a LAN address through
+    /// [`Self::with_network_config`] for an air-gapped fleet.
+    ///
+    /// Unlike [`Agent::new`], which has no network node at all, this is a
+    /// fully networked agent that simply knows no one yet. Peers given to
+    /// [`Self::with_bootstrap_peers`] or
+    /// [`Self::with_additional_bootstrap_peers`] are still used, so a private
+    /// network can bootstrap from its own nodes.
+    #[must_use]
+    pub fn offline(mut self) -> Self {
+        self.offline = true;
+        self.disable_peer_cache = true;
+        self
+    }
+
+    /// Fold the `max_connections`, offline and bootstrap overrides into the
+    /// network configuration, enabling networking with the defaults if any
+    /// is set.
+    fn apply_network_overrides(&mut self) {
+        if self.offline {
+            let config = self
+                .network_config
+                .get_or_insert_with(network::NetworkConfig::default);
+            config.bootstrap_nodes.clear();
+            config.port_mapping_enabled = false;
+            if config.bind_addr.is_none() {
+                config.bind_addr = Some(std::net::SocketAddr::from((
+                    std::net::Ipv4Addr::LOCALHOST,
+                    0,
+                )));
+            }
+        }
+        if let Some(max_connections) = self.max_connections {
+            self.network_config
+                .get_or_insert_with(network::NetworkConfig::default)
+                .max_connections = max_connections;
+        }
+        if let Some(peers) = self.bootstrap_peers.take() {
+            self.network_config
+                .get_or_insert_with(network::NetworkConfig::default)
+                .bootstrap_nodes = peers;
+        }
+        if !self.additional_bootstrap_peers.is_empty() {
+            let nodes = &mut self
+                .network_config
+                .get_or_insert_with(network::NetworkConfig::default)
+                .bootstrap_nodes;
+            for peer in std::mem::take(&mut self.additional_bootstrap_peers) {
+                if !nodes.contains(&peer) {
+                    nodes.push(peer);
+                }
+            }
+        }
+    }
+
     /// Set gossip overlay configuration.
     ///
     /// This is primarily used by x0xd to expose operational knobs such as
@@ -10073,6 +11256,20 @@ impl AgentBuilder {
         self
     }
 
+    /// Pin the pub/sub wire version this agent speaks.
+    ///
+    /// Publishes are encoded in at most `version` and inbound frames in a
+    /// newer format are dropped, regardless of what peers support. Intended
+    /// for interop testing and staged rollouts; see
+    /// [`gossip::pubsub::LATEST_WIRE_VERSION`] for the default.
+    ///
+    /// [`Self::build`] fails if `version` is not implemented by this build.
+    #[must_use]
+    pub fn with_wire_version(mut self, version: u8) -> Self {
+        self.wire_version = Some(version);
+        self
+    }
+
     /// Set a custom path for the contacts file.
     ///
     /// The contacts file persists trust levels and machine records for known
@@ -10104,6 +11301,36 @@ impl AgentBuilder {
         self
     }
 
+    /// Set the directory holding persisted task lists.
+    ///
+    /// Enables [`Agent::task_list_storage`] and
+    /// [`Agent::persisted_task_lists`]. Every task list the agent creates or
+    /// joins is saved here as it changes and restored from here when the
+    /// same topic is opened again, so tasks survive a restart without a
+    /// peer to re-sync from. Unset by default, in which case the agent knows
+    /// only the task lists opened in the current process.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Directory of `<list_id>.bin` files.
+    #[must_use]
+    pub fn with_task_list_storage_dir<P: AsRef<std::path::Path>>(mut self, path: P) -> Self {
+        self.task_list_storage_dir = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Bound the whole of [`Self::build`].
+    ///
+    /// Defaults to [`AGENT_BUILD_TIMEOUT_SECS`]. When the limit elapses,
+    /// `build` returns [`error::IdentityError::BuildTimeout`] and drops
+    /// whatever it had constructed so far (network node, gossip runtime).
+    /// Key files already written stay on disk.
+    #[must_use]
+    pub fn with_build_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.build_timeout = Some(timeout);
+        self
+    }
+
     /// Build and initialise the agent.
     ///
     /// This performs the following:
@@ -10119,27 +11346,49 @@ impl AgentBuilder {
     /// - Machine keypair generation fails
     /// - Storage I/O fails
     /// - Keypair deserialization fails
+    /// - A machine, agent, or user key file exists but cannot be read or
+    ///   decoded (it is never overwritten with a fresh key)
+    /// - Construction does not finish within the build timeout
+    ///   ([`error::IdentityError::BuildTimeout`]; see
+    ///   [`Self::with_build_timeout`])
     pub async fn build(self) -> error::Result<Agent> {
+        let limit = self
+            .build_timeout
+            .unwrap_or(std::time::Duration::from_secs(AGENT_BUILD_TIMEOUT_SECS));
+        bounded_build(limit, self.build_unbounded()).await
+    }
+
+    async fn build_unbounded(mut self) -> error::Result<Agent> {
+        self.apply_network_overrides();
+
         // Determine machine keypair source
+        //
+        // Only a missing key file may be replaced by a fresh key. A file that
+        // exists but cannot be read or decoded (permissions, lock, partial
+        // write) is an error: overwriting it would silently change identity.
         let machine_keypair = if let Some(path) = self.machine_key_path {
             // Try to load from custom path
             match storage::load_machine_keypair_from(&path).await {
                 Ok(kp) => kp,
-                Err(_) => {
+                Err(e) if is_key_file_missing(&e) => {
                     // Generate fresh keypair and save to custom path
                     let kp = identity::MachineKeypair::generate()?;
                     storage::save_machine_keypair_to(&kp, &path).await?;
                     kp
                 }
+                Err(e) => return Err(unreadable_key_error("machine", &path, e)),
             }
-        } else if storage::machine_keypair_exists().await {
-            // Load default machine keypair
-            storage::load_machine_keypair().await?
         } else {
-            // Generate and save default machine keypair
-            let kp = identity::MachineKeypair::generate()?;
-            storage::save_machine_keypair(&kp).await?;
-            kp
+            match storage::load_machine_keypair().await {
+                Ok(kp) => kp,
+                Err(e) if is_key_file_missing(&e) => {
+                    // Generate and save default machine keypair
+                    let kp = identity::MachineKeypair::generate()?;
+                    storage::save_machine_keypair(&kp).await?;
+                    kp
+                }
+                Err(e) => return Err(e),
+            }
         };
 
         // Resolve agent keypair: explicit > path-based > default storage > generate
@@ -10150,20 +11399,24 @@ impl AgentBuilder {
             // Custom path: load or generate+save
             match storage::load_agent_keypair_from(&path).await {
                 Ok(kp) => kp,
-                Err(_) => {
+                Err(e) if is_key_file_missing(&e) => {
                     let kp = identity::AgentKeypair::generate()?;
                     storage::save_agent_keypair_to(&kp, &path).await?;
                     kp
                 }
+                Err(e) => return Err(unreadable_key_error("agent", &path, e)),
             }
-        } else if storage::agent_keypair_exists().await {
-            // Default path exists: load it
-            storage::load_agent_keypair_default().await?
         } else {
-            // No stored key: generate and persist
-            let kp = identity::AgentKeypair::generate()?;
-            storage::save_agent_keypair_default(&kp).await?;
-            kp
+            match storage::load_agent_keypair_default().await {
+                Ok(kp) => kp,
+                Err(e) if is_key_file_missing(&e) => {
+                    // No stored key: generate and persist
+                    let kp = identity::AgentKeypair::generate()?;
+                    storage::save_agent_keypair_default(&kp).await?;
+                    kp
+                }
+                Err(e) => return Err(e),
+            }
         };
 
         // Resolve user keypair: explicit > path-based > default storage > None (opt-in)
@@ -10171,12 +11424,17 @@ impl AgentBuilder {
             Some(kp)
         } else if let Some(path) = self.user_key_path {
             // Custom path: load if exists, otherwise None (don't auto-generate)
-            storage::load_user_keypair_from(&path).await.ok()
-        } else if storage::user_keypair_exists().await {
-            // Default path exists: load it
-            storage::load_user_keypair().await.ok()
+            match storage::load_user_keypair_from(&path).await {
+                Ok(kp) => Some(kp),
+                Err(e) if is_key_file_missing(&e) => None,
+                Err(e) => return Err(unreadable_key_error("user", &path, e)),
+            }
         } else {
-            None
+            match storage::load_user_keypair().await {
+                Ok(kp) => Some(kp),
+                Err(e) if is_key_file_missing(&e) => None,
+                Err(e) => return Err(e),
+            }
         };
 
         // Build identity with optional user layer.
@@ -10196,7 +11454,7 @@ impl AgentBuilder {
         // The per-daemon `agent_cert_path` (set by `with_agent_cert_path()`)
         // is the structural fix for scenario (2); the agent_id check is the
         // defensive net in case two processes still land on the same path.
-        let identity = if let Some(user_kp) = user_keypair {
+        let mut identity = if let Some(user_kp) = user_keypair {
             let cert_path = self.agent_cert_path.clone();
             let existing_cert = if let Some(ref p) = cert_path {
                 if tokio::fs::try_exists(p).await.unwrap_or(false) {
@@ -10242,6 +11500,29 @@ impl AgentBuilder {
             identity::Identity::new(machine_keypair, agent_keypair)
         };
 
+        // Additional certificates: previously saved ones first (skipping any
+        // that no longer bind this agent or have expired), then those passed
+        // to the builder, which must all be valid.
+        let extra_certs_path = match self.agent_cert_path.as_deref() {
+            Some(p) => storage::additional_certificates_path(p),
+            None => storage::default_additional_certificates_path().await?,
+        };
+        for cert in storage::load_additional_certificates_from(&extra_certs_path).await? {
+            if let Err(e) = identity.add_agent_certificate(cert) {
+                tracing::warn!("dropping saved additional agent certificate: {e}");
+            }
+        }
+        if !self.additional_certificates.is_empty() {
+            for cert in self.additional_certificates {
+                identity.add_agent_certificate(cert)?;
+            }
+            storage::save_additional_certificates_to(
+                identity.additional_certificates(),
+                &extra_certs_path,
+            )
+            .await?;
+        }
+
         // Configure the bootstrap peer cache. The cache *instance* is owned
         // by the ant-quic endpoint (one cache per node, shared by transport
         // reconnection, x0x join phases and presence enrichment) — x0x only
@@ -10274,17 +11555,13 @@ impl AgentBuilder {
                 identity.machine_keypair().public_key().as_bytes(),
             )
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
-                    "invalid machine public key: {e}"
-                )))
+                error::IdentityError::Crypto(format!("invalid machine public key: {e}"))
             })?;
             let sk = ant_quic::MlDsaSecretKey::from_bytes(
                 identity.machine_keypair().secret_key().as_bytes(),
             )
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
-                    "invalid machine secret key: {e}"
-                )))
+                error::IdentityError::Crypto(format!("invalid machine secret key: {e}"))
             })?;
             Some((pk, sk))
         };
@@ -10342,10 +11619,7 @@ impl AgentBuilder {
             let node = network::NetworkNode::new(config, bootstrap_cache_config, machine_keypair)
                 .await
                 .map_err(|e| {
-                    error::IdentityError::Storage(std::io::Error::other(format!(
-                        "network initialization failed: {}",
-                        e
-                    )))
+                    error::IdentityError::Network(format!("network initialization failed: {}", e))
                 })?;
 
             // Verify identity unification: ant-quic PeerId must equal MachineId
@@ -10418,11 +11692,14 @@ impl AgentBuilder {
             )
             .await
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
-                    "gossip runtime initialization failed: {}",
-                    e
-                )))
+                error::IdentityError::Gossip(format!("gossip runtime initialization failed: {}", e))
             })?;
+            if let Some(version) = self.wire_version {
+                runtime
+                    .pubsub()
+                    .set_wire_version(version)
+                    .map_err(|e| error::IdentityError::Gossip(e.to_string()))?;
+            }
             Some(std::sync::Arc::new(runtime))
         } else {
             None
@@ -10462,10 +11739,7 @@ impl AgentBuilder {
                 bootstrap_cache.clone(),
             )
             .map_err(|e| {
-                error::IdentityError::Storage(std::io::Error::other(format!(
-                    "presence initialization failed: {}",
-                    e
-                )))
+                error::IdentityError::Gossip(format!("presence initialization failed: {}", e))
             })?;
             let pw_arc = std::sync::Arc::new(pw);
             // Wire presence into gossip runtime for Bulk dispatch
@@ -10534,6 +11808,7 @@ impl AgentBuilder {
             observed_prefix_enabled,
             presence,
             user_identity_consented: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            status: std::sync::Arc::new(std::sync::RwLock::new(None)),
             capability_store: std::sync::Arc::new(dm_capability::CapabilityStore::new()),
             dm_capabilities_tx: std::sync::Arc::new({
                 let (tx, _rx) = tokio::sync::watch::channel(dm::DmCapabilities::pending());
@@ -10545,6 +11820,9 @@ impl AgentBuilder {
             dm_inbox_service: tokio::sync::Mutex::new(None),
             revocation_set,
             identity_dir: self.identity_dir,
+            task_list_storage: self.task_list_storage_dir.map(crdt::TaskListStorage::new),
+            task_list_persistence: std::sync::Arc::default(),
+            persistence_policy: tokio::sync::watch::channel(crdt::PersistencePolicy::default()).0,
             shutdown_token: tokio_util::sync::CancellationToken::new(),
             tracked_tasks: std::sync::Arc::new(std::sync::Mutex::new(TrackedTasks {
                 closed: false,
@@ -10556,6 +11834,7 @@ impl AgentBuilder {
             connect_policy: std::sync::Arc::new(std::sync::RwLock::new(std::sync::Arc::new(
                 connect::ConnectPolicy::default(),
             ))),
+            task_list_registry: std::sync::Mutex::new(std::collections::HashMap::new()),
         })
     }
 }
@@ -10587,6 +11866,45 @@ pub struct TaskListHandle {
     /// (authenticated operation provenance). Threaded from the agent's
     /// keypair at construction; the secret key never leaves the handle.
     signing: std::sync::Arc<crate::gossip::SigningContext>,
+    /// Resolves task creators to their human owners for [`TaskSnapshot::owner`].
+    owners: TaskOwners,
+}
+
+/// Maps the agent that created a task to the user behind it.
+///
+/// Owners are resolved when tasks are listed rather than stored in the
+/// replicated task, so a user id only travels as far as the creating agent
+/// chose to disclose it.
+#[derive(Clone)]
+struct TaskOwners {
+    /// This agent and its user, when it has a user layer.
+    local: Option<(identity::AgentId, identity::UserId)>,
+    /// Other agents, whose user is the one attested by the certificate in
+    /// their verified identity announcement (present only with consent).
+    discovered: std::sync::Arc<
+        tokio::sync::RwLock<std::collections::HashMap<identity::AgentId, DiscoveredAgent>>,
+    >,
+}
+
+impl TaskOwners {
+    fn resolve(
+        &self,
+        creator: &identity::AgentId,
+        discovered: &std::collections::HashMap<identity::AgentId, DiscoveredAgent>,
+    ) -> Option<identity::UserId> {
+        match self.local {
+            Some((agent_id, user_id)) if agent_id == *creator => Some(user_id),
+            _ => discovered.get(creator).and_then(|agent| agent.user_id),
+        }
+    }
+}
+
+/// The error [`TaskListHandle`] returns for a task id not in the list.
+fn task_not_found(task_id: crdt::TaskId) -> error::IdentityError {
+    error::IdentityError::Storage(std::io::Error::new(
+        std::io::ErrorKind::NotFound,
+        format!("task {task_id} not found"),
+    ))
 }
 
 impl std::fmt::Debug for TaskListHandle {
@@ -10614,6 +11932,26 @@ impl TaskListHandle {
         self.sync.cancel_sync();
     }
 
+    /// Watch for changes merged from remote peers; see
+    /// [`crdt::TaskListSync::subscribe_changes`].
+    #[must_use]
+    pub fn subscribe_changes(&self) -> tokio::sync::watch::Receiver<u64> {
+        self.sync.subscribe_changes()
+    }
+
+    /// The list's canonical id, derived from its topic (see
+    /// [`crdt::TaskListId::from_topic`]).
+    #[must_use]
+    pub fn id(&self) -> crdt::TaskListId {
+        crdt::TaskListId::from_topic(self.sync.topic())
+    }
+
+    /// The gossip topic this list syncs over.
+    #[must_use]
+    pub fn topic(&self) -> &str {
+        self.sync.topic()
+    }
+
     /// Generate a fresh per-replica epoch at handle construction.
     ///
     /// Uses a CSPRNG incarnation nonce (64-bit random from `OsRng`) so a
@@ -10728,8 +12066,42 @@ impl TaskListHandle {
         title: String,
         description: String,
     ) -> error::Result<(crdt::TaskId, u64)> {
-        let (task_id, version, delta) = {
+        match self.add_task_fenced(title, description, None).await? {
+            TaskAddOutcome::Added { task_id, fence } => Ok((task_id, fence.revision)),
+            // Unreachable without an expected token; report it rather than panic.
+            TaskAddOutcome::StaleLocalVersion { .. } => Err(error::IdentityError::Storage(
+                std::io::Error::other("add_task rejected without a fence token"),
+            )),
+        }
+    }
+
+    /// Add a new task, optionally guarded by an expected fence token.
+    ///
+    /// The guard has the same local-replica semantics as
+    /// [`TaskListHandle::claim_task_versioned`]: when `expected` does not
+    /// match this replica's current token, nothing is added and
+    /// [`TaskAddOutcome::StaleLocalVersion`] is returned. It is advisory over
+    /// eventually-consistent state and never a distributed conflict.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the task cannot be added.
+    pub async fn add_task_fenced(
+        &self,
+        title: String,
+        description: String,
+        expected: Option<FenceToken>,
+    ) -> error::Result<TaskAddOutcome> {
+        let (task_id, fence, delta) = {
             let mut list = self.sync.write().await;
+            if let Some(expected) = expected {
+                let current = list.current_version();
+                if expected.epoch != self.replica_epoch || expected.revision != current {
+                    return Ok(TaskAddOutcome::StaleLocalVersion {
+                        current: self.current_fence(current),
+                    });
+                }
+            }
             let seq = list.next_seq();
             let task_id = crdt::TaskId::new(&title, &self.agent_id, seq);
             let metadata = crdt::TaskMetadata::new(title, description, 128, self.agent_id, seq);
@@ -10744,13 +12116,13 @@ impl TaskListHandle {
             let tag = (self.peer_id, seq);
             let version = list.current_version();
             let delta = crdt::TaskListDelta::for_add(task_id, task, tag, version);
-            (task_id, version, delta)
+            (task_id, self.current_fence(version), delta)
         };
         // Best-effort replication: local mutation succeeded regardless
         if let Err(e) = self.sync.publish_delta(self.peer_id, delta).await {
             tracing::warn!("failed to publish add_task delta: {}", e);
         }
-        Ok((task_id, version))
+        Ok(TaskAddOutcome::Added { task_id, fence })
     }
 
     /// Claim a task in the list.
@@ -10907,17 +12279,164 @@ impl TaskListHandle {
                 current_winner: winner,
             };
             let full_task = task.clone();
-            let version = list.current_version();
-            (
-                self.current_fence(version),
-                crdt::TaskListDelta::for_state_change(task_id, full_task, version),
-                advisory,
-            )
+            let version = list.current_version();
+            (
+                self.current_fence(version),
+                crdt::TaskListDelta::for_state_change(task_id, full_task, version),
+                advisory,
+            )
+        };
+        if let Err(e) = self.sync.publish_delta(self.peer_id, delta).await {
+            tracing::warn!("failed to publish complete_task delta: {}", e);
+        }
+        Ok(TaskMutationOutcome::Committed { fence, advisory })
+    }
+
+    /// Reopen a completed task so it can be claimed again.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - ID of the task to reopen
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the task cannot be reopened.
+    pub async fn reopen_task(&self, task_id: crdt::TaskId) -> error::Result<()> {
+        self.reopen_task_versioned(task_id, None).await.map(|_| ())
+    }
+
+    /// Reopen a task, optionally guarded by an expected list version.
+    ///
+    /// Semantics mirror [`TaskListHandle::complete_task_versioned`]. The
+    /// returned [`AdvisoryOwnership`] reports the local `reopen_record()`
+    /// snapshot at commit time: a concurrent completion stamped after this
+    /// reopen still wins once it merges.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the task does not exist or is not done.
+    pub async fn reopen_task_versioned(
+        &self,
+        task_id: crdt::TaskId,
+        expected: Option<FenceToken>,
+    ) -> error::Result<TaskMutationOutcome> {
+        let (fence, delta, advisory) = {
+            let mut list = self.sync.write().await;
+            if let Some(expected) = expected {
+                let current = list.current_version();
+                if expected.epoch != self.replica_epoch || expected.revision != current {
+                    return Ok(TaskMutationOutcome::StaleLocalVersion {
+                        current: self.current_fence(current),
+                    });
+                }
+            }
+            let seq = list.next_seq();
+            list.reopen_task(&task_id, self.agent_id, self.peer_id, seq, &self.signing)
+                .map_err(|e| {
+                    error::IdentityError::Storage(std::io::Error::other(format!(
+                        "reopen_task failed: {}",
+                        e
+                    )))
+                })?;
+            let task = list.get_task(&task_id).ok_or_else(|| {
+                error::IdentityError::Storage(std::io::Error::other(
+                    "task disappeared after reopen",
+                ))
+            })?;
+            let winner = task.reopen_record();
+            let advisory = AdvisoryOwnership {
+                agent: self.agent_id,
+                locally_winning: winner.is_some_and(|(a, _)| a == self.agent_id),
+                current_winner: winner,
+            };
+            let full_task = task.clone();
+            let version = list.current_version();
+            (
+                self.current_fence(version),
+                crdt::TaskListDelta::for_state_change(task_id, full_task, version),
+                advisory,
+            )
+        };
+        if let Err(e) = self.sync.publish_delta(self.peer_id, delta).await {
+            tracing::warn!("failed to publish reopen_task delta: {}", e);
+        }
+        Ok(TaskMutationOutcome::Committed { fence, advisory })
+    }
+
+    /// Set a task's priority (0-255).
+    ///
+    /// The priority is a last-writer-wins register, so concurrent updates
+    /// from other replicas resolve deterministically on merge.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`std::io::ErrorKind::NotFound`] if the task is
+    /// not in the list.
+    pub async fn set_priority(&self, task_id: crdt::TaskId, priority: u8) -> error::Result<()> {
+        self.update_task_field(task_id, "set_priority", |task, peer_id| {
+            task.update_priority(priority, peer_id);
+        })
+        .await
+    }
+
+    /// Assign a task to an agent, or unassign it with `None`.
+    ///
+    /// Assignment is a last-writer-wins register and is independent of
+    /// claiming: it records intent and grants no ownership.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`std::io::ErrorKind::NotFound`] if the task is
+    /// not in the list.
+    pub async fn assign(
+        &self,
+        task_id: crdt::TaskId,
+        assignee: Option<identity::AgentId>,
+    ) -> error::Result<()> {
+        self.update_task_field(task_id, "assign", |task, peer_id| {
+            task.update_assignee(assignee, peer_id);
+        })
+        .await
+    }
+
+    /// Apply an LWW register update to one task and publish the full task
+    /// as a state-change delta.
+    async fn update_task_field(
+        &self,
+        task_id: crdt::TaskId,
+        operation: &str,
+        update: impl FnOnce(&mut crdt::TaskItem, saorsa_gossip_types::PeerId),
+    ) -> error::Result<()> {
+        let delta = {
+            let mut list = self.sync.write().await;
+            let before = list.state_fingerprint();
+            let Some(task) = list.get_task_mut(&task_id) else {
+                return Err(task_not_found(task_id));
+            };
+            update(task, self.peer_id);
+            let full_task = task.clone();
+            list.commit_revision_if_changed(before);
+            crdt::TaskListDelta::for_state_change(task_id, full_task, list.current_version())
         };
         if let Err(e) = self.sync.publish_delta(self.peer_id, delta).await {
-            tracing::warn!("failed to publish complete_task delta: {}", e);
+            tracing::warn!("failed to publish {operation} delta: {}", e);
         }
-        Ok(TaskMutationOutcome::Committed { fence, advisory })
+        Ok(())
+    }
+
+    /// Audit history of a task: who added, claimed and completed it, and
+    /// when, oldest first.
+    ///
+    /// Derived from the replicated, signed operation attestations that sync
+    /// with the task (see [`crdt::TaskItem::history`] for the storage cost),
+    /// so it reflects every operation this replica has merged.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the task is not known to this replica.
+    pub async fn history(&self, task_id: crdt::TaskId) -> Option<Vec<crdt::TaskEvent>> {
+        let list = self.sync.read().await;
+        list.get_task(&task_id).map(crdt::TaskItem::history)
     }
 
     /// List all tasks in their current order.
@@ -10946,6 +12465,7 @@ impl TaskListHandle {
     ///
     /// Returns an error if the task list cannot be read.
     pub async fn list_tasks_with_version(&self) -> error::Result<(Vec<TaskSnapshot>, FenceToken)> {
+        let discovered = self.owners.discovered.read().await;
         let list = self.sync.read().await;
         let version = list.current_version();
         let tasks = list.tasks_ordered();
@@ -10960,18 +12480,51 @@ impl TaskListHandle {
                     description: task.description().to_string(),
                     state: task.current_state(),
                     assignee: task.assignee().copied(),
-                    owner: None,
+                    owner: self.owners.resolve(task.created_by(), &discovered),
                     priority: task.priority(),
                     claimed_by: claim.map(|(agent, _)| agent),
                     claimed_at: claim.map(|(_, ts)| ts),
                     completed_by: completion.map(|(agent, _)| agent),
                     completed_at: completion.map(|(_, ts)| ts),
+                    history: task.state_history(),
                 }
             })
             .collect();
         Ok((snapshots, self.current_fence(version)))
     }
 
+    /// Check the local replica for inconsistencies between membership, task
+    /// data and ordering. See [`crdt::TaskList::validate`].
+    pub async fn validate(&self) -> std::result::Result<(), Vec<crdt::Inconsistency>> {
+        self.sync.read().await.validate()
+    }
+
+    /// The list as a read-only JSON snapshot for dashboards and exports;
+    /// see [`crdt::TaskList::to_json_snapshot`]. The snapshot cannot be
+    /// imported back.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snapshot cannot be serialized.
+    pub async fn export_json(&self) -> error::Result<String> {
+        self.sync.read().await.to_json_snapshot().map_err(|e| {
+            error::IdentityError::Serialization(format!("task list export failed: {e}"))
+        })
+    }
+
+    /// Delta traffic counters for this list; see
+    /// [`crdt::TaskListSync::stats`].
+    #[must_use]
+    pub fn sync_stats(&self) -> crdt::SyncStats {
+        self.sync.stats()
+    }
+
+    /// The sync options this list was opened with.
+    #[must_use]
+    pub fn sync_options(&self) -> crdt::SyncOptions {
+        self.sync.options()
+    }
+
     /// The task list's current version counter.
     ///
     /// Incremented on every local or merged mutation. Useful as the
@@ -10984,6 +12537,33 @@ impl TaskListHandle {
         self.current_fence(revision)
     }
 
+    /// Remove a task from the list.
+    ///
+    /// The removal is an OR-Set tombstone and is published as a delta, so
+    /// other replicas drop the task when they merge it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`std::io::ErrorKind::NotFound`] if the task is
+    /// not in the list, or another error if the removal fails.
+    pub async fn remove_task(&self, task_id: crdt::TaskId) -> error::Result<()> {
+        let delta = {
+            let mut list = self.sync.write().await;
+            list.remove_task(&task_id).map_err(|e| match e {
+                crdt::CrdtError::TaskNotFound(id) => task_not_found(id),
+                e => error::IdentityError::Storage(std::io::Error::other(format!(
+                    "remove_task failed: {}",
+                    e
+                ))),
+            })?;
+            crdt::TaskListDelta::for_remove(task_id, list.current_version())
+        };
+        if let Err(e) = self.sync.publish_delta(self.peer_id, delta).await {
+            tracing::warn!("failed to publish remove_task delta: {}", e);
+        }
+        Ok(())
+    }
+
     /// Reorder tasks in the list.
     ///
     /// # Arguments
@@ -11160,9 +12740,9 @@ impl Agent {
         persist_path: Option<std::path::PathBuf>,
     ) -> error::Result<(std::sync::Arc<kv::KvStoreSync>, saorsa_gossip_types::PeerId)> {
         let runtime = self.gossip_runtime.as_ref().ok_or_else(|| {
-            error::IdentityError::Storage(std::io::Error::other(
-                "gossip runtime not initialized - configure agent with network first",
-            ))
+            error::IdentityError::NotInitialized(
+                "gossip runtime not initialized - configure agent with network first".to_string(),
+            )
         })?;
         let peer_id = runtime.peer_id();
         let sync = kv::KvStoreSync::new(
@@ -11327,7 +12907,8 @@ pub struct KvStoreHandle {
 }
 
 /// Serialized owner keypair for checkpoint signing (held only by the owner's
-/// handle). The bytes are reconstructed into keys at sign time.
+/// handle). The bytes are reconstructed into keys at sign time and the
+/// secret bytes are zeroed on drop.
 #[derive(Clone)]
 pub struct OwnerSigningMaterial {
     /// ML-DSA-65 public key bytes.
@@ -11336,6 +12917,14 @@ pub struct OwnerSigningMaterial {
     pub secret_key_bytes: Vec<u8>,
 }
 
+impl Drop for OwnerSigningMaterial {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.secret_key_bytes);
+    }
+}
+
+impl zeroize::ZeroizeOnDrop for OwnerSigningMaterial {}
+
 impl std::fmt::Debug for KvStoreHandle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("KvStoreHandle")
@@ -11424,7 +13013,7 @@ impl KvStoreHandle {
             .map(|d| d.as_millis() as u64)
             .unwrap_or(0);
         let pubkey = ant_quic::MlDsaPublicKey::from_bytes(&signing.public_key_bytes).ok()?;
-        let seckey = ant_quic::MlDsaSecretKey::from_bytes(&signing.secret_key_bytes).ok()?;
+        let mut seckey = ant_quic::MlDsaSecretKey::from_bytes(&signing.secret_key_bytes).ok()?;
         let cp = kv::store::make_owner_checkpoint(kv::store::OwnerCheckpointParams {
             topic,
             store_id: &id,
@@ -11435,8 +13024,9 @@ impl KvStoreHandle {
             checkpoint_seq: seq,
             content_root: root,
             timestamp: ts,
-        })
-        .ok()?;
+        });
+        zeroize::Zeroize::zeroize(&mut seckey);
+        let cp = cp.ok()?;
         store.latest_checkpoint = Some(cp.clone());
         store.highest_checkpoint_seq = seq;
         Some(cp)
@@ -11792,7 +13382,10 @@ pub struct TaskSnapshot {
     pub state: crdt::CheckboxState,
     /// Agent assigned to this task (if any).
     pub assignee: Option<identity::AgentId>,
-    /// Human owner of the agent that created this task (if known).
+    /// Human owner of the agent that created this task (if known): this
+    /// agent's user for its own tasks, otherwise the user attested in the
+    /// creator's verified identity announcement. `None` for agents without a
+    /// user layer or that have not disclosed one.
     pub owner: Option<identity::UserId>,
     /// Task priority (0-255, higher = more important).
     pub priority: u8,
@@ -11805,6 +13398,9 @@ pub struct TaskSnapshot {
     pub completed_by: Option<identity::AgentId>,
     /// Unix-millisecond timestamp of the winning completion, if done.
     pub completed_at: Option<u64>,
+    /// Every claim and completion this replica has merged, oldest first
+    /// (see [`crdt::TaskItem::state_history`]). Empty for unclaimed tasks.
+    pub history: Vec<crdt::CheckboxState>,
 }
 
 /// Outcome of a task-list mutation (claim or complete).
@@ -11844,6 +13440,24 @@ pub enum TaskMutationOutcome {
     },
 }
 
+/// Result of a fenced [`TaskListHandle::add_task_fenced`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskAddOutcome {
+    /// The task was added locally; `fence` is the new local token.
+    Added {
+        /// The new task's id.
+        task_id: crdt::TaskId,
+        /// The task list's fence token after the add.
+        fence: FenceToken,
+    },
+    /// The caller's fence token did not match this replica's current token;
+    /// nothing was added.
+    StaleLocalVersion {
+        /// The task list's current (unchanged) local fence token.
+        current: FenceToken,
+    },
+}
+
 /// Opaque local-replica fencing token.
 ///
 /// Combines a per-replica `epoch` (regenerated when the handle is rebuilt —
@@ -11902,7 +13516,8 @@ impl FenceToken {
     }
 }
 
-/// Advisory ownership snapshot reported after a local claim/complete commit.
+/// Advisory ownership snapshot reported after a local claim/complete/reopen
+/// commit.
 ///
 /// "Advisory" means this reflects the local replica's CRDT resolution at the
 /// instant of commit. It is **not** a distributed lock and **not** a promise
@@ -12188,6 +13803,34 @@ mod tests {
         s.parse().expect("valid SocketAddr literal in test")
     }
 
+    #[tokio::test]
+    async fn connect_bounded_caps_attempts_in_flight() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let addrs: Vec<std::net::SocketAddr> = (0..24u16)
+            .map(|port| sa(&format!("10.0.0.1:{}", 9000 + port)))
+            .collect();
+
+        let results = connect_bounded(addrs.iter().copied(), 2, |addr| {
+            let in_flight = Arc::clone(&in_flight);
+            let peak = Arc::clone(&peak);
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                addr
+            }
+        })
+        .await;
+
+        assert_eq!(results, addrs, "every attempt runs, in input order");
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+
     #[test]
     fn discovery_rebroadcast_is_one_shot_per_announcement_key() {
         let now = std::time::Instant::now();
@@ -12599,6 +14242,194 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn unsubscribe_releases_topic_and_requires_runtime() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let offline = Agent::builder()
+            .with_machine_key(dir.path().join("m1.key"))
+            .with_agent_key_path(dir.path().join("a1.key"))
+            .with_contact_store_path(dir.path().join("c1.json"))
+            .build()
+            .await
+            .expect("identity-only agent");
+        let err = offline.unsubscribe("topic").await.unwrap_err();
+        assert!(err.to_string().contains("gossip runtime not initialized"));
+        offline.shutdown().await;
+
+        let agent = Agent::builder()
+            .with_machine_key(dir.path().join("m2.key"))
+            .with_agent_key_path(dir.path().join("a2.key"))
+            .with_contact_store_path(dir.path().join("c2.json"))
+            .with_peer_cache_dir(dir.path().join("p2"))
+            .with_network_config(loopback_network_config())
+            .build()
+            .await
+            .expect("agent");
+        let pubsub = agent.gossip_runtime.as_ref().expect("runtime").pubsub();
+        let _sub = agent
+            .subscribe("unsubscribe-test")
+            .await
+            .expect("subscribe");
+        assert_eq!(pubsub.subscription_count().await, 1);
+
+        agent
+            .unsubscribe("unsubscribe-test")
+            .await
+            .expect("unsubscribe");
+        assert_eq!(pubsub.subscription_count().await, 0);
+        agent.unsubscribe("never-subscribed").await.expect("no-op");
+        agent.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn build_with_network_finishes_within_build_timeout() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let limit = std::time::Duration::from_secs(30);
+        let started = std::time::Instant::now();
+        let agent = Agent::builder()
+            .with_machine_key(dir.path().join("m.key"))
+            .with_agent_key_path(dir.path().join("a.key"))
+            .with_contact_store_path(dir.path().join("c.json"))
+            .with_peer_cache_disabled()
+            .with_network_config(loopback_network_config())
+            .with_build_timeout(limit)
+            .build()
+            .await
+            .expect("agent builds well inside the limit");
+        assert!(started.elapsed() < limit);
+        agent.shutdown().await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stalled_build_fails_with_build_timeout() {
+        let limit = std::time::Duration::from_secs(5);
+        let err = bounded_build(limit, async {
+            // Stand-in for a NetworkNode::new that never returns.
+            tokio::time::sleep(std::time::Duration::from_secs(3600)).await;
+            Ok(())
+        })
+        .await
+        .expect_err("stalled build must time out");
+        assert!(
+            matches!(err, error::IdentityError::BuildTimeout(d) if d == limit),
+            "unexpected error: {err:?}"
+        );
+
+        let ok = bounded_build(limit, async {
+            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+            Ok(7)
+        })
+        .await
+        .expect("build inside the limit");
+        assert_eq!(ok, 7);
+    }
+
+    #[tokio::test]
+    async fn with_max_connections_overrides_network_config() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let agent = Agent::builder()
+            .with_machine_key(dir.path().join("m.key"))
+            .with_agent_key_path(dir.path().join("a.key"))
+            .with_contact_store_path(dir.path().join("c.json"))
+            .with_peer_cache_dir(dir.path().join("p"))
+            .with_max_connections(7)
+            .with_network_config(loopback_network_config())
+            .build()
+            .await
+            .expect("agent");
+        let network = agent.network().expect("network enabled");
+        assert_eq!(network.config().max_connections, 7);
+        assert!(network.config().bootstrap_nodes.is_empty());
+        agent.shutdown().await;
+    }
+
+    #[test]
+    fn bootstrap_peer_overrides_replace_or_extend_defaults() {
+        let defaults = network::NetworkConfig::default().bootstrap_nodes;
+        assert!(!defaults.is_empty());
+        let custom: std::net::SocketAddr = "192.0.2.1:5483".parse().expect("addr");
+        let extra: std::net::SocketAddr = "192.0.2.2:5483".parse().expect("addr");
+
+        let mut replaced = Agent::builder().with_bootstrap_peers(vec![custom]);
+        replaced.apply_network_overrides();
+        let config = replaced.network_config.expect("networking enabled");
+        assert_eq!(config.bootstrap_nodes, vec![custom]);
+
+        let mut extended =
+            Agent::builder().with_additional_bootstrap_peers(vec![extra, defaults[0]]);
+        extended.apply_network_overrides();
+        let config = extended.network_config.expect("networking enabled");
+        let mut expected = defaults.clone();
+        expected.push(extra);
+        assert_eq!(config.bootstrap_nodes, expected);
+
+        // Replacement applies first regardless of call order.
+        let mut both = Agent::builder()
+            .with_additional_bootstrap_peers(vec![extra])
+            .with_bootstrap_peers(vec![custom]);
+        both.apply_network_overrides();
+        let config = both.network_config.expect("networking enabled");
+        assert_eq!(config.bootstrap_nodes, vec![custom, extra]);
+    }
+
+    #[test]
+    fn offline_clears_default_bootstrap_but_keeps_explicit_peers() {
+        let mut offline = Agent::builder().offline();
+        offline.apply_network_overrides();
+        assert!(offline.disable_peer_cache);
+        let config = offline.network_config.expect("networking enabled");
+        assert!(config.bootstrap_nodes.is_empty());
+        assert!(!config.port_mapping_enabled);
+        assert_eq!(config.bind_addr, Some("127.0.0.1:0".parse().expect("addr")));
+
+        let lan: std::net::SocketAddr = "192.0.2.10:5483".parse().expect("addr");
+        let mut private = Agent::builder()
+            .offline()
+            .with_network_config(network::NetworkConfig {
+                bind_addr: Some("0.0.0.0:0".parse().expect("addr")),
+                ..network::NetworkConfig::default()
+            })
+            .with_additional_bootstrap_peers(vec![lan]);
+        private.apply_network_overrides();
+        let config = private.network_config.expect("networking enabled");
+        assert_eq!(config.bootstrap_nodes, vec![lan]);
+        assert_eq!(config.bind_addr, Some("0.0.0.0:0".parse().expect("addr")));
+    }
+
+    #[tokio::test]
+    async fn offline_agents_join_instantly_and_connect_by_hand() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let offline_agent = |name: &str| {
+            Agent::builder()
+                .with_machine_key(dir.path().join(format!("{name}-machine.key")))
+                .with_agent_key_path(dir.path().join(format!("{name}-agent.key")))
+                .with_contact_store_path(dir.path().join(format!("{name}-contacts.json")))
+                .offline()
+                .build()
+        };
+        let alice = offline_agent("alice").await.expect("alice");
+        let bob = offline_agent("bob").await.expect("bob");
+
+        tokio::time::timeout(std::time::Duration::from_secs(5), alice.join_network())
+            .await
+            .expect("join_network returns without bootstrap attempts")
+            .expect("join_network");
+
+        let bob_network = bob.network().expect("offline agents are networked");
+        let bob_addr = bob_network.bound_addr().await.expect("bound address");
+        let bob_peer = alice
+            .network()
+            .expect("offline agents are networked")
+            .connect_addr(bob_addr)
+            .await
+            .expect("manual connect");
+        assert_eq!(bob_peer, bob_network.peer_id());
+        assert!(alice.subscribe("offline-topic").await.is_ok());
+
+        alice.shutdown().await;
+        bob.shutdown().await;
+    }
+
     #[tokio::test]
     async fn observed_prefix_gate_defaults_off_and_follows_network_config() {
         // Issue #120: the Agent-side gate must track the NetworkConfig flag —
@@ -12699,59 +14530,337 @@ mod tests {
         }
     }
 
-    fn normalize_loopback_addr(addr: std::net::SocketAddr) -> std::net::SocketAddr {
-        if addr.ip().is_unspecified() {
-            std::net::SocketAddr::new(
-                std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST),
-                addr.port(),
-            )
-        } else {
-            addr
-        }
+    fn normalize_loopback_addr(addr: std::net::SocketAddr) -> std::net::SocketAddr {
+        if addr.ip().is_unspecified() {
+            std::net::SocketAddr::new(
+                std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST),
+                addr.port(),
+            )
+        } else {
+            addr
+        }
+    }
+
+    #[tokio::test]
+    async fn shutdown_aborts_identity_heartbeat_task() {
+        struct DropFlag(std::sync::Arc<std::sync::atomic::AtomicBool>);
+
+        impl Drop for DropFlag {
+            fn drop(&mut self) {
+                self.0.store(true, std::sync::atomic::Ordering::Release);
+            }
+        }
+
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let agent = Agent::builder()
+            .with_machine_key(dir.path().join("machine.key"))
+            .with_agent_key_path(dir.path().join("agent.key"))
+            .with_contact_store_path(dir.path().join("contacts.json"))
+            .build()
+            .await
+            .expect("agent");
+
+        let dropped = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let dropped_for_task = std::sync::Arc::clone(&dropped);
+        let (started_tx, started_rx) = tokio::sync::oneshot::channel();
+        let handle = tokio::spawn(async move {
+            let _drop_flag = DropFlag(dropped_for_task);
+            let _ = started_tx.send(());
+            std::future::pending::<()>().await;
+        });
+        started_rx.await.expect("heartbeat task started");
+
+        *agent.heartbeat_handle.lock().await = Some(handle);
+        assert!(agent.heartbeat_handle.lock().await.is_some());
+
+        agent.shutdown().await;
+        assert!(agent.heartbeat_handle.lock().await.is_none());
+        assert!(dropped.load(std::sync::atomic::Ordering::Acquire));
+    }
+
+    /// Issue #126 / WS1.5: the delta-merge + state-request subscription loops
+    /// spawned by `create_kv_store` / `create_task_list` must be routed through
+    /// `spawn_tracked` so `Agent::shutdown()` drains and aborts them. Previously
+    /// they detached via bare `tokio::spawn` and only ended when the gossip
+    /// runtime later dropped its topic sender.
+    #[tokio::test]
+    async fn task_list_by_id_finds_live_lists_only() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let agent = Agent::builder()
+            .with_machine_key(dir.path().join("machine.key"))
+            .with_agent_key_path(dir.path().join("agent.key"))
+            .with_contact_store_path(dir.path().join("contacts.json"))
+            .with_peer_cache_disabled()
+            .with_network_config(loopback_network_config())
+            .build()
+            .await
+            .expect("agent");
+
+        let handle = agent
+            .create_task_list("by-id", "by-id-topic")
+            .await
+            .expect("create task list");
+        let id = crdt::TaskListId::from_topic("by-id-topic");
+        assert_eq!(handle.id(), id);
+        handle
+            .add_task("Find me".to_string(), String::new())
+            .await
+            .expect("add task");
+
+        let found = agent.task_list_by_id(&id).expect("registered list");
+        assert_eq!(found.topic(), "by-id-topic");
+        assert_eq!(found.version().await, handle.version().await);
+        assert_eq!(found.list_tasks().await.expect("tasks").len(), 1);
+        assert!(agent
+            .task_list_by_id(&crdt::TaskListId::from_topic("other-topic"))
+            .is_none());
+
+        handle.cancel_sync();
+        assert!(agent.task_list_by_id(&id).is_none());
+        agent.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn persisted_task_lists_reads_the_configured_directory() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let storage_dir = dir.path().join("task_lists");
+        let build = |storage: bool| {
+            let mut builder = Agent::builder()
+                .with_machine_key(dir.path().join("machine.key"))
+                .with_agent_key_path(dir.path().join("agent.key"))
+                .with_contact_store_path(dir.path().join("contacts.json"))
+                .with_peer_cache_disabled();
+            if storage {
+                builder = builder.with_task_list_storage_dir(&storage_dir);
+            }
+            builder.build()
+        };
+
+        let unconfigured = build(false).await.expect("agent");
+        assert!(unconfigured.task_list_storage().is_none());
+        assert!(unconfigured
+            .persisted_task_lists()
+            .await
+            .expect("no storage")
+            .is_empty());
+
+        let agent = build(true).await.expect("agent");
+        assert!(agent
+            .persisted_task_lists()
+            .await
+            .expect("missing dir")
+            .is_empty());
+        let id = crdt::TaskListId::from_topic("persisted-topic");
+        let list = crdt::TaskList::new(
+            id,
+            "Saved".to_string(),
+            saorsa_gossip_types::PeerId::new([1; 32]),
+        );
+        let storage = agent.task_list_storage().expect("configured");
+        storage.save_task_list(&id, &list).await.expect("save");
+        std::fs::write(storage_dir.join("notes.bin"), b"not a list").expect("stray file");
+
+        assert_eq!(agent.persisted_task_lists().await.expect("list"), vec![id]);
+        assert!(agent.task_list_by_id(&id).is_none(), "not rejoined");
     }
 
     #[tokio::test]
-    async fn shutdown_aborts_identity_heartbeat_task() {
-        struct DropFlag(std::sync::Arc<std::sync::atomic::AtomicBool>);
+    async fn task_list_with_storage_is_restored_on_reopen() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let build = || {
+            Agent::builder()
+                .with_machine_key(dir.path().join("machine.key"))
+                .with_agent_key_path(dir.path().join("agent.key"))
+                .with_contact_store_path(dir.path().join("contacts.json"))
+                .with_peer_cache_disabled()
+                .with_network_config(loopback_network_config())
+                .with_task_list_storage_dir(dir.path().join("task_lists"))
+                .build()
+        };
 
-        impl Drop for DropFlag {
-            fn drop(&mut self) {
-                self.0.store(true, std::sync::atomic::Ordering::Release);
+        let agent = build().await.expect("agent");
+        let list = agent
+            .create_task_list("Saved", "saved-list-topic")
+            .await
+            .expect("create task list");
+        let task_id = list
+            .add_task("Survive restart".to_string(), String::new())
+            .await
+            .expect("add task");
+        let id = list.id();
+        agent.shutdown().await;
+        let stats = agent.task_list_persistence_stats();
+        assert!(stats.saves >= 1 && stats.last_save_at.is_some());
+        drop(list);
+        drop(agent);
+
+        let agent = build().await.expect("agent after restart");
+        assert_eq!(agent.persisted_task_lists().await.expect("list"), vec![id]);
+        assert_eq!(
+            agent
+                .persisted_task_list_topic(&id)
+                .await
+                .expect("topic record")
+                .as_deref(),
+            Some("saved-list-topic")
+        );
+        let list = agent
+            .create_task_list("Saved", "saved-list-topic")
+            .await
+            .expect("reopen task list");
+        assert_eq!(
+            agent.task_list_persistence_stats().unsaved_lists,
+            0,
+            "nothing failed to save"
+        );
+        let tasks = list.list_tasks().await.expect("tasks");
+        assert!(tasks.iter().any(|t| t.id == task_id), "task restored");
+
+        let checkpoint = agent.checkpoint_task_list(&list).await.expect("checkpoint");
+        assert_eq!((checkpoint.list_id, checkpoint.task_count), (id, 1));
+        assert!(checkpoint.saved_at > 0);
+        agent.shutdown().await;
+        drop(list);
+        drop(agent);
+
+        let unconfigured = Agent::builder()
+            .with_machine_key(dir.path().join("machine.key"))
+            .with_agent_key_path(dir.path().join("agent.key"))
+            .with_contact_store_path(dir.path().join("contacts.json"))
+            .with_peer_cache_disabled()
+            .with_network_config(loopback_network_config())
+            .build()
+            .await
+            .expect("agent without storage");
+        let list = unconfigured
+            .create_task_list("Saved", "saved-list-topic")
+            .await
+            .expect("open without storage");
+        match unconfigured.checkpoint_task_list(&list).await {
+            Err(error::IdentityError::Storage(e)) => {
+                assert_eq!(e.kind(), std::io::ErrorKind::Unsupported);
             }
+            other => panic!("expected Unsupported storage error, got {other:?}"),
         }
 
+        // An unreadable snapshot fails the open instead of being replaced
+        // by an empty list.
+        let path = dir.path().join("task_lists").join(format!("{id}.bin"));
+        std::fs::write(&path, b"corrupt").expect("corrupt snapshot");
+        let agent = build().await.expect("agent after corruption");
+        assert!(agent
+            .create_task_list("Saved", "saved-list-topic")
+            .await
+            .is_err());
+        assert_eq!(std::fs::read(&path).expect("kept"), b"corrupt");
+        agent.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn persistence_policy_is_validated_and_applied() {
         let dir = tempfile::tempdir().expect("tmpdir");
         let agent = Agent::builder()
             .with_machine_key(dir.path().join("machine.key"))
             .with_agent_key_path(dir.path().join("agent.key"))
             .with_contact_store_path(dir.path().join("contacts.json"))
+            .with_peer_cache_disabled()
             .build()
             .await
             .expect("agent");
+        assert_eq!(
+            agent.persistence_policy(),
+            crdt::PersistencePolicy::default()
+        );
 
-        let dropped = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
-        let dropped_for_task = std::sync::Arc::clone(&dropped);
-        let (started_tx, started_rx) = tokio::sync::oneshot::channel();
-        let handle = tokio::spawn(async move {
-            let _drop_flag = DropFlag(dropped_for_task);
-            let _ = started_tx.send(());
-            std::future::pending::<()>().await;
-        });
-        started_rx.await.expect("heartbeat task started");
+        let slower = crdt::PersistencePolicy {
+            save_interval_secs: 60,
+        };
+        agent.set_persistence_policy(slower).expect("in bounds");
+        assert_eq!(agent.persistence_policy(), slower);
 
-        *agent.heartbeat_handle.lock().await = Some(handle);
-        assert!(agent.heartbeat_handle.lock().await.is_some());
+        let invalid = crdt::PersistencePolicy {
+            save_interval_secs: 0,
+        };
+        assert!(matches!(
+            agent.set_persistence_policy(invalid),
+            Err(crdt::CrdtError::InvalidPersistencePolicy(_))
+        ));
+        assert_eq!(
+            agent.persistence_policy(),
+            slower,
+            "rejected policy not applied"
+        );
+    }
+
+    #[tokio::test]
+    async fn create_task_list_with_options_applies_and_validates_interval() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let agent = Agent::builder()
+            .with_machine_key(dir.path().join("machine.key"))
+            .with_agent_key_path(dir.path().join("agent.key"))
+            .with_contact_store_path(dir.path().join("contacts.json"))
+            .with_peer_cache_disabled()
+            .with_network_config(loopback_network_config())
+            .build()
+            .await
+            .expect("agent");
+
+        let default = agent
+            .create_task_list("default", "default-interval-topic")
+            .await
+            .expect("create task list");
+        assert_eq!(default.sync_options(), crdt::SyncOptions::default());
+
+        let options = crdt::SyncOptions {
+            sync_interval_secs: 5,
+        };
+        let fast = agent
+            .create_task_list_with_options("fast", "fast-interval-topic", options)
+            .await
+            .expect("create task list with options");
+        assert_eq!(fast.sync_options(), options);
+
+        let too_long = crdt::SyncOptions {
+            sync_interval_secs: crdt::MAX_SYNC_INTERVAL_SECS + 1,
+        };
+        assert!(agent
+            .create_task_list_with_options("slow", "slow-interval-topic", too_long)
+            .await
+            .is_err());
+        agent.shutdown().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn shutdown_cancels_task_list_sync_loops() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let agent = Agent::builder()
+            .with_machine_key(dir.path().join("machine.key"))
+            .with_agent_key_path(dir.path().join("agent.key"))
+            .with_contact_store_path(dir.path().join("contacts.json"))
+            .with_peer_cache_disabled()
+            .with_network_config(loopback_network_config())
+            .build()
+            .await
+            .expect("agent");
+
+        let handle = agent
+            .create_task_list("drain", "drain-topic")
+            .await
+            .expect("create task list");
+        handle
+            .add_task("Survive shutdown".to_string(), String::new())
+            .await
+            .expect("add task");
+        assert!(!handle.sync.is_cancelled());
 
         agent.shutdown().await;
-        assert!(agent.heartbeat_handle.lock().await.is_none());
-        assert!(dropped.load(std::sync::atomic::Ordering::Acquire));
+
+        // The loops were told to stop rather than left for the grace-window
+        // abort, and the local replica still holds every applied edit.
+        assert!(handle.sync.is_cancelled());
+        assert_eq!(handle.list_tasks().await.expect("tasks").len(), 1);
     }
 
-    /// Issue #126 / WS1.5: the delta-merge + state-request subscription loops
-    /// spawned by `create_kv_store` / `create_task_list` must be routed through
-    /// `spawn_tracked` so `Agent::shutdown()` drains and aborts them. Previously
-    /// they detached via bare `tokio::spawn` and only ended when the gossip
-    /// runtime later dropped its topic sender.
     #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
     async fn shutdown_drains_crdt_kv_sync_tasks() {
         let dir = tempfile::tempdir().expect("tmpdir");
@@ -13327,6 +15436,191 @@ mod tests {
         agent.shutdown().await;
     }
 
+    /// A stale fence token rejects an add without touching the list; the
+    /// current token then succeeds.
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn add_task_fenced_rejects_stale_token() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let agent = Agent::builder()
+            .with_machine_key(dir.path().join("machine.key"))
+            .with_agent_key_path(dir.path().join("agent.key"))
+            .with_contact_store_path(dir.path().join("contacts.json"))
+            .with_peer_cache_disabled()
+            .with_network_config(loopback_network_config())
+            .build()
+            .await
+            .expect("agent");
+        let handle = agent
+            .create_task_list("add-fence", "add-fence-topic")
+            .await
+            .expect("create task list");
+
+        let stale = handle.version().await;
+        handle
+            .add_task("first".to_string(), String::new())
+            .await
+            .expect("add task");
+        let outcome = handle
+            .add_task_fenced("second".to_string(), String::new(), Some(stale))
+            .await
+            .expect("fenced add");
+        let current = match outcome {
+            crate::TaskAddOutcome::StaleLocalVersion { current } => current,
+            other => panic!("expected StaleLocalVersion, got {other:?}"),
+        };
+        assert_eq!(current, handle.version().await);
+        assert_eq!(handle.list_tasks().await.expect("list tasks").len(), 1);
+
+        let outcome = handle
+            .add_task_fenced("second".to_string(), String::new(), Some(current))
+            .await
+            .expect("fenced add");
+        assert!(
+            matches!(outcome, crate::TaskAddOutcome::Added { fence, .. } if fence.revision > current.revision)
+        );
+        assert_eq!(handle.list_tasks().await.expect("list tasks").len(), 2);
+        agent.shutdown().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn set_priority_and_assign_update_snapshot_and_version() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let agent = Agent::builder()
+            .with_machine_key(dir.path().join("machine.key"))
+            .with_agent_key_path(dir.path().join("agent.key"))
+            .with_contact_store_path(dir.path().join("contacts.json"))
+            .with_peer_cache_disabled()
+            .with_network_config(loopback_network_config())
+            .build()
+            .await
+            .expect("agent");
+        let handle = agent
+            .create_task_list("task-fields", "task-fields-topic")
+            .await
+            .expect("create task list");
+        let task_id = handle
+            .add_task("triage".to_string(), String::new())
+            .await
+            .expect("add task");
+
+        let before = handle.version().await;
+        handle.set_priority(task_id, 7).await.expect("set priority");
+        let assignee = identity::AgentId([0x42; 32]);
+        handle
+            .assign(task_id, Some(assignee))
+            .await
+            .expect("assign");
+        assert!(handle.version().await.revision > before.revision);
+
+        let tasks = handle.list_tasks().await.expect("list tasks");
+        assert_eq!(tasks[0].priority, 7);
+        assert_eq!(tasks[0].assignee, Some(assignee));
+
+        handle.assign(task_id, None).await.expect("unassign");
+        let tasks = handle.list_tasks().await.expect("list tasks");
+        assert_eq!(tasks[0].assignee, None);
+
+        let unknown = crdt::TaskId::from_bytes([0xee; 32]);
+        let missing = handle.set_priority(unknown, 1).await.expect_err("unknown");
+        assert!(matches!(
+            missing,
+            error::IdentityError::Storage(ref e) if e.kind() == std::io::ErrorKind::NotFound
+        ));
+        agent.shutdown().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn list_tasks_reports_creator_owner() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let user_key = identity::UserKeypair::generate().expect("user key");
+        let owned = Agent::builder()
+            .with_machine_key(dir.path().join("m1.key"))
+            .with_agent_key_path(dir.path().join("a1.key"))
+            .with_agent_cert_path(dir.path().join("a1.cert"))
+            .with_contact_store_path(dir.path().join("c1.json"))
+            .with_user_key(user_key)
+            .with_peer_cache_disabled()
+            .with_network_config(loopback_network_config())
+            .build()
+            .await
+            .expect("agent with user");
+        let handle = owned
+            .create_task_list("owned", "owned-topic")
+            .await
+            .expect("create task list");
+        handle
+            .add_task("mine".to_string(), String::new())
+            .await
+            .expect("add task");
+        let tasks = handle.list_tasks().await.expect("list tasks");
+        assert_eq!(tasks[0].owner, owned.user_id());
+        assert!(tasks[0].owner.is_some());
+        owned.shutdown().await;
+
+        let anonymous = Agent::builder()
+            .with_machine_key(dir.path().join("m2.key"))
+            .with_agent_key_path(dir.path().join("a2.key"))
+            .with_contact_store_path(dir.path().join("c2.json"))
+            .with_peer_cache_disabled()
+            .with_network_config(loopback_network_config())
+            .build()
+            .await
+            .expect("agent without user");
+        let handle = anonymous
+            .create_task_list("anonymous", "anonymous-topic")
+            .await
+            .expect("create task list");
+        handle
+            .add_task("nobody's".to_string(), String::new())
+            .await
+            .expect("add task");
+        let tasks = handle.list_tasks().await.expect("list tasks");
+        assert_eq!(tasks[0].owner, None);
+        anonymous.shutdown().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn remove_task_drops_it_from_list_tasks() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        let agent = Agent::builder()
+            .with_machine_key(dir.path().join("machine.key"))
+            .with_agent_key_path(dir.path().join("agent.key"))
+            .with_contact_store_path(dir.path().join("contacts.json"))
+            .with_peer_cache_disabled()
+            .with_network_config(loopback_network_config())
+            .build()
+            .await
+            .expect("agent");
+        let handle = agent
+            .create_task_list("remove-task", "remove-task-topic")
+            .await
+            .expect("create task list");
+
+        let keep = handle
+            .add_task("keep".to_string(), String::new())
+            .await
+            .expect("add task");
+        let removed = handle
+            .add_task("drop".to_string(), String::new())
+            .await
+            .expect("add task");
+        handle.remove_task(removed).await.expect("remove task");
+
+        let tasks = handle.list_tasks().await.expect("list tasks");
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].id, keep);
+
+        let missing = handle
+            .remove_task(removed)
+            .await
+            .expect_err("already removed");
+        assert!(matches!(
+            missing,
+            error::IdentityError::Storage(ref e) if e.kind() == std::io::ErrorKind::NotFound
+        ));
+        agent.shutdown().await;
+    }
+
     /// P1 fence (restart-ABA): a fence token captured before an incarnation
     /// change (daemon restart) must be rejected even when submitted at the
     /// SAME revision afterwards — the per-replica epoch component differs, so
@@ -15117,6 +17411,21 @@ mod tests {
         assert!(agent.subscribe("test-topic").await.is_err());
     }
 
+    #[tokio::test]
+    async fn subscribe_without_runtime_reports_not_initialized() {
+        // Callers must be able to match on the missing runtime instead of
+        // string-sniffing a storage error.
+        let agent = Agent::new().await.unwrap();
+        let err = match agent.subscribe("test-topic").await {
+            Ok(_) => panic!("subscribe without a gossip runtime must fail"),
+            Err(e) => e,
+        };
+        assert!(
+            matches!(err, error::IdentityError::NotInitialized(_)),
+            "expected NotInitialized, got {err:?}"
+        );
+    }
+
     #[tokio::test]
     async fn identity_announcement_machine_signature_verifies() {
         let agent = Agent::builder()
@@ -15284,6 +17593,7 @@ mod tests {
                 cert_not_after: cert.not_after(),
                 agent_certificate: Some(cert.clone()),
                 agent_public_key: cert.agent_public_key().to_vec(),
+                status: None,
             },
         );
 
@@ -15528,6 +17838,48 @@ mod tests {
         assert_eq!(decoded_machine.addresses, machine.addresses);
     }
 
+    #[tokio::test]
+    async fn announcement_status_uses_v3_envelope_and_is_signed() {
+        let temp = tempfile::tempdir().unwrap();
+        let agent = Agent::builder()
+            .with_machine_key(temp.path().join("machine.key"))
+            .with_agent_key_path(temp.path().join("agent.key"))
+            .with_agent_cert_path(temp.path().join("agent.cert"))
+            .with_contact_store_path(temp.path().join("contacts.json"))
+            .build()
+            .await
+            .unwrap();
+
+        // No status: still the v2 envelope, so v2 peers can read it.
+        let plain = agent.build_identity_announcement(false, false).unwrap();
+        let bytes = serialize_identity_announcement(&plain).unwrap();
+        assert!(bytes.starts_with(IDENTITY_ANNOUNCEMENT_V2_MAGIC));
+        let decoded = deserialize_identity_announcement(&bytes).unwrap();
+        assert_eq!(decoded.status, None);
+        decoded.verify().unwrap();
+
+        agent.set_status("working on task X".to_string()).unwrap();
+        let with_status = agent.build_identity_announcement(false, false).unwrap();
+        let bytes = serialize_identity_announcement(&with_status).unwrap();
+        assert!(bytes.starts_with(IDENTITY_ANNOUNCEMENT_V3_MAGIC));
+        let decoded = deserialize_identity_announcement(&bytes).unwrap();
+        assert_eq!(decoded.status.as_deref(), Some("working on task X"));
+        decoded.verify().unwrap();
+
+        // The machine signature covers the status.
+        let mut tampered = decoded;
+        tampered.status = Some("idle".to_string());
+        assert!(tampered.verify().is_err());
+        tampered.status = None;
+        assert!(tampered.verify().is_err());
+
+        assert!(agent
+            .set_status("x".repeat(MAX_AGENT_STATUS_BYTES + 1))
+            .is_err());
+        agent.set_status(String::new()).unwrap();
+        assert_eq!(agent.status(), None);
+    }
+
     #[tokio::test]
     async fn deserialize_identity_announcement_rejects_trailing_bytes() {
         let temp = tempfile::tempdir().unwrap();
@@ -15913,6 +18265,7 @@ fn discovered_agent_fixture(
         cert_not_after: None,
         agent_certificate: None,
         agent_public_key: Vec::new(),
+        status: None,
     }
 }
 
@@ -15962,18 +18315,21 @@ fn signed_identity_announcement_fixture(
         reachable_via: Vec::new(),
         relay_candidates: Vec::new(),
         agent_public_key: Vec::new(),
+        status: None,
     }
 }
 
 #[cfg(test)]
 fn verified_identity_origin_message(sender: &identity::AgentKeypair) -> gossip::PubSubMessage {
     gossip::PubSubMessage {
+        id: [0; 32],
         topic: "identity-ingest-test".to_string(),
         payload: bytes::Bytes::new(),
         sender: Some(sender.agent_id()),
         sender_public_key: Some(sender.public_key().as_bytes().to_vec()),
         verified: true,
         trust_level: None,
+        on_behalf_of: None,
     }
 }
 
@@ -54,7 +54,9 @@ impl MlsCipher {
     ///
     /// # Security
     /// **CRITICAL**: Never reuse the same counter with the same key. Counter reuse
-    /// completely breaks ChaCha20-Poly1305 security.
+    /// completely breaks ChaCha20-Poly1305 security. For group keys, take the
+    /// counter from [`crate::mls::MlsGroup::next_nonce_counter`], which is
+    /// unique per epoch and refuses to run past its bound.
     pub fn encrypt(&self, plaintext: &[u8], aad: &[u8], counter: u64) -> Result<Vec<u8>> {
         // Derive nonce from base_nonce XOR counter
         let nonce = self.derive_nonce(counter);
@@ -26,6 +26,14 @@ pub enum MlsError {
         received: u64,
     },
 
+    /// The epoch's nonce counter space is used up; the group key must be
+    /// rotated (new epoch) before any further encryption.
+    #[error("nonce space exhausted for epoch {epoch}: rotate the group key")]
+    NonceExhausted {
+        /// Epoch whose counters are exhausted.
+        epoch: u64,
+    },
+
     /// Encryption operation failed.
     #[error("encryption error: {0}")]
     EncryptionError(String),
@@ -10,10 +10,68 @@
 //! `MlsEncrypted` groups use the TreeKEM plane; this wrapper remains for
 //! grandfathered groups (see ADR-0010 / ADR-0012).
 
+use crate::groups::kem_envelope::AgentKemKeypair;
 use crate::identity::{AgentCertificate, AgentId, UserId};
-use crate::mls::{agent_id_to_member_id, MlsError, Result};
+use crate::mls::{agent_id_to_member_id, MlsCipher, MlsError, MlsKeySchedule, MlsWelcome, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Hard cap on AEAD nonce counters issued per epoch by one [`MlsGroup`].
+///
+/// Far below the 2^64 counter space so the counter can never wrap into a
+/// reused nonce; hitting it returns [`MlsError::NonceExhausted`] and the
+/// caller must rotate the key (commit a new epoch) before encrypting again.
+pub const MAX_MESSAGES_PER_EPOCH: u64 = 1 << 32;
+
+/// Domain separator in the AAD of [`MlsEpochMessage`] ciphertexts.
+const EPOCH_MESSAGE_AAD_LABEL: &[u8] = b"x0x-mls-epoch-message";
+
+/// Domain separator for ratcheting [`MlsGroup`]'s epoch secret.
+const EPOCH_SECRET_LABEL: &[u8] = b"x0x-mls-epoch-secret";
+
+/// Group message sealed under an epoch key from [`MlsKeySchedule`].
+///
+/// Produced by [`MlsGroup::encrypt_epoch_message`]. The group id and epoch
+/// travel in the clear so a recipient can pick the matching group and key
+/// before decrypting; both are also bound into the AEAD's associated data,
+/// so relabelling a ciphertext makes it fail authentication.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MlsEpochMessage {
+    group_id: Vec<u8>,
+    epoch: u64,
+    /// Nonce counter from [`MlsGroup::next_nonce_counter`].
+    counter: u64,
+    /// ChaCha20-Poly1305 ciphertext with tag.
+    ciphertext: Vec<u8>,
+}
+
+impl MlsEpochMessage {
+    /// Decode the wire form produced by [`MlsGroup::encrypt_epoch_message`].
+    ///
+    /// # Errors
+    /// Returns `MlsError::DecryptionError` if `bytes` is not an envelope.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        bincode::deserialize(bytes)
+            .map_err(|e| MlsError::DecryptionError(format!("envelope decode: {e}")))
+    }
+
+    /// Group the message was sealed for.
+    #[must_use]
+    pub fn group_id(&self) -> &[u8] {
+        &self.group_id
+    }
+
+    /// Epoch whose key sealed the message.
+    #[must_use]
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    fn aad(group_id: &[u8], epoch: u64) -> Vec<u8> {
+        [EPOCH_MESSAGE_AAD_LABEL, group_id, &epoch.to_le_bytes()].concat()
+    }
+}
 
 /// MLS group context containing cryptographic state.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -261,6 +319,17 @@ pub struct MlsGroup {
     pending_commits: Vec<MlsCommit>,
     /// Current epoch number.
     epoch: u64,
+    /// Random per-epoch starting point for nonce counters, so members that
+    /// share the epoch key are unlikely to walk overlapping counter ranges.
+    nonce_base: u64,
+    /// Nonce counters issued in the current epoch (reset on every epoch).
+    nonces_issued: AtomicU64,
+    /// Secret key material for the current epoch, mixed into
+    /// [`MlsKeySchedule`] so the epoch key cannot be computed from the
+    /// public group id and epoch. Random at creation, ratcheted forward by
+    /// every commit and handed to joiners only inside an ML-KEM-sealed
+    /// [`MlsWelcome`]; never leaves the process in the clear.
+    epoch_secret: [u8; 32],
 }
 
 impl MlsGroup {
@@ -270,12 +339,7 @@ impl MlsGroup {
     /// Returns `MlsError::SaorsaMls` if the inner group cannot be created.
     pub async fn new(group_id: Vec<u8>, initiator: AgentId) -> Result<Self> {
         let member_id = agent_id_to_member_id(&initiator);
-        let identity = saorsa_mls::MemberIdentity::generate(member_id)
-            .map_err(|e| MlsError::SaorsaMls(format!("identity generation: {e}")))?;
-        let config = saorsa_mls::GroupConfig::default();
-        let inner = saorsa_mls::MlsGroup::new(config, identity)
-            .await
-            .map_err(|e| MlsError::SaorsaMls(format!("group creation: {e}")))?;
+        let inner = Self::new_inner(member_id).await?;
 
         let context = MlsGroupContext::new(group_id.clone());
         let mut members = HashMap::new();
@@ -295,9 +359,97 @@ impl MlsGroup {
             member_to_agent,
             pending_commits: Vec::new(),
             epoch: 0,
+            nonce_base: rand::random(),
+            nonces_issued: AtomicU64::new(0),
+            epoch_secret: rand::random(),
         })
     }
 
+    /// Reconstructs a group from a Welcome addressed to `joiner`.
+    ///
+    /// `kem_keypair` is the joiner's ML-KEM-768 keypair; the welcome was
+    /// sealed to its public key. The result is at the welcome's epoch with
+    /// the sender's context, epoch secret and member list, so it derives
+    /// the same [`MlsKeySchedule`] as the existing members and can open
+    /// their [`Self::encrypt_epoch_message`] output.
+    ///
+    /// # Errors
+    /// Returns `MlsError::MemberNotInGroup` if the welcome is not addressed
+    /// to `joiner` or its member list does not include `joiner`,
+    /// `MlsError::DecryptionError` / `MlsError::MlsOperation` if it fails
+    /// to decrypt with `kem_keypair` or to verify, or
+    /// `MlsError::SaorsaMls` if the inner group cannot be created.
+    pub async fn join_from_welcome(
+        welcome: &MlsWelcome,
+        joiner: AgentId,
+        kem_keypair: &AgentKemKeypair,
+    ) -> Result<Self> {
+        let (context, epoch_secret, roster) = welcome.open(&joiner, kem_keypair)?;
+        if !roster.iter().any(|member| *member.agent_id() == joiner) {
+            return Err(MlsError::MemberNotInGroup(format!(
+                "{:?} is not in the welcomed member list",
+                joiner.as_bytes()
+            )));
+        }
+
+        let inner = Self::new_inner(agent_id_to_member_id(&joiner)).await?;
+        let mut members = HashMap::new();
+        let mut agent_to_member = HashMap::new();
+        let mut member_to_agent = HashMap::new();
+        for member in roster {
+            let agent_id = *member.agent_id();
+            let member_id = agent_id_to_member_id(&agent_id);
+            agent_to_member.insert(agent_id, member_id);
+            member_to_agent.insert(member_id, agent_id);
+            members.insert(agent_id, member);
+        }
+
+        Ok(Self {
+            group_id: context.group_id().to_vec(),
+            inner,
+            epoch: context.epoch(),
+            context,
+            members,
+            agent_to_member,
+            member_to_agent,
+            pending_commits: Vec::new(),
+            nonce_base: rand::random(),
+            nonces_issued: AtomicU64::new(0),
+            epoch_secret,
+        })
+    }
+
+    async fn new_inner(member_id: saorsa_mls::MemberId) -> Result<saorsa_mls::MlsGroup> {
+        let identity = saorsa_mls::MemberIdentity::generate(member_id)
+            .map_err(|e| MlsError::SaorsaMls(format!("identity generation: {e}")))?;
+        let config = saorsa_mls::GroupConfig::default();
+        saorsa_mls::MlsGroup::new(config, identity)
+            .await
+            .map_err(|e| MlsError::SaorsaMls(format!("group creation: {e}")))
+    }
+
+    /// Creates a Welcome that bootstraps `new_member` into the current
+    /// epoch: the group context, member list and epoch secret, sealed to
+    /// `kem_public_key` (the member's ML-KEM-768 public key) so only the
+    /// holder of the matching private key can open it.
+    ///
+    /// Add the member first ([`Self::add_member`]) so the welcome carries
+    /// the epoch that includes them.
+    ///
+    /// # Errors
+    /// Returns `MlsError::MemberNotInGroup` if `new_member` is not a member,
+    /// or `MlsError::EncryptionError` if `kem_public_key` is invalid or
+    /// sealing the welcome fails.
+    pub fn create_welcome(&self, new_member: AgentId, kem_public_key: &[u8]) -> Result<MlsWelcome> {
+        if !self.is_member(&new_member) {
+            return Err(MlsError::MemberNotInGroup(format!(
+                "{:?}",
+                new_member.as_bytes()
+            )));
+        }
+        MlsWelcome::create(self, &new_member, kem_public_key)
+    }
+
     /// Gets the group ID.
     #[must_use]
     pub fn group_id(&self) -> &[u8] {
@@ -384,6 +536,8 @@ impl MlsGroup {
             .insert(member, MlsMemberInfo::new(member, self.epoch + 1));
         self.epoch = self.epoch.saturating_add(1);
         self.context.increment_epoch();
+        self.reset_nonce_counter();
+        self.advance_epoch_secret(&new_transcript_hash);
         self.context
             .update_crypto_material(new_tree_hash, new_transcript_hash);
 
@@ -441,6 +595,8 @@ impl MlsGroup {
         self.members.remove(&member);
         self.epoch = self.epoch.saturating_add(1);
         self.context.increment_epoch();
+        self.reset_nonce_counter();
+        self.advance_epoch_secret(&new_transcript_hash);
         self.context
             .update_crypto_material(new_tree_hash, new_transcript_hash);
 
@@ -485,9 +641,17 @@ impl MlsGroup {
 
     /// Applies a commit to the group state.
     ///
+    /// Commits apply in strict epoch order: only a commit for the current
+    /// epoch is accepted. A commit that was already applied (stale) or one
+    /// that skips ahead (future) is rejected, so replicas that receive
+    /// commits out of order over gossip never double-advance the epoch or
+    /// double-add members. A rejected commit leaves the group unchanged.
+    ///
     /// # Errors
-    /// Returns `MlsError::MlsOperation` if the commit is for a different group,
-    /// or `MlsError::EpochMismatch` if epochs don't match.
+    /// Returns `MlsError::MlsOperation` if the commit is for a different group
+    /// or adds an existing member, `MlsError::MemberNotInGroup` if it removes
+    /// a non-member, or `MlsError::EpochMismatch` if its epoch is not the
+    /// current epoch.
     pub fn apply_commit(&mut self, commit: &MlsCommit) -> Result<()> {
         if commit.group_id != self.group_id {
             return Err(MlsError::MlsOperation(
@@ -502,20 +666,22 @@ impl MlsGroup {
             });
         }
 
+        // Stage membership changes so a commit that fails part-way leaves
+        // the group untouched.
+        let mut members = self.members.clone();
         for operation in &commit.operations {
             match operation {
                 CommitOperation::AddMember(agent_id) => {
-                    if self.members.contains_key(agent_id) {
+                    if members.contains_key(agent_id) {
                         return Err(MlsError::MlsOperation(format!(
                             "cannot add existing member {:?}",
                             agent_id.as_bytes()
                         )));
                     }
-                    self.members
-                        .insert(*agent_id, MlsMemberInfo::new(*agent_id, self.epoch + 1));
+                    members.insert(*agent_id, MlsMemberInfo::new(*agent_id, self.epoch + 1));
                 }
                 CommitOperation::RemoveMember(agent_id) => {
-                    if self.members.remove(agent_id).is_none() {
+                    if members.remove(agent_id).is_none() {
                         return Err(MlsError::MemberNotInGroup(format!(
                             "{:?}",
                             agent_id.as_bytes()
@@ -526,8 +692,11 @@ impl MlsGroup {
             }
         }
 
+        self.members = members;
         self.epoch = self.epoch.saturating_add(1);
         self.context.increment_epoch();
+        self.reset_nonce_counter();
+        self.advance_epoch_secret(&commit.new_transcript_hash);
         self.context.update_crypto_material(
             commit.new_tree_hash.clone(),
             commit.new_transcript_hash.clone(),
@@ -539,6 +708,52 @@ impl MlsGroup {
         Ok(())
     }
 
+    /// Reserve the next AEAD nonce counter for this epoch's key.
+    ///
+    /// Every [`crate::mls::MlsCipher::encrypt`] under a key derived from this
+    /// group must use a counter from here: counters are unique within the
+    /// epoch, and the supply is reset when the epoch advances.
+    ///
+    /// # Errors
+    /// Returns `MlsError::NonceExhausted` once [`MAX_MESSAGES_PER_EPOCH`]
+    /// counters have been issued; rotate the key before encrypting again.
+    pub fn next_nonce_counter(&self) -> Result<u64> {
+        let issued = self
+            .nonces_issued
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                (n < MAX_MESSAGES_PER_EPOCH).then_some(n + 1)
+            })
+            .map_err(|_| MlsError::NonceExhausted { epoch: self.epoch })?;
+        Ok(self.nonce_base.wrapping_add(issued))
+    }
+
+    /// Start a fresh nonce counter range for a new epoch key.
+    fn reset_nonce_counter(&mut self) {
+        self.nonce_base = rand::random();
+        *self.nonces_issued.get_mut() = 0;
+    }
+
+    /// Secret key material for the current epoch.
+    pub(crate) fn epoch_secret(&self) -> &[u8; 32] {
+        &self.epoch_secret
+    }
+
+    /// Ratchet the epoch secret into the epoch that was just entered.
+    ///
+    /// Keyed by the previous secret, so only replicas that held it derive
+    /// the next one. There is no forward secrecy or post-compromise
+    /// security: a removed member who kept the old secret can follow the
+    /// ratchet through the public commits.
+    fn advance_epoch_secret(&mut self, transcript_hash: &[u8]) {
+        let input = [
+            EPOCH_SECRET_LABEL,
+            transcript_hash,
+            &self.epoch.to_le_bytes(),
+        ]
+        .concat();
+        self.epoch_secret = *blake3::keyed_hash(&self.epoch_secret, &input).as_bytes();
+    }
+
     /// Encrypts a message using the group's saorsa-mls AEAD cipher.
     ///
     /// # Errors
@@ -563,6 +778,66 @@ impl MlsGroup {
             .decrypt_message(&msg)
             .map_err(|e| MlsError::DecryptionError(e.to_string()))
     }
+
+    /// Encrypts a message under this epoch's [`MlsKeySchedule`] key.
+    ///
+    /// Unlike [`Self::encrypt_message`], the key depends only on the shared
+    /// group context and epoch secret, so every replica holding that secret
+    /// at the same epoch can decrypt with [`Self::decrypt_epoch_message`].
+    /// Anyone without the secret sees only the group id and epoch. The result is a serialized
+    /// [`MlsEpochMessage`] tagged with the group id and epoch; the nonce is
+    /// the epoch's base nonce combined with a fresh
+    /// [`Self::next_nonce_counter`].
+    ///
+    /// # Errors
+    /// Returns `MlsError::NonceExhausted` when the epoch has no counters
+    /// left, or `MlsError::EncryptionError` if sealing fails.
+    pub fn encrypt_epoch_message(&self, plaintext: &[u8]) -> Result<Vec<u8>> {
+        let schedule = MlsKeySchedule::from_group(self)?;
+        let cipher = MlsCipher::new(
+            schedule.encryption_key().to_vec(),
+            schedule.base_nonce().to_vec(),
+        );
+        let counter = self.next_nonce_counter()?;
+        let aad = MlsEpochMessage::aad(&self.group_id, self.epoch);
+        let message = MlsEpochMessage {
+            group_id: self.group_id.clone(),
+            epoch: self.epoch,
+            counter,
+            ciphertext: cipher.encrypt(plaintext, &aad, counter)?,
+        };
+        bincode::serialize(&message)
+            .map_err(|e| MlsError::EncryptionError(format!("envelope encode: {e}")))
+    }
+
+    /// Decrypts a message produced by [`Self::encrypt_epoch_message`].
+    ///
+    /// # Errors
+    /// Returns `MlsError::MlsOperation` if the message is for another group,
+    /// `MlsError::EpochMismatch` if it was sealed at a different epoch, or
+    /// `MlsError::DecryptionError` if it is malformed or fails
+    /// authentication.
+    pub fn decrypt_epoch_message(&self, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        let message = MlsEpochMessage::from_bytes(ciphertext)?;
+        if message.group_id != self.group_id {
+            return Err(MlsError::MlsOperation(
+                "message is for a different group".to_string(),
+            ));
+        }
+        if message.epoch != self.epoch {
+            return Err(MlsError::EpochMismatch {
+                current: self.epoch,
+                received: message.epoch,
+            });
+        }
+        let schedule = MlsKeySchedule::from_group(self)?;
+        let cipher = MlsCipher::new(
+            schedule.encryption_key().to_vec(),
+            schedule.base_nonce().to_vec(),
+        );
+        let aad = MlsEpochMessage::aad(&message.group_id, message.epoch);
+        cipher.decrypt(&message.ciphertext, &aad, message.counter)
+    }
 }
 
 #[cfg(test)]
@@ -608,6 +883,37 @@ mod tests {
         assert!(group.is_member(&new_member));
     }
 
+    #[tokio::test]
+    async fn nonce_counters_are_unique_and_exhaustion_forces_rotation() {
+        let mut group = MlsGroup::new(b"nonce-group".to_vec(), test_agent_id(1))
+            .await
+            .unwrap();
+
+        // Many encryptions in one epoch never see the same counter twice.
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..10_000 {
+            assert!(seen.insert(group.next_nonce_counter().unwrap()));
+        }
+
+        // Running the epoch dry is a hard error, never silent reuse.
+        group
+            .nonces_issued
+            .store(MAX_MESSAGES_PER_EPOCH, Ordering::SeqCst);
+        assert!(matches!(
+            group.next_nonce_counter(),
+            Err(MlsError::NonceExhausted { epoch: 0 })
+        ));
+        assert!(matches!(
+            group.next_nonce_counter(),
+            Err(MlsError::NonceExhausted { .. })
+        ));
+
+        // Rotating to a new epoch (new key) restores the supply.
+        group.add_member(test_agent_id(2)).await.unwrap();
+        assert_eq!(group.current_epoch(), 1);
+        assert!(group.next_nonce_counter().is_ok());
+    }
+
     #[tokio::test]
     async fn test_add_duplicate_member() {
         let group_id = b"test-group".to_vec();
@@ -682,6 +988,104 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn stale_commit_is_rejected_without_reapplying() {
+        let group_id = b"test-group".to_vec();
+        let mut group = MlsGroup::new(group_id.clone(), test_agent_id(1))
+            .await
+            .unwrap();
+        let member = test_agent_id(2);
+        let commit = MlsCommit::new(
+            group_id,
+            0,
+            vec![CommitOperation::AddMember(member)],
+            vec![1],
+            vec![2],
+        );
+        group.apply_commit(&commit).unwrap();
+        assert_eq!(group.current_epoch(), 1);
+
+        // Re-delivery of the same commit is a clean error, not a second
+        // epoch bump or a second add.
+        assert!(matches!(
+            group.apply_commit(&commit),
+            Err(MlsError::EpochMismatch {
+                current: 1,
+                received: 0
+            })
+        ));
+        assert_eq!(group.current_epoch(), 1);
+        assert_eq!(group.members().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn future_commit_is_rejected() {
+        let group_id = b"test-group".to_vec();
+        let mut group = MlsGroup::new(group_id.clone(), test_agent_id(1))
+            .await
+            .unwrap();
+        let tree_hash = group.context().tree_hash().to_vec();
+        let future = MlsCommit::new(
+            group_id,
+            1,
+            vec![CommitOperation::AddMember(test_agent_id(2))],
+            vec![1],
+            vec![2],
+        );
+
+        assert!(matches!(
+            group.apply_commit(&future),
+            Err(MlsError::EpochMismatch {
+                current: 0,
+                received: 1
+            })
+        ));
+        assert_eq!(group.current_epoch(), 0);
+        assert_eq!(group.members().len(), 1);
+        assert_eq!(group.context().tree_hash(), tree_hash.as_slice());
+    }
+
+    #[tokio::test]
+    async fn pending_commit_applies_once() {
+        let mut group = MlsGroup::new(b"test-group".to_vec(), test_agent_id(1))
+            .await
+            .unwrap();
+        let commit = group.commit().unwrap();
+
+        group.apply_commit(&commit).unwrap();
+        assert!(matches!(
+            group.apply_commit(&commit),
+            Err(MlsError::EpochMismatch { .. })
+        ));
+        assert_eq!(group.current_epoch(), 1);
+    }
+
+    #[tokio::test]
+    async fn rejected_commit_leaves_membership_unchanged() {
+        let group_id = b"test-group".to_vec();
+        let initiator = test_agent_id(1);
+        let mut group = MlsGroup::new(group_id.clone(), initiator).await.unwrap();
+        // The first operation is valid, the second is not.
+        let commit = MlsCommit::new(
+            group_id,
+            0,
+            vec![
+                CommitOperation::AddMember(test_agent_id(2)),
+                CommitOperation::AddMember(initiator),
+            ],
+            vec![],
+            vec![],
+        );
+
+        assert!(matches!(
+            group.apply_commit(&commit),
+            Err(MlsError::MlsOperation(_))
+        ));
+        assert_eq!(group.members().len(), 1);
+        assert!(!group.is_member(&test_agent_id(2)));
+        assert_eq!(group.current_epoch(), 0);
+    }
+
     #[tokio::test]
     async fn test_encrypt_decrypt_message() {
         let group_id = b"test-encrypt".to_vec();
@@ -697,6 +1101,172 @@ mod tests {
         assert_eq!(decrypted, plaintext);
     }
 
+    /// Alice's group at epoch 1 and Bob's replica, joined from her welcome.
+    async fn two_members_at_epoch_one() -> (MlsGroup, MlsGroup) {
+        let bob_id = test_agent_id(2);
+        let bob_kem = AgentKemKeypair::generate().unwrap();
+        let mut alice = MlsGroup::new(b"epoch-messages".to_vec(), test_agent_id(1))
+            .await
+            .unwrap();
+        alice.add_member(bob_id).await.unwrap();
+        let welcome = alice.create_welcome(bob_id, &bob_kem.public_bytes).unwrap();
+        let bob = MlsGroup::join_from_welcome(&welcome, bob_id, &bob_kem)
+            .await
+            .unwrap();
+        (alice, bob)
+    }
+
+    #[tokio::test]
+    async fn epoch_message_roundtrips_between_members() {
+        let (alice, bob) = two_members_at_epoch_one().await;
+
+        let sealed = alice.encrypt_epoch_message(b"hello bob").unwrap();
+        let envelope = MlsEpochMessage::from_bytes(&sealed).unwrap();
+        assert_eq!(envelope.group_id(), b"epoch-messages");
+        assert_eq!(envelope.epoch(), 1);
+        assert_eq!(bob.decrypt_epoch_message(&sealed).unwrap(), b"hello bob");
+
+        let reply = bob.encrypt_epoch_message(b"hi alice").unwrap();
+        assert_eq!(alice.decrypt_epoch_message(&reply).unwrap(), b"hi alice");
+
+        // An envelope relabelled for another group is refused.
+        let mut forged = envelope.clone();
+        forged.group_id = b"other-group".to_vec();
+        let forged = bincode::serialize(&forged).unwrap();
+        assert!(matches!(
+            bob.decrypt_epoch_message(&forged),
+            Err(MlsError::MlsOperation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn epoch_message_needs_the_epoch_secret() {
+        // Same group id, same public commit, but no shared epoch secret:
+        // knowing the group id and epoch is not enough to decrypt.
+        let group_id = b"epoch-messages".to_vec();
+        let mut alice = MlsGroup::new(group_id.clone(), test_agent_id(1))
+            .await
+            .unwrap();
+        let mut outsider = MlsGroup::new(group_id, test_agent_id(3)).await.unwrap();
+        let commit = alice.commit().unwrap();
+        alice.apply_commit(&commit).unwrap();
+        outsider.apply_commit(&commit).unwrap();
+        assert_eq!(outsider.context(), alice.context());
+
+        let sealed = alice.encrypt_epoch_message(b"members only").unwrap();
+        assert!(matches!(
+            outsider.decrypt_epoch_message(&sealed),
+            Err(MlsError::DecryptionError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn welcomed_member_decrypts_the_next_message() {
+        let creator = test_agent_id(1);
+        let joiner = test_agent_id(2);
+        let joiner_kem = AgentKemKeypair::generate().unwrap();
+        let mut group = MlsGroup::new(b"welcome-join".to_vec(), creator)
+            .await
+            .unwrap();
+        group.add_member(joiner).await.unwrap();
+        let welcome = group
+            .create_welcome(joiner, &joiner_kem.public_bytes)
+            .unwrap();
+
+        let mut joined = MlsGroup::join_from_welcome(&welcome, joiner, &joiner_kem)
+            .await
+            .unwrap();
+        assert_eq!(joined.group_id(), group.group_id());
+        assert_eq!(joined.current_epoch(), group.current_epoch());
+        assert_eq!(joined.members(), group.members());
+        assert_eq!(joined.context(), group.context());
+
+        let sealed = group.encrypt_epoch_message(b"welcome aboard").unwrap();
+        assert_eq!(
+            joined.decrypt_epoch_message(&sealed).unwrap(),
+            b"welcome aboard"
+        );
+        let reply = joined.encrypt_epoch_message(b"thanks").unwrap();
+        assert_eq!(group.decrypt_epoch_message(&reply).unwrap(), b"thanks");
+
+        // Both replicas ratchet the epoch secret through the next commit.
+        let rotate = group.commit().unwrap();
+        group.apply_commit(&rotate).unwrap();
+        joined.apply_commit(&rotate).unwrap();
+        let sealed = group.encrypt_epoch_message(b"epoch two").unwrap();
+        assert_eq!(joined.decrypt_epoch_message(&sealed).unwrap(), b"epoch two");
+    }
+
+    #[tokio::test]
+    async fn welcome_is_bound_to_its_addressee() {
+        let kem = AgentKemKeypair::generate().unwrap();
+        let mut group = MlsGroup::new(b"welcome-bound".to_vec(), test_agent_id(1))
+            .await
+            .unwrap();
+        group.add_member(test_agent_id(2)).await.unwrap();
+
+        assert!(matches!(
+            group.create_welcome(test_agent_id(3), &kem.public_bytes),
+            Err(MlsError::MemberNotInGroup(_))
+        ));
+
+        let welcome = group
+            .create_welcome(test_agent_id(2), &kem.public_bytes)
+            .unwrap();
+        assert!(matches!(
+            MlsGroup::join_from_welcome(&welcome, test_agent_id(3), &kem).await,
+            Err(MlsError::MemberNotInGroup(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn non_invitee_cannot_open_the_welcome() {
+        let invitee = test_agent_id(2);
+        let invitee_kem = AgentKemKeypair::generate().unwrap();
+        let eavesdropper_kem = AgentKemKeypair::generate().unwrap();
+        let mut group = MlsGroup::new(b"welcome-sealed".to_vec(), test_agent_id(1))
+            .await
+            .unwrap();
+        group.add_member(invitee).await.unwrap();
+        let welcome = group
+            .create_welcome(invitee, &invitee_kem.public_bytes)
+            .unwrap();
+
+        // A relay holding the welcome and the invitee's public AgentId
+        // still cannot open it without the invitee's KEM private key.
+        assert!(matches!(
+            MlsGroup::join_from_welcome(&welcome, invitee, &eavesdropper_kem).await,
+            Err(MlsError::DecryptionError(_))
+        ));
+        let wire = bincode::serialize(&welcome).unwrap();
+        assert!(!wire.windows(32).any(|w| w == group.epoch_secret()));
+    }
+
+    #[tokio::test]
+    async fn epoch_message_from_another_epoch_is_rejected() {
+        let (mut alice, bob) = two_members_at_epoch_one().await;
+        let rotate = alice.commit().unwrap();
+        alice.apply_commit(&rotate).unwrap();
+
+        let sealed = alice.encrypt_epoch_message(b"epoch two").unwrap();
+        assert!(matches!(
+            bob.decrypt_epoch_message(&sealed),
+            Err(MlsError::EpochMismatch {
+                current: 1,
+                received: 2
+            })
+        ));
+
+        let old = bob.encrypt_epoch_message(b"epoch one").unwrap();
+        assert!(matches!(
+            alice.decrypt_epoch_message(&old),
+            Err(MlsError::EpochMismatch {
+                current: 2,
+                received: 1
+            })
+        ));
+    }
+
     #[tokio::test]
     async fn test_context_updates_on_commit() {
         let group_id = b"test-group".to_vec();
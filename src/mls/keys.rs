@@ -42,7 +42,9 @@ impl MlsKeySchedule {
     ///
     /// # Security
     /// Keys are deterministically derived using BLAKE3 for both speed and security.
-    /// Each epoch produces completely different keys, providing forward secrecy.
+    /// Each epoch produces completely different keys. The group's secret epoch
+    /// material is part of the input, so the public group id, epoch and
+    /// context hashes alone do not determine the key.
     pub fn from_group(group: &MlsGroup) -> Result<Self> {
         let epoch = group.current_epoch();
         let context = group.context();
@@ -59,6 +61,7 @@ impl MlsKeySchedule {
         secret_material.extend_from_slice(context.tree_hash());
         secret_material.extend_from_slice(context.confirmed_transcript_hash());
         secret_material.extend_from_slice(&epoch.to_le_bytes());
+        secret_material.extend_from_slice(group.epoch_secret());
         let secret = blake3::hash(&secret_material).as_bytes().to_vec();
 
         // Derive encryption key from secret
@@ -18,7 +18,10 @@ pub mod welcome;
 
 pub use cipher::MlsCipher;
 pub use error::{MlsError, Result};
-pub use group::{CommitOperation, MlsCommit, MlsGroup, MlsGroupContext, MlsMemberInfo};
+pub use group::{
+    CommitOperation, MlsCommit, MlsEpochMessage, MlsGroup, MlsGroupContext, MlsMemberInfo,
+    MAX_MESSAGES_PER_EPOCH,
+};
 pub use keys::MlsKeySchedule;
 pub use treekem::TreeKemMlsGroup;
 pub use welcome::MlsWelcome;
@@ -4,8 +4,9 @@
 //! to invite new agents to join an encrypted group. Welcome messages contain the
 //! encrypted group secrets needed for the invitee to derive encryption keys.
 
+use crate::groups::kem_envelope::{self, AgentKemKeypair};
 use crate::identity::AgentId;
-use crate::mls::{MlsCipher, MlsError, MlsGroup, MlsGroupContext, Result};
+use crate::mls::{MlsCipher, MlsError, MlsGroup, MlsGroupContext, MlsMemberInfo, Result};
 use blake3;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -14,22 +15,36 @@ use std::collections::HashMap;
 ///
 /// The Welcome message contains all the information needed for an invitee to join
 /// an MLS group and derive the current encryption keys. Group secrets are encrypted
-/// per-invitee to ensure only authorized agents can join.
+/// under a random welcome key that is sealed to the invitee's ML-KEM-768 public
+/// key, so only the holder of the matching private key can open them.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MlsWelcome {
     /// Unique identifier for the group being joined.
     group_id: Vec<u8>,
     /// Current epoch of the group when welcome was created.
     epoch: u64,
+    /// Welcome key sealed to each invitee's ML-KEM-768 public key.
+    sealed_keys: HashMap<AgentId, SealedWelcomeKey>,
     /// Encrypted group secrets, keyed by invitee AgentId.
     /// Each invitee gets their own encrypted copy of the secrets.
     encrypted_group_secrets: HashMap<AgentId, Vec<u8>>,
     /// Serialized ratchet tree for the invitee to reconstruct group state.
     tree: Vec<u8>,
+    /// Encrypted member list, keyed by invitee AgentId like the secrets.
+    #[serde(default)]
+    encrypted_rosters: HashMap<AgentId, Vec<u8>>,
     /// Confirmation tag authenticating the welcome message.
     confirmation_tag: Vec<u8>,
 }
 
+/// Welcome key sealed with [`kem_envelope::seal_group_secret_to_recipient`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SealedWelcomeKey {
+    kem_ciphertext: Vec<u8>,
+    nonce: [u8; 12],
+    ciphertext: Vec<u8>,
+}
+
 impl MlsWelcome {
     /// Creates a new Welcome message for an invitee.
     ///
@@ -39,38 +54,67 @@ impl MlsWelcome {
     /// # Arguments
     /// * `group` - The MLS group to invite the member to
     /// * `invitee` - The AgentId of the agent being invited
+    /// * `invitee_kem_public_key` - The invitee's ML-KEM-768 public key
     ///
     /// # Returns
     /// A new `MlsWelcome` message ready to be sent to the invitee.
     ///
     /// # Errors
-    /// Returns `MlsError::EncryptionError` if secret encryption fails.
+    /// Returns `MlsError::EncryptionError` if the public key is invalid or
+    /// secret encryption fails.
     ///
     /// # Security
-    /// The group secrets are encrypted using a key derived from the invitee's AgentId
-    /// and the group's current epoch. Only the invitee can decrypt these secrets.
-    pub fn create(group: &MlsGroup, invitee: &AgentId) -> Result<Self> {
+    /// The group secrets, including the epoch secret, are encrypted under a
+    /// fresh random key sealed to `invitee_kem_public_key` with ML-KEM-768.
+    /// Only the holder of the matching private key can decrypt them.
+    pub fn create(
+        group: &MlsGroup,
+        invitee: &AgentId,
+        invitee_kem_public_key: &[u8],
+    ) -> Result<Self> {
         let context = group.context();
         let epoch = context.epoch();
         let group_id = context.group_id().to_vec();
+        let aad = Self::build_aad(&group_id, epoch, invitee);
 
-        // Derive encryption key for this invitee from their AgentId
-        let invitee_key = Self::derive_invitee_key(invitee, &group_id, epoch);
+        // Seal a fresh welcome key to the invitee's KEM public key
+        let welcome_key: [u8; 32] = rand::random();
+        let (kem_ciphertext, nonce, ciphertext) = kem_envelope::seal_group_secret_to_recipient(
+            invitee_kem_public_key,
+            &aad,
+            &welcome_key,
+        )
+        .map_err(|e| MlsError::EncryptionError(format!("welcome key seal: {e}")))?;
+        let mut sealed_keys = HashMap::new();
+        sealed_keys.insert(
+            *invitee,
+            SealedWelcomeKey {
+                kem_ciphertext,
+                nonce,
+                ciphertext,
+            },
+        );
 
         // Create cipher for encrypting secrets
-        let cipher = MlsCipher::new(invitee_key, vec![0u8; 12]);
+        let cipher = MlsCipher::new(welcome_key.to_vec(), vec![0u8; 12]);
 
         // Serialize group secrets (simplified - in full MLS this would be more complex)
-        let group_secrets = Self::serialize_group_secrets(context);
+        let group_secrets = Self::serialize_group_secrets(context, group.epoch_secret());
 
         // Encrypt secrets for invitee
-        let aad = Self::build_aad(&group_id, epoch, invitee);
         let encrypted_secrets = cipher.encrypt(&group_secrets, &aad, 0)?;
 
         // Build encrypted secrets map
         let mut encrypted_group_secrets = HashMap::new();
         encrypted_group_secrets.insert(*invitee, encrypted_secrets);
 
+        // Encrypt the member list under the same welcome key (next counter)
+        let roster: Vec<&MlsMemberInfo> = group.members().values().collect();
+        let roster = bincode::serialize(&roster)
+            .map_err(|e| MlsError::EncryptionError(format!("roster serialization: {e}")))?;
+        let mut encrypted_rosters = HashMap::new();
+        encrypted_rosters.insert(*invitee, cipher.encrypt(&roster, &aad, 1)?);
+
         // Serialize tree (simplified - actual MLS would include full ratchet tree)
         let tree = Self::serialize_tree(context);
 
@@ -80,8 +124,10 @@ impl MlsWelcome {
         Ok(Self {
             group_id,
             epoch,
+            sealed_keys,
             encrypted_group_secrets,
             tree,
+            encrypted_rosters,
             confirmation_tag,
         })
     }
@@ -133,53 +179,97 @@ impl MlsWelcome {
     ///
     /// # Arguments
     /// * `agent_id` - The AgentId of the agent accepting the invitation
+    /// * `kem_keypair` - That agent's ML-KEM-768 keypair
     ///
     /// # Returns
     /// The `MlsGroupContext` needed to join the group.
     ///
     /// # Errors
     /// * `MlsError::MemberNotInGroup` if no secrets encrypted for this agent
-    /// * `MlsError::DecryptionError` if secret decryption fails
+    /// * `MlsError::DecryptionError` if the welcome key cannot be opened with
+    ///   `kem_keypair` or secret decryption fails
     /// * `MlsError::MlsOperation` if context reconstruction fails
     ///
     /// # Security
-    /// Only the intended invitee can decrypt the group secrets. If this agent's
-    /// ID does not match the invitee, decryption will fail.
-    pub fn accept(&self, agent_id: &AgentId) -> Result<MlsGroupContext> {
+    /// Only the holder of the invitee's ML-KEM-768 private key can open the
+    /// welcome key, and with it the group secrets.
+    pub fn accept(
+        &self,
+        agent_id: &AgentId,
+        kem_keypair: &AgentKemKeypair,
+    ) -> Result<MlsGroupContext> {
+        let cipher = self.welcome_cipher(agent_id, kem_keypair)?;
+        let (context, _epoch_secret) = self.open_group_secrets(agent_id, &cipher)?;
+        Ok(context)
+    }
+
+    /// Opens everything carried for `agent_id`: the group context, the
+    /// epoch secret and the member list.
+    ///
+    /// # Errors
+    /// The same errors as [`Self::accept`], plus `MlsError::MemberNotInGroup`
+    /// if no roster was encrypted for this agent.
+    pub(crate) fn open(
+        &self,
+        agent_id: &AgentId,
+        kem_keypair: &AgentKemKeypair,
+    ) -> Result<(MlsGroupContext, [u8; 32], Vec<MlsMemberInfo>)> {
+        let cipher = self.welcome_cipher(agent_id, kem_keypair)?;
+        let (context, epoch_secret) = self.open_group_secrets(agent_id, &cipher)?;
+        let encrypted_roster = self
+            .encrypted_rosters
+            .get(agent_id)
+            .ok_or_else(|| MlsError::MemberNotInGroup(format!("{:?}", agent_id)))?;
+        let aad = Self::build_aad(&self.group_id, self.epoch, agent_id);
+        let roster = cipher.decrypt(encrypted_roster, &aad, 1)?;
+        let roster = bincode::deserialize(&roster)
+            .map_err(|e| MlsError::DecryptionError(format!("roster deserialization: {e}")))?;
+        Ok((context, epoch_secret, roster))
+    }
+
+    /// Verifies the welcome and opens the welcome key sealed for `agent_id`.
+    fn welcome_cipher(
+        &self,
+        agent_id: &AgentId,
+        kem_keypair: &AgentKemKeypair,
+    ) -> Result<MlsCipher> {
         // Verify the welcome first
         self.verify()?;
 
+        let sealed = self
+            .sealed_keys
+            .get(agent_id)
+            .ok_or_else(|| MlsError::MemberNotInGroup(format!("{:?}", agent_id)))?;
+        let aad = Self::build_aad(&self.group_id, self.epoch, agent_id);
+        let welcome_key = kem_envelope::open_group_secret(
+            kem_keypair,
+            &aad,
+            &sealed.kem_ciphertext,
+            &sealed.nonce,
+            &sealed.ciphertext,
+        )
+        .map_err(|e| MlsError::DecryptionError(format!("welcome key open: {e}")))?;
+        Ok(MlsCipher::new(welcome_key.to_vec(), vec![0u8; 12]))
+    }
+
+    /// Decrypts the group secrets for `agent_id` with the opened welcome key.
+    fn open_group_secrets(
+        &self,
+        agent_id: &AgentId,
+        cipher: &MlsCipher,
+    ) -> Result<(MlsGroupContext, [u8; 32])> {
         // Find encrypted secrets for this agent
         let encrypted_secrets = self
             .encrypted_group_secrets
             .get(agent_id)
             .ok_or_else(|| MlsError::MemberNotInGroup(format!("{:?}", agent_id)))?;
 
-        // Derive decryption key
-        let invitee_key = Self::derive_invitee_key(agent_id, &self.group_id, self.epoch);
-        let cipher = MlsCipher::new(invitee_key, vec![0u8; 12]);
-
         // Decrypt group secrets
         let aad = Self::build_aad(&self.group_id, self.epoch, agent_id);
         let group_secrets = cipher.decrypt(encrypted_secrets, &aad, 0)?;
 
         // Deserialize and reconstruct group context
-        Self::deserialize_group_context(&group_secrets, &self.group_id, self.epoch)
-    }
-
-    /// Derives an encryption key for a specific invitee.
-    ///
-    /// Uses BLAKE3 to derive a 32-byte key from the invitee's AgentId, group ID,
-    /// and current epoch.
-    fn derive_invitee_key(invitee: &AgentId, group_id: &[u8], epoch: u64) -> Vec<u8> {
-        let mut key_material = Vec::new();
-        key_material.extend_from_slice(invitee.as_bytes());
-        key_material.extend_from_slice(group_id);
-        key_material.extend_from_slice(&epoch.to_le_bytes());
-        key_material.extend_from_slice(b"welcome-key");
-
-        let hash = blake3::hash(&key_material);
-        hash.as_bytes()[..32].to_vec()
+        Self::deserialize_group_secrets(&group_secrets, &self.group_id, self.epoch)
     }
 
     /// Builds additional authenticated data for encryption.
@@ -195,13 +285,14 @@ impl MlsWelcome {
     /// Serializes group secrets for encryption.
     ///
     /// In a full MLS implementation, this would include the complete key schedule.
-    /// Here we include the essential context hashes needed to derive keys.
-    fn serialize_group_secrets(context: &MlsGroupContext) -> Vec<u8> {
+    /// Here we include the context hashes and the epoch secret needed to derive keys.
+    fn serialize_group_secrets(context: &MlsGroupContext, epoch_secret: &[u8; 32]) -> Vec<u8> {
         let mut secrets = Vec::new();
         secrets.extend_from_slice(context.group_id());
         secrets.extend_from_slice(&context.epoch().to_le_bytes());
         secrets.extend_from_slice(context.tree_hash());
         secrets.extend_from_slice(context.confirmed_transcript_hash());
+        secrets.extend_from_slice(epoch_secret);
         secrets
     }
 
@@ -231,19 +322,25 @@ impl MlsWelcome {
         blake3::hash(&tag_material).as_bytes().to_vec()
     }
 
-    /// Deserializes group context from decrypted secrets.
-    fn deserialize_group_context(
+    /// Deserializes group context and epoch secret from decrypted secrets.
+    fn deserialize_group_secrets(
         secrets: &[u8],
         expected_group_id: &[u8],
         expected_epoch: u64,
-    ) -> Result<MlsGroupContext> {
+    ) -> Result<(MlsGroupContext, [u8; 32])> {
         // Validate minimum length
-        if secrets.len() < expected_group_id.len() + 8 {
+        if secrets.len() < expected_group_id.len() + 8 + 32 {
             return Err(MlsError::MlsOperation(
                 "invalid group secrets length".to_string(),
             ));
         }
 
+        // Split off the trailing epoch secret
+        let (secrets, epoch_secret) = secrets.split_at(secrets.len() - 32);
+        let epoch_secret: [u8; 32] = epoch_secret
+            .try_into()
+            .map_err(|_| MlsError::MlsOperation("invalid epoch secret".to_string()))?;
+
         let mut offset = 0;
 
         // Extract and verify group_id
@@ -276,11 +373,14 @@ impl MlsWelcome {
         // Extract confirmed_transcript_hash (rest)
         let confirmed_transcript_hash = secrets[offset..].to_vec();
 
-        Ok(MlsGroupContext::new_with_material(
-            group_id,
-            epoch,
-            tree_hash,
-            confirmed_transcript_hash,
+        Ok((
+            MlsGroupContext::new_with_material(
+                group_id,
+                epoch,
+                tree_hash,
+                confirmed_transcript_hash,
+            ),
+            epoch_secret,
         ))
     }
 
@@ -317,16 +417,23 @@ mod tests {
         identity.agent_id()
     }
 
+    fn create_test_kem_keypair() -> AgentKemKeypair {
+        AgentKemKeypair::generate().expect("KEM keypair generation failed")
+    }
+
     #[tokio::test]
     async fn test_welcome_creation() {
         let (group, _creator) = create_test_group().await;
         let invitee = create_test_invitee();
+        let invitee_kem = create_test_kem_keypair();
 
-        let welcome = MlsWelcome::create(&group, &invitee).expect("welcome creation failed");
+        let welcome = MlsWelcome::create(&group, &invitee, &invitee_kem.public_bytes)
+            .expect("welcome creation failed");
 
         assert_eq!(welcome.group_id(), group.context().group_id());
         assert_eq!(welcome.epoch(), group.current_epoch());
         assert!(welcome.encrypted_group_secrets.contains_key(&invitee));
+        assert!(welcome.sealed_keys.contains_key(&invitee));
         assert!(!welcome.tree.is_empty());
         assert_eq!(welcome.confirmation_tag.len(), 32);
     }
@@ -335,8 +442,10 @@ mod tests {
     async fn test_welcome_verification() {
         let (group, _creator) = create_test_group().await;
         let invitee = create_test_invitee();
+        let invitee_kem = create_test_kem_keypair();
 
-        let welcome = MlsWelcome::create(&group, &invitee).expect("welcome creation failed");
+        let welcome = MlsWelcome::create(&group, &invitee, &invitee_kem.public_bytes)
+            .expect("welcome creation failed");
 
         // Valid welcome should verify
         assert!(welcome.verify().is_ok());
@@ -346,8 +455,10 @@ mod tests {
     async fn test_welcome_verification_rejects_empty_group_id() {
         let (group, _creator) = create_test_group().await;
         let invitee = create_test_invitee();
+        let invitee_kem = create_test_kem_keypair();
 
-        let mut welcome = MlsWelcome::create(&group, &invitee).expect("welcome creation failed");
+        let mut welcome = MlsWelcome::create(&group, &invitee, &invitee_kem.public_bytes)
+            .expect("welcome creation failed");
         welcome.group_id = Vec::new();
 
         assert!(welcome.verify().is_err());
@@ -357,8 +468,10 @@ mod tests {
     async fn test_welcome_verification_rejects_empty_tree() {
         let (group, _creator) = create_test_group().await;
         let invitee = create_test_invitee();
+        let invitee_kem = create_test_kem_keypair();
 
-        let mut welcome = MlsWelcome::create(&group, &invitee).expect("welcome creation failed");
+        let mut welcome = MlsWelcome::create(&group, &invitee, &invitee_kem.public_bytes)
+            .expect("welcome creation failed");
         welcome.tree = Vec::new();
 
         assert!(welcome.verify().is_err());
@@ -368,8 +481,10 @@ mod tests {
     async fn test_welcome_verification_rejects_invalid_tag() {
         let (group, _creator) = create_test_group().await;
         let invitee = create_test_invitee();
+        let invitee_kem = create_test_kem_keypair();
 
-        let mut welcome = MlsWelcome::create(&group, &invitee).expect("welcome creation failed");
+        let mut welcome = MlsWelcome::create(&group, &invitee, &invitee_kem.public_bytes)
+            .expect("welcome creation failed");
         welcome.confirmation_tag = vec![0u8; 16]; // Wrong length
 
         assert!(welcome.verify().is_err());
@@ -379,11 +494,15 @@ mod tests {
     async fn test_welcome_accept_by_invitee() {
         let (group, _creator) = create_test_group().await;
         let invitee = create_test_invitee();
+        let invitee_kem = create_test_kem_keypair();
 
-        let welcome = MlsWelcome::create(&group, &invitee).expect("welcome creation failed");
+        let welcome = MlsWelcome::create(&group, &invitee, &invitee_kem.public_bytes)
+            .expect("welcome creation failed");
 
         // Invitee accepts the welcome
-        let context = welcome.accept(&invitee).expect("accept failed");
+        let context = welcome
+            .accept(&invitee, &invitee_kem)
+            .expect("accept failed");
 
         assert_eq!(context.group_id(), group.context().group_id());
         assert_eq!(context.epoch(), group.current_epoch());
@@ -393,59 +512,50 @@ mod tests {
     async fn test_welcome_accept_rejects_wrong_agent() {
         let (group, _creator) = create_test_group().await;
         let invitee = create_test_invitee();
+        let invitee_kem = create_test_kem_keypair();
         let wrong_agent = create_test_invitee();
 
-        let welcome = MlsWelcome::create(&group, &invitee).expect("welcome creation failed");
+        let welcome = MlsWelcome::create(&group, &invitee, &invitee_kem.public_bytes)
+            .expect("welcome creation failed");
 
         // Wrong agent tries to accept
-        let result = welcome.accept(&wrong_agent);
+        let result = welcome.accept(&wrong_agent, &invitee_kem);
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), MlsError::MemberNotInGroup(_)));
     }
 
-    #[test]
-    fn test_invitee_key_derivation_is_deterministic() {
-        let invitee = create_test_invitee();
-        let group_id = b"test-group";
-        let epoch = 5;
-
-        let key1 = MlsWelcome::derive_invitee_key(&invitee, group_id, epoch);
-        let key2 = MlsWelcome::derive_invitee_key(&invitee, group_id, epoch);
-
-        assert_eq!(key1, key2);
-        assert_eq!(key1.len(), 32);
-    }
-
     #[tokio::test]
-    async fn test_invitee_key_varies_with_epoch() {
+    async fn test_welcome_accept_rejects_wrong_kem_key() {
+        let (group, _creator) = create_test_group().await;
         let invitee = create_test_invitee();
-        let group_id = b"test-group";
+        let invitee_kem = create_test_kem_keypair();
+        let other_kem = create_test_kem_keypair();
 
-        let key1 = MlsWelcome::derive_invitee_key(&invitee, group_id, 1);
-        let key2 = MlsWelcome::derive_invitee_key(&invitee, group_id, 2);
+        let welcome = MlsWelcome::create(&group, &invitee, &invitee_kem.public_bytes)
+            .expect("welcome creation failed");
 
-        assert_ne!(key1, key2);
+        // Knowing the invitee's AgentId is not enough without its KEM key
+        let result = welcome.accept(&invitee, &other_kem);
+        assert!(matches!(result, Err(MlsError::DecryptionError(_))));
     }
 
     #[tokio::test]
-    async fn test_invitee_key_varies_with_agent() {
-        let invitee1 = create_test_invitee();
-        let invitee2 = create_test_invitee();
-        let group_id = b"test-group";
-        let epoch = 1;
-
-        let key1 = MlsWelcome::derive_invitee_key(&invitee1, group_id, epoch);
-        let key2 = MlsWelcome::derive_invitee_key(&invitee2, group_id, epoch);
+    async fn test_welcome_rejects_invalid_kem_public_key() {
+        let (group, _creator) = create_test_group().await;
+        let invitee = create_test_invitee();
 
-        assert_ne!(key1, key2);
+        let result = MlsWelcome::create(&group, &invitee, b"not a KEM key");
+        assert!(matches!(result, Err(MlsError::EncryptionError(_))));
     }
 
     #[tokio::test]
     async fn test_welcome_serialization() {
         let (group, _creator) = create_test_group().await;
         let invitee = create_test_invitee();
+        let invitee_kem = create_test_kem_keypair();
 
-        let welcome = MlsWelcome::create(&group, &invitee).expect("welcome creation failed");
+        let welcome = MlsWelcome::create(&group, &invitee, &invitee_kem.public_bytes)
+            .expect("welcome creation failed");
 
         // Serialize and deserialize (using bincode since HashMap<AgentId, _> doesn't work with JSON)
         let serialized = bincode::serialize(&welcome).expect("serialization failed");
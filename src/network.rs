@@ -61,12 +61,21 @@ pub const DEFAULT_METRICS_PORT: u16 = 12600;
 /// Default maximum connections.
 pub const DEFAULT_MAX_CONNECTIONS: u32 = 32;
 
+/// Default cap on connection attempts in flight while joining the network.
+pub const DEFAULT_MAX_CONCURRENT_CONNECTS: usize = 16;
+
 /// Default connection timeout.
 pub const DEFAULT_CONNECTION_TIMEOUT: Duration = Duration::from_secs(30);
 
 /// Default stats collection interval.
 pub const DEFAULT_STATS_INTERVAL: Duration = Duration::from_secs(60);
 
+/// First backoff of [`NetworkNode::send_with_retry`]; doubles per attempt.
+pub const SEND_RETRY_BASE_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Longest backoff of [`NetworkNode::send_with_retry`].
+pub const SEND_RETRY_MAX_BACKOFF: Duration = Duration::from_secs(5);
+
 /// Default age after which an idle pooled QUIC connection is evicted.
 const CONNECTION_POOL_IDLE_EVICT_AFTER: Duration = Duration::from_secs(300);
 
@@ -205,12 +214,20 @@ pub struct NetworkConfig {
     #[serde(default = "default_max_connections")]
     pub max_connections: u32,
 
-    /// Timeout for establishing connections.
-    #[serde(default = "default_connection_timeout")]
+    /// Timeout for establishing connections. Accepts `"30s"`-style strings
+    /// (see [`parse_duration`]); must be non-zero.
+    #[serde(
+        default = "default_connection_timeout",
+        deserialize_with = "deserialize_nonzero_duration"
+    )]
     pub connection_timeout: Duration,
 
-    /// Interval for collecting and reporting stats.
-    #[serde(default = "default_stats_interval")]
+    /// Interval for collecting and reporting stats. Accepts `"60s"`-style
+    /// strings (see [`parse_duration`]); must be non-zero.
+    #[serde(
+        default = "default_stats_interval",
+        deserialize_with = "deserialize_nonzero_duration"
+    )]
     pub stats_interval: Duration,
 
     /// Pinned bootstrap peer IDs. When non-empty, `dial_bootstrap()` rejects
@@ -227,6 +244,16 @@ pub struct NetworkConfig {
     #[serde(default = "default_max_peers_per_ip")]
     pub max_peers_per_ip: u32,
 
+    /// Max connection attempts in flight at once when joining the network
+    /// (bootstrap addresses and cached peers), probing bootstrap peers
+    /// ([`crate::bootstrap::probe`]) or connecting through
+    /// [`crate::bootstrap::BootstrapConnector::connect_multiple`]. Bounds
+    /// the QUIC handshakes, and their CPU and file descriptors, when
+    /// reconnecting from a large peer cache. `0` is treated as 1.
+    /// Default: 16.
+    #[serde(default = "default_max_concurrent_connects")]
+    pub max_concurrent_connects: usize,
+
     /// X0X-0062 reviewer P2 #2: surface ant-quic's best-effort UPnP IGD
     /// port-mapping toggle at the x0x config layer so daemon operators on
     /// networks without IGD support (or with policy against it) can
@@ -274,6 +301,11 @@ pub struct NetworkConfig {
     /// `/peers`.
     #[serde(default)]
     pub observed_prefix_enabled: bool,
+    /// Schedule of the bootstrap retry rounds in
+    /// [`crate::Agent::join_network`]. Defaults to three rounds, 10 s then
+    /// 15 s apart, each delay jittered by ±20%.
+    #[serde(default)]
+    pub bootstrap_retry: BootstrapRetryConfig,
 }
 
 /// X0X-0070b: TOML-shaped configuration for the peer-relay fallback
@@ -437,6 +469,91 @@ impl PeerRelayConfig {
     }
 }
 
+/// TOML-shaped schedule for the bootstrap connection rounds of
+/// [`crate::Agent::join_network`]. Round 1 runs immediately; before each
+/// later round the agent sleeps `initial_delay_ms × multiplier^(round − 2)`,
+/// capped at `max_delay_ms` and then jittered by up to `±jitter` of itself
+/// so agents restarted together do not redial the bootstrap nodes in
+/// lockstep.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BootstrapRetryConfig {
+    /// Connection rounds in total, including the first. `1` disables
+    /// retries. Defaults to `3`.
+    #[serde(default = "default_bootstrap_retry_max_rounds")]
+    pub max_rounds: u32,
+
+    /// Delay (milliseconds) before round 2. Defaults to `10000`.
+    #[serde(default = "default_bootstrap_retry_initial_delay_ms")]
+    pub initial_delay_ms: u64,
+
+    /// Growth factor per further round. Defaults to `1.5` (10 s, then
+    /// 15 s). Values below `1` are treated as `1`.
+    #[serde(default = "default_bootstrap_retry_multiplier")]
+    pub multiplier: f64,
+
+    /// Cap (milliseconds) on any delay before jitter. Defaults to `60000`.
+    #[serde(default = "default_bootstrap_retry_max_delay_ms")]
+    pub max_delay_ms: u64,
+
+    /// Random jitter as a fraction of each delay. Defaults to `0.2`
+    /// (±20%); clamped to `[0, 1]`.
+    #[serde(default = "default_bootstrap_retry_jitter")]
+    pub jitter: f64,
+}
+
+fn default_bootstrap_retry_max_rounds() -> u32 {
+    3
+}
+
+fn default_bootstrap_retry_initial_delay_ms() -> u64 {
+    10_000
+}
+
+fn default_bootstrap_retry_multiplier() -> f64 {
+    1.5
+}
+
+fn default_bootstrap_retry_max_delay_ms() -> u64 {
+    60_000
+}
+
+fn default_bootstrap_retry_jitter() -> f64 {
+    0.2
+}
+
+impl Default for BootstrapRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_rounds: default_bootstrap_retry_max_rounds(),
+            initial_delay_ms: default_bootstrap_retry_initial_delay_ms(),
+            multiplier: default_bootstrap_retry_multiplier(),
+            max_delay_ms: default_bootstrap_retry_max_delay_ms(),
+            jitter: default_bootstrap_retry_jitter(),
+        }
+    }
+}
+
+impl BootstrapRetryConfig {
+    /// Delay before `round` (2 or later) with a random jitter sample.
+    #[must_use]
+    pub fn jittered_delay(&self, round: u32) -> Duration {
+        use rand::Rng as _;
+        self.delay_before_round(round, rand::thread_rng().gen_range(-1.0..=1.0))
+    }
+
+    /// Delay before `round` (2 or later) for a jitter `sample` in
+    /// `[-1, 1]`: `-1` shortens the base delay by the full jitter fraction,
+    /// `1` lengthens it by the same amount.
+    #[must_use]
+    pub fn delay_before_round(&self, round: u32, sample: f64) -> Duration {
+        let exponent = i32::try_from(round.saturating_sub(2)).unwrap_or(i32::MAX);
+        let base_ms = (self.initial_delay_ms as f64 * self.multiplier.max(1.0).powi(exponent))
+            .min(self.max_delay_ms as f64);
+        let jitter = self.jitter.clamp(0.0, 1.0) * sample.clamp(-1.0, 1.0);
+        Duration::from_secs_f64((base_ms * (1.0 + jitter)).max(0.0) / 1_000.0)
+    }
+}
+
 fn default_max_connections() -> u32 {
     DEFAULT_MAX_CONNECTIONS
 }
@@ -457,6 +574,71 @@ fn default_max_peers_per_ip() -> u32 {
     3
 }
 
+fn default_max_concurrent_connects() -> usize {
+    DEFAULT_MAX_CONCURRENT_CONNECTS
+}
+
+/// Parse a human-friendly duration: an integer followed by `ms`, `s`, `m`
+/// or `h` (`"250ms"`, `"30s"`, `"5m"`, `"1h"`). A bare integer is seconds.
+///
+/// # Errors
+///
+/// Returns a description of the problem for a missing or non-integer
+/// amount, an unknown unit, or an overflowing value.
+pub fn parse_duration(text: &str) -> Result<Duration, String> {
+    let text = text.trim();
+    let split = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    let (amount, unit) = text.split_at(split);
+    let amount: u64 = amount
+        .parse()
+        .map_err(|_| format!("invalid duration {text:?}: expected e.g. \"30s\""))?;
+    let millis_per_unit = match unit.trim() {
+        "ms" => 1,
+        "" | "s" => 1_000,
+        "m" => 60_000,
+        "h" => 3_600_000,
+        other => {
+            return Err(format!(
+                "invalid duration {text:?}: unknown unit {other:?} (use ms, s, m or h)"
+            ))
+        }
+    };
+    amount
+        .checked_mul(millis_per_unit)
+        .map(Duration::from_millis)
+        .ok_or_else(|| format!("invalid duration {text:?}: too large"))
+}
+
+/// Deserialize a non-zero [`Duration`] from a [`parse_duration`] string, an
+/// integer number of seconds, or serde's default `{ secs, nanos }` form
+/// (what [`NetworkConfig`] serializes to).
+fn deserialize_nonzero_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum DurationRepr {
+        Text(String),
+        Secs(u64),
+        Struct { secs: u64, nanos: u32 },
+    }
+
+    let duration = match DurationRepr::deserialize(deserializer)? {
+        DurationRepr::Text(text) => parse_duration(&text).map_err(serde::de::Error::custom)?,
+        DurationRepr::Secs(secs) => Duration::from_secs(secs),
+        DurationRepr::Struct { secs, nanos } => Duration::new(secs, nanos),
+    };
+    if duration.is_zero() {
+        return Err(serde::de::Error::custom(
+            "duration must be greater than zero",
+        ));
+    }
+    Ok(duration)
+}
+
 /// Quick check whether the host can bind an IPv6 socket.
 ///
 /// Returns `false` if IPv6 is not available (e.g., containers, VMs,
@@ -486,10 +668,12 @@ impl Default for NetworkConfig {
             pinned_bootstrap_peers: std::collections::HashSet::new(),
             inbound_allowlist: std::collections::HashSet::new(),
             max_peers_per_ip: 3,
+            max_concurrent_connects: DEFAULT_MAX_CONCURRENT_CONNECTS,
             port_mapping_enabled: true,
             peer_relay: PeerRelayConfig::default(),
             network_id: None,
             observed_prefix_enabled: false,
+            bootstrap_retry: BootstrapRetryConfig::default(),
         }
     }
 }
@@ -509,6 +693,81 @@ pub struct NetworkStats {
     pub peer_count: usize,
 }
 
+/// One bootstrap-cache peer in human-readable form.
+///
+/// ant-quic persists its cache as raw bytes and `SystemTime` structs; this
+/// is the operator-facing shape served by `GET /peers/cache`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PeerCacheEntry {
+    /// Peer ID as 64 hex characters.
+    pub peer_id: String,
+    /// Known socket addresses for the peer.
+    pub addresses: Vec<String>,
+    /// Successful connections recorded for the peer.
+    pub success_count: u32,
+    /// Failed connection attempts recorded for the peer.
+    pub failure_count: u32,
+    /// Total connection attempts (successes + failures).
+    pub attempts: u32,
+    /// Average RTT in milliseconds (0 when never measured).
+    pub avg_rtt_ms: u32,
+    /// ant-quic quality score in `[0.0, 1.0]`.
+    pub quality_score: f64,
+    /// Last time the peer was seen, in Unix seconds.
+    pub last_seen: u64,
+}
+
+impl PeerCacheEntry {
+    fn from_cached(peer: &ant_quic::bootstrap_cache::CachedPeer) -> Self {
+        let stats = &peer.stats;
+        Self {
+            peer_id: hex::encode(peer.peer_id.0),
+            addresses: peer.addresses.iter().map(ToString::to_string).collect(),
+            success_count: stats.success_count,
+            failure_count: stats.failure_count,
+            attempts: stats.success_count.saturating_add(stats.failure_count),
+            avg_rtt_ms: stats.avg_rtt_ms,
+            quality_score: peer.quality_score,
+            last_seen: peer
+                .last_seen
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+        }
+    }
+}
+
+/// Connection quality for one connected peer, sampled from ant-quic's
+/// connection health and transport stats.
+///
+/// Served by [`NetworkNode::peer_metrics`] from a cache refreshed every
+/// [`NetworkConfig::stats_interval`], or on demand with
+/// [`NetworkNode::refresh_peer_metrics`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeerMetrics {
+    /// Smoothed round-trip time; `None` when transport stats were unavailable.
+    pub rtt: Option<Duration>,
+    /// Packets sent on the connection.
+    pub sent_packets: Option<u64>,
+    /// Packets declared lost on the connection.
+    pub lost_packets: Option<u64>,
+    /// Most recent send or receive on the connection.
+    pub last_activity: Option<Instant>,
+    /// When this sample was taken.
+    pub sampled_at: Instant,
+}
+
+impl PeerMetrics {
+    /// Fraction of sent packets declared lost, in `[0.0, 1.0]`. `None`
+    /// until a packet has been sent.
+    #[must_use]
+    pub fn loss_rate(&self) -> Option<f64> {
+        match (self.lost_packets, self.sent_packets) {
+            (Some(lost), Some(sent)) if sent > 0 => Some((lost as f64 / sent as f64).min(1.0)),
+            _ => None,
+        }
+    }
+}
+
 /// Snapshot of the x0x-side QUIC connection pool.
 #[derive(Debug, Clone, Serialize)]
 pub struct ConnectionPoolDiagnosticsSnapshot {
@@ -1385,6 +1644,32 @@ async fn forward_gossip_payload(
     }
 }
 
+async fn sample_peer_metrics(node: &Node, peer_id: &AntPeerId) -> PeerMetrics {
+    let health = node.connection_health(peer_id).await;
+    let transport = node.connection_transport_stats(peer_id).await;
+    PeerMetrics {
+        rtt: transport
+            .as_ref()
+            .map(|ts| Duration::from_millis(ts.rtt_ms)),
+        sent_packets: transport.as_ref().map(|ts| ts.sent_packets),
+        lost_packets: transport.as_ref().map(|ts| ts.lost_packets),
+        last_activity: health.last_sent_at.max(health.last_received_at),
+        sampled_at: Instant::now(),
+    }
+}
+
+/// Re-sample every connected peer, dropping cached entries for peers that
+/// have disconnected.
+async fn refresh_peer_metrics_cache(node: &Node, cache: &Mutex<HashMap<AntPeerId, PeerMetrics>>) {
+    let mut sampled = HashMap::new();
+    for conn in node.connected_peers().await {
+        sampled.insert(conn.peer_id, sample_peer_metrics(node, &conn.peer_id).await);
+    }
+    *cache
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = sampled;
+}
+
 async fn disconnect_pool_candidates(
     node: &Node,
     event_sender: &broadcast::Sender<NetworkEvent>,
@@ -1437,6 +1722,108 @@ async fn disconnect_pool_candidates(
     }
 }
 
+/// Default number of idle buffers a [`FrameBufferPool`] keeps.
+pub const FRAME_POOL_DEFAULT_MAX_BUFFERS: usize = 64;
+
+/// Default largest capacity a [`FrameBufferPool`] will retain. Larger
+/// buffers (bulk sync, file chunks) are freed instead of pinning memory.
+pub const FRAME_POOL_DEFAULT_MAX_RETAINED_CAPACITY: usize = 64 * 1024;
+
+/// Reusable outbound frame buffers for the hot send paths.
+///
+/// Every gossip or direct send prefixes its body with a stream-type byte
+/// (and sometimes the sender id) before handing one contiguous slice to
+/// ant-quic. Allocating a fresh `Vec` per frame churns the allocator on
+/// busy agents; the pool hands out cleared buffers instead and takes them
+/// back when the [`PooledFrame`] is dropped.
+#[derive(Debug)]
+pub struct FrameBufferPool {
+    buffers: Mutex<Vec<Vec<u8>>>,
+    max_buffers: usize,
+    max_retained_capacity: usize,
+}
+
+impl Default for FrameBufferPool {
+    fn default() -> Self {
+        Self::new(
+            FRAME_POOL_DEFAULT_MAX_BUFFERS,
+            FRAME_POOL_DEFAULT_MAX_RETAINED_CAPACITY,
+        )
+    }
+}
+
+impl FrameBufferPool {
+    /// Create a pool keeping at most `max_buffers` idle buffers, each no
+    /// larger than `max_retained_capacity` bytes.
+    #[must_use]
+    pub fn new(max_buffers: usize, max_retained_capacity: usize) -> Self {
+        Self {
+            buffers: Mutex::new(Vec::new()),
+            max_buffers,
+            max_retained_capacity,
+        }
+    }
+
+    /// Concatenate `parts` into a pooled buffer.
+    ///
+    /// The buffer is always cleared before reuse, so a frame never carries
+    /// bytes from a previous message.
+    pub fn frame(&self, parts: &[&[u8]]) -> PooledFrame<'_> {
+        let len: usize = parts.iter().map(|part| part.len()).sum();
+        let mut buf = self
+            .buffers
+            .lock()
+            .ok()
+            .and_then(|mut buffers| buffers.pop())
+            .unwrap_or_default();
+        buf.clear();
+        buf.reserve(len);
+        for part in parts {
+            buf.extend_from_slice(part);
+        }
+        PooledFrame { pool: self, buf }
+    }
+
+    /// Number of idle buffers currently held.
+    #[must_use]
+    pub fn idle(&self) -> usize {
+        self.buffers.lock().map_or(0, |buffers| buffers.len())
+    }
+
+    fn give_back(&self, mut buf: Vec<u8>) {
+        if buf.capacity() > self.max_retained_capacity {
+            return;
+        }
+        buf.clear();
+        if let Ok(mut buffers) = self.buffers.lock() {
+            if buffers.len() < self.max_buffers {
+                buffers.push(buf);
+            }
+        }
+    }
+}
+
+/// A frame borrowed from a [`FrameBufferPool`]; returned to it on drop.
+#[derive(Debug)]
+pub struct PooledFrame<'a> {
+    pool: &'a FrameBufferPool,
+    buf: Vec<u8>,
+}
+
+impl std::ops::Deref for PooledFrame<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.buf
+    }
+}
+
+impl Drop for PooledFrame<'_> {
+    fn drop(&mut self) {
+        self.pool.give_back(std::mem::take(&mut self.buf));
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct NetworkNode {
     /// ant-quic P2P node (wrapped in `Arc<RwLock>` for shared async access).
@@ -1503,6 +1890,8 @@ pub struct NetworkNode {
     /// connection churn cannot black-hole its gossip frames. Purged on a
     /// mismatched hello.
     plane_cleared_at: Arc<Mutex<HashMap<AntPeerId, Instant>>>,
+    /// Per-peer [`PeerMetrics`], refreshed every `config.stats_interval`.
+    peer_metrics: Arc<Mutex<HashMap<AntPeerId, PeerMetrics>>>,
     /// Handles to the background tasks spawned at construction (receiver, accept
     /// loop, connection-pool eviction).
     ///
@@ -1513,6 +1902,8 @@ pub struct NetworkNode {
     /// never receives another packet/connection. (Note: ant-quic frees the bound
     /// UDP socket only on process exit — saorsa-labs/ant-quic#196.)
     background_tasks: Arc<Mutex<Vec<tokio::task::JoinHandle<()>>>>,
+    /// Reusable buffers for framing outbound gossip and direct sends.
+    frame_pool: Arc<FrameBufferPool>,
 }
 
 impl NetworkNode {
@@ -1670,25 +2061,30 @@ impl NetworkNode {
             reconnect_suppressions: Arc::new(Mutex::new(HashMap::new())),
             plane_peers: Arc::new(Mutex::new(HashMap::new())),
             plane_cleared_at: Arc::new(Mutex::new(HashMap::new())),
+            peer_metrics: Arc::new(Mutex::new(HashMap::new())),
             background_tasks: Arc::new(Mutex::new(Vec::new())),
+            frame_pool: Arc::new(FrameBufferPool::default()),
         };
 
         let receiver = network_node.spawn_receiver();
         let accept = network_node.spawn_accept_loop();
         let eviction = network_node.spawn_connection_pool_eviction();
         let plane_gatekeeper = network_node.spawn_plane_gatekeeper();
+        let metrics = network_node.spawn_peer_metrics_refresh();
         // Record the handles so `shutdown` can abort them (letting it take the
         // node write lock and shut the node down without deadlocking). This runs
         // at construction before the node is shared, so there is no contention;
         // if the lock is somehow poisoned, recover the guard rather than panic
         // (the handles are only used for clean teardown).
         match network_node.background_tasks.lock() {
-            Ok(mut tasks) => tasks.extend([receiver, accept, eviction, plane_gatekeeper]),
-            Err(poisoned) => {
-                poisoned
-                    .into_inner()
-                    .extend([receiver, accept, eviction, plane_gatekeeper])
-            }
+            Ok(mut tasks) => tasks.extend([receiver, accept, eviction, plane_gatekeeper, metrics]),
+            Err(poisoned) => poisoned.into_inner().extend([
+                receiver,
+                accept,
+                eviction,
+                plane_gatekeeper,
+                metrics,
+            ]),
         }
 
         Ok(network_node)
@@ -1709,10 +2105,48 @@ impl NetworkNode {
     /// [`ant_quic::Node::bootstrap_cache`]) — enrich and query it freely,
     /// but do not call `start_maintenance` on it: the endpoint already
     /// runs maintenance.
+    ///
+    /// Lifecycle: the cache is loaded from its directory when the node is
+    /// built and seeds reconnection; every inbound and outbound connection
+    /// records the peer, its address and a success, and failed dials record
+    /// a failure against peers cached at that address. Endpoint maintenance
+    /// saves it periodically and [`crate::Agent::shutdown`] saves it once
+    /// more on the way out.
     pub fn bootstrap_cache(&self) -> Option<Arc<ant_quic::BootstrapCache>> {
         self.bootstrap_cache.clone()
     }
 
+    /// Human-readable dump of the bootstrap peer cache, best peers first.
+    ///
+    /// Empty when the node runs without a cache.
+    pub async fn peer_cache_entries(&self) -> Vec<PeerCacheEntry> {
+        let Some(cache) = self.bootstrap_cache.as_ref() else {
+            return Vec::new();
+        };
+        let mut entries: Vec<PeerCacheEntry> = cache
+            .all_peers()
+            .await
+            .iter()
+            .map(PeerCacheEntry::from_cached)
+            .collect();
+        entries.sort_by(|a, b| {
+            b.quality_score
+                .total_cmp(&a.quality_score)
+                .then_with(|| a.peer_id.cmp(&b.peer_id))
+        });
+        entries
+    }
+
+    /// [`Self::peer_cache_entries`] rendered as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NetworkError::CacheError`] if encoding fails.
+    pub async fn peer_cache_json(&self) -> NetworkResult<String> {
+        serde_json::to_string_pretty(&self.peer_cache_entries().await)
+            .map_err(|e| NetworkError::CacheError(e.to_string()))
+    }
+
     /// Get the configured bind address (may contain port 0 before binding).
     ///
     /// # Returns
@@ -1921,6 +2355,29 @@ impl NetworkNode {
         let _ = self.event_sender.send(event);
     }
 
+    /// Cached connection quality for a connected peer.
+    ///
+    /// Refreshed every [`NetworkConfig::stats_interval`]; `None` for a peer
+    /// that was not connected at the last refresh. Call
+    /// [`Self::refresh_peer_metrics`] first when a fresh sample matters.
+    #[must_use]
+    pub fn peer_metrics(&self, peer_id: &AntPeerId) -> Option<PeerMetrics> {
+        self.peer_metrics
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(peer_id)
+            .cloned()
+    }
+
+    /// Re-sample [`PeerMetrics`] for every connected peer now instead of
+    /// waiting for the next periodic refresh.
+    pub async fn refresh_peer_metrics(&self) {
+        let Some(node) = self.node.read().await.as_ref().cloned() else {
+            return;
+        };
+        refresh_peer_metrics_cache(&node, &self.peer_metrics).await;
+    }
+
     /// Snapshot x0x-side connection-pool diagnostics.
     #[must_use]
     pub fn connection_pool_diagnostics(&self) -> ConnectionPoolDiagnosticsSnapshot {
@@ -2387,6 +2844,7 @@ impl NetworkNode {
                 self.emit_event(NetworkEvent::PeerConnected {
                     peer_id: peer_conn.peer_id.0,
                     address: addr,
+                    direction: ConnectionDirection::Outbound,
                 });
                 self.note_connection_pool_activity(peer_conn.peer_id).await;
                 tracing::info!(
@@ -2473,6 +2931,7 @@ impl NetworkNode {
         self.emit_event(NetworkEvent::PeerConnected {
             peer_id: peer_conn.peer_id.0,
             address: addr,
+            direction: ConnectionDirection::Outbound,
         });
         self.note_connection_pool_activity(peer_conn.peer_id).await;
 
@@ -2550,6 +3009,7 @@ impl NetworkNode {
         self.emit_event(NetworkEvent::PeerConnected {
             peer_id: peer_conn.peer_id.0,
             address: addr,
+            direction: ConnectionDirection::Outbound,
         });
         self.note_connection_pool_activity(peer_conn.peer_id).await;
 
@@ -2639,6 +3099,32 @@ impl NetworkNode {
         Ok(())
     }
 
+    /// Disconnect every connected peer while keeping the node running.
+    ///
+    /// Each peer is torn down with [`DisconnectReason::Admin`], so a
+    /// [`NetworkEvent::PeerDisconnected`] is emitted per peer and automatic
+    /// redial is suppressed, but the endpoint stays bound: new inbound and
+    /// explicit outbound connections are accepted as usual. Failures on
+    /// individual peers are logged and do not stop the sweep.
+    ///
+    /// Returns the number of peers disconnected.
+    pub async fn disconnect_all(&self) -> usize {
+        let mut disconnected = 0;
+        for peer_id in self.connected_peers().await {
+            match self
+                .disconnect_with_reason(&peer_id, DisconnectReason::Admin)
+                .await
+            {
+                Ok(()) => disconnected += 1,
+                Err(e) => warn!(
+                    peer_id_prefix = %hex_prefix(&peer_id.0, 4),
+                    "disconnect_all: failed to disconnect peer: {e}"
+                ),
+            }
+        }
+        disconnected
+    }
+
     /// Record a reconnect-suppression tombstone for `peer_id`.
     ///
     /// Idempotent: re-recording refreshes `set_at`. A no-op for
@@ -3185,13 +3671,12 @@ impl NetworkNode {
         self.get_or_connect_pooled_peer(peer_id).await?;
 
         // Wire format: [stream_type][sender_agent_id: 32 bytes][payload]
-        let mut buf = Vec::with_capacity(1 + 32 + payload.len());
-        buf.push(stream_type);
-        buf.extend_from_slice(sender_agent_id);
-        buf.extend_from_slice(payload);
+        let buf = self
+            .frame_pool
+            .frame(&[&[stream_type], sender_agent_id, payload]);
 
         let node = self.require_node().await?;
-        node.send(peer_id, &buf)
+        node.send(peer_id, &buf[..])
             .await
             .map_err(|e| NetworkError::ConnectionFailed(format!("send failed: {}", e)))?;
         self.note_connection_pool_activity(*peer_id).await;
@@ -3206,6 +3691,82 @@ impl NetworkNode {
         Ok(())
     }
 
+    /// Send a gossip-framed message, reconnecting to `addr` if the peer is
+    /// not connected and retrying with exponential backoff.
+    ///
+    /// Each attempt dials `addr` first when `peer` is not connected, then
+    /// sends as [`saorsa_gossip_transport::GossipTransport::send_to_peer`]
+    /// does. Up to `retries` further attempts follow a failure, waiting
+    /// [`SEND_RETRY_BASE_BACKOFF`] doubled per attempt (capped at
+    /// [`SEND_RETRY_MAX_BACKOFF`]) in between, so a transient disconnect
+    /// from churn does not lose the message.
+    ///
+    /// # Errors
+    ///
+    /// [`SendRetryError::ConnectFailed`] if the last attempt could not
+    /// connect (including `addr` answering as a different peer),
+    /// [`SendRetryError::SendFailed`] if it connected but the send failed.
+    ///
+    /// [`SendRetryError::ConnectFailed`]: crate::error::SendRetryError::ConnectFailed
+    /// [`SendRetryError::SendFailed`]: crate::error::SendRetryError::SendFailed
+    pub async fn send_with_retry(
+        &self,
+        peer: AntPeerId,
+        addr: SocketAddr,
+        stream_type: GossipStreamType,
+        data: Bytes,
+        retries: u32,
+    ) -> Result<(), crate::error::SendRetryError> {
+        use crate::error::SendRetryError;
+
+        let mut backoff = SEND_RETRY_BASE_BACKOFF;
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let failure = match self.connect_for_retry(peer, addr).await {
+                Err(source) => SendRetryError::ConnectFailed {
+                    addr,
+                    attempts: attempt,
+                    source,
+                },
+                Ok(()) => match <Self as saorsa_gossip_transport::GossipTransport>::send_to_peer(
+                    self,
+                    ant_to_gossip_peer_id(&peer),
+                    stream_type,
+                    data.clone(),
+                )
+                .await
+                {
+                    Ok(()) => return Ok(()),
+                    Err(e) => SendRetryError::SendFailed {
+                        attempts: attempt,
+                        reason: e.to_string(),
+                    },
+                },
+            };
+            if attempt > retries {
+                return Err(failure);
+            }
+            debug!("send_with_retry: attempt {attempt} to {peer:?} failed ({failure}); retrying in {backoff:?}");
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(SEND_RETRY_MAX_BACKOFF);
+        }
+    }
+
+    /// Make sure `peer` is connected, dialling `addr` if it is not.
+    async fn connect_for_retry(&self, peer: AntPeerId, addr: SocketAddr) -> NetworkResult<()> {
+        if self.is_connected(&peer).await {
+            return Ok(());
+        }
+        let connected = self.connect_addr(addr).await?;
+        if connected != peer {
+            return Err(NetworkError::ConnectionFailed(format!(
+                "{addr} answered as {connected:?}, expected {peer:?}"
+            )));
+        }
+        Ok(())
+    }
+
     /// Receive the next direct message.
     ///
     /// Blocks until a direct message is received. Returns the sender's
@@ -3376,6 +3937,9 @@ impl NetworkNode {
                         if data.is_empty() {
                             continue;
                         }
+                        // Own the frame once so every arm below can hand out
+                        // zero-copy `slice`s instead of copying the body.
+                        let data = Bytes::from(data);
 
                         // Parse stream type from first byte (safe: data is non-empty)
                         let type_byte = data[0];
@@ -3383,7 +3947,7 @@ impl NetworkNode {
                         // Handle direct messages separately (0x10)
                         if type_byte == DIRECT_MESSAGE_STREAM_TYPE {
                             // Direct message: forward to direct channel (includes full payload with sender AgentId)
-                            let payload = Bytes::copy_from_slice(&data[1..]);
+                            let payload = data.slice(1..);
 
                             // Enforce max payload size (16 MB) to prevent memory exhaustion
                             // payload = 32-byte AgentId prefix + actual data, so effective
@@ -3536,7 +4100,7 @@ impl NetworkNode {
                         };
 
                         // Extract payload (everything after the type byte)
-                        let payload = Bytes::copy_from_slice(&data[1..]);
+                        let payload = data.slice(1..);
 
                         debug!(
                             "[1/6 network] recv: {} bytes ({:?}) from peer {:?}",
@@ -3693,6 +4257,7 @@ impl NetworkNode {
                         let _ = event_sender.send(NetworkEvent::PeerConnected {
                             peer_id: peer_conn.peer_id.0,
                             address: addr,
+                            direction: ConnectionDirection::Inbound,
                         });
                         let evicted = connection_pool.note_activity(peer_conn.peer_id);
                         if !evicted.is_empty() {
@@ -3718,6 +4283,28 @@ impl NetworkNode {
         })
     }
 
+    fn spawn_peer_metrics_refresh(&self) -> tokio::task::JoinHandle<()> {
+        let node = Arc::clone(&self.node);
+        let cache = Arc::clone(&self.peer_metrics);
+        // Deserialization rejects zero, but a struct literal may still pass
+        // it, and `interval` panics on zero.
+        let period = self.config.stats_interval.max(Duration::from_secs(1));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+
+                let Some(node_ref) = node.read().await.as_ref().cloned() else {
+                    debug!("Node not initialized, peer metrics refresh stopping");
+                    break;
+                };
+                refresh_peer_metrics_cache(&node_ref, &cache).await;
+            }
+        })
+    }
+
     fn spawn_connection_pool_eviction(&self) -> tokio::task::JoinHandle<()> {
         let node = Arc::clone(&self.node);
         let event_sender = self.event_sender.clone();
@@ -4001,9 +4588,7 @@ impl saorsa_gossip_transport::GossipTransport for NetworkNode {
         }
 
         // Prepare message: [stream_type_byte | data]
-        let mut buf = Vec::with_capacity(1 + data.len());
-        buf.push(stream_type.to_byte());
-        buf.extend_from_slice(&data);
+        let buf = self.frame_pool.frame(&[&[stream_type.to_byte()], &data]);
 
         // Send via ant-quic Node
         //
@@ -4015,7 +4600,7 @@ impl saorsa_gossip_transport::GossipTransport for NetworkNode {
             .as_ref()
             .ok_or_else(|| anyhow::anyhow!("node not initialized"))?;
 
-        node.send(&ant_peer, &buf)
+        node.send(&ant_peer, &buf[..])
             .await
             .map_err(|e| anyhow::anyhow!("send failed: {}", e))?;
         drop(node_guard);
@@ -4187,6 +4772,15 @@ fn reconnect_suppression_is_live(
     live
 }
 
+/// Which side opened a connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionDirection {
+    /// The remote peer dialed this node.
+    Inbound,
+    /// This node dialed the remote peer.
+    Outbound,
+}
+
 /// Events emitted by the network node.
 #[derive(Debug, Clone)]
 pub enum NetworkEvent {
@@ -4196,6 +4790,8 @@ pub enum NetworkEvent {
         peer_id: [u8; 32],
         /// The peer's address.
         address: SocketAddr,
+        /// Whether we dialed the peer or it dialed us.
+        direction: ConnectionDirection,
     },
 
     /// A peer disconnected.
@@ -4453,6 +5049,35 @@ mod tests {
         assert_eq!(snapshot.lru_evictions_total, 1);
     }
 
+    /// A reused frame buffer must never expose bytes from the previous
+    /// message — a short DM following a long one would otherwise ship the
+    /// tail of someone else's payload.
+    #[test]
+    fn pooled_frames_never_leak_previous_message_bytes() {
+        let pool = FrameBufferPool::default();
+        let long = pool.frame(&[&[0x10], &[0xAA; 32], b"a much longer secret body"]);
+        let long_ptr = long.as_ptr();
+        drop(long);
+        assert_eq!(pool.idle(), 1);
+
+        let short = pool.frame(&[&[0x00], b"hi"]);
+        assert_eq!(short.as_ptr(), long_ptr, "buffer should be reused");
+        assert_eq!(&short[..], &[0x00, b'h', b'i']);
+    }
+
+    #[test]
+    fn frame_pool_bounds_retained_buffers() {
+        let pool = FrameBufferPool::new(1, 16);
+        drop(pool.frame(&[&[0u8; 64]]));
+        assert_eq!(pool.idle(), 0, "oversized buffers are freed");
+
+        let a = pool.frame(&[b"a"]);
+        let b = pool.frame(&[b"b"]);
+        drop(a);
+        drop(b);
+        assert_eq!(pool.idle(), 1, "idle buffers capped at max_buffers");
+    }
+
     #[test]
     fn pre_send_probe_not_needed_for_fresh_ready_connection() {
         let health = ant_quic::ConnectionHealth {
@@ -4575,6 +5200,7 @@ async fn test_network_node_subscribe_events() {
     let event = NetworkEvent::PeerConnected {
         peer_id: [1; 32],
         address: "127.0.0.1:9000".parse().unwrap(),
+        direction: ConnectionDirection::Outbound,
     };
     node.emit_event(event);
 
@@ -4583,9 +5209,14 @@ async fn test_network_node_subscribe_events() {
     assert!(received.is_ok());
 
     match received.unwrap() {
-        NetworkEvent::PeerConnected { peer_id, address } => {
+        NetworkEvent::PeerConnected {
+            peer_id,
+            address,
+            direction,
+        } => {
             assert_eq!(peer_id, [1; 32]);
             assert_eq!(address, "127.0.0.1:9000".parse().unwrap());
+            assert_eq!(direction, ConnectionDirection::Outbound);
         }
         _ => panic!("Expected PeerConnected event"),
     }
@@ -4690,6 +5321,285 @@ async fn network_node_in_memory_cache_writes_nothing() {
     assert_eq!(cache.peer_count().await, 1, "runtime behaviour unchanged");
 }
 
+/// Connections feed the cache, and a node built on the same cache
+/// directory starts out knowing the peers the last one saved.
+#[tokio::test]
+async fn connections_are_cached_and_survive_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache_dir = dir.path().join("peers");
+    let cache_dir = cache_dir.as_path();
+    let cached_node = || async move {
+        let cache_config = ant_quic::BootstrapCacheConfig::builder()
+            .cache_dir(cache_dir)
+            .min_peers_to_save(1)
+            .build();
+        let config = NetworkConfig {
+            bind_addr: Some("127.0.0.1:0".parse().unwrap()),
+            bootstrap_nodes: Vec::new(),
+            port_mapping_enabled: false,
+            ..NetworkConfig::default()
+        };
+        NetworkNode::new(config, Some(cache_config), None)
+            .await
+            .unwrap()
+    };
+
+    let alice = cached_node().await;
+    let (_bob, bob_addr) = loopback_node().await;
+    let bob_id = alice.connect_addr(bob_addr).await.unwrap();
+    let bob_hex = hex::encode(bob_id.0);
+
+    let entries = alice.peer_cache_entries().await;
+    let entry = entries
+        .iter()
+        .find(|e| e.peer_id == bob_hex)
+        .expect("connected peer cached");
+    assert!(entry.addresses.contains(&bob_addr.to_string()));
+    assert!(entry.success_count >= 1);
+
+    alice
+        .bootstrap_cache()
+        .expect("cache configured")
+        .save()
+        .await
+        .unwrap();
+    alice.shutdown().await;
+
+    let restarted = cached_node().await;
+    assert!(
+        restarted
+            .peer_cache_entries()
+            .await
+            .iter()
+            .any(|e| e.peer_id == bob_hex),
+        "saved peers are loaded on startup"
+    );
+}
+
+/// The peer-cache dump is what operators read when a node won't rejoin the
+/// mesh, so it must show the hex id, address and recorded outcomes rather
+/// than ant-quic's opaque on-disk encoding.
+#[tokio::test]
+async fn peer_cache_json_is_human_readable() {
+    let cache_config = ant_quic::BootstrapCacheConfig::builder()
+        .persist(false)
+        .build();
+    let config = NetworkConfig {
+        bind_addr: Some("127.0.0.1:0".parse().unwrap()),
+        bootstrap_nodes: Vec::new(),
+        ..NetworkConfig::default()
+    };
+    let node = NetworkNode::new(config, Some(cache_config), None)
+        .await
+        .unwrap();
+    let cache = node.bootstrap_cache().expect("cache configured");
+    let peer = ant_quic::PeerId([0xab; 32]);
+    cache
+        .add_seed(peer, vec!["127.0.0.1:9000".parse().unwrap()])
+        .await;
+    cache.record_success(&peer, 12).await;
+    cache.record_failure(&peer).await;
+
+    let entries = node.peer_cache_entries().await;
+    assert_eq!(entries.len(), 1);
+    let entry = &entries[0];
+    assert_eq!(entry.peer_id, "ab".repeat(32));
+    assert_eq!(entry.addresses, vec!["127.0.0.1:9000".to_string()]);
+    assert_eq!(entry.success_count, 1);
+    assert_eq!(entry.failure_count, 1);
+    assert_eq!(entry.attempts, 2);
+    assert!(entry.last_seen > 0);
+
+    let json: serde_json::Value =
+        serde_json::from_str(&node.peer_cache_json().await.unwrap()).unwrap();
+    assert_eq!(json[0]["peer_id"], "ab".repeat(32));
+    assert_eq!(json[0]["addresses"][0], "127.0.0.1:9000");
+}
+
+/// A test node on an ephemeral loopback port, without port mapping, and the
+/// address to dial it on.
+#[cfg(test)]
+async fn loopback_node() -> (NetworkNode, SocketAddr) {
+    let config = NetworkConfig {
+        bind_addr: Some("127.0.0.1:0".parse().unwrap()),
+        bootstrap_nodes: Vec::new(),
+        port_mapping_enabled: false,
+        ..NetworkConfig::default()
+    };
+    let node = NetworkNode::new(config, None, None).await.unwrap();
+    let port = node.bound_addr().await.expect("bound address").port();
+    let addr: SocketAddr = format!("127.0.0.1:{port}").parse().unwrap();
+    (node, addr)
+}
+
+/// `disconnect_all` must empty the peer set without tearing down the node:
+/// the endpoint keeps accepting connections afterwards.
+#[tokio::test]
+async fn disconnect_all_drops_peers_but_keeps_node_reachable() {
+    let wait_connected = |node: NetworkNode, peer: AntPeerId| async move {
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        while !node.is_connected(&peer).await {
+            assert!(std::time::Instant::now() < deadline, "peer never connected");
+            tokio::time::sleep(std::time::Duration::from_millis(25)).await;
+        }
+    };
+
+    let (alice, alice_addr) = loopback_node().await;
+    let (_bob, bob_addr) = loopback_node().await;
+    let mut events = alice.subscribe();
+
+    let bob_id = alice.connect_addr(bob_addr).await.unwrap();
+    wait_connected(alice.clone(), bob_id).await;
+
+    assert_eq!(alice.disconnect_all().await, 1);
+    assert!(alice.connected_peers().await.is_empty());
+    let disconnected = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+        loop {
+            if let Ok(NetworkEvent::PeerDisconnected { peer_id, reason }) = events.recv().await {
+                break (peer_id, reason);
+            }
+        }
+    })
+    .await
+    .expect("PeerDisconnected event");
+    assert_eq!(disconnected, (bob_id.0, DisconnectReason::Admin));
+
+    // The node is still alive: a fresh peer can dial in.
+    let (carol, _) = loopback_node().await;
+    let alice_id = carol.connect_addr(alice_addr).await.unwrap();
+    assert_eq!(alice_id, alice.peer_id());
+    wait_connected(alice.clone(), carol.peer_id()).await;
+}
+
+/// `peer_metrics` reports RTT and last activity for a connected peer once
+/// the cache is refreshed, and forgets the peer after it disconnects.
+#[tokio::test]
+async fn peer_metrics_report_rtt_and_activity_for_connected_peer() {
+    let (alice, _) = loopback_node().await;
+    let (_bob, bob_addr) = loopback_node().await;
+    let bob_id = alice.connect_addr(bob_addr).await.unwrap();
+    assert_eq!(alice.peer_metrics(&bob_id), None, "not sampled yet");
+
+    // Round-trip some application traffic so both directions are active.
+    alice
+        .probe_peer(bob_id, std::time::Duration::from_secs(5))
+        .await
+        .expect("node initialized")
+        .expect("probe");
+    alice.refresh_peer_metrics().await;
+
+    let metrics = alice.peer_metrics(&bob_id).expect("connected peer sampled");
+    assert!(metrics.rtt.is_some(), "rtt populated: {metrics:?}");
+    let last_activity = metrics.last_activity.expect("last activity populated");
+    assert!(last_activity <= metrics.sampled_at);
+
+    alice.disconnect(&bob_id).await.unwrap();
+    alice.refresh_peer_metrics().await;
+    assert_eq!(alice.peer_metrics(&bob_id), None);
+}
+
+/// `send_with_retry` dials a peer that is not connected yet, and refuses
+/// an address that answers as someone else.
+#[tokio::test]
+async fn send_with_retry_connects_lazily_and_checks_peer_identity() {
+    let (alice, _) = loopback_node().await;
+    let (bob, bob_addr) = loopback_node().await;
+    let (carol, carol_addr) = loopback_node().await;
+    let bob_id = bob.peer_id();
+    assert!(!alice.is_connected(&bob_id).await);
+
+    alice
+        .send_with_retry(
+            bob_id,
+            bob_addr,
+            GossipStreamType::Bulk,
+            Bytes::from_static(b"lazy hello"),
+            2,
+        )
+        .await
+        .expect("send after lazy connect");
+    assert!(alice.is_connected(&bob_id).await);
+
+    let (from, stream_type, data) =
+        tokio::time::timeout(std::time::Duration::from_secs(5), bob.receive_message())
+            .await
+            .expect("timed out waiting for frame")
+            .expect("receive");
+    assert_eq!(from, ant_to_gossip_peer_id(&alice.peer_id()));
+    assert_eq!(stream_type, GossipStreamType::Bulk);
+    assert_eq!(data.as_ref(), b"lazy hello");
+
+    // Carol's address does not belong to the peer we asked for.
+    let still_connected = alice
+        .send_with_retry(
+            bob_id,
+            carol_addr,
+            GossipStreamType::Bulk,
+            Bytes::from_static(b"to bob"),
+            0,
+        )
+        .await;
+    assert!(
+        still_connected.is_ok(),
+        "bob is still connected, so carol is never dialled"
+    );
+    alice.disconnect(&bob_id).await.unwrap();
+    let err = alice
+        .send_with_retry(
+            bob_id,
+            carol_addr,
+            GossipStreamType::Bulk,
+            Bytes::from_static(b"misrouted"),
+            1,
+        )
+        .await
+        .expect_err("wrong peer at address");
+    assert!(
+        matches!(
+            err,
+            crate::error::SendRetryError::ConnectFailed { attempts: 2, .. }
+        ),
+        "unexpected error: {err:?}"
+    );
+    drop(carol);
+}
+
+/// The dialer reports the connection as outbound, the acceptor as inbound.
+#[tokio::test]
+async fn peer_connected_reports_direction() {
+    let direction_for = |mut events: broadcast::Receiver<NetworkEvent>, peer: [u8; 32]| async move {
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            loop {
+                if let Ok(NetworkEvent::PeerConnected {
+                    peer_id, direction, ..
+                }) = events.recv().await
+                {
+                    if peer_id == peer {
+                        break direction;
+                    }
+                }
+            }
+        })
+        .await
+        .expect("PeerConnected event")
+    };
+
+    let (alice, _) = loopback_node().await;
+    let (bob, bob_addr) = loopback_node().await;
+    let alice_events = alice.subscribe();
+    let bob_events = bob.subscribe();
+
+    let bob_id = alice.connect_addr(bob_addr).await.unwrap();
+    assert_eq!(
+        direction_for(alice_events, bob_id.0).await,
+        ConnectionDirection::Outbound
+    );
+    assert_eq!(
+        direction_for(bob_events, alice.peer_id().0).await,
+        ConnectionDirection::Inbound
+    );
+}
+
 /// Test that connections between local nodes are bidirectionally visible.
 ///
 /// This reproduces the "phantom connection" bug where `connect_addr()` succeeds
@@ -4722,10 +5632,12 @@ async fn test_mesh_connections_are_bidirectional() {
             pinned_bootstrap_peers: std::collections::HashSet::new(),
             inbound_allowlist: std::collections::HashSet::new(),
             max_peers_per_ip: 3,
+            max_concurrent_connects: DEFAULT_MAX_CONCURRENT_CONNECTS,
             port_mapping_enabled: true,
             peer_relay: PeerRelayConfig::default(),
             network_id: None,
             observed_prefix_enabled: false,
+            bootstrap_retry: BootstrapRetryConfig::default(),
         };
 
         let node = NetworkNode::new(config, None, None).await.unwrap();
@@ -4821,13 +5733,34 @@ async fn test_mesh_connections_are_bidirectional() {
 /// Each message includes a unique ID, sender information, topic, payload,
 /// timestamp, and sequence number for ordering.
 ///
+/// # `Message` vs [`PubSubMessage`](crate::gossip::PubSubMessage)
+///
+/// `Message` is a self-describing, serializable envelope (JSON / bincode)
+/// for storing or forwarding a message outside the gossip pipeline. A
+/// `PubSubMessage` is what a [`Subscription`](crate::gossip::Subscription)
+/// delivers: the decoded wire frame plus the result of signature
+/// verification and the local trust lookup. They share `topic`, `payload`
+/// and the sender id; convert with `Message::try_from(pubsub_message)` and
+/// `PubSubMessage::from(message)`. The conversion back to `PubSubMessage` is
+/// always unverified; check a signed `Message` with [`Message::verify`]
+/// first.
+///
+/// # Signatures
+///
+/// [`Message::sign`] attaches an ML-DSA-65 signature over the id, sender,
+/// topic, payload, timestamp and sequence, made by the agent whose
+/// `AgentId` is `sender`. [`Message::verify`] checks it against that
+/// agent's public key. The signature is optional and omitted from the
+/// serialized form when absent, so unsigned messages encode exactly as
+/// before and older encodings still decode.
+///
 /// # Examples
 ///
 /// ```no_run
 /// use x0x::network::Message;
 ///
 /// let message = Message::new(
-///     [1; 32],  // sender peer_id
+///     [1; 32],  // sender AgentId bytes
 ///     "chat".to_string(),
 ///     b"Hello, world!".to_vec(),
 /// ).expect("Failed to create message");
@@ -4840,7 +5773,8 @@ pub struct Message {
     /// Unique message identifier (BLAKE3 hash of content).
     pub id: [u8; 32],
 
-    /// Sender's peer ID.
+    /// Sending agent's `AgentId` bytes (not its machine/peer ID); the key
+    /// [`Message::sign`] and [`Message::verify`] use.
     pub sender: [u8; 32],
 
     /// Topic for gossip pub/sub routing.
@@ -4854,14 +5788,33 @@ pub struct Message {
 
     /// Sequence number for total ordering of messages from a sender.
     pub sequence: u64,
+
+    /// ML-DSA-65 signature by `sender`; see [`Message::sign`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Vec<u8>>,
+}
+
+/// [`Message`] as encoded before it gained `signature`; bincode has no
+/// field names, so those encodings need their own shape to decode.
+#[derive(Deserialize)]
+struct UnsignedMessage {
+    id: [u8; 32],
+    sender: [u8; 32],
+    topic: String,
+    payload: Vec<u8>,
+    timestamp: u64,
+    sequence: u64,
 }
 
+/// Domain separator for [`Message`] signatures.
+const MESSAGE_SIGNATURE_DOMAIN: &[u8] = b"x0x-network-message-v1";
+
 impl Message {
     /// Create a new message with automatic timestamp and ID generation.
     ///
     /// # Arguments
     ///
-    /// * `sender` - The peer ID of the message sender.
+    /// * `sender` - The `AgentId` bytes of the sending agent.
     /// * `topic` - The topic string for routing.
     /// * `payload` - The message payload bytes.
     ///
@@ -4883,14 +5836,74 @@ impl Message {
             payload,
             timestamp,
             sequence: 0,
+            signature: None,
         })
     }
 
+    /// Bytes covered by the signature: every field except the signature,
+    /// with the variable-length ones length-prefixed.
+    fn signing_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(
+            MESSAGE_SIGNATURE_DOMAIN.len() + 96 + self.topic.len() + self.payload.len(),
+        );
+        buf.extend_from_slice(MESSAGE_SIGNATURE_DOMAIN);
+        buf.extend_from_slice(&self.id);
+        buf.extend_from_slice(&self.sender);
+        buf.extend_from_slice(&(self.topic.len() as u64).to_be_bytes());
+        buf.extend_from_slice(self.topic.as_bytes());
+        buf.extend_from_slice(&(self.payload.len() as u64).to_be_bytes());
+        buf.extend_from_slice(&self.payload);
+        buf.extend_from_slice(&self.timestamp.to_be_bytes());
+        buf.extend_from_slice(&self.sequence.to_be_bytes());
+        buf
+    }
+
+    /// Sign the message with the sending agent's key, replacing any
+    /// existing signature. Changing any field afterwards invalidates it.
+    ///
+    /// # Errors
+    ///
+    /// [`NetworkError::InvalidMessage`] if `sender` is not `ctx`'s agent;
+    /// [`NetworkError::SerializationError`] if signing fails.
+    pub fn sign(&mut self, ctx: &crate::gossip::SigningContext) -> NetworkResult<()> {
+        if ctx.agent_id.0 != self.sender {
+            return Err(NetworkError::InvalidMessage(
+                "message sender is not the signing agent".to_string(),
+            ));
+        }
+        self.signature = Some(ctx.sign(&self.signing_bytes())?);
+        Ok(())
+    }
+
+    /// Whether the message carries a valid signature by `public_key`, and
+    /// `public_key` belongs to the agent named as `sender`. Unsigned
+    /// messages never verify.
+    #[must_use]
+    pub fn verify(&self, public_key: &ant_quic::MlDsaPublicKey) -> bool {
+        let Some(signature) = &self.signature else {
+            return false;
+        };
+        if crate::identity::AgentId::from_public_key(public_key).0 != self.sender {
+            return false;
+        }
+        let Ok(signature) =
+            ant_quic::crypto::raw_public_keys::pqc::MlDsaSignature::from_bytes(signature)
+        else {
+            return false;
+        };
+        ant_quic::crypto::raw_public_keys::pqc::verify_with_ml_dsa(
+            public_key,
+            &self.signing_bytes(),
+            &signature,
+        )
+        .is_ok()
+    }
+
     /// Create a message with an explicit sequence number.
     ///
     /// # Arguments
     ///
-    /// * `sender` - The peer ID of the message sender.
+    /// * `sender` - The `AgentId` bytes of the sending agent.
     /// * `topic` - The topic string for routing.
     /// * `payload` - The message payload bytes.
     /// * `sequence` - The sequence number for ordering.
@@ -4974,12 +5987,28 @@ impl Message {
     /// Returns `NetworkError` if binary deserialization fails.
     pub fn from_binary(data: &[u8]) -> NetworkResult<Self> {
         use bincode::Options;
-        bincode::options()
-            .with_fixint_encoding()
-            .with_limit(MAX_MESSAGE_DESERIALIZE_SIZE)
-            .allow_trailing_bytes()
-            .deserialize(data)
-            .map_err(|e| NetworkError::SerializationError(format!("Binary decode failed: {}", e)))
+        let options = || {
+            bincode::options()
+                .with_fixint_encoding()
+                .with_limit(MAX_MESSAGE_DESERIALIZE_SIZE)
+                .allow_trailing_bytes()
+        };
+        // Unsigned messages encode without the signature field, so a decode
+        // that runs out of input there is retried without it.
+        options().deserialize(data).or_else(|e| {
+            let unsigned: UnsignedMessage = options().deserialize(data).map_err(|_| {
+                NetworkError::SerializationError(format!("Binary decode failed: {}", e))
+            })?;
+            Ok(Self {
+                id: unsigned.id,
+                sender: unsigned.sender,
+                topic: unsigned.topic,
+                payload: unsigned.payload,
+                timestamp: unsigned.timestamp,
+                sequence: unsigned.sequence,
+                signature: None,
+            })
+        })
     }
 
     /// Get the size of this message when serialized to binary.
@@ -5001,6 +6030,45 @@ impl Message {
     }
 }
 
+/// Wrap a delivered pub/sub message in a [`Message`] envelope, stamped with
+/// the current time. `sender` is the signer's `AgentId` bytes.
+///
+/// # Errors
+///
+/// [`NetworkError::InvalidMessage`] for an unsigned (sender-less) message;
+/// [`NetworkError::TimestampError`] if the clock is before the Unix epoch.
+impl TryFrom<crate::gossip::PubSubMessage> for Message {
+    type Error = NetworkError;
+
+    fn try_from(msg: crate::gossip::PubSubMessage) -> NetworkResult<Self> {
+        let sender = msg.sender.ok_or_else(|| {
+            NetworkError::InvalidMessage("unsigned pub/sub message has no sender".to_string())
+        })?;
+        Self::new(sender.0, msg.topic, msg.payload.to_vec())
+    }
+}
+
+/// Present a [`Message`] as a subscription delivery. The result is never
+/// `verified`: the envelope has no signature to check, and trusting it
+/// here would let a stored copy launder an unverified sender.
+impl From<Message> for crate::gossip::PubSubMessage {
+    fn from(msg: Message) -> Self {
+        let sender = crate::identity::AgentId(msg.sender);
+        Self {
+            // Not `msg.id`: that hash covers the envelope timestamp, and a
+            // replayed copy must dedup against the live delivery.
+            id: Self::content_id(Some(&sender), &msg.topic, &msg.payload),
+            topic: msg.topic,
+            payload: Bytes::from(msg.payload),
+            sender: Some(sender),
+            sender_public_key: None,
+            verified: false,
+            trust_level: None,
+            on_behalf_of: None,
+        }
+    }
+}
+
 /// Get the current Unix timestamp in seconds.
 ///
 /// # Returns
@@ -5025,7 +6093,7 @@ fn current_timestamp() -> NetworkResult<u64> {
 ///
 /// # Arguments
 ///
-/// * `sender` - The sender's peer ID.
+/// * `sender` - The sender's `AgentId` bytes.
 /// * `topic` - The message topic.
 /// * `payload` - The message payload.
 /// * `timestamp` - The message timestamp.
@@ -5530,6 +6598,125 @@ mod message_tests {
         assert_eq!(deserialized.topic, topic);
     }
 
+    fn pubsub_message(sender: Option<[u8; 32]>, verified: bool) -> crate::gossip::PubSubMessage {
+        crate::gossip::PubSubMessage {
+            id: [0; 32],
+            topic: "convert".to_string(),
+            payload: Bytes::from_static(b"shared fields"),
+            sender: sender.map(crate::identity::AgentId),
+            sender_public_key: Some(vec![1, 2, 3]),
+            verified,
+            trust_level: None,
+            on_behalf_of: None,
+        }
+    }
+
+    #[test]
+    fn message_pubsub_roundtrip_preserves_shared_fields() {
+        let original = pubsub_message(Some([5; 32]), true);
+        let msg = Message::try_from(original.clone()).unwrap();
+        assert_eq!(msg.topic, original.topic);
+        assert_eq!(msg.payload, original.payload.to_vec());
+        assert_eq!(msg.sender, [5; 32]);
+
+        let back = crate::gossip::PubSubMessage::from(msg);
+        assert_eq!(back.topic, original.topic);
+        assert_eq!(back.payload, original.payload);
+        assert_eq!(back.sender, original.sender);
+        // The envelope carries no signature, so verification must not
+        // survive the round trip.
+        assert!(!back.verified);
+        assert!(back.sender_public_key.is_none());
+    }
+
+    /// `sender` is the agent, not the machine: a delivery from a real agent
+    /// key converts to a `Message` whose `sender` is that `AgentId`.
+    #[test]
+    fn message_sender_is_the_agent_id_of_the_delivery() {
+        let agent = crate::identity::AgentKeypair::generate().unwrap();
+        let machine = crate::identity::MachineKeypair::generate().unwrap();
+        let delivered = pubsub_message(Some(agent.agent_id().0), true);
+
+        let msg = Message::try_from(delivered).unwrap();
+        assert_eq!(msg.sender, agent.agent_id().0);
+        assert_ne!(msg.sender, machine.machine_id().0);
+
+        let back = crate::gossip::PubSubMessage::from(msg);
+        assert_eq!(back.sender, Some(agent.agent_id()));
+    }
+
+    #[test]
+    fn signed_message_verifies_and_detects_tampering() {
+        let kp = crate::identity::AgentKeypair::generate().unwrap();
+        let ctx = crate::gossip::SigningContext::from_keypair(&kp);
+        let mut msg =
+            Message::with_sequence(kp.agent_id().0, "signed".to_string(), b"hi".to_vec(), 7)
+                .unwrap();
+        assert!(!msg.verify(kp.public_key()), "unsigned never verifies");
+
+        msg.sign(&ctx).unwrap();
+        assert!(msg.verify(kp.public_key()));
+        for decoded in [
+            Message::from_json(&msg.to_json().unwrap()).unwrap(),
+            Message::from_binary(&msg.to_binary().unwrap()).unwrap(),
+        ] {
+            assert_eq!(decoded, msg);
+            assert!(decoded.verify(kp.public_key()));
+        }
+
+        let other = crate::identity::AgentKeypair::generate().unwrap();
+        assert!(!msg.verify(other.public_key()), "key must belong to sender");
+        let mut tampered = msg.clone();
+        tampered.sequence = 8;
+        assert!(!tampered.verify(kp.public_key()));
+        let mut tampered = msg.clone();
+        tampered.payload = b"ho".to_vec();
+        assert!(!tampered.verify(kp.public_key()));
+
+        // Only the sender can sign.
+        let mut forged = Message::new([9; 32], "signed".to_string(), b"hi".to_vec()).unwrap();
+        assert!(matches!(
+            forged.sign(&ctx),
+            Err(NetworkError::InvalidMessage(_))
+        ));
+    }
+
+    #[test]
+    fn unsigned_message_encodes_as_before_signatures() {
+        #[derive(Serialize)]
+        struct Legacy<'a> {
+            id: [u8; 32],
+            sender: [u8; 32],
+            topic: &'a str,
+            payload: &'a [u8],
+            timestamp: u64,
+            sequence: u64,
+        }
+        let msg =
+            Message::with_sequence([3; 32], "legacy".to_string(), b"old".to_vec(), 2).unwrap();
+        let legacy = Legacy {
+            id: msg.id,
+            sender: msg.sender,
+            topic: &msg.topic,
+            payload: &msg.payload,
+            timestamp: msg.timestamp,
+            sequence: msg.sequence,
+        };
+
+        let legacy_binary = bincode::serialize(&legacy).unwrap();
+        assert_eq!(msg.to_binary().unwrap(), legacy_binary);
+        assert_eq!(Message::from_binary(&legacy_binary).unwrap(), msg);
+        let legacy_json = serde_json::to_vec(&legacy).unwrap();
+        assert_eq!(msg.to_json().unwrap(), legacy_json);
+        assert_eq!(Message::from_json(&legacy_json).unwrap(), msg);
+    }
+
+    #[test]
+    fn unsigned_pubsub_message_has_no_message_form() {
+        let err = Message::try_from(pubsub_message(None, false)).unwrap_err();
+        assert!(matches!(err, NetworkError::InvalidMessage(_)));
+    }
+
     #[test]
     fn test_current_timestamp_positive() {
         let ts = current_timestamp().unwrap();
@@ -5562,6 +6749,73 @@ mod message_tests {
         assert_eq!(default_max_peers_per_ip(), 3);
     }
 
+    #[test]
+    fn default_max_concurrent_connects_value() {
+        assert_eq!(default_max_concurrent_connects(), 16);
+    }
+
+    #[test]
+    fn bootstrap_retry_defaults_match_legacy_schedule() {
+        let retry = BootstrapRetryConfig::default();
+        assert_eq!(retry.max_rounds, 3);
+        assert_eq!(retry.delay_before_round(2, 0.0), Duration::from_secs(10));
+        assert_eq!(retry.delay_before_round(3, 0.0), Duration::from_secs(15));
+        // ±20% at the extremes of the jitter range.
+        assert_eq!(retry.delay_before_round(2, -1.0), Duration::from_secs(8));
+        assert_eq!(retry.delay_before_round(2, 1.0), Duration::from_secs(12));
+        for _ in 0..100 {
+            let delay = retry.jittered_delay(3);
+            assert!((Duration::from_secs(12)..=Duration::from_secs(18)).contains(&delay));
+        }
+    }
+
+    #[test]
+    fn bootstrap_retry_delay_is_capped_and_clamped() {
+        let retry = BootstrapRetryConfig {
+            max_rounds: 10,
+            initial_delay_ms: 1_000,
+            multiplier: 4.0,
+            max_delay_ms: 5_000,
+            jitter: 3.0,
+        };
+        assert_eq!(retry.delay_before_round(4, 0.0), Duration::from_secs(5));
+        // Jitter is clamped to 100%, so the delay never goes negative.
+        assert_eq!(retry.delay_before_round(4, -1.0), Duration::ZERO);
+        assert_eq!(retry.delay_before_round(4, 1.0), Duration::from_secs(10));
+
+        let parsed: NetworkConfig =
+            toml::from_str("[bootstrap_retry]\nmax_rounds = 5\n").expect("parse");
+        assert_eq!(parsed.bootstrap_retry.max_rounds, 5);
+        assert_eq!(parsed.bootstrap_retry.initial_delay_ms, 10_000);
+    }
+
+    #[test]
+    fn config_durations_parse_human_friendly_strings() {
+        let config: NetworkConfig =
+            toml::from_str("connection_timeout = \"30s\"\nstats_interval = \"2m\"")
+                .expect("durations parse");
+        assert_eq!(config.connection_timeout, Duration::from_secs(30));
+        assert_eq!(config.stats_interval, Duration::from_secs(120));
+
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration("45"), Ok(Duration::from_secs(45)));
+        assert!(parse_duration("30 parsecs").is_err());
+        assert!(parse_duration("s").is_err());
+
+        let defaults = NetworkConfig::default();
+        let json = serde_json::to_string(&defaults).expect("serialize");
+        let roundtrip: NetworkConfig = serde_json::from_str(&json).expect("legacy form parses");
+        assert_eq!(roundtrip.connection_timeout, defaults.connection_timeout);
+    }
+
+    #[test]
+    fn config_rejects_zero_connection_timeout() {
+        let err = toml::from_str::<NetworkConfig>("connection_timeout = \"0s\"")
+            .expect_err("zero timeout rejected");
+        assert!(err.to_string().contains("greater than zero"), "{err}");
+        assert!(toml::from_str::<NetworkConfig>("stats_interval = 0").is_err());
+    }
+
     #[test]
     fn network_config_defaults_are_consistent() {
         let config = NetworkConfig::default();
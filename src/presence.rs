@@ -165,6 +165,7 @@ pub fn presence_record_to_discovered_agent(
         cert_not_after: None,
         agent_certificate: None,
         agent_public_key: Vec::new(), // populated when identity heartbeat arrives
+        status: None,
     })
 }
 
@@ -749,6 +750,7 @@ mod tests {
             cert_not_after: None,
             agent_certificate: None,
             agent_public_key: Vec::new(),
+            status: None,
         }
     }
 
@@ -553,14 +553,7 @@ fn manifest_policy(
 }
 
 fn parse_owner_hex(hex_str: &str) -> Option<crate::identity::AgentId> {
-    let bytes = hex::decode(hex_str).ok()?;
-    if bytes.len() == crate::identity::PEER_ID_LENGTH {
-        let mut arr = [0u8; crate::identity::PEER_ID_LENGTH];
-        arr.copy_from_slice(&bytes);
-        Some(crate::identity::AgentId(arr))
-    } else {
-        None
-    }
+    hex_str.parse().ok()
 }
 
 /// Rehydrate every persisted subscription by driving the same `Agent`
@@ -636,12 +629,33 @@ async fn rehydrate_one(state: Arc<AppState>, entry: CrdtSubscriptionEntry) -> Re
             if state.task_lists.read().await.contains_key(&entry.id) {
                 return RehydrateOutcome::AlreadyPresent; // re-created via REST since startup
             }
+            if !state.auto_rejoin_task_lists {
+                tracing::info!(
+                    id = %entry.id,
+                    "auto_rejoin_task_lists is off — leaving task list dormant"
+                );
+                return RehydrateOutcome::Skipped;
+            }
             let result = match entry.role.as_str() {
                 ROLE_JOINED => state.agent.join_task_list(&entry.topic).await,
                 ROLE_CREATED => {
+                    // Lists created with a custom interval keep it across
+                    // restarts; a missing or out-of-range value falls back
+                    // to the default rather than losing the list.
+                    let mut options = x0x::crdt::SyncOptions::default();
+                    if let Some(secs) = entry
+                        .extra
+                        .get("sync_interval_secs")
+                        .and_then(serde_json::Value::as_u64)
+                    {
+                        options.sync_interval_secs = secs;
+                    }
+                    if options.validate().is_err() {
+                        options = x0x::crdt::SyncOptions::default();
+                    }
                     state
                         .agent
-                        .create_task_list(&entry.name, &entry.topic)
+                        .create_task_list_with_options(&entry.name, &entry.topic, options)
                         .await
                 }
                 other => {
@@ -660,6 +674,7 @@ async fn rehydrate_one(state: Arc<AppState>, entry: CrdtSubscriptionEntry) -> Re
                     // lists. Must run before insertion so the sync listener
                     // starts with the correct authorized set.
                     super::routes::apply_group_authorization(&state, &entry.id, &handle).await;
+                    super::routes::watch_task_list_updates(&state, &entry.id, &handle).await;
                     state
                         .task_lists
                         .write()
@@ -673,6 +688,11 @@ async fn rehydrate_one(state: Arc<AppState>, entry: CrdtSubscriptionEntry) -> Re
                         id = %entry.id,
                         "failed to rehydrate task list after restart: {e}"
                     );
+                    state
+                        .task_list_recovery_failures
+                        .write()
+                        .await
+                        .insert(entry.id.clone(), e.to_string());
                     RehydrateOutcome::Skipped
                 }
             }
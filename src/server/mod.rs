@@ -11,6 +11,7 @@ use crate as x0x;
 
 mod auth;
 mod crdt_subscriptions;
+mod rate_limit;
 mod routes;
 mod sse;
 mod state;
@@ -22,47 +23,55 @@ mod ws;
 use routes::{
     ack_diagnostics, add_contact, add_machine, add_mls_member, add_named_group_member, add_task,
     agent_info, agent_reachability, agent_sign, agent_user_id_handler, agent_verify,
-    agents_by_user_handler, announce_identity, apply_direct_kv_store_delta,
+    agent_words_handler, agents_by_user_handler, announce_identity, apply_direct_kv_store_delta,
     apply_named_group_metadata_event, apply_upgrade, approve_join_request, ban_group_member,
-    bootstrap_cache_stats, broadcast_current_manifest, cancel_join_request, check_upgrade,
+    bootstrap_cache_stats, bootstrap_health, broadcast_current_manifest, cancel_join_request,
+    check_upgrade, checkpoint_all_task_lists, checkpoint_task_list, clear_presence_status,
     connect_agent, connect_diagnostics_handler, connect_machine, connectivity_diagnostics,
-    create_discovery_subscription, create_group_invite, create_join_request, create_kv_store,
-    create_mls_group, create_mls_welcome, create_named_group, create_task_list, delete_contact,
-    delete_discovery_subscription, delete_kv_value, delete_machine, direct_connections,
-    direct_message_send_config, direct_send, discover_groups, discover_groups_nearby,
-    discovered_agent, discovered_agents, discovered_machine, discovered_machines, dm_diagnostics,
+    contact_reachability, contacts_audit, create_discovery_subscription, create_group_invite,
+    create_join_request, create_kv_store, create_mls_group, create_mls_welcome, create_named_group,
+    create_task_list, delete_contact, delete_discovery_subscription, delete_kv_value,
+    delete_machine, direct_connections, direct_message_send_config, direct_send,
+    disconnect_all_peers, discover_groups, discover_groups_nearby, discovered_agent,
+    discovered_agents, discovered_machine, discovered_machines, dm_diagnostics,
     ensure_named_group_listeners, evaluate_trust, exec_cancel, exec_diagnostics, exec_run,
-    exec_sessions, file_accept_handler, file_reject_handler, file_send_handler,
-    file_transfer_status_handler, file_transfers_handler, find_agent, forward_add, forward_list,
-    forward_remove, get_a2a_agent_card, get_agent_card, get_constitution, get_constitution_json,
-    get_group_card, get_group_public_messages, get_group_state, get_group_state_commits,
-    get_kv_value, get_mls_group, get_named_group, get_named_group_members, gossip_diagnostics,
-    groups_diagnostics, handle_file_message, handle_join_result_message,
-    handle_treekem_catchup_request, handle_treekem_catchup_response, handle_welcome_blob_message,
-    health, history_diagnostics, history_list, history_purge, history_search, history_stats,
-    identity_revocations, identity_revoke, import_agent_card, import_group_card,
-    ingest_public_message, introduction, join_group_via_invite, join_kv_store, leave_group,
-    list_contacts, list_discovery_subscriptions, list_join_requests, list_kv_keys, list_kv_stores,
-    list_machines, list_mls_groups, list_named_groups, list_revocations, list_task_lists,
-    list_tasks, load_named_groups, load_treekem_member_key_packages, machine_for_agent_handler,
-    machines_by_user_handler, mls_decrypt, mls_encrypt, named_group_metadata_event_kind,
-    network_status, peer_health_handler, peers, pin_machine, presence, presence_find,
+    exec_sessions, export_contacts, export_task_list, file_accept_handler, file_reject_handler,
+    file_send_handler, file_transfer_status_handler, file_transfers_handler, find_agent,
+    forward_add, forward_list, forward_remove, get_a2a_agent_card, get_agent_card,
+    get_constitution, get_constitution_json, get_group_card, get_group_public_messages,
+    get_group_state, get_group_state_commits, get_kv_value, get_mls_group, get_named_group,
+    get_named_group_members, gossip_diagnostics, groups_diagnostics, handle_file_message,
+    handle_join_result_message, handle_treekem_catchup_request, handle_treekem_catchup_response,
+    handle_welcome_blob_message, health, health_persistence, history_diagnostics, history_list,
+    history_purge, history_search, history_stats, identity_revocations, identity_revoke,
+    import_agent_card, import_contacts, import_group_card, ingest_public_message, introduction,
+    join_group_via_invite, join_kv_store, leave_group, list_contacts, list_discovery_subscriptions,
+    list_join_requests, list_kv_keys, list_kv_stores, list_machines, list_mls_groups,
+    list_named_groups, list_revocations, list_subscriptions, list_task_lists, list_tasks,
+    load_named_groups, load_treekem_member_key_packages, machine_for_agent_handler,
+    machines_by_user_handler, metrics, mls_decrypt, mls_encrypt, named_group_metadata_event_kind,
+    network_status, peer_cache, peer_health_handler, peers, pin_machine, presence, presence_find,
     presence_foaf, presence_online, presence_status, probe_peer_handler, publish,
     publish_group_card_to_discovery, put_kv_value, quick_trust, recover_treekem_named_journals,
-    reject_join_request, remove_mls_member, remove_named_group_member, restore_treekem_groups,
-    revoke_contact, run_fallback_github_poll, run_gossip_update_listener, run_startup_update_check,
-    seal_group_state, secure_group_decrypt, secure_group_encrypt, secure_group_reseal,
-    secure_open_envelope_adversarial, send_group_public_message, set_group_display_name,
-    shutdown_handler, spawn_directory_resubscribe, spawn_global_discovery_listener,
+    reject_join_request, remove_mls_member, remove_named_group_member, remove_task,
+    restore_subscriptions, restore_treekem_groups, revoke_contact, run_fallback_github_poll,
+    run_gossip_update_listener, run_startup_update_check, seal_group_state, secure_group_decrypt,
+    secure_group_encrypt, secure_group_reseal, secure_open_envelope_adversarial,
+    send_group_public_message, set_group_display_name, set_presence_status, shutdown_handler,
+    spawn_contact_trust_events, spawn_directory_resubscribe, spawn_global_discovery_listener,
     spawn_global_public_message_listener, spawn_listed_to_contacts_listener, status,
-    streams_diagnostics, subscribe, unban_group_member, unpin_machine, unsubscribe, update_contact,
-    update_group_policy, update_member_role, update_named_group, update_task, withdraw_group_state,
-    JoinResultMessage, KvStoreDirectDelta, NamedGroupMetadataEvent, SelfPublishedReleaseManifests,
-    TreeKemCatchupRequest, TreeKemCatchupResponse, WelcomeBlobMessage,
-    DIRECTORY_DIGEST_INTERVAL_SECS, DIRECTORY_RESUBSCRIBE_JITTER_MS,
+    streams_diagnostics, subscribe, task_history, task_list_sync_stats, unban_group_member,
+    unpin_machine, unsubscribe, update_contact, update_group_policy, update_member_role,
+    update_named_group, update_persistence_policy, update_task, validate_task_list,
+    withdraw_group_state, JoinResultMessage, KvStoreDirectDelta, NamedGroupMetadataEvent,
+    SelfPublishedReleaseManifests, TreeKemCatchupRequest, TreeKemCatchupResponse,
+    WelcomeBlobMessage, DIRECTORY_DIGEST_INTERVAL_SECS, DIRECTORY_RESUBSCRIBE_JITTER_MS,
     GROUP_PUBLIC_MESSAGE_DM_PREFIX, KV_STORE_DELTA_DM_PREFIX,
 };
-use sse::{direct_events_sse, events_sse, peer_events_handler, presence_events, SseEvent};
+use sse::{
+    direct_events_sse, events_sse, peer_events_handler, presence_events, sse_diagnostics, SseEvent,
+    SseStats,
+};
 use state::AppState;
 pub use state::{
     default_api_address, default_bind_address, default_data_dir, validate_instance_name,
@@ -121,6 +130,9 @@ fn parse_optional_json<T: serde::de::DeserializeOwned + Default>(
 
 const DM_INBOX_START_MAX_ATTEMPTS: u32 = 120;
 const DM_INBOX_START_RETRY_DELAY: Duration = Duration::from_millis(250);
+/// Events buffered per `/events` client, on the daemon-wide broadcast and on
+/// each `/subscribe` feed, before a slow client starts lagging.
+const SSE_BROADCAST_CAPACITY: usize = 256;
 
 /// Run the daemon HTTP/WebSocket server to completion.
 ///
@@ -305,12 +317,14 @@ pub async fn serve_with_options(
         pinned_bootstrap_peers: std::collections::HashSet::new(),
         inbound_allowlist: std::collections::HashSet::new(),
         max_peers_per_ip: 3,
+        max_concurrent_connects: x0x::network::DEFAULT_MAX_CONCURRENT_CONNECTS,
         // CLI flag wins over config TOML so operators can override on a
         // single invocation without editing the config file.
         port_mapping_enabled: config.port_mapping_enabled && !cli_no_port_mapping,
         peer_relay: config.peer_relay.clone(),
         network_id,
         observed_prefix_enabled: config.observed_prefix_enabled,
+        bootstrap_retry: config.bootstrap_retry.clone(),
     };
 
     let contacts_path = config.data_dir.join("contacts.json");
@@ -326,6 +340,7 @@ pub async fn serve_with_options(
         .with_gossip_config(config.gossip.clone())
         .with_peer_cache_dir(cache_dir)
         .with_contact_store_path(&contacts_path)
+        .with_task_list_storage_dir(config.data_dir.join("task_lists"))
         .with_history(history_config)
         .with_heartbeat_interval(config.heartbeat_interval_secs)
         .with_identity_ttl(config.identity_ttl_secs);
@@ -433,7 +448,7 @@ pub async fn serve_with_options(
         );
     }
 
-    let (broadcast_tx, _) = broadcast::channel::<SseEvent>(256);
+    let (broadcast_tx, _) = broadcast::channel::<SseEvent>(SSE_BROADCAST_CAPACITY);
     // Load or generate the per-daemon ML-KEM-768 keypair. Persisted under
     // `<data_dir>/agent_kem.key` with mode 0600. This keypair is the root of
     // trust for `SecureShareDelivered` — only the holder of the secret half
@@ -465,6 +480,10 @@ pub async fn serve_with_options(
     // DM inbox trust evaluator observes the same mutations made by REST
     // contact/card endpoints.
     let contacts = Arc::clone(agent.contacts());
+    contacts
+        .write()
+        .await
+        .set_auto_promote_after(config.auto_promote_after);
     agent.set_contacts(Arc::clone(&contacts));
     tracing::info!("Contact store loaded from {}", contacts_path.display());
 
@@ -559,11 +578,15 @@ pub async fn serve_with_options(
         history_record_topics: config.history.record_topics.clone(),
         history_config: config.history.clone(),
         subscriptions: RwLock::new(HashMap::new()),
+        subscriptions_path: config.data_dir.join("subscriptions.json"),
+        subscriptions_persistence_lock: Mutex::new(()),
         task_lists: RwLock::new(HashMap::new()),
         kv_stores: RwLock::new(HashMap::new()),
         crdt_subscriptions: RwLock::new(crdt_subscriptions::CrdtSubscriptionManifest::default()),
         crdt_subscriptions_path: config.data_dir.join("crdt-subscriptions.json"),
         kv_store_state_dir: config.data_dir.join("kv-stores"),
+        auto_rejoin_task_lists: config.auto_rejoin_task_lists,
+        task_list_recovery_failures: RwLock::new(HashMap::new()),
         crdt_subscriptions_persistence_lock: Mutex::new(()),
         crdt_handle_locks: RwLock::new(HashMap::new()),
         named_groups: RwLock::new(named_groups),
@@ -603,6 +626,7 @@ pub async fn serve_with_options(
         ws_sessions: RwLock::new(HashMap::new()),
         ws_topics: RwLock::new(HashMap::new()),
         ws_outbound_stats: Arc::new(WsOutboundStats::default()),
+        sse_stats: Arc::new(SseStats::default()),
         api_address: actual_api_addr,
         start_time: Instant::now(),
         broadcast_tx,
@@ -672,9 +696,14 @@ pub async fn serve_with_options(
     // manifest now (before REST handlers can mutate it) — the actual
     // re-create/re-join runs after `join_network` in the join task below.
     crdt_subscriptions::load(&state).await;
+    // Re-create the `/subscribe` streams recorded before the restart (new
+    // subscription ids, same topics) before the network join below, so no
+    // delivery arrives ahead of its SSE forwarder.
+    restore_subscriptions(&state).await;
     // Phase C.2: subscribe inbound direct messages for the
     // ListedToContacts pairwise sync channel.
     bg_tasks.extend(spawn_listed_to_contacts_listener(Arc::clone(&state)).await);
+    bg_tasks.extend(spawn_contact_trust_events(Arc::clone(&state)).await);
     // Phase E: subscribe to a stable global SignedPublic message fallback so
     // first messages are not dependent on a brand-new per-group topic tree.
     bg_tasks.extend(spawn_global_public_message_listener(Arc::clone(&state)).await);
@@ -1146,9 +1175,12 @@ pub async fn serve_with_options(
     }
 
     // Build router
+    let rate_limiter = Arc::new(rate_limit::RateLimiter::new(config.rate_limit.clone()));
     let app = Router::new()
         .route("/health", get(health))
+        .route("/health/persistence", get(health_persistence))
         .route("/status", get(status))
+        .route("/metrics", get(metrics))
         .route("/agent", get(agent_info))
         .route("/introduction", get(introduction))
         .route("/agent/card", get(get_agent_card))
@@ -1164,11 +1196,16 @@ pub async fn serve_with_options(
         .route("/publish", post(publish))
         .route("/subscribe", post(subscribe))
         .route("/subscribe/:id", delete(unsubscribe))
+        .route("/subscriptions", get(list_subscriptions))
         .route("/events", get(events_sse))
         .route("/presence", get(presence))
         .route("/presence/online", get(presence_online))
         .route("/presence/foaf", get(presence_foaf))
         .route("/presence/find/:id", get(presence_find))
+        .route(
+            "/presence/status",
+            post(set_presence_status).delete(clear_presence_status),
+        )
         .route("/presence/status/:id", get(presence_status))
         .route("/presence/events", get(presence_events))
         .route("/agents/discovered", get(discovered_agents))
@@ -1180,9 +1217,13 @@ pub async fn serve_with_options(
         .route("/users/:user_id/agents", get(agents_by_user_handler))
         .route("/users/:user_id/machines", get(machines_by_user_handler))
         .route("/agent/user-id", get(agent_user_id_handler))
+        .route("/agent/words", get(agent_words_handler))
         .route("/contacts", get(list_contacts))
         .route("/contacts", post(add_contact))
         .route("/contacts/trust", post(quick_trust))
+        .route("/contacts/audit", get(contacts_audit))
+        .route("/contacts/export", get(export_contacts))
+        .route("/contacts/import", post(import_contacts))
         .route("/contacts/:agent_id", patch(update_contact))
         .route("/contacts/:agent_id", delete(delete_contact))
         .route(
@@ -1197,7 +1238,17 @@ pub async fn serve_with_options(
         .route("/task-lists", post(create_task_list))
         .route("/task-lists/:id/tasks", get(list_tasks))
         .route("/task-lists/:id/tasks", post(add_task))
-        .route("/task-lists/:id/tasks/:tid", patch(update_task))
+        .route(
+            "/task-lists/:id/tasks/:tid",
+            patch(update_task).delete(remove_task),
+        )
+        .route("/task-lists/:id/tasks/:tid/history", get(task_history))
+        .route("/task-lists/:id/validate", get(validate_task_list))
+        .route("/task-lists/:id/sync-stats", get(task_list_sync_stats))
+        .route("/task-lists/:id/export", get(export_task_list))
+        .route("/task-lists/:id/checkpoint", post(checkpoint_task_list))
+        .route("/checkpoint-all", post(checkpoint_all_task_lists))
+        .route("/persistence/policy", patch(update_persistence_policy))
         // Named group endpoints
         .route("/groups", post(create_named_group))
         .route("/groups", get(list_named_groups))
@@ -1298,6 +1349,10 @@ pub async fn serve_with_options(
         // Contact trust extensions
         .route("/contacts/:agent_id/revoke", post(revoke_contact))
         .route("/contacts/:agent_id/revocations", get(list_revocations))
+        .route(
+            "/contacts/:agent_id/reachability",
+            get(contact_reachability),
+        )
         .route(
             "/contacts/:agent_id/machines/:machine_id/pin",
             post(pin_machine).delete(unpin_machine),
@@ -1311,6 +1366,7 @@ pub async fn serve_with_options(
         .route("/upgrade/apply", post(apply_upgrade))
         // Network diagnostics
         .route("/network/bootstrap-cache", get(bootstrap_cache_stats))
+        .route("/bootstrap/health", get(bootstrap_health))
         .route("/diagnostics/connectivity", get(connectivity_diagnostics))
         .route("/diagnostics/history", get(history_diagnostics))
         // ADR-0023 durable-history read surface
@@ -1324,6 +1380,7 @@ pub async fn serve_with_options(
         .route("/diagnostics/exec", get(exec_diagnostics))
         .route("/diagnostics/connect", get(connect_diagnostics_handler))
         .route("/diagnostics/ws", get(ws_diagnostics))
+        .route("/diagnostics/sse", get(sse_diagnostics))
         .route("/exec/run", post(exec_run))
         .route("/exec/cancel", post(exec_cancel))
         .route("/exec/sessions", get(exec_sessions))
@@ -1335,6 +1392,8 @@ pub async fn serve_with_options(
         .route("/peers/:peer_id/probe", post(probe_peer_handler))
         .route("/peers/:peer_id/health", get(peer_health_handler))
         .route("/peers/events", get(peer_events_handler))
+        .route("/peers/cache", get(peer_cache))
+        .route("/peers/disconnect-all", post(disconnect_all_peers))
         // WebSocket endpoints
         .route("/ws", get(ws_handler))
         .route("/ws/direct", get(ws_direct_handler))
@@ -1367,6 +1426,12 @@ pub async fn serve_with_options(
                 .allow_methods(AllowMethods::any())
                 .allow_headers(AllowHeaders::any())
         })
+        // Token-bucket limits on mutating requests; inside auth so
+        // unauthenticated requests never spend tokens.
+        .layer(axum::middleware::from_fn_with_state(
+            rate_limiter,
+            rate_limit::rate_limit_middleware,
+        ))
         // Bearer-token authentication: all control-plane endpoints.
         .layer(axum::middleware::from_fn_with_state(
             Arc::clone(&state),
@@ -1644,32 +1709,18 @@ async fn start_dm_inbox_when_gossip_ready(
     }
 }
 
-/// Parse a 64-character hex string into an AgentId.
+/// Parse a 64-character hex string (or the Base32 form) into an AgentId.
 fn parse_agent_id_hex(hex_str: &str) -> Result<AgentId, String> {
-    let bytes = hex::decode(hex_str).map_err(|e| format!("invalid hex: {e}"))?;
-    if bytes.len() != 32 {
-        return Err(format!(
-            "expected 32 bytes (64 hex chars), got {}",
-            bytes.len()
-        ));
-    }
-    let mut arr = [0u8; 32];
-    arr.copy_from_slice(&bytes);
-    Ok(AgentId(arr))
+    hex_str
+        .parse()
+        .map_err(|e: x0x::identity::ParseIdError| e.to_string())
 }
 
-/// Parse a 64-character hex string into a MachineId.
+/// Parse a 64-character hex string (or the Base32 form) into a MachineId.
 fn parse_machine_id_hex(hex_str: &str) -> Result<MachineId, String> {
-    let bytes = hex::decode(hex_str).map_err(|e| format!("invalid hex: {e}"))?;
-    if bytes.len() != 32 {
-        return Err(format!(
-            "expected 32 bytes (64 hex chars), got {}",
-            bytes.len()
-        ));
-    }
-    let mut arr = [0u8; 32];
-    arr.copy_from_slice(&bytes);
-    Ok(MachineId(arr))
+    hex_str
+        .parse()
+        .map_err(|e: x0x::identity::ParseIdError| e.to_string())
 }
 
 /// Build a uniform `{ "ok": false, "error": <msg> }` JSON error response paired
@@ -0,0 +1,309 @@
+//! Token-bucket rate limiting for mutating API requests.
+//!
+//! A runaway local client could otherwise hammer `/publish` (and through it
+//! the mesh) or brute-force contact operations. Every `POST`, `PUT`,
+//! `PATCH` and `DELETE` draws one token: from its own bucket when
+//! [`RateLimitConfig::endpoints`] names the request path, otherwise from a
+//! single global bucket. A request finding its bucket empty gets `429 Too
+//! Many Requests` with a `Retry-After` header. Reads and `/health` are never
+//! limited.
+//!
+//! The decision lives in [`RateLimiter::check`], which takes `now` so the
+//! unit tests can drive refill without sleeping; [`rate_limit_middleware`]
+//! is a thin wrapper over it.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex as StdMutex;
+use std::time::{Duration, Instant};
+
+use axum::extract::State;
+use axum::http::{header, Method, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::{Deserialize, Serialize};
+
+/// Bucket key shared by every path without its own rule.
+const GLOBAL_BUCKET: &str = "*";
+
+/// Refill rate and capacity of one token bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitRule {
+    /// Tokens added per second (values below 1 are treated as 1).
+    pub per_second: u32,
+    /// Bucket capacity: requests allowed in a burst (values below 1 are
+    /// treated as 1).
+    pub burst: u32,
+}
+
+/// Daemon rate-limit configuration (TOML: `[rate_limit]`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitConfig {
+    /// Apply rate limiting at all.
+    /// Default: true
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+
+    /// Refill rate of the global bucket.
+    /// Default: 50
+    #[serde(default = "default_per_second")]
+    pub per_second: u32,
+
+    /// Capacity of the global bucket.
+    /// Default: 200
+    #[serde(default = "default_burst")]
+    pub burst: u32,
+
+    /// Per-path overrides keyed by exact request path (e.g. `"/publish"`).
+    /// Each listed path gets its own bucket instead of sharing the global
+    /// one.
+    #[serde(default)]
+    pub endpoints: HashMap<String, RateLimitRule>,
+}
+
+const fn default_enabled() -> bool {
+    true
+}
+
+const fn default_per_second() -> u32 {
+    50
+}
+
+const fn default_burst() -> u32 {
+    200
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            per_second: default_per_second(),
+            burst: default_burst(),
+            endpoints: HashMap::new(),
+        }
+    }
+}
+
+/// Tokens left in one bucket as of `refilled_at`.
+struct TokenBucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn full(rule: RateLimitRule, now: Instant) -> Self {
+        Self {
+            tokens: f64::from(rule.burst.max(1)),
+            refilled_at: now,
+        }
+    }
+
+    /// Take one token, or return how long until one is available.
+    fn take(&mut self, rule: RateLimitRule, now: Instant) -> Result<(), Duration> {
+        let rate = f64::from(rule.per_second.max(1));
+        let elapsed = now
+            .saturating_duration_since(self.refilled_at)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * rate).min(f64::from(rule.burst.max(1)));
+        self.refilled_at = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / rate))
+        }
+    }
+}
+
+/// Token buckets for the configured rules. Critical sections are a map
+/// lookup and a little arithmetic, so a `std::sync::Mutex` suffices.
+pub(super) struct RateLimiter {
+    config: RateLimitConfig,
+    buckets: StdMutex<HashMap<String, TokenBucket>>,
+}
+
+impl RateLimiter {
+    pub(super) fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            buckets: StdMutex::new(HashMap::new()),
+        }
+    }
+
+    /// Decide whether a request may proceed. `Err` carries the time until
+    /// the request's bucket has a token again.
+    pub(super) fn check(&self, method: &Method, path: &str, now: Instant) -> Result<(), Duration> {
+        if !self.config.enabled || !is_mutating(method) || is_rate_limit_exempt_path(path) {
+            return Ok(());
+        }
+        let (key, rule) = match self.config.endpoints.get(path) {
+            Some(rule) => (path, *rule),
+            None => (
+                GLOBAL_BUCKET,
+                RateLimitRule {
+                    per_second: self.config.per_second,
+                    burst: self.config.burst,
+                },
+            ),
+        };
+        let mut buckets = self.buckets.lock().unwrap_or_else(|p| p.into_inner());
+        buckets
+            .entry(key.to_string())
+            .or_insert_with(|| TokenBucket::full(rule, now))
+            .take(rule, now)
+    }
+}
+
+/// Rate-limit middleware: 429 with `Retry-After` once a bucket is empty.
+pub(super) async fn rate_limit_middleware(
+    State(limiter): State<Arc<RateLimiter>>,
+    req: axum::http::Request<axum::body::Body>,
+    next: axum::middleware::Next,
+) -> Response {
+    match limiter.check(req.method(), req.uri().path(), Instant::now()) {
+        Ok(()) => next.run(req).await,
+        Err(retry_after) => too_many_requests(retry_after),
+    }
+}
+
+fn too_many_requests(retry_after: Duration) -> Response {
+    // Retry-After is whole seconds; round up so a client that honours it
+    // finds a token waiting.
+    let secs = (retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0)).max(1);
+    (
+        StatusCode::TOO_MANY_REQUESTS,
+        [(header::RETRY_AFTER, secs.to_string())],
+        Json(serde_json::json!({
+            "ok": false,
+            "error": "rate limit exceeded",
+        })),
+    )
+        .into_response()
+}
+
+fn is_mutating(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+    )
+}
+
+fn is_rate_limit_exempt_path(path: &str) -> bool {
+    path == "/health"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::routing::post;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    fn limiter(per_second: u32, burst: u32) -> RateLimiter {
+        RateLimiter::new(RateLimitConfig {
+            per_second,
+            burst,
+            ..RateLimitConfig::default()
+        })
+    }
+
+    #[test]
+    fn bucket_empties_after_burst_and_refills_over_time() {
+        let limiter = limiter(2, 3);
+        let now = Instant::now();
+        for _ in 0..3 {
+            assert_eq!(limiter.check(&Method::POST, "/publish", now), Ok(()));
+        }
+        let retry = limiter
+            .check(&Method::POST, "/publish", now)
+            .expect_err("burst spent");
+        assert_eq!(retry, Duration::from_millis(500));
+
+        // Half a second refills exactly one token at 2/s.
+        let later = now + Duration::from_millis(500);
+        assert_eq!(limiter.check(&Method::POST, "/publish", later), Ok(()));
+        assert!(limiter.check(&Method::POST, "/publish", later).is_err());
+    }
+
+    #[test]
+    fn reads_health_and_disabled_config_are_never_limited() {
+        let limiter = limiter(1, 1);
+        let now = Instant::now();
+        assert_eq!(limiter.check(&Method::POST, "/publish", now), Ok(()));
+        assert!(limiter.check(&Method::DELETE, "/contacts/ab", now).is_err());
+        for _ in 0..10 {
+            assert_eq!(limiter.check(&Method::GET, "/peers", now), Ok(()));
+            assert_eq!(limiter.check(&Method::POST, "/health", now), Ok(()));
+        }
+
+        let disabled = RateLimiter::new(RateLimitConfig {
+            enabled: false,
+            per_second: 1,
+            burst: 1,
+            endpoints: HashMap::new(),
+        });
+        for _ in 0..10 {
+            assert_eq!(disabled.check(&Method::POST, "/publish", now), Ok(()));
+        }
+    }
+
+    #[test]
+    fn endpoint_rule_has_its_own_bucket() {
+        let mut config = RateLimitConfig {
+            per_second: 1,
+            burst: 1,
+            ..RateLimitConfig::default()
+        };
+        config.endpoints.insert(
+            "/publish".to_string(),
+            RateLimitRule {
+                per_second: 1,
+                burst: 3,
+            },
+        );
+        let limiter = RateLimiter::new(config);
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            assert_eq!(limiter.check(&Method::POST, "/publish", now), Ok(()));
+        }
+        assert!(limiter.check(&Method::POST, "/publish", now).is_err());
+        // The global bucket is untouched by /publish traffic.
+        assert_eq!(limiter.check(&Method::POST, "/contacts", now), Ok(()));
+        assert!(limiter.check(&Method::POST, "/contacts", now).is_err());
+    }
+
+    #[tokio::test]
+    async fn middleware_returns_429_with_retry_after_then_recovers() {
+        let limiter = Arc::new(limiter(20, 2));
+        let app = Router::new()
+            .route("/publish", post(|| async { "ok" }))
+            .layer(axum::middleware::from_fn_with_state(
+                limiter,
+                rate_limit_middleware,
+            ));
+        let request = || {
+            axum::http::Request::post("/publish")
+                .body(axum::body::Body::empty())
+                .expect("request")
+        };
+
+        for _ in 0..2 {
+            let resp = app.clone().oneshot(request()).await.expect("response");
+            assert_eq!(resp.status(), StatusCode::OK);
+        }
+        let resp = app.clone().oneshot(request()).await.expect("response");
+        assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            resp.headers()
+                .get(header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok()),
+            Some("1")
+        );
+
+        // One token refills every 50 ms at 20/s.
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        let resp = app.oneshot(request()).await.expect("response");
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+}
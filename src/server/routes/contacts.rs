@@ -1,6 +1,6 @@
 //! Contact route handlers (`category: "contacts"`) for the x0x daemon:
 //! `/contacts` CRUD, `/contacts/trust`, `/contacts/:agent_id/revoke`,
-//! `/contacts/:agent_id/revocations`.
+//! `/contacts/:agent_id/revocations`, `/contacts/:agent_id/reachability`.
 //!
 //! Extracted verbatim from `server/mod.rs` (#125 / WS1.4 routes-1).
 
@@ -14,9 +14,10 @@ use serde::{Deserialize, Serialize};
 
 use crate as x0x;
 
+use super::super::sse::SseEvent;
 use super::super::state::AppState;
 use super::super::{not_found, parse_agent_id_hex};
-use crate::contacts::{IdentityType, TrustLevel};
+use crate::contacts::{AuditEntry, ContactsExport, IdentityType, MergeStrategy, TrustLevel};
 
 /// POST /contacts request body.
 #[derive(Debug, Deserialize)]
@@ -273,6 +274,127 @@ pub(in crate::server) async fn list_revocations(
     )
 }
 
+/// GET /contacts/audit — recent trust-level changes, oldest first.
+pub(in crate::server) async fn contacts_audit(
+    State(state): State<Arc<AppState>>,
+) -> impl IntoResponse {
+    let store = state.contacts.read().await;
+    let entries: Vec<serde_json::Value> =
+        store.audit_entries().iter().map(audit_entry_json).collect();
+    Json(serde_json::json!({ "ok": true, "entries": entries }))
+}
+
+fn audit_entry_json(e: &AuditEntry) -> serde_json::Value {
+    serde_json::json!({
+        "timestamp": e.timestamp,
+        "agent_id": hex::encode(e.agent_id.0),
+        "old_level": e.old_level,
+        "new_level": e.new_level,
+        "source": e.source,
+    })
+}
+
+/// Forward contact-store audit entries to `/events` as
+/// `contact:trust_changed`, so UIs see trust changes (including
+/// auto-promotions) as they happen.
+pub(in crate::server) async fn spawn_contact_trust_events(
+    state: Arc<AppState>,
+) -> Vec<tokio::task::JoinHandle<()>> {
+    let mut audit_rx = state.contacts.read().await.subscribe_audit();
+    let mut shutdown_rx = state.shutdown_notify.subscribe();
+    vec![tokio::spawn(async move {
+        loop {
+            let entry = tokio::select! {
+                _ = shutdown_rx.changed() => break,
+                entry = audit_rx.recv() => entry,
+            };
+            match entry {
+                Ok(entry) => {
+                    // No SSE receivers is not an error.
+                    let _ = state.broadcast_tx.send(SseEvent {
+                        event_type: "contact:trust_changed".to_string(),
+                        data: audit_entry_json(&entry),
+                    });
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "contact trust events lagged");
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    })]
+}
+
+/// GET /contacts/export — contacts and revocations in the contacts-file
+/// format, ready for `POST /contacts/import` on another agent.
+pub(in crate::server) async fn export_contacts(
+    State(state): State<Arc<AppState>>,
+) -> impl IntoResponse {
+    let export = state.contacts.read().await.export();
+    Json(serde_json::json!({
+        "ok": true,
+        "contacts": export.contacts,
+        "revocations": export.revocations,
+    }))
+}
+
+/// POST /contacts/import — merge an exported trust list into the store.
+pub(in crate::server) async fn import_contacts(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<ImportContactsRequest>,
+) -> impl IntoResponse {
+    let summary = state
+        .contacts
+        .write()
+        .await
+        .import(req.export, req.strategy);
+    Json(serde_json::json!({
+        "ok": true,
+        "strategy": req.strategy,
+        "added": summary.added,
+        "replaced": summary.replaced,
+        "kept": summary.kept,
+        "revocations_added": summary.revocations_added,
+    }))
+}
+
+/// GET /contacts/:agent_id/reachability — check whether an agent is
+/// connected or connectable, without sending application traffic.
+pub(in crate::server) async fn contact_reachability(
+    State(state): State<Arc<AppState>>,
+    Path(agent_id_hex): Path<String>,
+) -> impl IntoResponse {
+    let agent_id = match parse_agent_id_hex(&agent_id_hex) {
+        Ok(id) => id,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({ "ok": false, "error": e })),
+            );
+        }
+    };
+
+    match state.agent.verify_contact_reachability(&agent_id).await {
+        Ok(report) => (
+            StatusCode::OK,
+            Json(serde_json::json!({
+                "ok": true,
+                "agent_id": agent_id_hex,
+                "reachable": report.is_reachable(),
+                "status": report.status,
+                "address": report.address.map(|a| a.to_string()),
+            })),
+        ),
+        Err(e) => {
+            tracing::error!("verify_contact_reachability failed: {e}");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({ "ok": false, "error": "reachability check failed" })),
+            )
+        }
+    }
+}
+
 /// Contact entry for API responses.
 #[derive(Debug, Serialize)]
 pub(in crate::server) struct ContactEntry {
@@ -283,6 +405,16 @@ pub(in crate::server) struct ContactEntry {
     last_seen: Option<u64>,
 }
 
+/// POST /contacts/import request body: a `GET /contacts/export` response
+/// plus an optional merge strategy (default `keep_existing`).
+#[derive(Debug, Deserialize)]
+pub(in crate::server) struct ImportContactsRequest {
+    #[serde(flatten)]
+    export: ContactsExport,
+    #[serde(default)]
+    strategy: MergeStrategy,
+}
+
 /// POST /contacts/:agent_id/revoke request body.
 #[derive(Debug, Deserialize)]
 pub(in crate::server) struct RevokeContactRequest {
@@ -51,11 +51,16 @@ pub(in crate::server) struct ConnectMachineRequest {
 }
 
 /// POST /direct/send request body.
+///
+/// `recipient` / `payload_base64` are accepted as aliases for `agent_id` /
+/// `payload`.
 #[derive(Debug, Deserialize)]
 pub(in crate::server) struct DirectSendRequest {
     /// Target agent ID as 64-character hex string.
+    #[serde(alias = "recipient")]
     pub(in crate::server) agent_id: String,
     /// Base64-encoded payload.
+    #[serde(alias = "payload_base64")]
     pub(in crate::server) payload: String,
     /// Prefer the raw-QUIC path when a live direct connection exists.
     #[serde(default)]
@@ -443,5 +448,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn direct_send_request_accepts_recipient_aliases() {
+        let req: DirectSendRequest = serde_json::from_value(serde_json::json!({
+            "recipient": "ab".repeat(32),
+            "payload_base64": "aGk=",
+        }))
+        .expect("aliased body");
+        assert_eq!(req.agent_id, "ab".repeat(32));
+        assert_eq!(req.payload, "aGk=");
+        assert!(!req.require_gossip);
+    }
+
     // ── ADR-0016 R2: REST pre-check (exact §3 string + status code) ─────
 }
@@ -45,6 +45,10 @@ pub(in crate::server) struct MlsDecryptRequest {
     pub(in crate::server) ciphertext: String,
     /// Epoch used for encryption.
     pub(in crate::server) epoch: u64,
+    /// Nonce counter returned by `/encrypt`. Absent for ciphertexts from
+    /// older daemons, which sealed under `counter = epoch`.
+    #[serde(default)]
+    pub(in crate::server) counter: Option<u64>,
 }
 
 /// POST /mls/groups/:id/welcome request body.
@@ -52,6 +56,10 @@ pub(in crate::server) struct MlsDecryptRequest {
 pub(in crate::server) struct CreateWelcomeRequest {
     /// Invitee agent ID as hex string.
     pub(in crate::server) agent_id: String,
+    /// Invitee ML-KEM-768 public key (base64). Defaults to the key from the
+    /// invitee's capability advert.
+    #[serde(default)]
+    pub(in crate::server) kem_public_key_b64: Option<String>,
 }
 
 /// POST /mls/groups — create a new MLS group.
@@ -266,7 +274,15 @@ pub(in crate::server) async fn mls_encrypt(
         Err(resp) => return resp,
     };
 
-    match cipher.encrypt(&plaintext, &[], epoch) {
+    let counter = match group.next_nonce_counter() {
+        Ok(counter) => counter,
+        Err(e) => {
+            tracing::warn!("mls_encrypt refused: {e}");
+            return api_error(StatusCode::CONFLICT, format!("{e}"));
+        }
+    };
+
+    match cipher.encrypt(&plaintext, &[], counter) {
         Ok(ciphertext) => {
             drop(groups);
             secure_group_effect_response_after_terminality_recheck(
@@ -276,7 +292,8 @@ pub(in crate::server) async fn mls_encrypt(
                 serde_json::json!({
                 "ok": true,
                 "ciphertext": BASE64.encode(&ciphertext),
-                "epoch": epoch
+                "epoch": epoch,
+                "counter": counter
                 }),
             )
             .await
@@ -309,7 +326,8 @@ pub(in crate::server) async fn mls_decrypt(
         Err(resp) => return resp,
     };
 
-    match cipher.decrypt(&ciphertext, &[], req.epoch) {
+    let counter = req.counter.unwrap_or(req.epoch);
+    match cipher.decrypt(&ciphertext, &[], counter) {
         Ok(plaintext) => {
             drop(groups);
             secure_group_effect_response_after_terminality_recheck(
@@ -350,12 +368,35 @@ pub(in crate::server) async fn create_mls_welcome(
         }
     };
 
+    // The welcome is sealed to the invitee's KEM key, so one is required.
+    let kem_public_key = match req.kem_public_key_b64.as_deref() {
+        Some(encoded) => match decode_base64_payload(encoded) {
+            Ok(key) => key,
+            Err(resp) => return resp,
+        },
+        None => match state
+            .agent
+            .capability_store()
+            .lookup(&invitee)
+            .map(|caps| caps.kem_public_key)
+            .filter(|key| !key.is_empty())
+        {
+            Some(key) => key,
+            None => {
+                return api_error(
+                    StatusCode::CONFLICT,
+                    "no ML-KEM public key known for the invitee; pass kem_public_key_b64",
+                );
+            }
+        },
+    };
+
     let groups = state.mls_groups.read().await;
     let Some(group) = groups.get(&id) else {
         return not_found("group not found");
     };
 
-    match x0x::mls::MlsWelcome::create(group, &invitee) {
+    match x0x::mls::MlsWelcome::create(group, &invitee, &kem_public_key) {
         Ok(welcome) => {
             let welcome_bytes = match bincode::serialize(&welcome) {
                 Ok(b) => b,
@@ -1,7 +1,7 @@
 //! Identity route handlers (`category: "identity"`) for the x0x daemon:
 //! `/agent`, `/introduction`, `/announce`, `/agent/card`,
 //! `/.well-known/agent-card.json`, `/agent/card/import`, `/agent/sign`,
-//! `/agent/verify`, `/agent/user-id`.
+//! `/agent/verify`, `/agent/user-id`, `/agent/words`.
 //!
 //! Extracted verbatim from `server/mod.rs` (#125 / WS1.4 routes-1).
 
@@ -715,6 +715,7 @@ pub(in crate::server) async fn import_agent_card(
                 cert_not_after: None,
                 agent_certificate: None,
                 agent_public_key: Vec::new(),
+                status: None,
             })
             .await;
     }
@@ -1046,6 +1047,36 @@ pub(in crate::server) async fn agent_user_id_handler(
     }))
 }
 
+/// GET /agent/words — the `/agent` ids in four-word form.
+///
+/// Each id is rendered on its own (`user_words` is not the combined
+/// `agent @ user` form). Falls back to `hex:<id>` in builds without the
+/// `words` feature.
+pub(in crate::server) async fn agent_words_handler(
+    State(state): State<Arc<AppState>>,
+) -> Json<ApiResponse<AgentWordsData>> {
+    Json(ApiResponse {
+        ok: true,
+        data: agent_words(
+            &state.agent.agent_id(),
+            &state.agent.machine_id(),
+            state.agent.user_id().as_ref(),
+        ),
+    })
+}
+
+fn agent_words(
+    agent_id: &x0x::identity::AgentId,
+    machine_id: &x0x::identity::MachineId,
+    user_id: Option<&x0x::identity::UserId>,
+) -> AgentWordsData {
+    AgentWordsData {
+        agent_words: x0x::words::render_hex_id(&hex::encode(agent_id.as_bytes())),
+        machine_words: x0x::words::render_hex_id(&hex::encode(machine_id.as_bytes())),
+        user_words: user_id.map(|u| x0x::words::render_hex_id(&hex::encode(u.as_bytes()))),
+    }
+}
+
 /// Deserialize a field as `Some(value)` whenever the field is present —
 /// even when the value is JSON null — so present-but-null can be
 /// distinguished from an omitted field (serde's `Option<T>` maps both
@@ -1069,6 +1100,14 @@ pub(in crate::server) struct AgentData {
     kem_public_key_b64: String,
 }
 
+/// Four-word agent identity response.
+#[derive(Debug, Serialize)]
+pub(in crate::server) struct AgentWordsData {
+    agent_words: String,
+    machine_words: String,
+    user_words: Option<String>,
+}
+
 /// Introduction card response (fields vary by trust level).
 #[derive(Debug, Serialize)]
 pub(in crate::server) struct IntroductionCardData {
@@ -1205,3 +1244,51 @@ pub(in crate::server) async fn identity_revocations(
         Json(serde_json::json!({ "revocations": items })),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use x0x::identity::{AgentId, MachineId, UserId};
+
+    #[test]
+    fn agent_words_omit_missing_user() {
+        let words = agent_words(&AgentId([1; 32]), &MachineId([2; 32]), None);
+        assert!(words.user_words.is_none());
+    }
+
+    #[cfg(feature = "words")]
+    #[test]
+    fn agent_words_decode_back_to_ids() {
+        let (agent, machine, user) = (
+            AgentId([0x11; 32]),
+            MachineId([0x22; 32]),
+            UserId([0x33; 32]),
+        );
+        let words = agent_words(&agent, &machine, Some(&user));
+        let user_words = words.user_words.expect("user words");
+
+        for (rendered, id) in [
+            (&words.agent_words, agent.as_bytes()),
+            (&words.machine_words, machine.as_bytes()),
+            (&user_words, user.as_bytes()),
+        ] {
+            assert!(
+                !x0x::words::is_hex_fallback(rendered),
+                "expected words: {rendered}"
+            );
+            let prefix = x0x::words::decode_identity_prefix(rendered).expect("decode");
+            assert!(
+                hex::encode(id).starts_with(&prefix),
+                "{rendered} decoded to {prefix}, not a prefix of the id"
+            );
+        }
+    }
+
+    #[cfg(not(feature = "words"))]
+    #[test]
+    fn agent_words_fall_back_to_hex() {
+        let words = agent_words(&AgentId([0x11; 32]), &MachineId([0x22; 32]), None);
+        assert_eq!(words.agent_words, format!("hex:{}", "11".repeat(32)));
+        assert_eq!(words.machine_words, format!("hex:{}", "22".repeat(32)));
+    }
+}
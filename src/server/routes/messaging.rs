@@ -5,7 +5,7 @@
 
 use super::super::sse::SseEvent;
 use super::super::state::AppState;
-use super::super::{api_error, bad_request, not_found};
+use super::super::{api_error, bad_request, not_found, SSE_BROADCAST_CAPACITY};
 use crate as x0x;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
@@ -13,8 +13,11 @@ use axum::response::IntoResponse;
 use axum::Json;
 use base64::engine::general_purpose::STANDARD as BASE64;
 use base64::Engine;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use tokio::sync::broadcast;
 use x0x::logging::LogHexId;
 
 /// Record one verified message from an opted-in topic (ADR-0023 §4).
@@ -65,13 +68,34 @@ fn record_topic_message(
 pub(in crate::server) struct RestSubscription {
     /// Topic the subscription the subscription is for (retained for diagnostics/logging).
     topic: String,
-    /// Forwarder task draining the gossip subscription into the SSE broadcast.
+    /// Backpressure counters of the underlying gossip subscription, kept
+    /// here because the `Subscription` itself lives in the forwarder task.
+    overflow: Arc<x0x::gossip::SubscriptionOverflow>,
+    /// This subscription's fan-out to `/events` clients. Its own bounded
+    /// broadcast, so a client's `Lagged(n)` on it is charged here.
+    events: broadcast::Sender<SseEvent>,
+    /// Events of this subscription skipped by lagging `/events` clients,
+    /// summed over clients.
+    sse_dropped: AtomicU64,
+    /// Forwarder task draining the gossip subscription into `events`.
     /// Aborting it drops the underlying `Subscription`, which releases the
     /// gossip topic ref-count and ends delivery — without this, an
     /// unsubscribed stream would keep forwarding messages to SSE forever.
     forwarder: tokio::task::JoinHandle<()>,
 }
 
+impl RestSubscription {
+    /// A receiver on this subscription's `/events` fan-out.
+    pub(in crate::server) fn subscribe_events(&self) -> broadcast::Receiver<SseEvent> {
+        self.events.subscribe()
+    }
+
+    /// Charge `skipped` events lost by one lagging `/events` client.
+    pub(in crate::server) fn record_sse_lag(&self, skipped: u64) {
+        self.sse_dropped.fetch_add(skipped, Ordering::Relaxed);
+    }
+}
+
 /// POST /publish request body.
 #[derive(Debug, Deserialize)]
 pub(in crate::server) struct PublishRequest {
@@ -95,6 +119,10 @@ pub(in crate::server) async fn publish(
     if req.topic.is_empty() {
         return bad_request("topic must not be empty");
     }
+    // Task-list sync topics would feed every replica's CRDT.
+    if let Err(e) = x0x::gossip::check_app_topic(&req.topic) {
+        return bad_request(format!("{e}"));
+    }
 
     // Decode base64 payload
     let payload = match BASE64.decode(&req.payload) {
@@ -119,69 +147,9 @@ pub(in crate::server) async fn subscribe(
     State(state): State<Arc<AppState>>,
     Json(req): Json<SubscribeRequest>,
 ) -> impl IntoResponse {
-    match state.agent.subscribe(&req.topic).await {
-        Ok(sub) => {
-            let id = format!("{:016x}", rand::random::<u64>());
-            // Spawn background task to forward messages to SSE broadcast
-            let broadcast_tx = state.broadcast_tx.clone();
-            let topic = req.topic.clone();
-            let mut recv_sub = sub;
-            let sub_id = id.clone();
-            // ADR-0023 §4 topic opt-in: record this topic's verified traffic
-            // when `[history] record_topics` lists it (local ingest option).
-            let history = if state.history_record_topics.contains(&req.topic) {
-                state.agent.history().cloned()
-            } else {
-                None
-            };
-            let forwarder = tokio::spawn(async move {
-                while let Some(msg) = recv_sub.recv().await {
-                    if let Some(history) = history.as_ref() {
-                        record_topic_message(history, &topic, &msg);
-                    }
-                    tracing::info!(
-                        topic = %topic,
-                        sub_id = %sub_id,
-                        payload_len = msg.payload.len(),
-                        "[5/6 x0xd] received from subscriber channel, broadcasting to SSE"
-                    );
-                    let event = SseEvent {
-                        event_type: "message".to_string(),
-                        data: serde_json::json!({
-                            "subscription_id": sub_id,
-                            "topic": topic,
-                            "payload": BASE64.encode(&msg.payload),
-                            "sender": msg.sender.map(|s| hex::encode(s.0)),
-                            "verified": msg.verified,
-                            "trust_level": msg.trust_level.map(|t| t.to_string()),
-                        }),
-                    };
-                    match broadcast_tx.send(event) {
-                        Ok(n) => tracing::info!(
-                            topic = %topic,
-                            receivers = n,
-                            "[5/6 x0xd] broadcast sent to {n} SSE receivers"
-                        ),
-                        Err(_) => tracing::warn!(
-                            topic = %LogHexId::topic(&topic),
-                            "[5/6 x0xd] broadcast send failed (no SSE receivers)"
-                        ),
-                    }
-                }
-            });
-
-            // Track the forwarder task so the DELETE handler can abort it.
-            // Aborting drops the underlying `Subscription`, releasing the
-            // gossip topic ref-count and stopping SSE delivery.
-            let mut subs = state.subscriptions.write().await;
-            subs.insert(
-                id.clone(),
-                RestSubscription {
-                    topic: req.topic.clone(),
-                    forwarder,
-                },
-            );
-
+    match start_subscription(&state, &req.topic).await {
+        Ok(id) => {
+            save_subscriptions(&state).await;
             (
                 StatusCode::OK,
                 Json(serde_json::json!({ "ok": true, "subscription_id": id })),
@@ -191,13 +159,226 @@ pub(in crate::server) async fn subscribe(
     }
 }
 
+/// Subscribe to `topic`, spawn its SSE forwarder and track it under a fresh
+/// subscription id, which is returned.
+async fn start_subscription(state: &Arc<AppState>, topic: &str) -> x0x::error::Result<String> {
+    let sub = state.agent.subscribe(topic).await?;
+    let id = format!("{:016x}", rand::random::<u64>());
+    let overflow = sub.overflow();
+    let mut recv_sub = sub;
+    let sub_id = id.clone();
+    let forwarded_topic = topic.to_string();
+    // ADR-0023 §4 topic opt-in: record this topic's verified traffic
+    // when `[history] record_topics` lists it (local ingest option).
+    let history = if state.history_record_topics.iter().any(|t| t == topic) {
+        state.agent.history().cloned()
+    } else {
+        None
+    };
+    // Hold the write lock until the stream is tracked: `/events` clients
+    // connecting meanwhile wait, and those already connected get the feed
+    // before the forwarder can send anything on it.
+    let mut subscriptions = state.subscriptions.write().await;
+    let (events, _) = broadcast::channel::<SseEvent>(SSE_BROADCAST_CAPACITY);
+    state.sse_stats.attach(&id, &events);
+    // Spawn background task to forward messages to the SSE feed
+    let events_tx = events.clone();
+    let forwarder = tokio::spawn(async move {
+        let topic = forwarded_topic;
+        while let Some(msg) = recv_sub.recv().await {
+            if let Some(history) = history.as_ref() {
+                record_topic_message(history, &topic, &msg);
+            }
+            tracing::info!(
+                topic = %topic,
+                sub_id = %sub_id,
+                payload_len = msg.payload.len(),
+                "[5/6 x0xd] received from subscriber channel, broadcasting to SSE"
+            );
+            let event = SseEvent {
+                event_type: "message".to_string(),
+                data: serde_json::json!({
+                    "subscription_id": sub_id,
+                    "topic": topic,
+                    "payload": BASE64.encode(&msg.payload),
+                    "sender": msg.sender.map(|s| hex::encode(s.0)),
+                    "verified": msg.verified,
+                    "trust_level": msg.trust_level.map(|t| t.to_string()),
+                }),
+            };
+            match events_tx.send(event) {
+                Ok(n) => tracing::info!(
+                    topic = %topic,
+                    receivers = n,
+                    "[5/6 x0xd] broadcast sent to {n} SSE receivers"
+                ),
+                Err(_) => tracing::warn!(
+                    topic = %LogHexId::topic(&topic),
+                    "[5/6 x0xd] broadcast send failed (no SSE receivers)"
+                ),
+            }
+        }
+    });
+
+    // Track the forwarder task so the DELETE handler can abort it.
+    // Aborting drops the underlying `Subscription`, releasing the
+    // gossip topic ref-count and stopping SSE delivery.
+    subscriptions.insert(
+        id.clone(),
+        RestSubscription {
+            topic: topic.to_string(),
+            overflow,
+            events,
+            sse_dropped: AtomicU64::new(0),
+            forwarder,
+        },
+    );
+    Ok(id)
+}
+
+/// On-disk form of `subscriptions.json`: the topics of live `/subscribe`
+/// streams.
+///
+/// Only the subscription intent survives a restart. Gossip is not a
+/// store-and-forward queue, so payloads published while the daemon was down
+/// are not recoverable; restored streams also get fresh subscription ids.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct PersistedSubscriptions {
+    #[serde(default)]
+    topics: Vec<String>,
+}
+
+impl PersistedSubscriptions {
+    /// Distinct topics of `subs`, sorted.
+    fn from_live(subs: &HashMap<String, RestSubscription>) -> Self {
+        let topics: BTreeSet<&str> = subs.values().map(|sub| sub.topic.as_str()).collect();
+        Self {
+            topics: topics.into_iter().map(str::to_string).collect(),
+        }
+    }
+}
+
+/// Read `subscriptions.json` (best-effort: a missing, unreadable or corrupt
+/// file yields no topics).
+async fn read_persisted_subscriptions(path: &std::path::Path) -> PersistedSubscriptions {
+    match tokio::fs::read(path).await {
+        Ok(bytes) => match serde_json::from_slice(&bytes) {
+            Ok(persisted) => persisted,
+            Err(e) => {
+                tracing::warn!("failed to parse subscriptions file {}: {e}", path.display());
+                PersistedSubscriptions::default()
+            }
+        },
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            tracing::debug!("no persisted subscriptions at {}", path.display());
+            PersistedSubscriptions::default()
+        }
+        Err(e) => {
+            tracing::warn!("failed to read subscriptions file {}: {e}", path.display());
+            PersistedSubscriptions::default()
+        }
+    }
+}
+
+/// Write the current topic set to `subscriptions.json` (best-effort).
+async fn save_subscriptions(state: &AppState) {
+    // Snapshot under the persistence lock so an older snapshot cannot
+    // rename over a newer one.
+    let _guard = state.subscriptions_persistence_lock.lock().await;
+    let persisted = PersistedSubscriptions::from_live(&state.subscriptions.read().await);
+    let path = &state.subscriptions_path;
+    let bytes = match serde_json::to_vec_pretty(&persisted) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::warn!("failed to serialise subscriptions: {e}");
+            return;
+        }
+    };
+    if let Some(parent) = path.parent() {
+        let _ = tokio::fs::create_dir_all(parent).await;
+    }
+    let tmp = path.with_extension("json.tmp");
+    let result = match tokio::fs::write(&tmp, &bytes).await {
+        Ok(()) => tokio::fs::rename(&tmp, path).await,
+        Err(e) => Err(e),
+    };
+    if let Err(e) = result {
+        tracing::warn!("failed to persist subscriptions to {}: {e}", path.display());
+    }
+}
+
+/// Re-create the `/subscribe` streams recorded in `subscriptions.json`.
+///
+/// Called at startup before the network join, so restored streams are
+/// forwarding to SSE by the time peers deliver anything. Each gets a new
+/// subscription id.
+pub(in crate::server) async fn restore_subscriptions(state: &Arc<AppState>) {
+    let persisted = read_persisted_subscriptions(&state.subscriptions_path).await;
+    let mut restored = 0usize;
+    for topic in &persisted.topics {
+        match start_subscription(state, topic).await {
+            Ok(_) => restored += 1,
+            Err(e) => tracing::warn!(
+                topic = %LogHexId::topic(topic),
+                "failed to restore subscription: {e}"
+            ),
+        }
+    }
+    if restored > 0 {
+        tracing::info!(
+            "restored {restored} subscriptions from {}",
+            state.subscriptions_path.display()
+        );
+    }
+}
+
+/// Per-subscription view served by `GET /subscriptions`, sorted by id.
+///
+/// `dropped` counts messages lost on the way to `/events` clients: those
+/// refused by the subscription's full gossip queue plus those skipped by
+/// lagging SSE clients (`sse_dropped`, summed over clients); `buffered` is
+/// the current queue depth.
+fn subscription_summaries(subs: &HashMap<String, RestSubscription>) -> Vec<serde_json::Value> {
+    let mut summaries: Vec<serde_json::Value> = subs
+        .iter()
+        .map(|(id, sub)| {
+            let sse_dropped = sub.sse_dropped.load(Ordering::Relaxed);
+            serde_json::json!({
+                "subscription_id": id,
+                "topic": sub.topic,
+                "dropped": sub.overflow.dropped() + sse_dropped,
+                "sse_dropped": sse_dropped,
+                "buffered": sub.overflow.buffered(),
+            })
+        })
+        .collect();
+    summaries.sort_by(|a, b| {
+        a["subscription_id"]
+            .as_str()
+            .cmp(&b["subscription_id"].as_str())
+    });
+    summaries
+}
+
+/// GET /subscriptions
+pub(in crate::server) async fn list_subscriptions(
+    State(state): State<Arc<AppState>>,
+) -> impl IntoResponse {
+    let subs = state.subscriptions.read().await;
+    let subscriptions = subscription_summaries(&subs);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({ "ok": true, "subscriptions": subscriptions })),
+    )
+}
+
 /// DELETE /subscribe/:id
 pub(in crate::server) async fn unsubscribe(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
 ) -> impl IntoResponse {
-    let mut subs = state.subscriptions.write().await;
-    if let Some(sub) = subs.remove(&id) {
+    let removed = state.subscriptions.write().await.remove(&id);
+    if let Some(sub) = removed {
         // Stop the forwarder task. Dropping its `Subscription` releases the
         // gossip topic ref-count and ends message delivery for this stream.
         sub.forwarder.abort();
@@ -206,8 +387,49 @@ pub(in crate::server) async fn unsubscribe(
             topic = %sub.topic,
             "unsubscribed: forwarder aborted, gossip subscription released"
         );
+        save_subscriptions(&state).await;
         (StatusCode::OK, Json(serde_json::json!({ "ok": true })))
     } else {
         not_found("subscription not found")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The persisted topic set collapses duplicate streams and survives a
+    /// write/read round trip; a corrupt file restores nothing.
+    #[tokio::test]
+    async fn persisted_subscriptions_roundtrip_distinct_topics() {
+        let mut subs = HashMap::new();
+        for (id, topic) in [("a", "news"), ("b", "alerts"), ("c", "news")] {
+            subs.insert(
+                id.to_string(),
+                RestSubscription {
+                    topic: topic.to_string(),
+                    overflow: Arc::default(),
+                    events: broadcast::channel(1).0,
+                    sse_dropped: AtomicU64::new(0),
+                    forwarder: tokio::spawn(async {}),
+                },
+            );
+        }
+        let persisted = PersistedSubscriptions::from_live(&subs);
+        assert_eq!(persisted.topics, vec!["alerts", "news"]);
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("subscriptions.json");
+        assert_eq!(
+            read_persisted_subscriptions(&path).await,
+            PersistedSubscriptions::default()
+        );
+        tokio::fs::write(&path, serde_json::to_vec(&persisted).expect("encode"))
+            .await
+            .expect("write");
+        assert_eq!(read_persisted_subscriptions(&path).await, persisted);
+
+        tokio::fs::write(&path, b"{not json").await.expect("write");
+        assert!(read_persisted_subscriptions(&path).await.topics.is_empty());
+    }
+}
@@ -29,8 +29,9 @@ pub(super) use connect::{
     connect_diagnostics_handler, forward_add, forward_list, forward_remove, streams_diagnostics,
 };
 pub(super) use contacts::{
-    add_contact, delete_contact, list_contacts, list_revocations, quick_trust, revoke_contact,
-    update_contact,
+    add_contact, contact_reachability, contacts_audit, delete_contact, export_contacts,
+    import_contacts, list_contacts, list_revocations, quick_trust, revoke_contact,
+    spawn_contact_trust_events, update_contact,
 };
 pub(super) use direct::{
     connect_agent, connect_machine, direct_connections, direct_message_send_config, direct_send,
@@ -52,15 +53,17 @@ pub(super) use history::{
     history_diagnostics, history_list, history_purge, history_search, history_stats,
 };
 pub(super) use identity::{
-    agent_info, agent_sign, agent_user_id_handler, agent_verify, announce_identity,
-    get_a2a_agent_card, get_agent_card, identity_revocations, identity_revoke, import_agent_card,
-    introduction,
+    agent_info, agent_sign, agent_user_id_handler, agent_verify, agent_words_handler,
+    announce_identity, get_a2a_agent_card, get_agent_card, identity_revocations, identity_revoke,
+    import_agent_card, introduction,
 };
 pub(super) use machines::{
     add_machine, delete_machine, discovered_machine, discovered_machines, list_machines,
     machines_by_user_handler, pin_machine, unpin_machine,
 };
-pub(super) use messaging::{publish, subscribe, unsubscribe, RestSubscription};
+pub(super) use messaging::{
+    list_subscriptions, publish, restore_subscriptions, subscribe, unsubscribe, RestSubscription,
+};
 pub(super) use named_groups::{
     add_named_group_member, apply_named_group_metadata_event, approve_join_request,
     ban_group_member, cancel_join_request, create_discovery_subscription, create_group_invite,
@@ -85,22 +88,27 @@ pub(super) use named_groups::{
     GROUP_PUBLIC_MESSAGE_DM_PREFIX,
 };
 pub(super) use network::{
-    ack_diagnostics, bootstrap_cache_stats, connectivity_diagnostics, dm_diagnostics,
-    gossip_diagnostics, groups_diagnostics, network_status, peer_health_handler, peers,
-    probe_peer_handler,
+    ack_diagnostics, bootstrap_cache_stats, bootstrap_health, connectivity_diagnostics,
+    disconnect_all_peers, dm_diagnostics, gossip_diagnostics, groups_diagnostics, network_status,
+    peer_cache, peer_health_handler, peers, probe_peer_handler,
 };
 pub(super) use presence::{
-    presence, presence_find, presence_foaf, presence_online, presence_status,
+    clear_presence_status, presence, presence_find, presence_foaf, presence_online,
+    presence_status, set_presence_status,
 };
 pub(super) use status::{
-    get_constitution, get_constitution_json, health, shutdown_handler, status,
+    get_constitution, get_constitution_json, health, health_persistence, metrics, shutdown_handler,
+    status,
 };
 pub(super) use stores::{
     apply_direct_kv_store_delta, create_kv_store, delete_kv_value, get_kv_value, join_kv_store,
     list_kv_keys, list_kv_stores, put_kv_value, KvStoreDirectDelta, KV_STORE_DELTA_DM_PREFIX,
 };
 pub(super) use tasks::{
-    add_task, apply_group_authorization, create_task_list, list_task_lists, list_tasks, update_task,
+    add_task, apply_group_authorization, checkpoint_all_task_lists, checkpoint_task_list,
+    create_task_list, export_task_list, list_task_lists, list_tasks, remove_task, task_history,
+    task_list_sync_stats, update_persistence_policy, update_task, validate_task_list,
+    watch_task_list_updates,
 };
 pub(super) use trust::evaluate_trust;
 pub(super) use upgrade::{
@@ -14003,7 +14003,7 @@ pub(in crate::server) type WelcomeFetchWaiter =
 mod tests {
     use super::*;
 
-    use super::super::super::sse::SseEvent;
+    use super::super::super::sse::{SseEvent, SseStats};
     use super::super::super::state::DaemonUpdateConfig;
     use super::super::super::ws::WsOutboundStats;
     use super::super::super::{auth, crdt_subscriptions};
@@ -14015,6 +14015,7 @@ mod tests {
     use tokio::sync::{broadcast, mpsc, watch};
 
     mod cache_hardening_followup;
+    mod pubsub_endpoints;
 
     fn fake_group_state_commit(
         group_id: &str,
@@ -14650,11 +14651,15 @@ mod tests {
             history_record_topics: Vec::new(),
             history_config: x0x::history::HistoryConfig::default(),
             subscriptions: RwLock::new(HashMap::new()),
+            subscriptions_path: data_dir.join("subscriptions.json"),
+            subscriptions_persistence_lock: Mutex::new(()),
             task_lists: RwLock::new(HashMap::new()),
             kv_stores: RwLock::new(HashMap::new()),
             crdt_subscriptions: RwLock::new(crdt_subscriptions::CrdtSubscriptionManifest::default()),
             crdt_subscriptions_path: data_dir.join("crdt-subscriptions.json"),
             kv_store_state_dir: data_dir.join("kv-stores"),
+            auto_rejoin_task_lists: true,
+            task_list_recovery_failures: RwLock::new(HashMap::new()),
             crdt_subscriptions_persistence_lock: Mutex::new(()),
             crdt_handle_locks: RwLock::new(HashMap::new()),
             named_groups: RwLock::new(named_groups),
@@ -14690,6 +14695,7 @@ mod tests {
             ws_sessions: RwLock::new(HashMap::new()),
             ws_topics: RwLock::new(HashMap::new()),
             ws_outbound_stats: Arc::new(WsOutboundStats::default()),
+            sse_stats: Arc::new(SseStats::default()),
             api_address: "127.0.0.1:0".parse().expect("valid test API address"),
             start_time: Instant::now(),
             broadcast_tx,
@@ -16761,6 +16767,7 @@ mod tests {
                     .expect("ciphertext present")
                     .to_string(),
                 epoch: encrypted.0["epoch"].as_u64().expect("epoch present"),
+                counter: Some(encrypted.0["counter"].as_u64().expect("counter present")),
             }),
         )
         .await;
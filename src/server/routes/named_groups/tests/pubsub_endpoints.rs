@@ -0,0 +1,125 @@
+use super::*;
+
+use super::super::super::super::sse::events_sse;
+use super::super::super::super::SSE_BROADCAST_CAPACITY;
+use super::super::super::messaging::{list_subscriptions, publish, subscribe};
+use tokio_stream::StreamExt as _;
+
+/// Test state whose agent has a gossip runtime (loopback, no bootstrap), so
+/// the publish and subscribe handlers run for real.
+async fn networked_test_state() -> Result<(Arc<AppState>, tempfile::TempDir)> {
+    let dir = tempfile::tempdir()?;
+    let data_dir = dir.path();
+    let agent = Arc::new(
+        Agent::builder()
+            .with_machine_key(data_dir.join("machine.key"))
+            .with_agent_key(x0x::identity::AgentKeypair::generate()?)
+            .with_agent_cert_path(data_dir.join("agent.cert"))
+            .with_peer_cache_disabled()
+            .with_contact_store_path(data_dir.join("contacts.json"))
+            .with_network_config(x0x::network::NetworkConfig {
+                bind_addr: Some("127.0.0.1:0".parse()?),
+                bootstrap_nodes: Vec::new(),
+                ..Default::default()
+            })
+            .build()
+            .await?,
+    );
+    let state = secure_endpoint_test_state_at(data_dir, agent).await?;
+    Ok((state, dir))
+}
+
+async fn publish_json(state: &Arc<AppState>, topic: &str, payload: &[u8]) -> Result<StatusCode> {
+    let req = serde_json::from_value(serde_json::json!({
+        "topic": topic,
+        "payload": BASE64.encode(payload),
+    }))?;
+    let response = publish(State(Arc::clone(state)), Json(req))
+        .await
+        .into_response();
+    Ok(response.status())
+}
+
+/// An `/events` client that stops reading while its subscription keeps
+/// publishing loses events past the buffer, and `GET /subscriptions`
+/// charges them to that subscription.
+#[tokio::test]
+async fn stalled_events_client_shows_up_as_dropped_in_subscriptions() -> Result<()> {
+    let (state, _dir) = networked_test_state().await?;
+    let topic = "local:sse-lag/check";
+
+    let req = serde_json::from_value(serde_json::json!({ "topic": topic }))?;
+    let response = subscribe(State(Arc::clone(&state)), Json(req))
+        .await
+        .into_response();
+    let (status, body) = response_json(response).await?;
+    assert_eq!(status, StatusCode::OK, "subscribe: {body}");
+    let sub_id = body["subscription_id"]
+        .as_str()
+        .context("subscription_id")?
+        .to_string();
+
+    // Connected, but nobody reads the stream while the topic fills up.
+    let events = events_sse(State(Arc::clone(&state))).await.into_response();
+    for i in 0..2 * SSE_BROADCAST_CAPACITY {
+        let status = publish_json(&state, topic, format!("msg-{i}").as_bytes()).await?;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    // Wait for the forwarder to drain the gossip queue into the feed.
+    tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            let (_, body) = response_json(
+                list_subscriptions(State(Arc::clone(&state)))
+                    .await
+                    .into_response(),
+            )
+            .await?;
+            if body["subscriptions"][0]["buffered"] == 0 {
+                return Ok::<_, anyhow::Error>(());
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    })
+    .await
+    .context("forwarder did not drain the subscription")??;
+
+    // The client wakes up: its first read notices the lag.
+    let mut frames = events.into_body().into_data_stream();
+    tokio::time::timeout(Duration::from_secs(10), frames.next())
+        .await
+        .context("no SSE frame")?
+        .context("SSE stream ended")??;
+
+    let (status, body) = response_json(
+        list_subscriptions(State(Arc::clone(&state)))
+            .await
+            .into_response(),
+    )
+    .await?;
+    assert_eq!(status, StatusCode::OK);
+    let summary = &body["subscriptions"][0];
+    assert_eq!(summary["subscription_id"], sub_id.as_str());
+    let dropped = summary["dropped"].as_u64().context("dropped")?;
+    let sse_dropped = summary["sse_dropped"].as_u64().context("sse_dropped")?;
+    assert!(dropped > 0, "{body}");
+    assert!(sse_dropped > 0 && sse_dropped <= dropped, "{body}");
+    Ok(())
+}
+
+/// POST /publish refuses task-list sync topics (both the gossip and the
+/// `local:` form) but still accepts other `x0x.` app topics the GUI uses.
+#[tokio::test]
+async fn publish_refuses_only_task_list_sync_topics() -> Result<()> {
+    let (state, _dir) = networked_test_state().await?;
+
+    for topic in ["x0x/tasklist/v1/shared", "local:x0x/tasklist/v1/shared"] {
+        let status = publish_json(&state, topic, b"forged").await?;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{topic}");
+    }
+    for topic in ["x0x.space.abc.feed", "local:x0x.group.abc.chat"] {
+        let status = publish_json(&state, topic, b"hello").await?;
+        assert_eq!(status, StatusCode::OK, "{topic}");
+    }
+    Ok(())
+}
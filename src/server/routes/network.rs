@@ -161,6 +161,77 @@ pub(in crate::server) async fn bootstrap_cache_stats(
     }
 }
 
+/// GET /bootstrap/health — probe every configured bootstrap peer once.
+///
+/// Per-peer reachability and dial latency from [`x0x::bootstrap::probe`],
+/// bounded by `?timeout_ms` (default 2s, clamped to `[100, 30000]`).
+pub(in crate::server) async fn bootstrap_health(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Query(q): axum::extract::Query<ProbeQuery>,
+) -> impl IntoResponse {
+    let Some(network) = state.agent.network() else {
+        return api_error(StatusCode::SERVICE_UNAVAILABLE, "network not initialized");
+    };
+    let timeout_ms = q.timeout_ms.unwrap_or(2_000).clamp(100, 30_000);
+    let peers = x0x::bootstrap::probe(
+        network,
+        &network.config().bootstrap_nodes,
+        Duration::from_millis(timeout_ms),
+    )
+    .await;
+    let reachable = peers.iter().filter(|p| p.reachable).count();
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "ok": true,
+            "total": peers.len(),
+            "reachable": reachable,
+            "timeout_ms": timeout_ms,
+            "peers": peers,
+        })),
+    )
+}
+
+/// GET /peers/cache — human-readable dump of the bootstrap peer cache.
+pub(in crate::server) async fn peer_cache(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    match state.agent.network() {
+        Some(network) => {
+            let peers = network.peer_cache_entries().await;
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "ok": true,
+                    "count": peers.len(),
+                    "peers": peers
+                })),
+            )
+        }
+        None => api_error(StatusCode::SERVICE_UNAVAILABLE, "network not initialized"),
+    }
+}
+
+/// POST /peers/disconnect-all — drop every connection, keep the node up.
+///
+/// Emergency isolation: peers are disconnected with an admin reason (no
+/// automatic redial) while the endpoint stays bound for new connections.
+pub(in crate::server) async fn disconnect_all_peers(
+    State(state): State<Arc<AppState>>,
+) -> impl IntoResponse {
+    match state.agent.network() {
+        Some(network) => {
+            let disconnected = network.disconnect_all().await;
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "ok": true,
+                    "disconnected": disconnected,
+                })),
+            )
+        }
+        None => api_error(StatusCode::SERVICE_UNAVAILABLE, "network not initialized"),
+    }
+}
+
 fn duration_millis_u64(duration: Duration) -> u64 {
     u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
 }
@@ -583,6 +654,7 @@ pub(in crate::server) async fn gossip_diagnostics(
                 "stats": snap,
                 "pubsub_stages": pubsub_stages,
                 "dispatcher": state.agent.gossip_dispatch_stats(),
+                "pause": state.agent.gossip_pause_stats(),
                 "recv_pump": state.agent.recv_pump_diagnostics(),
                 "discovery_cache_entries": {
                     "agents": agents,
@@ -684,7 +756,8 @@ fn parse_peer_id(hex_str: &str) -> Result<ant_quic::PeerId, (StatusCode, Json<se
     Ok(ant_quic::PeerId(arr))
 }
 
-/// Query for `POST /peers/:peer_id/probe` — optional timeout (default 2s).
+/// Query for `POST /peers/:peer_id/probe` and `GET /bootstrap/health` —
+/// optional timeout (default 2s).
 #[derive(Debug, serde::Deserialize, Default)]
 pub(in crate::server) struct ProbeQuery {
     /// Probe timeout in milliseconds; clamped to `[100, 30000]`.
@@ -34,13 +34,27 @@ fn default_foaf_timeout_ms() -> u64 {
 }
 
 /// GET /presence
+///
+/// `statuses` maps agent ids to the status each one set with
+/// `Agent::set_status`; agents without a status are omitted.
 pub(in crate::server) async fn presence(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     match state.agent.presence().await {
         Ok(agents) => {
             let entries: Vec<String> = agents.iter().map(|a| hex::encode(a.as_bytes())).collect();
+            let mut statuses = serde_json::Map::new();
+            for agent_id in &agents {
+                let status = state
+                    .agent
+                    .cached_agent(agent_id)
+                    .await
+                    .and_then(|agent| agent.status);
+                if let Some(status) = status {
+                    statuses.insert(hex::encode(agent_id.as_bytes()), status.into());
+                }
+            }
             (
                 StatusCode::OK,
-                Json(serde_json::json!({ "ok": true, "agents": entries })),
+                Json(serde_json::json!({ "ok": true, "agents": entries, "statuses": statuses })),
             )
         }
         Err(e) => api_error(StatusCode::INTERNAL_SERVER_ERROR, format!("{e}")),
@@ -163,3 +177,88 @@ pub(in crate::server) async fn presence_status(
         Json(serde_json::json!({ "ok": true, "online": online, "agent": entry })),
     )
 }
+
+/// Body of `POST /presence/status`.
+#[derive(Debug, Deserialize)]
+pub(in crate::server) struct SetPresenceStatusRequest {
+    status: String,
+}
+
+/// POST /presence/status
+///
+/// Set the status carried on this agent's identity announcements. It goes
+/// out with the next heartbeat.
+pub(in crate::server) async fn set_presence_status(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<SetPresenceStatusRequest>,
+) -> impl IntoResponse {
+    apply_presence_status(&state.agent, req.status)
+}
+
+/// DELETE /presence/status
+///
+/// Clear the advertised status.
+pub(in crate::server) async fn clear_presence_status(
+    State(state): State<Arc<AppState>>,
+) -> impl IntoResponse {
+    apply_presence_status(&state.agent, String::new())
+}
+
+fn apply_presence_status(
+    agent: &x0x::Agent,
+    status: String,
+) -> (StatusCode, Json<serde_json::Value>) {
+    if status.len() > x0x::MAX_AGENT_STATUS_BYTES {
+        return bad_request(format!(
+            "status must be at most {} bytes",
+            x0x::MAX_AGENT_STATUS_BYTES
+        ));
+    }
+    if status.chars().any(char::is_control) {
+        return bad_request("status must not contain control characters");
+    }
+    match agent.set_status(status) {
+        Ok(()) => (
+            StatusCode::OK,
+            Json(serde_json::json!({ "ok": true, "status": agent.status() })),
+        ),
+        Err(e) => bad_request(format!("{e}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn presence_status_is_set_and_cleared_on_next_announcement() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let agent = x0x::Agent::builder()
+            .with_machine_key(temp.path().join("machine.key"))
+            .with_agent_key_path(temp.path().join("agent.key"))
+            .with_agent_cert_path(temp.path().join("agent.cert"))
+            .with_contact_store_path(temp.path().join("contacts.json"))
+            .build()
+            .await
+            .expect("agent");
+
+        let (status, Json(body)) = apply_presence_status(&agent, "reviewing PRs".to_string());
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "reviewing PRs");
+        let announcement = agent.build_announcement(false, false).expect("announce");
+        assert_eq!(announcement.status.as_deref(), Some("reviewing PRs"));
+
+        let (status, _) = apply_presence_status(&agent, "line\nbreak".to_string());
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, _) =
+            apply_presence_status(&agent, "x".repeat(x0x::MAX_AGENT_STATUS_BYTES + 1));
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(agent.status().as_deref(), Some("reviewing PRs"));
+
+        let (status, Json(body)) = apply_presence_status(&agent, String::new());
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["status"].is_null());
+        let announcement = agent.build_announcement(false, false).expect("announce");
+        assert_eq!(announcement.status, None);
+    }
+}
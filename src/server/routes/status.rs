@@ -10,7 +10,10 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
 use serde::Serialize;
+use std::fmt::Write as _;
 use std::sync::Arc;
+use x0x::gossip::PubSubStatsSnapshot;
+use x0x::network::NetworkStats;
 
 /// Generic JSON response wrapper.
 #[derive(Debug, Serialize)]
@@ -53,6 +56,76 @@ fn classify_health(peers: usize, uptime_secs: u64) -> (&'static str, Option<Stri
     }
 }
 
+/// Persistence health response (`GET /health/persistence`).
+#[derive(Debug, Serialize)]
+pub(in crate::server) struct PersistenceHealthData {
+    status: String,
+    /// Unix ms of the last task-list snapshot save; null before the first.
+    last_save_at: Option<u64>,
+    saves: u64,
+    save_failures: u64,
+    /// Open task lists whose latest save failed (changes not yet on disk).
+    unsaved_task_lists: usize,
+    /// Bytes of task-list and kv-store snapshots under the data dir.
+    storage_bytes: u64,
+    /// Task lists from the subscription manifest that failed to rejoin at
+    /// startup and have not been re-created since.
+    failed_task_list_recoveries: Vec<TaskListRecoveryFailure>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    degraded_reason: Option<String>,
+}
+
+/// A task list startup rehydration could not rejoin.
+#[derive(Debug, Serialize)]
+pub(in crate::server) struct TaskListRecoveryFailure {
+    id: String,
+    error: String,
+}
+
+/// Classify `GET /health/persistence`: degraded while any list's changes
+/// are missing from disk or any list failed to come back after a restart.
+fn classify_persistence(
+    unsaved: usize,
+    failed_recoveries: usize,
+) -> (&'static str, Option<String>) {
+    match (unsaved, failed_recoveries) {
+        (0, 0) => ("healthy", None),
+        (unsaved, 0) => (
+            "degraded",
+            Some(format!("{unsaved} task list(s) failed to save")),
+        ),
+        (0, failed) => (
+            "degraded",
+            Some(format!(
+                "{failed} task list(s) failed to recover at startup"
+            )),
+        ),
+        (unsaved, failed) => (
+            "degraded",
+            Some(format!(
+                "{unsaved} task list(s) failed to save; \
+                 {failed} task list(s) failed to recover at startup"
+            )),
+        ),
+    }
+}
+
+/// Total size of the files directly inside `dir`; zero if it is missing.
+async fn dir_bytes(dir: &std::path::Path) -> u64 {
+    let Ok(mut entries) = tokio::fs::read_dir(dir).await else {
+        return 0;
+    };
+    let mut total = 0u64;
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        if let Ok(meta) = entry.metadata().await {
+            if meta.is_file() {
+                total = total.saturating_add(meta.len());
+            }
+        }
+    }
+    total
+}
+
 /// Rich runtime status response.
 #[derive(Debug, Serialize)]
 pub(in crate::server) struct StatusData {
@@ -90,6 +163,56 @@ pub(in crate::server) async fn health(
     })
 }
 
+/// GET /health/persistence — snapshot save activity, storage use and
+/// task lists that failed to recover, for spotting stalled saves or a
+/// filling disk before a restart depends on them.
+pub(in crate::server) async fn health_persistence(
+    State(state): State<Arc<AppState>>,
+) -> Json<ApiResponse<PersistenceHealthData>> {
+    let stats = state.agent.task_list_persistence_stats();
+    let task_list_bytes = match state.agent.task_list_storage() {
+        Some(storage) => storage.usage_bytes().await.unwrap_or_else(|e| {
+            tracing::warn!("failed to measure task-list storage: {e}");
+            0
+        }),
+        None => 0,
+    };
+    let storage_bytes = task_list_bytes.saturating_add(dir_bytes(&state.kv_store_state_dir).await);
+
+    let failed_task_list_recoveries: Vec<TaskListRecoveryFailure> = {
+        let open = state.task_lists.read().await;
+        let mut failed: Vec<_> = state
+            .task_list_recovery_failures
+            .read()
+            .await
+            .iter()
+            .filter(|(id, _)| !open.contains_key(*id))
+            .map(|(id, error)| TaskListRecoveryFailure {
+                id: id.clone(),
+                error: error.clone(),
+            })
+            .collect();
+        failed.sort_by(|a, b| a.id.cmp(&b.id));
+        failed
+    };
+    let (status, degraded_reason) =
+        classify_persistence(stats.unsaved_lists, failed_task_list_recoveries.len());
+
+    Json(ApiResponse {
+        ok: true,
+        data: PersistenceHealthData {
+            status: status.to_string(),
+            last_save_at: stats.last_save_at,
+            saves: stats.saves,
+            save_failures: stats.save_failures,
+            unsaved_task_lists: stats.unsaved_lists,
+            storage_bytes,
+            failed_task_list_recoveries,
+            degraded_reason,
+        },
+    })
+}
+
 /// GET /status — rich runtime status with connectivity state machine.
 pub(in crate::server) async fn status(
     State(state): State<Arc<AppState>>,
@@ -177,6 +300,160 @@ pub(in crate::server) async fn status(
     })
 }
 
+/// GET /metrics — Prometheus text exposition of daemon counters.
+pub(in crate::server) async fn metrics(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let network = match state.agent.network() {
+        Some(network) => Some(network.stats().await),
+        None => None,
+    };
+    let sample = MetricsSample {
+        uptime_secs: state.start_time.elapsed().as_secs(),
+        connected_peers: state.agent.peers().await.map(|p| p.len()).unwrap_or(0),
+        network,
+        subscriptions: state.subscriptions.read().await.len(),
+        task_lists: state.task_lists.read().await.len(),
+        sse_clients: state.sse_stats.clients(),
+        sse_lagged_events: state.sse_stats.lagged_events(),
+        pubsub: state.agent.gossip_stats(),
+    };
+    (
+        StatusCode::OK,
+        [("content-type", "text/plain; version=0.0.4; charset=utf-8")],
+        render_metrics(&sample),
+    )
+}
+
+/// Values exported by `GET /metrics`, gathered once per scrape.
+struct MetricsSample {
+    uptime_secs: u64,
+    connected_peers: usize,
+    /// `None` when the agent runs without a network node.
+    network: Option<NetworkStats>,
+    subscriptions: usize,
+    task_lists: usize,
+    sse_clients: u64,
+    sse_lagged_events: u64,
+    /// `None` until the gossip runtime is up.
+    pubsub: Option<PubSubStatsSnapshot>,
+}
+
+/// Render `sample` in the Prometheus text format (version 0.0.4).
+///
+/// Network and PubSub families are omitted, rather than reported as zero,
+/// while their subsystem is absent so dashboards show a gap, not a reset.
+fn render_metrics(sample: &MetricsSample) -> String {
+    let mut out = String::new();
+    let mut family = |name: &str, kind: &str, help: &str, samples: &[(&str, u64)]| {
+        let _ = writeln!(out, "# HELP {name} {help}");
+        let _ = writeln!(out, "# TYPE {name} {kind}");
+        for (labels, value) in samples {
+            let _ = writeln!(out, "{name}{labels} {value}");
+        }
+    };
+
+    family(
+        "x0x_build_info",
+        "gauge",
+        "Daemon version; always 1.",
+        &[(&format!("{{version=\"{}\"}}", x0x::VERSION), 1)],
+    );
+    family(
+        "x0x_uptime_seconds",
+        "gauge",
+        "Seconds since the daemon started.",
+        &[("", sample.uptime_secs)],
+    );
+    family(
+        "x0x_connected_peers",
+        "gauge",
+        "Gossip peers currently connected.",
+        &[("", sample.connected_peers as u64)],
+    );
+    family(
+        "x0x_subscriptions",
+        "gauge",
+        "Active REST topic subscriptions.",
+        &[("", sample.subscriptions as u64)],
+    );
+    family(
+        "x0x_task_lists",
+        "gauge",
+        "Task lists open in this daemon.",
+        &[("", sample.task_lists as u64)],
+    );
+    family(
+        "x0x_sse_clients",
+        "gauge",
+        "Clients connected to /events.",
+        &[("", sample.sse_clients)],
+    );
+    family(
+        "x0x_sse_lagged_events_total",
+        "counter",
+        "Events skipped by /events clients that fell behind the broadcast buffer.",
+        &[("", sample.sse_lagged_events)],
+    );
+    if let Some(network) = &sample.network {
+        family(
+            "x0x_connections_total",
+            "counter",
+            "Connections established (direct and relayed).",
+            &[("", network.total_connections)],
+        );
+        family(
+            "x0x_active_connections",
+            "gauge",
+            "Connections currently open.",
+            &[("", u64::from(network.active_connections))],
+        );
+        family(
+            "x0x_bytes_sent_total",
+            "counter",
+            "Bytes sent, as reported by the transport.",
+            &[("", network.bytes_sent)],
+        );
+        family(
+            "x0x_bytes_received_total",
+            "counter",
+            "Bytes received, as reported by the transport (0 until the transport exposes it).",
+            &[("", network.bytes_received)],
+        );
+    }
+    if let Some(pubsub) = &sample.pubsub {
+        family(
+            "x0x_pubsub_messages_published_total",
+            "counter",
+            "Messages published by this agent.",
+            &[("", pubsub.publish_total)],
+        );
+        family(
+            "x0x_pubsub_messages_delivered_total",
+            "counter",
+            "Inbound messages delivered to a local subscriber.",
+            &[("", pubsub.delivered_to_subscriber)],
+        );
+        family(
+            "x0x_pubsub_messages_dropped_total",
+            "counter",
+            "Inbound messages dropped, by reason.",
+            &[
+                (
+                    "{reason=\"slow_subscriber\"}",
+                    pubsub.slow_subscriber_dropped,
+                ),
+                (
+                    "{reason=\"subscriber_closed\"}",
+                    pubsub.subscriber_channel_closed,
+                ),
+                ("{reason=\"duplicate\"}", pubsub.incoming_duplicate_dropped),
+                ("{reason=\"oversize\"}", pubsub.incoming_oversize_dropped),
+                ("{reason=\"decode_failed\"}", pubsub.incoming_decode_failed),
+            ],
+        );
+    }
+    out
+}
+
 /// POST /shutdown — trigger graceful daemon shutdown.
 pub(in crate::server) async fn shutdown_handler(
     State(state): State<Arc<AppState>>,
@@ -215,7 +492,7 @@ pub(in crate::server) async fn get_constitution_json() -> impl IntoResponse {
 
 #[cfg(test)]
 mod tests {
-    use super::classify_health;
+    use super::{classify_health, classify_persistence, dir_bytes, render_metrics, MetricsSample};
 
     /// WHY (issue #262): a wedged-transport daemon — up for hours, zero
     /// peers, silent socket — must not read `healthy` to fleet monitoring.
@@ -243,4 +520,101 @@ mod tests {
         assert_eq!(status, "healthy");
         assert!(reason.is_none());
     }
+
+    /// Unsaved changes or an unrecovered list mean a restart would lose
+    /// state, so either one degrades persistence health and says why.
+    #[test]
+    fn persistence_degrades_on_unsaved_or_unrecovered_lists() {
+        assert_eq!(classify_persistence(0, 0), ("healthy", None));
+        for (unsaved, failed, expect) in [
+            (2, 0, "2 task list(s) failed to save"),
+            (0, 1, "1 task list(s) failed to recover"),
+            (1, 1, "failed to recover"),
+        ] {
+            let (status, reason) = classify_persistence(unsaved, failed);
+            assert_eq!(status, "degraded");
+            assert!(reason.expect("reason").contains(expect));
+        }
+    }
+
+    #[tokio::test]
+    async fn dir_bytes_sums_files_and_tolerates_missing_dir() {
+        let dir = tempfile::tempdir().expect("tmpdir");
+        assert_eq!(dir_bytes(&dir.path().join("missing")).await, 0);
+        std::fs::write(dir.path().join("a.bin"), [0u8; 10]).expect("write");
+        std::fs::write(dir.path().join("b.bin"), [0u8; 5]).expect("write");
+        std::fs::create_dir(dir.path().join("nested")).expect("mkdir");
+        assert_eq!(dir_bytes(dir.path()).await, 15);
+    }
+
+    #[test]
+    fn metrics_render_as_prometheus_text() {
+        let pubsub = crate::gossip::PubSubStats::default();
+        pubsub
+            .delivered_to_subscriber
+            .store(7, std::sync::atomic::Ordering::Relaxed);
+        pubsub
+            .slow_subscriber_dropped
+            .store(2, std::sync::atomic::Ordering::Relaxed);
+        let text = render_metrics(&MetricsSample {
+            uptime_secs: 42,
+            connected_peers: 3,
+            network: Some(crate::network::NetworkStats {
+                total_connections: 5,
+                active_connections: 3,
+                bytes_sent: 1024,
+                bytes_received: 0,
+                peer_count: 3,
+            }),
+            subscriptions: 1,
+            task_lists: 2,
+            sse_clients: 4,
+            sse_lagged_events: 9,
+            pubsub: Some(pubsub.snapshot()),
+        });
+
+        for line in [
+            "# TYPE x0x_uptime_seconds gauge",
+            "x0x_uptime_seconds 42",
+            "x0x_connected_peers 3",
+            "# TYPE x0x_connections_total counter",
+            "x0x_connections_total 5",
+            "x0x_active_connections 3",
+            "x0x_bytes_sent_total 1024",
+            "x0x_subscriptions 1",
+            "x0x_task_lists 2",
+            "# TYPE x0x_sse_clients gauge",
+            "x0x_sse_clients 4",
+            "# TYPE x0x_sse_lagged_events_total counter",
+            "x0x_sse_lagged_events_total 9",
+            "x0x_pubsub_messages_delivered_total 7",
+            "x0x_pubsub_messages_dropped_total{reason=\"slow_subscriber\"} 2",
+        ] {
+            assert!(text.lines().any(|l| l == line), "missing {line:?}");
+        }
+        // Every sample line is `name[{labels}] value`.
+        for line in text.lines().filter(|l| !l.starts_with('#')) {
+            let (_, value) = line.rsplit_once(' ').expect("name and value");
+            assert!(value.parse::<u64>().is_ok(), "bad sample {line:?}");
+        }
+    }
+
+    /// A daemon without a network node or gossip runtime must not report
+    /// zeroed transport counters, which would read as a counter reset.
+    #[test]
+    fn metrics_omit_absent_subsystems() {
+        let text = render_metrics(&MetricsSample {
+            uptime_secs: 1,
+            connected_peers: 0,
+            network: None,
+            subscriptions: 0,
+            task_lists: 0,
+            sse_clients: 0,
+            sse_lagged_events: 0,
+            pubsub: None,
+        });
+        assert!(text.contains("x0x_uptime_seconds 1"));
+        assert!(!text.contains("x0x_bytes_sent_total"));
+        assert!(!text.contains("x0x_pubsub_"));
+    }
 }
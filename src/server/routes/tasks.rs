@@ -3,10 +3,12 @@
 //! Extracted verbatim from `src/server/mod.rs` as part of the #125 / WS1.4
 //! server decomposition. The router registrations stay in the parent module.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use axum::extract::{Path, State};
-use axum::http::StatusCode;
+use axum::http::{header, HeaderMap, StatusCode};
 use axum::response::IntoResponse;
 use axum::Json;
 use serde::{Deserialize, Serialize};
@@ -14,6 +16,7 @@ use serde::{Deserialize, Serialize};
 use crate as x0x;
 
 use super::super::crdt_subscriptions;
+use super::super::sse::SseEvent;
 use super::super::state::AppState;
 use super::super::{api_error, bad_request, forbidden, not_found};
 
@@ -173,6 +176,129 @@ pub(in crate::server) async fn apply_group_authorization(
     handle.set_authorized_agents(agents).await;
 }
 
+/// Delay between the first change of a burst and its `task:updated` diff.
+/// Initial sync merges many deltas; diffing once per window keeps `/events`
+/// from flooding.
+const TASK_EVENT_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// `(state, title)` of each task keyed by hex task id, as last reported.
+type TaskEventSnapshot = BTreeMap<String, (String, String)>;
+
+fn task_event_snapshot(tasks: &[x0x::TaskSnapshot]) -> TaskEventSnapshot {
+    tasks
+        .iter()
+        .map(|t| (t.id.to_string(), (t.state.to_string(), t.title.clone())))
+        .collect()
+}
+
+/// `task:updated` payloads for every task added, changed or removed between
+/// two snapshots. Removed tasks report `state: "removed"` with their last
+/// known title.
+fn task_update_events(
+    list_id: &str,
+    before: &TaskEventSnapshot,
+    after: &TaskEventSnapshot,
+) -> Vec<serde_json::Value> {
+    let event = |task_id: &str, state: &str, title: &str| {
+        serde_json::json!({
+            "list_id": list_id,
+            "task_id": task_id,
+            "state": state,
+            "title": title,
+        })
+    };
+    let changed = after
+        .iter()
+        .filter(|(task_id, now)| before.get(*task_id) != Some(*now))
+        .map(|(task_id, (state, title))| event(task_id, state, title));
+    let removed = before
+        .iter()
+        .filter(|(task_id, _)| !after.contains_key(*task_id))
+        .map(|(task_id, (_, title))| event(task_id, "removed", title));
+    changed.chain(removed).collect()
+}
+
+/// Publish remote changes to a task list as `task:updated` events on
+/// `/events`. Call before registering `handle` under `id`.
+///
+/// The watcher keeps only the list's change receiver and looks the handle
+/// up under `id` after each burst, so it ends once the list's sync is
+/// dropped or the list is no longer registered.
+pub(in crate::server) async fn watch_task_list_updates(
+    state: &Arc<AppState>,
+    id: &str,
+    handle: &x0x::TaskListHandle,
+) {
+    // Subscribe before the baseline so no change can fall between them.
+    let mut changes = handle.subscribe_changes();
+    let mut seen = match handle.list_tasks().await {
+        Ok(tasks) => task_event_snapshot(&tasks),
+        Err(e) => {
+            tracing::warn!(id = %id, "task list unreadable; no task:updated events: {e}");
+            return;
+        }
+    };
+    let list_id = handle.id().to_string();
+    let state = Arc::clone(state);
+    let id = id.to_string();
+    tokio::spawn(async move {
+        while changes.changed().await.is_ok() {
+            tokio::time::sleep(TASK_EVENT_DEBOUNCE).await;
+            changes.borrow_and_update();
+            let tasks = {
+                let lists = state.task_lists.read().await;
+                let Some(handle) = lists.get(&id) else {
+                    return;
+                };
+                match handle.list_tasks().await {
+                    Ok(tasks) => tasks,
+                    Err(e) => {
+                        tracing::warn!(id = %id, "failed to list tasks for task:updated: {e}");
+                        continue;
+                    }
+                }
+            };
+            let current = task_event_snapshot(&tasks);
+            for data in task_update_events(&list_id, &seen, &current) {
+                // No SSE receivers is not an error.
+                let _ = state.broadcast_tx.send(SseEvent {
+                    event_type: "task:updated".to_string(),
+                    data,
+                });
+            }
+            seen = current;
+        }
+    });
+}
+
+/// Resolve a `:id` path parameter to the key [`AppState::task_lists`] uses,
+/// which is the list's topic.
+///
+/// `:id` may be the topic the list was created or joined with, or its
+/// canonical [`x0x::crdt::TaskListId`] as 64 hex chars (the `list_id` field
+/// of `GET /task-lists`). A registered topic always wins, so a list whose
+/// topic happens to be 64 hex chars stays reachable by topic; otherwise a
+/// 64-hex value is looked up with [`x0x::Agent::task_list_by_id`]. Anything
+/// else is returned unchanged and treated as a topic. Access checks run on
+/// the resolved topic.
+pub(in crate::server) async fn resolve_task_list_key(state: &Arc<AppState>, id: String) -> String {
+    if state.task_lists.read().await.contains_key(&id) {
+        return id;
+    }
+    let list_id = match hex::decode(&id) {
+        Ok(bytes) if bytes.len() == 32 => {
+            let mut arr = [0u8; 32];
+            arr.copy_from_slice(&bytes);
+            x0x::crdt::TaskListId::new(arr)
+        }
+        _ => return id,
+    };
+    match state.agent.task_list_by_id(&list_id) {
+        Some(handle) => handle.topic().to_string(),
+        None => id,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Request / response DTOs
 // ---------------------------------------------------------------------------
@@ -182,6 +308,18 @@ pub(in crate::server) async fn apply_group_authorization(
 pub(in crate::server) struct CreateTaskListRequest {
     pub(in crate::server) name: String,
     pub(in crate::server) topic: String,
+    /// Seconds between state requests while the list has not converged
+    /// (1-3600); omit for the default. See [`x0x::crdt::SyncOptions`].
+    #[serde(default)]
+    pub(in crate::server) sync_interval_secs: Option<u64>,
+}
+
+/// PATCH /persistence/policy request body.
+#[derive(Debug, Deserialize)]
+pub(in crate::server) struct PersistencePolicyRequest {
+    /// Seconds between saves of each open task list (1-3600). See
+    /// [`x0x::crdt::PersistencePolicy`].
+    pub(in crate::server) save_interval_secs: u64,
 }
 
 /// POST /task-lists/:id/tasks request body.
@@ -190,13 +328,23 @@ pub(in crate::server) struct AddTaskRequest {
     pub(in crate::server) title: String,
     #[serde(default)]
     pub(in crate::server) description: Option<String>,
+    /// Optional local-replica fencing precondition; same contract as
+    /// [`UpdateTaskRequest::fence_token`].
+    #[serde(default)]
+    pub(in crate::server) fence_token: Option<String>,
 }
 
 /// PATCH /task-lists/:id/tasks/:tid request body.
 #[derive(Debug, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub(in crate::server) struct UpdateTaskRequest {
-    pub(in crate::server) action: String, // "claim" or "complete"
+    pub(in crate::server) action: String, // "claim", "complete", "reopen", "assign" or "set_priority"
+    /// `assign`: hex agent id of the new assignee; omit or `null` to unassign.
+    #[serde(default)]
+    pub(in crate::server) assignee: Option<String>,
+    /// `set_priority`: new priority (0-255).
+    #[serde(default)]
+    pub(in crate::server) priority: Option<u8>,
     /// Optional **local-replica** fencing precondition (opaque token). Echo
     /// the `fence_token` from a prior GET/mutation verbatim. If it does not
     /// match THIS daemon's current `(epoch, revision)`, the mutation is
@@ -213,6 +361,12 @@ pub(in crate::server) struct UpdateTaskRequest {
 pub(in crate::server) struct TaskListEntry {
     pub(in crate::server) id: String,
     pub(in crate::server) topic: String,
+    /// Canonical hex [`x0x::crdt::TaskListId`]; also accepted as `:id`.
+    pub(in crate::server) list_id: String,
+    /// `false` for a list found only in task-list storage: it is on disk
+    /// but not open in this process, so it does not sync and its tasks
+    /// cannot be read until it is created or joined again.
+    pub(in crate::server) rejoined: bool,
 }
 
 /// Task snapshot for API response.
@@ -236,6 +390,52 @@ pub(in crate::server) struct TaskEntry {
     pub(in crate::server) completed_at: Option<u64>,
 }
 
+/// Resolve the fence token for a mutation from the body field and the
+/// `If-Match` header (an entity tag, quoted or bare).
+///
+/// Either source may be used; when both are present they must agree.
+/// Absent ⇒ `Ok(None)` (unconditional commit); present but malformed or
+/// conflicting ⇒ a 400 ready to return, never a silent downgrade.
+pub(in crate::server) fn expected_fence_token(
+    headers: &HeaderMap,
+    body_token: Option<&str>,
+) -> Result<Option<x0x::FenceToken>, (StatusCode, Json<serde_json::Value>)> {
+    let header_token = match headers.get(header::IF_MATCH) {
+        None => None,
+        Some(value) => match value.to_str() {
+            Ok(value) => Some(value.trim().trim_matches('"')),
+            Err(_) => return Err(bad_request("malformed_fence_token")),
+        },
+    };
+    let token = match (body_token, header_token) {
+        (Some(body), Some(header)) if body != header => {
+            return Err(bad_request("conflicting_fence_token"));
+        }
+        (Some(token), _) | (None, Some(token)) => token,
+        (None, None) => return Ok(None),
+    };
+    // Machine-readable error code — the API contract (and
+    // daemon_api_claim_malformed_fence_token_is_rejected_non_mutating)
+    // matches on this exact string.
+    x0x::FenceToken::from_wire(token)
+        .map(Some)
+        .map_err(|_| bad_request("malformed_fence_token"))
+}
+
+/// 409 body for a mutation whose fence token is stale.
+fn stale_fence_response(current: x0x::FenceToken) -> (StatusCode, Json<serde_json::Value>) {
+    (
+        StatusCode::CONFLICT,
+        Json(serde_json::json!({
+            "ok": false,
+            "error": "stale_local_version",
+            "current_version": current.revision,
+            "fence_token": current.to_wire(),
+            "cas": { "scope": "local_replica" },
+        })),
+    )
+}
+
 // ---------------------------------------------------------------------------
 // Handlers
 // ---------------------------------------------------------------------------
@@ -256,14 +456,47 @@ pub(in crate::server) async fn list_task_lists(
     // the collection from enumerating them.) Red-team review of #166 found
     // this collection endpoint was the sole unguarded path.
     let mut entries = Vec::with_capacity(ids.len());
+    let mut open = std::collections::HashSet::new();
     for id in ids {
+        let list_id = x0x::crdt::TaskListId::from_topic(&id);
+        open.insert(list_id);
         if ensure_task_list_access(&state, &id).await.is_ok() {
             entries.push(TaskListEntry {
                 id: id.clone(),
+                list_id: list_id.to_string(),
                 topic: id, // topic is used as ID
+                rejoined: true,
             });
         }
     }
+    // Lists on disk from an earlier run, so a restart does not appear to
+    // lose them. A storage read failure only hides these extra entries.
+    // They pass the same membership guard via the topic recorded with the
+    // snapshot; one with no readable topic cannot be checked and is hidden
+    // (fail closed) until it is reopened.
+    match state.agent.persisted_task_lists().await {
+        Ok(persisted) => {
+            for list_id in persisted.into_iter().filter(|id| !open.contains(id)) {
+                let topic = match state.agent.persisted_task_list_topic(&list_id).await {
+                    Ok(Some(topic)) => topic,
+                    Ok(None) => continue,
+                    Err(e) => {
+                        tracing::warn!("{e}");
+                        continue;
+                    }
+                };
+                if ensure_task_list_access(&state, &topic).await.is_ok() {
+                    entries.push(TaskListEntry {
+                        id: list_id.to_string(),
+                        list_id: list_id.to_string(),
+                        topic,
+                        rejoined: false,
+                    });
+                }
+            }
+        }
+        Err(e) => tracing::warn!("failed to list persisted task lists: {e}"),
+    }
     Json(serde_json::json!({ "ok": true, "task_lists": entries }))
 }
 
@@ -276,6 +509,13 @@ pub(in crate::server) async fn create_task_list(
     if let Err(denied) = ensure_task_list_access(&state, &req.topic).await {
         return denied;
     }
+    let mut options = x0x::crdt::SyncOptions::default();
+    if let Some(secs) = req.sync_interval_secs {
+        options.sync_interval_secs = secs;
+    }
+    if let Err(e) = options.validate() {
+        return bad_request(e.to_string());
+    }
     let id = req.topic.clone();
     // Reserve the entire handle+manifest transaction for this (kind,id) so
     // a concurrent create/rehydrate for the same id cannot interleave handle
@@ -290,13 +530,19 @@ pub(in crate::server) async fn create_task_list(
     if state.task_lists.read().await.contains_key(&id) {
         return api_error(StatusCode::CONFLICT, "task list already exists");
     }
-    match state.agent.create_task_list(&req.name, &req.topic).await {
+    match state
+        .agent
+        .create_task_list_with_options(&req.name, &req.topic, options)
+        .await
+    {
         Ok(handle) => {
             let version = handle.version().await;
+            let handle_id = handle.id();
             // Apply group authorization at the CRDT layer so remote admission
             // rejects nonmember operations for group-scoped lists. Runs inside
             // the reservation guard (serialized per (kind,id)).
             apply_group_authorization(&state, &id, &handle).await;
+            watch_task_list_updates(&state, &id, &handle).await;
             state.task_lists.write().await.insert(id.clone(), handle);
             // Persist the registration so it survives a daemon restart
             // (rehydrated after join_network — see crdt_subscriptions). This
@@ -309,7 +555,12 @@ pub(in crate::server) async fn create_task_list(
                 name: req.name.clone(),
                 topic: req.topic.clone(),
                 role: crdt_subscriptions::ROLE_CREATED.to_string(),
-                extra: serde_json::Map::new(),
+                extra: req
+                    .sync_interval_secs
+                    .map(|secs| {
+                        serde_json::Map::from_iter([("sync_interval_secs".into(), secs.into())])
+                    })
+                    .unwrap_or_default(),
             };
             if let Err(e) = crdt_subscriptions::record(&state, entry).await {
                 tracing::error!(
@@ -332,6 +583,7 @@ pub(in crate::server) async fn create_task_list(
                 Json(serde_json::json!({
                     "ok": true,
                     "id": id,
+                    "list_id": handle_id.to_string(),
                     "version": version.revision,
                     "fence_token": version.to_wire(),
                     "committed": "local",
@@ -347,6 +599,7 @@ pub(in crate::server) async fn list_tasks(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
 ) -> impl IntoResponse {
+    let id = resolve_task_list_key(&state, id).await;
     // #153: group-scoped task lists require local-agent membership.
     if let Err(denied) = ensure_task_list_access(&state, &id).await {
         return denied;
@@ -387,12 +640,234 @@ pub(in crate::server) async fn list_tasks(
     }
 }
 
+/// GET /task-lists/:id/validate
+///
+/// Diagnostic: reports disagreements between the local replica's
+/// membership, task data and ordering. Always 200 for a known list;
+/// `consistent` carries the verdict.
+pub(in crate::server) async fn validate_task_list(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    let id = resolve_task_list_key(&state, id).await;
+    // #153: group-scoped task lists require local-agent membership.
+    if let Err(denied) = ensure_task_list_access(&state, &id).await {
+        return denied;
+    }
+    let lists = state.task_lists.read().await;
+    let Some(handle) = lists.get(&id) else {
+        return not_found("task list not found");
+    };
+
+    let inconsistencies: Vec<serde_json::Value> = match handle.validate().await {
+        Ok(()) => Vec::new(),
+        Err(found) => found
+            .iter()
+            .map(|i| {
+                serde_json::json!({
+                    "kind": i.kind(),
+                    "task_id": format!("{}", i.task_id()),
+                    "detail": i.to_string(),
+                })
+            })
+            .collect(),
+    };
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "ok": true,
+            "consistent": inconsistencies.is_empty(),
+            "inconsistencies": inconsistencies,
+        })),
+    )
+}
+
+/// GET /task-lists/:id/export
+///
+/// Read-only JSON snapshot of the list for external tools; see
+/// [`x0x::crdt::TaskList::to_json_snapshot`].
+pub(in crate::server) async fn export_task_list(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    let id = resolve_task_list_key(&state, id).await;
+    // #153: group-scoped task lists require local-agent membership.
+    if let Err(denied) = ensure_task_list_access(&state, &id).await {
+        return denied;
+    }
+    let lists = state.task_lists.read().await;
+    let Some(handle) = lists.get(&id) else {
+        return not_found("task list not found");
+    };
+
+    let snapshot = match handle.export_json().await {
+        Ok(json) => serde_json::from_str::<serde_json::Value>(&json),
+        Err(e) => return api_error(StatusCode::INTERNAL_SERVER_ERROR, format!("{e}")),
+    };
+    let snapshot = match snapshot {
+        Ok(snapshot) => snapshot,
+        Err(e) => return api_error(StatusCode::INTERNAL_SERVER_ERROR, format!("{e}")),
+    };
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "ok": true,
+            "snapshot": snapshot,
+        })),
+    )
+}
+
+/// GET /task-lists/:id/sync-stats
+pub(in crate::server) async fn task_list_sync_stats(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    let id = resolve_task_list_key(&state, id).await;
+    // #153: group-scoped task lists require local-agent membership.
+    if let Err(denied) = ensure_task_list_access(&state, &id).await {
+        return denied;
+    }
+    let lists = state.task_lists.read().await;
+    let Some(handle) = lists.get(&id) else {
+        return not_found("task list not found");
+    };
+
+    let stats = handle.sync_stats();
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "ok": true,
+            "deltas_sent": stats.deltas_sent,
+            "deltas_received": stats.deltas_received,
+            "bytes_sent": stats.bytes_sent,
+            "bytes_received": stats.bytes_received,
+            "last_sync_at": stats.last_sync_at,
+            "merge_conflicts_resolved": stats.merge_conflicts_resolved,
+        })),
+    )
+}
+
+/// JSON shape of one checkpoint in the checkpoint responses.
+fn checkpoint_json(id: &str, checkpoint: &x0x::crdt::TaskListCheckpoint) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "list_id": checkpoint.list_id.to_string(),
+        "version": checkpoint.version,
+        "task_count": checkpoint.task_count,
+        "saved_at": checkpoint.saved_at,
+    })
+}
+
+/// 409 when the daemon has nowhere to save task lists.
+fn persistence_disabled(state: &AppState) -> Option<(StatusCode, Json<serde_json::Value>)> {
+    state
+        .agent
+        .task_list_storage()
+        .is_none()
+        .then(|| api_error(StatusCode::CONFLICT, "task-list persistence is disabled"))
+}
+
+/// POST /task-lists/:id/checkpoint — save the list to disk now.
+pub(in crate::server) async fn checkpoint_task_list(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    let id = resolve_task_list_key(&state, id).await;
+    // #153: group-scoped task lists require local-agent membership.
+    if let Err(denied) = ensure_task_list_access(&state, &id).await {
+        return denied;
+    }
+    if let Some(disabled) = persistence_disabled(&state) {
+        return disabled;
+    }
+    let Some(handle) = state.task_lists.read().await.get(&id).cloned() else {
+        return not_found("task list not found");
+    };
+
+    match state.agent.checkpoint_task_list(&handle).await {
+        Ok(checkpoint) => {
+            let mut body = checkpoint_json(&id, &checkpoint);
+            body["ok"] = serde_json::Value::Bool(true);
+            (StatusCode::OK, Json(body))
+        }
+        Err(e) => api_error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+/// POST /checkpoint-all — save every open task list to disk now.
+pub(in crate::server) async fn checkpoint_all_task_lists(
+    State(state): State<Arc<AppState>>,
+) -> impl IntoResponse {
+    if let Some(disabled) = persistence_disabled(&state) {
+        return disabled;
+    }
+    let mut handles: Vec<(String, x0x::TaskListHandle)> = state
+        .task_lists
+        .read()
+        .await
+        .iter()
+        .map(|(id, handle)| (id.clone(), handle.clone()))
+        .collect();
+    handles.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut checkpoints = Vec::new();
+    let mut failed = Vec::new();
+    for (id, handle) in &handles {
+        match state.agent.checkpoint_task_list(handle).await {
+            Ok(checkpoint) => checkpoints.push(checkpoint_json(id, &checkpoint)),
+            Err(e) => failed.push(serde_json::json!({ "id": id, "error": e.to_string() })),
+        }
+    }
+    let status = if failed.is_empty() {
+        StatusCode::OK
+    } else {
+        StatusCode::INTERNAL_SERVER_ERROR
+    };
+    (
+        status,
+        Json(serde_json::json!({
+            "ok": failed.is_empty(),
+            "checkpoints": checkpoints,
+            "failed": failed,
+        })),
+    )
+}
+
+/// PATCH /persistence/policy — change how often task lists are saved,
+/// live, for every open list.
+pub(in crate::server) async fn update_persistence_policy(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<PersistencePolicyRequest>,
+) -> impl IntoResponse {
+    if let Some(disabled) = persistence_disabled(&state) {
+        return disabled;
+    }
+    let policy = x0x::crdt::PersistencePolicy {
+        save_interval_secs: req.save_interval_secs,
+    };
+    if let Err(e) = state.agent.set_persistence_policy(policy) {
+        return bad_request(e.to_string());
+    }
+    let applied = state.agent.persistence_policy();
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "ok": true,
+            "save_interval_secs": applied.save_interval_secs,
+            "min_save_interval_secs": x0x::crdt::MIN_SAVE_INTERVAL_SECS,
+            "max_save_interval_secs": x0x::crdt::MAX_SAVE_INTERVAL_SECS,
+        })),
+    )
+}
+
 /// POST /task-lists/:id/tasks
 pub(in crate::server) async fn add_task(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
+    headers: HeaderMap,
     Json(req): Json<AddTaskRequest>,
 ) -> impl IntoResponse {
+    let id = resolve_task_list_key(&state, id).await;
     // #153: group-scoped task lists require local-agent membership (write too).
     if let Err(denied) = ensure_task_list_access(&state, &id).await {
         return denied;
@@ -401,20 +876,26 @@ pub(in crate::server) async fn add_task(
     let Some(handle) = lists.get(&id) else {
         return not_found("task list not found");
     };
+    let expected = match expected_fence_token(&headers, req.fence_token.as_deref()) {
+        Ok(expected) => expected,
+        Err(rejected) => return rejected,
+    };
 
     match handle
-        .add_task_versioned(req.title, req.description.unwrap_or_default())
+        .add_task_fenced(req.title, req.description.unwrap_or_default(), expected)
         .await
     {
-        Ok((task_id, version)) => (
+        Ok(x0x::TaskAddOutcome::Added { task_id, fence }) => (
             StatusCode::CREATED,
             Json(serde_json::json!({
                 "ok": true,
                 "task_id": format!("{task_id}"),
-                "version": version,
+                "version": fence.revision,
+                "fence_token": fence.to_wire(),
                 "committed": "local",
             })),
         ),
+        Ok(x0x::TaskAddOutcome::StaleLocalVersion { current }) => stale_fence_response(current),
         Err(e) => api_error(StatusCode::INTERNAL_SERVER_ERROR, format!("{e}")),
     }
 }
@@ -423,8 +904,10 @@ pub(in crate::server) async fn add_task(
 pub(in crate::server) async fn update_task(
     State(state): State<Arc<AppState>>,
     Path((id, tid)): Path<(String, String)>,
+    headers: HeaderMap,
     Json(req): Json<UpdateTaskRequest>,
 ) -> impl IntoResponse {
+    let id = resolve_task_list_key(&state, id).await;
     // #153: group-scoped task lists require local-agent membership (write too).
     if let Err(denied) = ensure_task_list_access(&state, &id).await {
         return denied;
@@ -453,24 +936,25 @@ pub(in crate::server) async fn update_task(
     // **present but malformed** (parse error ⇒ 400 BAD_REQUEST, non-mutating).
     // Collapsing the two would let a corrupt/legacy/attacker token silently
     // downgrade a fenced request to an unfenced mutation.
-    let expected = match req.fence_token.as_deref() {
-        None => None,
-        Some(s) => match x0x::FenceToken::from_wire(s) {
-            Ok(token) => Some(token),
-            Err(_) => {
-                // Machine-readable error code — the API contract (and
-                // daemon_api_claim_malformed_fence_token_is_rejected_non_mutating)
-                // matches on this exact string.
-                return bad_request("malformed_fence_token");
-            }
-        },
+    let expected = match expected_fence_token(&headers, req.fence_token.as_deref()) {
+        Ok(expected) => expected,
+        Err(rejected) => return rejected,
     };
 
     let result = match req.action.as_str() {
         "claim" => handle.claim_task_versioned(task_id, expected).await,
         "complete" => handle.complete_task_versioned(task_id, expected).await,
+        "reopen" => handle.reopen_task_versioned(task_id, expected).await,
+        "assign" | "set_priority" => {
+            if expected.is_some() {
+                return bad_request("fence_token is only supported for claim, complete and reopen");
+            }
+            return update_task_field(handle, task_id, &req).await;
+        }
         _ => {
-            return bad_request("action must be 'claim' or 'complete'");
+            return bad_request(
+                "action must be 'claim', 'complete', 'reopen', 'assign' or 'set_priority'",
+            );
         }
     };
 
@@ -508,20 +992,158 @@ pub(in crate::server) async fn update_task(
                 })),
             )
         }
-        Ok(x0x::TaskMutationOutcome::StaleLocalVersion { current }) => (
-            StatusCode::CONFLICT,
-            Json(serde_json::json!({
-                "ok": false,
-                "error": "stale_local_version",
-                "current_version": current.revision,
-                "fence_token": current.to_wire(),
-                "cas": { "scope": "local_replica" },
-            })),
+        Ok(x0x::TaskMutationOutcome::StaleLocalVersion { current }) => {
+            stale_fence_response(current)
+        }
+        Err(e) => api_error(StatusCode::INTERNAL_SERVER_ERROR, format!("{e}")),
+    }
+}
+
+/// PATCH `assign` / `set_priority`: plain LWW register updates, with no
+/// advisory-ownership resolution to report.
+async fn update_task_field(
+    handle: &x0x::TaskListHandle,
+    task_id: x0x::crdt::TaskId,
+    req: &UpdateTaskRequest,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let result = if req.action == "assign" {
+        let assignee = match req.assignee.as_deref().map(hex::decode) {
+            None => None,
+            Some(Ok(bytes)) if bytes.len() == 32 => {
+                let mut arr = [0u8; 32];
+                arr.copy_from_slice(&bytes);
+                Some(x0x::identity::AgentId(arr))
+            }
+            Some(_) => return bad_request("invalid assignee (expected 64 hex chars)"),
+        };
+        handle.assign(task_id, assignee).await
+    } else {
+        let Some(priority) = req.priority else {
+            return bad_request("set_priority requires a priority (0-255)");
+        };
+        handle.set_priority(task_id, priority).await
+    };
+
+    match result {
+        Ok(()) => {
+            let fence = handle.version().await;
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "ok": true,
+                    "version": fence.revision,
+                    "fence_token": fence.to_wire(),
+                    "committed": "local",
+                })),
+            )
+        }
+        Err(x0x::error::IdentityError::Storage(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+            not_found("task not found")
+        }
+        Err(e) => api_error(StatusCode::INTERNAL_SERVER_ERROR, format!("{e}")),
+    }
+}
+
+/// DELETE /task-lists/:id/tasks/:tid
+pub(in crate::server) async fn remove_task(
+    State(state): State<Arc<AppState>>,
+    Path((id, tid)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let id = resolve_task_list_key(&state, id).await;
+    // #153: group-scoped task lists require local-agent membership (write too).
+    if let Err(denied) = ensure_task_list_access(&state, &id).await {
+        return denied;
+    }
+    let lists = state.task_lists.read().await;
+    let Some(handle) = lists.get(&id) else {
+        return not_found("task list not found");
+    };
+
+    let task_id_bytes: [u8; 32] = match hex::decode(&tid) {
+        Ok(bytes) if bytes.len() == 32 => {
+            let mut arr = [0u8; 32];
+            arr.copy_from_slice(&bytes);
+            arr
+        }
+        _ => {
+            return bad_request("invalid task ID (expected 64 hex chars)");
+        }
+    };
+    let task_id = x0x::crdt::TaskId::from_bytes(task_id_bytes);
+
+    match handle.remove_task(task_id).await {
+        Ok(()) => (
+            StatusCode::OK,
+            Json(serde_json::json!({ "ok": true, "committed": "local" })),
         ),
+        Err(x0x::error::IdentityError::Storage(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+            not_found("task not found")
+        }
         Err(e) => api_error(StatusCode::INTERNAL_SERVER_ERROR, format!("{e}")),
     }
 }
 
+/// GET /task-lists/:id/tasks/:tid/history
+///
+/// Audit trail of a task: who added, claimed and completed it, and when,
+/// oldest first. Every concurrent claim is listed, not just the winner.
+pub(in crate::server) async fn task_history(
+    State(state): State<Arc<AppState>>,
+    Path((id, tid)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let id = resolve_task_list_key(&state, id).await;
+    // #153: group-scoped task lists require local-agent membership.
+    if let Err(denied) = ensure_task_list_access(&state, &id).await {
+        return denied;
+    }
+    let lists = state.task_lists.read().await;
+    let Some(handle) = lists.get(&id) else {
+        return not_found("task list not found");
+    };
+
+    let task_id_bytes: [u8; 32] = match hex::decode(&tid) {
+        Ok(bytes) if bytes.len() == 32 => {
+            let mut arr = [0u8; 32];
+            arr.copy_from_slice(&bytes);
+            arr
+        }
+        _ => {
+            return bad_request("invalid task ID (expected 64 hex chars)");
+        }
+    };
+    let Some(events) = handle
+        .history(x0x::crdt::TaskId::from_bytes(task_id_bytes))
+        .await
+    else {
+        return not_found("task not found");
+    };
+
+    let events: Vec<serde_json::Value> = events
+        .into_iter()
+        .map(|event| {
+            let kind = match event.kind {
+                x0x::crdt::TaskEventKind::Added => "added",
+                x0x::crdt::TaskEventKind::Claimed => "claimed",
+                x0x::crdt::TaskEventKind::Completed => "completed",
+                x0x::crdt::TaskEventKind::Reopened => "reopened",
+            };
+            serde_json::json!({
+                "kind": kind,
+                "agent_id": hex::encode(event.agent_id.as_bytes()),
+                "timestamp": event.timestamp,
+            })
+        })
+        .collect();
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "ok": true,
+            "task_id": tid,
+            "events": events,
+        })),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -629,4 +1251,110 @@ mod tests {
             serde_json::from_str::<UpdateTaskRequest>(r#"{"action":"claim","fence_tokn":"1:2"}"#);
         assert!(typo.is_err(), "typo'd field name must be rejected");
     }
+
+    #[test]
+    fn update_task_request_parses_assign_and_set_priority() {
+        let assign = serde_json::from_str::<UpdateTaskRequest>(&format!(
+            r#"{{"action":"assign","assignee":"{}"}}"#,
+            "ab".repeat(32)
+        ))
+        .expect("assign parses");
+        assert_eq!(assign.assignee.as_deref(), Some("ab".repeat(32).as_str()));
+
+        let priority =
+            serde_json::from_str::<UpdateTaskRequest>(r#"{"action":"set_priority","priority":9}"#)
+                .expect("set_priority parses");
+        assert_eq!(priority.priority, Some(9));
+
+        let out_of_range = serde_json::from_str::<UpdateTaskRequest>(
+            r#"{"action":"set_priority","priority":256}"#,
+        );
+        assert!(out_of_range.is_err(), "priority must fit in a u8");
+    }
+
+    // ── task:updated diffing ────────────────────────────────────────────
+
+    fn snapshot(entries: &[(&str, &str, &str)]) -> TaskEventSnapshot {
+        entries
+            .iter()
+            .map(|(id, state, title)| (id.to_string(), (state.to_string(), title.to_string())))
+            .collect()
+    }
+
+    #[test]
+    fn task_update_events_cover_added_changed_and_removed_tasks() {
+        let before = snapshot(&[
+            ("a", "empty", "Write"),
+            ("b", "empty", "Test"),
+            ("c", "empty", "Ship"),
+        ]);
+        let after = snapshot(&[
+            ("a", "empty", "Write"),
+            ("b", "claimed:01", "Test"),
+            ("d", "empty", "Docs"),
+        ]);
+
+        let events = task_update_events("list", &before, &after);
+        let summary: Vec<(&str, &str, &str)> = events
+            .iter()
+            .map(|e| {
+                assert_eq!(e["list_id"], "list");
+                (
+                    e["task_id"].as_str().expect("task_id"),
+                    e["state"].as_str().expect("state"),
+                    e["title"].as_str().expect("title"),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("b", "claimed:01", "Test"),
+                ("d", "empty", "Docs"),
+                ("c", "removed", "Ship")
+            ]
+        );
+        assert!(task_update_events("list", &after, &after).is_empty());
+    }
+
+    // ── expected_fence_token: If-Match and body field share one contract ──
+
+    fn if_match(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_MATCH, value.parse().expect("header value"));
+        headers
+    }
+
+    #[test]
+    fn fence_token_comes_from_body_or_if_match() {
+        let none = expected_fence_token(&HeaderMap::new(), None).expect("absent");
+        assert_eq!(none, None);
+
+        let body = expected_fence_token(&HeaderMap::new(), Some("1:2"))
+            .expect("body token")
+            .expect("present");
+        assert_eq!((body.epoch, body.revision), (1, 2));
+
+        // Entity tags are usually quoted; the bare form is accepted too.
+        for value in ["\"1:2\"", "1:2"] {
+            let header = expected_fence_token(&if_match(value), None)
+                .expect("header token")
+                .expect("present");
+            assert_eq!(header, body);
+        }
+        assert!(expected_fence_token(&if_match("\"1:2\""), Some("1:2")).is_ok());
+    }
+
+    #[test]
+    fn fence_token_rejects_malformed_or_conflicting_sources() {
+        let (status, Json(body)) =
+            expected_fence_token(&if_match("garbage"), None).expect_err("malformed");
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"], "malformed_fence_token");
+
+        let (status, Json(body)) =
+            expected_fence_token(&if_match("1:2"), Some("1:3")).expect_err("conflicting");
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"], "conflicting_fence_token");
+    }
 }
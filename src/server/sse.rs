@@ -5,15 +5,23 @@
 //! stream handlers plus the `SseEvent` broadcast type. Handlers are
 //! `pub(super)` — wired into the router by the parent module.
 
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
 use std::time::Duration;
 
 use axum::extract::State;
 use axum::http::StatusCode;
 use axum::response::sse::{Event, Sse};
+use axum::response::IntoResponse;
+use axum::Json;
 use base64::engine::general_purpose::STANDARD as BASE64;
 use base64::Engine;
 use serde::Serialize;
+use tokio::sync::{broadcast, mpsc};
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{StreamExt, StreamMap};
 
 use super::api_error;
 use super::routes::{discovered_agent_entry, DiscoveredAgentEntry};
@@ -22,27 +30,197 @@ use super::state::AppState;
 /// SSE event broadcast to connected clients.
 #[derive(Debug, Clone, Serialize)]
 pub(super) struct SseEvent {
-    /// Event type: "message", "peer:connected", "peer:disconnected".
+    /// Event type, e.g. "message", "peer:connected", "peer:disconnected",
+    /// "task:updated".
     #[serde(rename = "type")]
     pub(super) event_type: String,
     /// Event payload (JSON value).
     pub(super) data: serde_json::Value,
 }
 
+/// One `/subscribe` stream's event channel, handed to an `/events` client.
+#[derive(Debug)]
+pub(super) struct SubscriptionFeed {
+    /// Subscription id the events belong to.
+    pub(super) id: String,
+    /// Receiver on the subscription's own bounded broadcast.
+    pub(super) events: broadcast::Receiver<SseEvent>,
+}
+
+/// `/events` client observability, surfaced via `GET /diagnostics/sse`.
+///
+/// The `/events` fan-out is made of bounded broadcast channels: a client
+/// that reads slower than the daemon publishes falls behind and silently
+/// loses events, which shows up as "my web UI stopped getting events".
+#[derive(Debug, Default)]
+pub(super) struct SseStats {
+    /// `/events` clients currently connected.
+    clients: AtomicU64,
+    /// Events skipped by lagging clients, summed over all clients.
+    lagged_events: AtomicU64,
+    /// Number of times any client fell behind the broadcast buffer.
+    lag_incidents: AtomicU64,
+    /// Feed inboxes of connected clients, keyed by client number, so a
+    /// `/subscribe` stream started later still reaches them.
+    inboxes: StdMutex<HashMap<u64, mpsc::UnboundedSender<SubscriptionFeed>>>,
+    next_client: AtomicU64,
+}
+
+impl SseStats {
+    /// Register a connected client and return the inbox on which it gets
+    /// the feeds of `/subscribe` streams started from now on. The count
+    /// drops and the inbox closes when the guard does.
+    fn connect(self: &Arc<Self>) -> (SseClientGuard, mpsc::UnboundedReceiver<SubscriptionFeed>) {
+        self.clients.fetch_add(1, Ordering::Relaxed);
+        let client = self.next_client.fetch_add(1, Ordering::Relaxed);
+        let (inbox, feeds) = mpsc::unbounded_channel();
+        self.inboxes
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(client, inbox);
+        let guard = SseClientGuard {
+            stats: Arc::clone(self),
+            client,
+        };
+        (guard, feeds)
+    }
+
+    /// Hand every connected client a receiver on a new subscription's
+    /// `events` channel.
+    ///
+    /// Callers hold the subscriptions write lock, and [`events_sse`] holds
+    /// its read lock while it connects, so each client sees every stream
+    /// either in its snapshot or here, never neither.
+    pub(super) fn attach(&self, id: &str, events: &broadcast::Sender<SseEvent>) {
+        self.inboxes
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .retain(|_, inbox| {
+                inbox
+                    .send(SubscriptionFeed {
+                        id: id.to_string(),
+                        events: events.subscribe(),
+                    })
+                    .is_ok()
+            });
+    }
+
+    /// `/events` clients currently connected.
+    pub(super) fn clients(&self) -> u64 {
+        self.clients.load(Ordering::Relaxed)
+    }
+
+    /// Events skipped by lagging clients, summed over all clients.
+    pub(super) fn lagged_events(&self) -> u64 {
+        self.lagged_events.load(Ordering::Relaxed)
+    }
+
+    fn record_lag(&self, skipped: u64) {
+        self.lagged_events.fetch_add(skipped, Ordering::Relaxed);
+        self.lag_incidents.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Keeps one `/events` client counted for as long as its stream lives.
+/// Axum drops the stream when the client disconnects.
+struct SseClientGuard {
+    stats: Arc<SseStats>,
+    client: u64,
+}
+
+impl Drop for SseClientGuard {
+    fn drop(&mut self) {
+        self.stats.clients.fetch_sub(1, Ordering::Relaxed);
+        self.stats
+            .inboxes
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .remove(&self.client);
+    }
+}
+
+/// Build the `/diagnostics/sse` JSON payload. Pure over the stats so the
+/// counter→payload mapping is unit-testable without an `AppState` fixture.
+fn sse_diagnostics_payload(stats: &SseStats, broadcast_capacity: usize) -> serde_json::Value {
+    serde_json::json!({
+        "ok": true,
+        "sse_clients": stats.clients.load(Ordering::Relaxed),
+        "sse_broadcast_capacity": broadcast_capacity,
+        "sse_lagged_events": stats.lagged_events.load(Ordering::Relaxed),
+        "sse_lag_incidents": stats.lag_incidents.load(Ordering::Relaxed),
+    })
+}
+
+/// GET /diagnostics/sse — `/events` client count and lag counters.
+pub(super) async fn sse_diagnostics(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    (
+        StatusCode::OK,
+        Json(sse_diagnostics_payload(
+            &state.sse_stats,
+            super::SSE_BROADCAST_CAPACITY,
+        )),
+    )
+}
+
 /// GET /events — Server-Sent Events stream.
+///
+/// Merges the daemon-wide broadcast with one feed per `/subscribe` stream.
+/// Each feed is its own bounded broadcast, so `Lagged(n)` on a feed is
+/// exactly `n` events of that subscription lost to this client, and is
+/// charged to its `sse_dropped`.
 pub(super) async fn events_sse(
     State(state): State<Arc<AppState>>,
 ) -> Sse<impl tokio_stream::Stream<Item = Result<Event, std::convert::Infallible>>> {
     tracing::info!("[6/6 x0xd] SSE client connected to /events");
     let mut rx = state.broadcast_tx.subscribe();
     let mut shutdown_rx = state.shutdown_notify.subscribe();
+    let stats = Arc::clone(&state.sse_stats);
+    let mut feeds = StreamMap::new();
+    // Counted before the response is returned so the gauge is already
+    // accurate once the client sees headers; released with the stream.
+    // Connecting under the read lock means no `/subscribe` stream can start
+    // between the snapshot and the inbox registration.
+    let (client, mut inbox) = {
+        let subscriptions = state.subscriptions.read().await;
+        for (id, sub) in subscriptions.iter() {
+            feeds.insert(id.clone(), BroadcastStream::new(sub.subscribe_events()));
+        }
+        stats.connect()
+    };
     let stream = async_stream::stream! {
+        let _client = client;
         loop {
             tokio::select! {
                 _ = shutdown_rx.changed() => {
                     tracing::info!("[6/6 x0xd] SSE client closing due to daemon shutdown");
                     break;
                 }
+                Some(feed) = inbox.recv() => {
+                    feeds.insert(feed.id, BroadcastStream::new(feed.events));
+                }
+                Some((id, result)) = feeds.next() => {
+                    match result {
+                        Ok(event) => {
+                            tracing::info!(
+                                sub_id = %id,
+                                "[6/6 x0xd] SSE delivering subscription event to client"
+                            );
+                            let data = serde_json::to_string(&event).unwrap_or_default();
+                            yield Ok(Event::default().event(event.event_type).data(data));
+                        }
+                        Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                            stats.record_lag(skipped);
+                            if let Some(sub) = state.subscriptions.read().await.get(&id) {
+                                sub.record_sse_lag(skipped);
+                            }
+                            tracing::warn!(
+                                sub_id = %id,
+                                skipped,
+                                "[6/6 x0xd] SSE client lagged behind subscription stream; events dropped for this client"
+                            );
+                        }
+                    }
+                }
                 result = rx.recv() => {
                     match result {
                         Ok(event) => {
@@ -54,7 +232,12 @@ pub(super) async fn events_sse(
                             yield Ok(Event::default().event(event.event_type).data(data));
                         }
                         Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
-                            tracing::warn!(skipped, "[6/6 x0xd] SSE client lagged behind broadcast stream");
+                            stats.record_lag(skipped);
+                            tracing::warn!(
+                                skipped,
+                                clients = stats.clients.load(Ordering::Relaxed),
+                                "[6/6 x0xd] SSE client lagged behind broadcast stream; events dropped for this client"
+                            );
                         }
                         Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
                     }
@@ -62,7 +245,13 @@ pub(super) async fn events_sse(
             }
         }
     };
-    Sse::new(stream)
+    // Periodic keep-alives make a silently vanished client fail a write, so
+    // its stream (and its `sse_clients` slot) is released on quiet daemons.
+    Sse::new(stream).keep_alive(
+        axum::response::sse::KeepAlive::new()
+            .interval(Duration::from_secs(15))
+            .text("ping"),
+    )
 }
 
 /// GET /presence/events
@@ -77,8 +266,6 @@ pub(super) async fn presence_events(
 ) -> Sse<impl tokio_stream::Stream<Item = Result<Event, std::convert::Infallible>>> {
     let mut shutdown_rx = state.shutdown_notify.subscribe();
     let stream = async_stream::stream! {
-        use std::collections::HashMap;
-
         let mut previous: HashMap<String, DiscoveredAgentEntry> = HashMap::new();
         let mut interval = tokio::time::interval(Duration::from_secs(1));
         interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
@@ -387,4 +574,49 @@ mod tests {
         assert_eq!(data["received_at"], 1_774_860_000);
         assert!(data["trust_decision"].is_null());
     }
+
+    /// The client gauge must follow connections exactly: a count stuck at
+    /// its high-water mark would hide the "UI disconnected" case it exists
+    /// to diagnose.
+    #[test]
+    fn sse_client_count_tracks_connect_and_disconnect() {
+        let stats = Arc::new(SseStats::default());
+        let (first, _) = stats.connect();
+        let (second, _) = stats.connect();
+        assert_eq!(sse_diagnostics_payload(&stats, 256)["sse_clients"], 2);
+
+        drop(first);
+        assert_eq!(sse_diagnostics_payload(&stats, 256)["sse_clients"], 1);
+        drop(second);
+        assert_eq!(sse_diagnostics_payload(&stats, 256)["sse_clients"], 0);
+    }
+
+    /// A subscription started after a client connected must still reach it,
+    /// and a disconnected client must stop being handed feeds.
+    #[test]
+    fn attached_feeds_reach_connected_clients_only() {
+        let stats = Arc::new(SseStats::default());
+        let (client, mut inbox) = stats.connect();
+        let (gone, mut gone_inbox) = stats.connect();
+        drop(gone);
+
+        let (events, _) = broadcast::channel::<SseEvent>(4);
+        stats.attach("aa", &events);
+        let feed = inbox.try_recv().expect("connected client gets the feed");
+        assert_eq!(feed.id, "aa");
+        assert!(gone_inbox.try_recv().is_err());
+        assert_eq!(events.receiver_count(), 1);
+        drop(client);
+    }
+
+    #[test]
+    fn sse_lag_is_counted_per_incident_and_per_event() {
+        let stats = Arc::new(SseStats::default());
+        stats.record_lag(7);
+        stats.record_lag(3);
+        let payload = sse_diagnostics_payload(&stats, 256);
+        assert_eq!(payload["sse_lagged_events"], 10);
+        assert_eq!(payload["sse_lag_incidents"], 2);
+        assert_eq!(payload["sse_broadcast_capacity"], 256);
+    }
 }
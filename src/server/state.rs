@@ -24,12 +24,13 @@ use crate::{Agent, KvStoreHandle, TaskListHandle};
 // name private items of its parent, so no `pub(super)` is needed on them —
 // they are imported here and claimed by their own submodules later.
 use super::auth::SessionStore;
+use super::rate_limit::RateLimitConfig;
 use super::routes::{
     ExpectedJoinResultInviter, FileChunkAckSlot, NamedGroupMetadataEvent, PendingJoinResult,
     PendingTreeKemMetadataEvent, PendingWelcome, PendingWelcomeReceive, RestSubscription,
     WelcomeFetchWaiter,
 };
-use super::sse::SseEvent;
+use super::sse::{SseEvent, SseStats};
 use super::ws::{SharedTopicState, WsOutboundStats, WsSession};
 
 fn validate_instance_name_grammar(name: &str) -> anyhow::Result<()> {
@@ -258,6 +259,12 @@ pub struct DaemonConfig {
     #[serde(default)]
     pub(super) observed_prefix_enabled: bool,
 
+    /// Bootstrap retry schedule (TOML `[bootstrap_retry]`): rounds,
+    /// initial delay, growth, cap and jitter. Defaults to three rounds,
+    /// 10 s then 15 s apart, jittered ±20%.
+    #[serde(default)]
+    pub(super) bootstrap_retry: x0x::network::BootstrapRetryConfig,
+
     /// Update configuration.
     #[serde(default)]
     pub(super) update: DaemonUpdateConfig,
@@ -307,6 +314,13 @@ pub struct DaemonConfig {
     #[serde(default)]
     pub(super) presence_offline_timeout_secs: Option<u64>,
 
+    /// Promote `Unknown` contacts to `Known` after this many distinct
+    /// verified messages from them. Opt-in; promotion never goes beyond
+    /// `Known` or touches `Blocked` contacts.
+    /// Default: None (disabled)
+    #[serde(default)]
+    pub(super) auto_promote_after: Option<u32>,
+
     /// Instance name for multi-agent support.
     /// When set, identity and data are scoped to this name.
     #[serde(default)]
@@ -375,6 +389,21 @@ pub struct DaemonConfig {
     /// process will exit without anything to restart it.
     #[serde(default)]
     pub zero_peer_restart_secs: Option<u64>,
+
+    /// Token-bucket limits on mutating API requests (TOML: `[rate_limit]`).
+    /// Default: on, 50 requests/s with bursts of 200 across all mutating
+    /// endpoints; `[rate_limit.endpoints."/publish"]` gives a path its own
+    /// bucket. Exceeding a limit returns 429 with `Retry-After`.
+    #[serde(default)]
+    pub(super) rate_limit: RateLimitConfig,
+
+    /// Rejoin the task lists recorded in the subscription manifest on
+    /// startup, restoring their tasks from `<data_dir>/task_lists` so
+    /// `GET /task-lists/:id/tasks` answers before any peer re-syncs them.
+    /// When false, lists stay dormant until re-created or re-joined.
+    /// Default: true
+    #[serde(default = "default_auto_rejoin_task_lists")]
+    pub(super) auto_rejoin_task_lists: bool,
 }
 
 /// Default QUIC port: 5483 (LIVE on a phone keypad).
@@ -511,6 +540,10 @@ fn default_rendezvous_enabled() -> bool {
     true
 }
 
+fn default_auto_rejoin_task_lists() -> bool {
+    true
+}
+
 fn default_rendezvous_validity_ms() -> u64 {
     3_600_000 // 1 hour
 }
@@ -572,6 +605,7 @@ impl Default for DaemonConfig {
             port_mapping_enabled: default_port_mapping_enabled(),
             peer_relay: x0x::network::PeerRelayConfig::default(),
             observed_prefix_enabled: false,
+            bootstrap_retry: x0x::network::BootstrapRetryConfig::default(),
             update: DaemonUpdateConfig::default(),
             history: default_history_config(),
             gossip: x0x::gossip::GossipConfig::default(),
@@ -583,6 +617,7 @@ impl Default for DaemonConfig {
             presence_beacon_interval_secs: None,
             presence_event_poll_interval_secs: None,
             presence_offline_timeout_secs: None,
+            auto_promote_after: None,
             instance_name: None,
             identity_dir: None,
             directory_digest_interval_secs: None,
@@ -591,6 +626,8 @@ impl Default for DaemonConfig {
             forward: x0x::forward::ForwardConfig::default(),
             network_id: None,
             zero_peer_restart_secs: None,
+            rate_limit: RateLimitConfig::default(),
+            auto_rejoin_task_lists: default_auto_rejoin_task_lists(),
         }
     }
 }
@@ -605,6 +642,11 @@ pub(super) struct AppState {
     /// operators can see the retention bounds in force.
     pub(super) history_config: x0x::history::HistoryConfig,
     pub(super) subscriptions: RwLock<HashMap<String, RestSubscription>>,
+    /// Topics of `subscriptions`, persisted so `/subscribe` streams are
+    /// restored on restart (`<data_dir>/subscriptions.json`).
+    pub(super) subscriptions_path: PathBuf,
+    /// Serializes snapshot-and-write of `subscriptions.json`.
+    pub(super) subscriptions_persistence_lock: Mutex<()>,
     pub(super) task_lists: RwLock<HashMap<String, TaskListHandle>>,
     pub(super) kv_stores: RwLock<HashMap<String, KvStoreHandle>>,
     /// Persisted task-list/kv-store subscription manifest so registrations
@@ -619,6 +661,12 @@ pub(super) struct AppState {
     /// store contents — and in particular `AppendOnly` immutability knowledge
     /// — survive a daemon restart instead of coming back as empty replicas.
     pub(super) kv_store_state_dir: PathBuf,
+    /// Whether startup rehydration rejoins persisted task lists (config
+    /// `auto_rejoin_task_lists`).
+    pub(super) auto_rejoin_task_lists: bool,
+    /// Task lists startup rehydration failed to rejoin, keyed by manifest id,
+    /// with the error. Surfaced by `GET /health/persistence`.
+    pub(super) task_list_recovery_failures: RwLock<HashMap<String, String>>,
     /// Serializes snapshot-and-write of `crdt-subscriptions.json` so an older
     /// in-memory snapshot cannot rename over a newer one after a concurrent
     /// `crdt_subscriptions::record` (the snapshot-after-unlock lost update).
@@ -726,6 +774,8 @@ pub(super) struct AppState {
     pub(super) ws_topics: RwLock<HashMap<String, SharedTopicState>>,
     /// Per-WS-outbound-queue observability (drop / slow-consumer-close counters).
     pub(super) ws_outbound_stats: Arc<WsOutboundStats>,
+    /// `/events` SSE client count and lag counters.
+    pub(super) sse_stats: Arc<SseStats>,
     pub(super) api_address: SocketAddr,
     pub(super) start_time: Instant,
     pub(super) broadcast_tx: broadcast::Sender<SseEvent>,
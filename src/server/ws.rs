@@ -918,6 +918,16 @@ async fn handle_ws_command(
                     return;
                 }
             };
+            if let Err(e) = x0x::gossip::check_app_topic(&topic) {
+                feed_droppable(
+                    tx,
+                    WsOutbound::Error {
+                        message: e.to_string(),
+                    },
+                    stats,
+                );
+                return;
+            }
 
             if let Err(e) = state.agent.publish(&topic, bytes).await {
                 tracing::error!("ws publish failed: {e}");
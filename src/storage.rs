@@ -242,6 +242,173 @@ pub fn deserialize_agent_keypair_with_expiry(bytes: &[u8]) -> Result<(AgentKeypa
     ))
 }
 
+/// Magic marker prefixing a passphrase-encrypted keypair export.
+const EXPORT_MAGIC: &[u8; 4] = b"X0KE";
+
+/// Current export format: Argon2id key derivation + ChaCha20-Poly1305.
+///
+/// Bump this whenever the KDF or cipher changes so importers reject exports
+/// they cannot read instead of reporting a wrong passphrase.
+const EXPORT_VERSION: u8 = 1;
+
+/// Argon2id cost used for new exports (OWASP's 19 MiB / 2 passes / 1 lane).
+/// The costs travel in the header, so raising them later keeps old exports
+/// importable.
+const EXPORT_ARGON2_M_COST_KIB: u32 = 19 * 1024;
+const EXPORT_ARGON2_T_COST: u32 = 2;
+const EXPORT_ARGON2_P_COST: u32 = 1;
+
+/// Largest memory cost an import will honour (1 GiB), so a crafted header
+/// cannot make the importer allocate without bound.
+const EXPORT_ARGON2_MAX_M_COST_KIB: u32 = 1024 * 1024;
+
+/// Largest pass count and lane count an import will honour, so a crafted
+/// header cannot pin the importer's CPU for hours before the tag check.
+const EXPORT_ARGON2_MAX_T_COST: u32 = 10;
+const EXPORT_ARGON2_MAX_P_COST: u32 = 16;
+
+const EXPORT_SALT_LEN: usize = 16;
+const EXPORT_NONCE_LEN: usize = 12;
+/// Magic, version, three `u32` costs, salt and nonce.
+const EXPORT_HEADER_LEN: usize = 4 + 1 + 3 * 4 + EXPORT_SALT_LEN + EXPORT_NONCE_LEN;
+
+/// Encrypt an AgentKeypair under a passphrase for transfer to another
+/// machine.
+///
+/// The output is a versioned header — [`EXPORT_MAGIC`], format version,
+/// Argon2id costs, salt and nonce — followed by the ChaCha20-Poly1305
+/// ciphertext of the regular key-file bytes. The header is authenticated as
+/// associated data, so tampering with the costs fails the import.
+///
+/// # Errors
+///
+/// Returns [`IdentityError::Crypto`] if key derivation or encryption fails.
+pub fn export_agent_keypair_encrypted(kp: &AgentKeypair, passphrase: &str) -> Result<Vec<u8>> {
+    let params = argon2::Params::new(
+        EXPORT_ARGON2_M_COST_KIB,
+        EXPORT_ARGON2_T_COST,
+        EXPORT_ARGON2_P_COST,
+        Some(32),
+    )
+    .map_err(|e| IdentityError::Crypto(format!("invalid Argon2 parameters: {e}")))?;
+    export_agent_keypair_with_params(kp, passphrase, &params)
+}
+
+fn export_agent_keypair_with_params(
+    kp: &AgentKeypair,
+    passphrase: &str,
+    params: &argon2::Params,
+) -> Result<Vec<u8>> {
+    use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+    use rand::RngCore;
+
+    let mut salt = [0u8; EXPORT_SALT_LEN];
+    let mut nonce = [0u8; EXPORT_NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut salt);
+    rand::thread_rng().fill_bytes(&mut nonce);
+
+    let mut out = Vec::with_capacity(EXPORT_HEADER_LEN);
+    out.extend_from_slice(EXPORT_MAGIC);
+    out.push(EXPORT_VERSION);
+    out.extend_from_slice(&params.m_cost().to_le_bytes());
+    out.extend_from_slice(&params.t_cost().to_le_bytes());
+    out.extend_from_slice(&params.p_cost().to_le_bytes());
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce);
+
+    let key = derive_export_key(passphrase, &salt, params.clone())?;
+    let plaintext = zeroize::Zeroizing::new(serialize_agent_keypair(kp)?);
+    let cipher = chacha20poly1305::ChaCha20Poly1305::new(chacha20poly1305::Key::from_slice(&*key));
+    let ciphertext = cipher
+        .encrypt(
+            chacha20poly1305::Nonce::from_slice(&nonce),
+            Payload {
+                msg: &plaintext,
+                aad: &out,
+            },
+        )
+        .map_err(|_| IdentityError::Crypto("keypair encryption failed".to_string()))?;
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+/// Decrypt an AgentKeypair produced by [`export_agent_keypair_encrypted`].
+///
+/// # Errors
+///
+/// Returns [`IdentityError::Serialization`] if the bytes are not an export
+/// or use an unknown format version or out-of-range costs, and
+/// [`IdentityError::Crypto`] if the passphrase is wrong or the export was
+/// modified.
+pub fn import_agent_keypair_encrypted(bytes: &[u8], passphrase: &str) -> Result<AgentKeypair> {
+    use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+
+    if bytes.len() < EXPORT_HEADER_LEN || &bytes[..EXPORT_MAGIC.len()] != EXPORT_MAGIC {
+        return Err(IdentityError::Serialization(
+            "not an encrypted x0x keypair export".to_string(),
+        ));
+    }
+    let version = bytes[EXPORT_MAGIC.len()];
+    if version != EXPORT_VERSION {
+        return Err(IdentityError::Serialization(format!(
+            "unsupported keypair export version {version} (this build reads {EXPORT_VERSION})"
+        )));
+    }
+    let (header, ciphertext) = bytes.split_at(EXPORT_HEADER_LEN);
+    let cost = |at: usize| {
+        u32::from_le_bytes([header[at], header[at + 1], header[at + 2], header[at + 3]])
+    };
+    let (m_cost, t_cost, p_cost) = (cost(5), cost(9), cost(13));
+    if m_cost > EXPORT_ARGON2_MAX_M_COST_KIB {
+        return Err(IdentityError::Serialization(format!(
+            "keypair export memory cost {m_cost} KiB exceeds the {EXPORT_ARGON2_MAX_M_COST_KIB} KiB limit"
+        )));
+    }
+    if t_cost > EXPORT_ARGON2_MAX_T_COST {
+        return Err(IdentityError::Serialization(format!(
+            "keypair export time cost {t_cost} exceeds the {EXPORT_ARGON2_MAX_T_COST} pass limit"
+        )));
+    }
+    if p_cost > EXPORT_ARGON2_MAX_P_COST {
+        return Err(IdentityError::Serialization(format!(
+            "keypair export parallelism {p_cost} exceeds the {EXPORT_ARGON2_MAX_P_COST} lane limit"
+        )));
+    }
+    let params = argon2::Params::new(m_cost, t_cost, p_cost, Some(32))
+        .map_err(|e| IdentityError::Serialization(format!("invalid keypair export costs: {e}")))?;
+    let salt = &header[17..17 + EXPORT_SALT_LEN];
+    let nonce = &header[17 + EXPORT_SALT_LEN..];
+
+    let key = derive_export_key(passphrase, salt, params)?;
+    let cipher = chacha20poly1305::ChaCha20Poly1305::new(chacha20poly1305::Key::from_slice(&*key));
+    let plaintext = zeroize::Zeroizing::new(
+        cipher
+            .decrypt(
+                chacha20poly1305::Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: header,
+                },
+            )
+            .map_err(|_| {
+                IdentityError::Crypto("wrong passphrase or corrupted keypair export".to_string())
+            })?,
+    );
+    deserialize_agent_keypair(&plaintext)
+}
+
+fn derive_export_key(
+    passphrase: &str,
+    salt: &[u8],
+    params: argon2::Params,
+) -> Result<zeroize::Zeroizing<[u8; 32]>> {
+    let mut key = zeroize::Zeroizing::new([0u8; 32]);
+    argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), salt, &mut *key)
+        .map_err(|e| IdentityError::Crypto(format!("key derivation failed: {e}")))?;
+    Ok(key)
+}
+
 /// x0x configuration directory path.
 const X0X_DIR: &str = ".x0x";
 
@@ -257,6 +424,13 @@ const USER_KEY_FILE: &str = "user.key";
 /// Agent certificate file name.
 const AGENT_CERT_FILE: &str = "agent.cert";
 
+/// Extension of the file holding additional (non-primary) agent
+/// certificates, stored beside the primary: `agent.cert` → `agent.certs`.
+const ADDITIONAL_CERTS_EXTENSION: &str = "certs";
+
+/// Magic marker prefixing an additional-certificates file.
+const ADDITIONAL_CERTS_MAGIC: &[u8; 4] = b"X0CS";
+
 /// Revocation set file name.
 const REVOCATION_FILE: &str = "revocations.bin";
 
@@ -645,6 +819,68 @@ pub async fn load_agent_certificate_from<P: AsRef<Path>>(path: P) -> Result<Agen
     AgentCertificate::from_storage_bytes(&bytes)
 }
 
+/// Path of the additional-certificates file that accompanies the primary
+/// certificate at `agent_cert_path`.
+///
+/// The primary stays in its own single-certificate file so older releases
+/// can still read it; only certificates from other users live here.
+#[must_use]
+pub fn additional_certificates_path(agent_cert_path: &Path) -> std::path::PathBuf {
+    agent_cert_path.with_extension(ADDITIONAL_CERTS_EXTENSION)
+}
+
+/// Default path of the additional-certificates file (`~/.x0x/agent.certs`).
+pub async fn default_additional_certificates_path() -> Result<std::path::PathBuf> {
+    Ok(additional_certificates_path(
+        &x0x_dir().await?.join(AGENT_CERT_FILE),
+    ))
+}
+
+/// Save additional agent certificates to the specified file path.
+///
+/// Written as [`ADDITIONAL_CERTS_MAGIC`] followed by the bincode list of each
+/// certificate's [`AgentCertificate::to_storage_bytes`], so every entry keeps
+/// its own v1/v2 encoding.
+pub async fn save_additional_certificates_to<P: AsRef<Path>>(
+    certs: &[AgentCertificate],
+    path: P,
+) -> Result<()> {
+    let entries = certs
+        .iter()
+        .map(AgentCertificate::to_storage_bytes)
+        .collect::<Result<Vec<_>>>()?;
+    let body =
+        bincode::serialize(&entries).map_err(|e| IdentityError::Serialization(e.to_string()))?;
+    let mut bytes = Vec::with_capacity(ADDITIONAL_CERTS_MAGIC.len() + body.len());
+    bytes.extend_from_slice(ADDITIONAL_CERTS_MAGIC);
+    bytes.extend_from_slice(&body);
+    write_private_file(path.as_ref(), bytes).await
+}
+
+/// Load additional agent certificates from the specified file path.
+///
+/// A missing file is an empty list.
+pub async fn load_additional_certificates_from<P: AsRef<Path>>(
+    path: P,
+) -> Result<Vec<AgentCertificate>> {
+    let bytes = match tokio::fs::read(path).await {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(IdentityError::from(e)),
+    };
+    let body = bytes
+        .strip_prefix(ADDITIONAL_CERTS_MAGIC.as_slice())
+        .ok_or_else(|| {
+            IdentityError::Serialization("missing additional-certificates marker".to_string())
+        })?;
+    let entries: Vec<Vec<u8>> =
+        bincode::deserialize(body).map_err(|e| IdentityError::Serialization(e.to_string()))?;
+    entries
+        .iter()
+        .map(|entry| AgentCertificate::from_storage_bytes(entry))
+        .collect()
+}
+
 /// Default path for the revocation set file.
 ///
 /// When `identity_dir` is provided (multi-instance daemons), the file is
@@ -717,11 +953,242 @@ pub async fn save_revocation_set_bytes(bytes: Vec<u8>, identity_dir: Option<&Pat
     write_private_file(&path, bytes).await
 }
 
+/// Key files holding secret material, overwritten before removal by
+/// [`wipe_identity`]. `agent_kem.key` lives in the data directory.
+const SECRET_KEY_FILES: &[&str] = &[MACHINE_KEY_FILE, AGENT_KEY_FILE, USER_KEY_FILE];
+
+/// Data-directory key file holding the agent's KEM secret.
+const AGENT_KEM_KEY_FILE: &str = "agent_kem.key";
+
+/// Files and directories removed by [`wipe_identity`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WipeReport {
+    /// Secret key files overwritten with random bytes, then removed.
+    pub overwritten: Vec<std::path::PathBuf>,
+    /// Certificates, revocations, contacts and the peer cache, removed.
+    pub removed: Vec<std::path::PathBuf>,
+}
+
+impl WipeReport {
+    /// True when nothing was found to wipe.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.overwritten.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Securely delete the identity of a profile, for decommissioning a machine.
+///
+/// `profile` selects a named instance (`x0x --name <profile>`): keys in
+/// `~/.x0x-<profile>` and data in `<data dir>/x0x-<profile>`; `None` is the
+/// default `~/.x0x` and `<data dir>/x0x`. See [`wipe_identity_in`] for what
+/// is removed.
+///
+/// # Errors
+///
+/// Refuses to run unless `confirm` is `true`. Also fails if the home or
+/// data directory cannot be determined, or a file cannot be wiped.
+pub async fn wipe_identity(profile: Option<&str>, confirm: bool) -> Result<WipeReport> {
+    let (key_dir, data_dir_name) = match profile {
+        Some(profile) => (format!("{X0X_DIR}-{profile}"), format!("x0x-{profile}")),
+        None => (X0X_DIR.to_string(), "x0x".to_string()),
+    };
+    let home = dirs::home_dir().ok_or_else(|| {
+        IdentityError::from(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "home directory not found",
+        ))
+    })?;
+    let data = dirs::data_dir().ok_or_else(|| {
+        IdentityError::from(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "data directory not found",
+        ))
+    })?;
+    wipe_identity_in(&home.join(key_dir), &data.join(data_dir_name), confirm).await
+}
+
+/// Securely delete the identity stored in `key_dir` and `data_dir`.
+///
+/// Secret key files (`machine.key`, `agent.key`, `user.key`, and
+/// `agent_kem.key` in the data directory) are overwritten with random bytes
+/// and synced before being unlinked. Certificates, the revocation set,
+/// `contacts.json` and the `peers/` cache are removed. Missing files are
+/// skipped; other data (groups, stores, history) is left in place.
+///
+/// Overwriting cannot reach copies the filesystem or device keeps elsewhere
+/// (copy-on-write filesystems, SSD wear levelling, snapshots, backups); use
+/// full-disk encryption where that matters.
+///
+/// # Errors
+///
+/// Refuses to run unless `confirm` is `true`. Stops at the first file that
+/// cannot be overwritten or removed; the report is lost in that case, so
+/// re-run to finish.
+pub async fn wipe_identity_in(
+    key_dir: &Path,
+    data_dir: &Path,
+    confirm: bool,
+) -> Result<WipeReport> {
+    if !confirm {
+        return Err(IdentityError::from(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "refusing to wipe identity without explicit confirmation",
+        )));
+    }
+    let mut report = WipeReport::default();
+
+    let secret_files = SECRET_KEY_FILES
+        .iter()
+        .map(|name| key_dir.join(name))
+        .chain(std::iter::once(data_dir.join(AGENT_KEM_KEY_FILE)));
+    for path in secret_files {
+        if overwrite_and_remove(&path).await? {
+            report.overwritten.push(path);
+        }
+    }
+
+    let agent_cert = key_dir.join(AGENT_CERT_FILE);
+    let plain_files = [
+        additional_certificates_path(&agent_cert),
+        agent_cert,
+        key_dir.join(REVOCATION_FILE),
+        data_dir.join("contacts.json"),
+    ];
+    for path in plain_files {
+        match fs::remove_file(&path).await {
+            Ok(()) => report.removed.push(path),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(IdentityError::from(e)),
+        }
+    }
+    let peer_cache = data_dir.join("peers");
+    match fs::remove_dir_all(&peer_cache).await {
+        Ok(()) => report.removed.push(peer_cache),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(IdentityError::from(e)),
+    }
+
+    Ok(report)
+}
+
+/// Overwrite `path` with random bytes, sync, and unlink it. Returns `false`
+/// if the file does not exist.
+async fn overwrite_and_remove(path: &Path) -> Result<bool> {
+    use rand::RngCore;
+    use tokio::io::AsyncWriteExt;
+
+    let len = match fs::metadata(path).await {
+        Ok(meta) => meta.len(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(IdentityError::from(e)),
+    };
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .open(path)
+        .await
+        .map_err(IdentityError::from)?;
+    let mut noise = vec![0u8; 8192];
+    let mut remaining = len;
+    while remaining > 0 {
+        let chunk = remaining.min(noise.len() as u64) as usize;
+        rand::thread_rng().fill_bytes(&mut noise[..chunk]);
+        file.write_all(&noise[..chunk])
+            .await
+            .map_err(IdentityError::from)?;
+        remaining -= chunk as u64;
+    }
+    file.sync_all().await.map_err(IdentityError::from)?;
+    drop(file);
+    fs::remove_file(path).await.map_err(IdentityError::from)?;
+    Ok(true)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::identity::{AgentKeypair, MachineKeypair};
 
+    /// Minimum Argon2 costs: these tests exercise the format, not the KDF.
+    fn cheap_params() -> argon2::Params {
+        argon2::Params::new(8, 1, 1, Some(32)).unwrap()
+    }
+
+    #[test]
+    fn encrypted_export_roundtrips_with_the_passphrase() {
+        let original = AgentKeypair::generate().unwrap();
+        let exported = export_agent_keypair_encrypted(&original, "correct horse").unwrap();
+        assert_eq!(&exported[..4], EXPORT_MAGIC);
+        assert_eq!(exported[4], EXPORT_VERSION);
+
+        let imported = import_agent_keypair_encrypted(&exported, "correct horse").unwrap();
+        assert_eq!(imported.agent_id(), original.agent_id());
+        assert_eq!(
+            imported.secret_key().as_bytes(),
+            original.secret_key().as_bytes()
+        );
+    }
+
+    #[test]
+    fn encrypted_export_rejects_wrong_passphrase_and_tampering() {
+        let kp = AgentKeypair::generate().unwrap();
+        let exported = export_agent_keypair_with_params(&kp, "right", &cheap_params()).unwrap();
+        assert!(import_agent_keypair_encrypted(&exported, "right").is_ok());
+
+        assert!(matches!(
+            import_agent_keypair_encrypted(&exported, "wrong"),
+            Err(IdentityError::Crypto(_))
+        ));
+        // The costs are authenticated: raising t_cost breaks the tag.
+        let mut tampered = exported.clone();
+        tampered[9] += 1;
+        assert!(matches!(
+            import_agent_keypair_encrypted(&tampered, "right"),
+            Err(IdentityError::Crypto(_))
+        ));
+    }
+
+    #[test]
+    fn encrypted_export_rejects_excessive_costs_before_deriving() {
+        let kp = AgentKeypair::generate().unwrap();
+        let exported = export_agent_keypair_with_params(&kp, "pw", &cheap_params()).unwrap();
+        // Offsets of m_cost, t_cost and p_cost in the header.
+        for (offset, cost) in [
+            (5, EXPORT_ARGON2_MAX_M_COST_KIB + 1),
+            (9, EXPORT_ARGON2_MAX_T_COST + 1),
+            (13, EXPORT_ARGON2_MAX_P_COST + 1),
+            (9, u32::MAX),
+        ] {
+            let mut crafted = exported.clone();
+            crafted[offset..offset + 4].copy_from_slice(&cost.to_le_bytes());
+            // Rejected as malformed, not after a (huge) derivation fails the
+            // tag check.
+            let err = import_agent_keypair_encrypted(&crafted, "pw").unwrap_err();
+            assert!(
+                matches!(&err, IdentityError::Serialization(msg) if msg.contains("exceeds")),
+                "{err}"
+            );
+        }
+    }
+
+    #[test]
+    fn encrypted_export_rejects_unknown_versions_and_plain_key_files() {
+        let kp = AgentKeypair::generate().unwrap();
+        let mut exported = export_agent_keypair_with_params(&kp, "pw", &cheap_params()).unwrap();
+        exported[4] = EXPORT_VERSION + 1;
+        let err = import_agent_keypair_encrypted(&exported, "pw").unwrap_err();
+        assert!(
+            matches!(&err, IdentityError::Serialization(msg) if msg.contains("version")),
+            "{err}"
+        );
+
+        let plain = serialize_agent_keypair(&kp).unwrap();
+        assert!(matches!(
+            import_agent_keypair_encrypted(&plain, "pw"),
+            Err(IdentityError::Serialization(_))
+        ));
+    }
+
     #[tokio::test]
     async fn test_keypair_serialization_roundtrip() {
         // Test MachineKeypair
@@ -906,6 +1373,35 @@ mod tests {
         dir.join(MACHINE_KEY_FILE).exists()
     }
 
+    #[tokio::test]
+    async fn test_additional_certificates_roundtrip() {
+        let agent_kp = AgentKeypair::generate().unwrap();
+        let alice = UserKeypair::generate().unwrap();
+        let bob = UserKeypair::generate().unwrap();
+        let certs = vec![
+            AgentCertificate::issue(&alice, &agent_kp).unwrap(),
+            AgentCertificate::issue_with_expiry(&bob, &agent_kp, Some(u64::MAX)).unwrap(),
+        ];
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = additional_certificates_path(&temp_dir.path().join(AGENT_CERT_FILE));
+        assert_eq!(path.file_name().unwrap(), "agent.certs");
+        assert!(load_additional_certificates_from(&path)
+            .await
+            .unwrap()
+            .is_empty());
+
+        save_additional_certificates_to(&certs, &path)
+            .await
+            .unwrap();
+        let loaded = load_additional_certificates_from(&path).await.unwrap();
+        assert_eq!(loaded, certs);
+        for (cert, user) in loaded.iter().zip([&alice, &bob]) {
+            cert.verify().unwrap();
+            assert_eq!(cert.user_id().unwrap(), user.user_id());
+        }
+    }
+
     // ========================================================================
     // #124 / WS1.3 tranche 3 — storage / identity error paths.
     //
@@ -1147,4 +1643,49 @@ mod tests {
             "the plain loader must recover key material from a v2 file"
         );
     }
+
+    #[tokio::test]
+    async fn test_wipe_identity_removes_files_and_reports_them() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let key_dir = temp_dir.path().join("keys");
+        let data_dir = temp_dir.path().join("data");
+        std::fs::create_dir_all(data_dir.join("peers")).unwrap();
+        std::fs::create_dir_all(&key_dir).unwrap();
+        for name in ["machine.key", "agent.key", "agent.cert", "agent.certs"] {
+            std::fs::write(key_dir.join(name), b"secret").unwrap();
+        }
+        for name in ["agent_kem.key", "contacts.json", "peers/cache.bin"] {
+            std::fs::write(data_dir.join(name), b"state").unwrap();
+        }
+        std::fs::write(data_dir.join("named_groups.json"), b"{}").unwrap();
+
+        assert!(wipe_identity_in(&key_dir, &data_dir, false).await.is_err());
+        assert!(key_dir.join("agent.key").exists(), "unconfirmed wipe ran");
+
+        let report = wipe_identity_in(&key_dir, &data_dir, true).await.unwrap();
+        assert_eq!(
+            report.overwritten,
+            vec![
+                key_dir.join("machine.key"),
+                key_dir.join("agent.key"),
+                data_dir.join("agent_kem.key"),
+            ]
+        );
+        assert_eq!(
+            report.removed,
+            vec![
+                key_dir.join("agent.certs"),
+                key_dir.join("agent.cert"),
+                data_dir.join("contacts.json"),
+                data_dir.join("peers"),
+            ]
+        );
+        for path in report.overwritten.iter().chain(&report.removed) {
+            assert!(!path.exists(), "{} survived the wipe", path.display());
+        }
+        assert!(data_dir.join("named_groups.json").exists());
+
+        let again = wipe_identity_in(&key_dir, &data_dir, true).await.unwrap();
+        assert!(again.is_empty());
+    }
 }
@@ -0,0 +1,301 @@
+//! Four-word speakable renderings of ids and addresses.
+//!
+//! Words come from `four-word-networking`, compiled in by the default
+//! `words` feature. Rendering never fails the surrounding response: when the
+//! feature is off, or the encoder rejects an input, an id is rendered as
+//! [`HEX_FALLBACK_PREFIX`] followed by its hex form so callers (and humans)
+//! can tell the two apart. Decoding words back to ids or addresses has no
+//! such fallback and returns [`WordsError::Disabled`] without the feature.
+//!
+//! Identity words encode only a leading prefix of an id — enough to confirm
+//! or look one up, not to rebuild its 32 bytes. [`resolve_identity`] turns
+//! words back into a full id by matching that prefix against ids the caller
+//! already knows; a `hex:<id>` fallback rendering resolves exactly on its
+//! own.
+
+use crate::identity::{AgentId, UserId};
+
+/// Prefix marking a rendering that fell back to hex.
+pub const HEX_FALLBACK_PREFIX: &str = "hex:";
+
+/// Whether this build can render and decode words at all.
+pub const WORDS_ENABLED: bool = cfg!(feature = "words");
+
+/// Errors from decoding words.
+#[derive(Debug, thiserror::Error)]
+pub enum WordsError {
+    /// Built without the `words` feature.
+    #[error("four-word encoding is not available in this build (enable the `words` feature)")]
+    Disabled,
+
+    /// The encoder rejected the input.
+    #[error("{0}")]
+    Invalid(String),
+
+    /// No known id starts with the prefix the words encode.
+    #[error("no known id matches identity words (prefix 0x{0})")]
+    NoMatch(String),
+
+    /// More than one known id starts with the prefix the words encode.
+    #[error("{count} known ids match identity words (prefix 0x{prefix}); use the full id")]
+    Ambiguous {
+        /// Hex prefix the words decoded to.
+        prefix: String,
+        /// Number of candidates sharing it.
+        count: usize,
+    },
+}
+
+/// Four-word form of a hex-encoded id, or `None` when unavailable.
+#[must_use]
+pub fn encode_hex_id(hex_id: &str) -> Option<String> {
+    #[cfg(feature = "words")]
+    {
+        four_word_networking::IdentityEncoder::new()
+            .encode_hex(hex_id)
+            .ok()
+            .map(|words| words.to_string())
+    }
+    #[cfg(not(feature = "words"))]
+    {
+        let _ = hex_id;
+        None
+    }
+}
+
+/// `hex:<id>` — the fallback rendering of a hex-encoded id.
+#[must_use]
+pub fn hex_fallback(hex_id: &str) -> String {
+    format!("{HEX_FALLBACK_PREFIX}{hex_id}")
+}
+
+/// True when `rendered` is a hex fallback rather than words.
+#[must_use]
+pub fn is_hex_fallback(rendered: &str) -> bool {
+    rendered.starts_with(HEX_FALLBACK_PREFIX)
+}
+
+/// Four-word form of a hex-encoded id, falling back to `hex:<id>`.
+#[must_use]
+pub fn render_hex_id(hex_id: &str) -> String {
+    encode_hex_id(hex_id).unwrap_or_else(|| hex_fallback(hex_id))
+}
+
+/// Identity words for an agent, combined with its user when present
+/// (`agent words @ user words`), falling back to hex for both halves.
+#[must_use]
+pub fn render_identity(agent_id: &AgentId, user_id: Option<&UserId>) -> String {
+    #[cfg(feature = "words")]
+    {
+        let encoder = four_word_networking::IdentityEncoder::new();
+        let words = match user_id {
+            Some(user_id) => encoder
+                .encode_full(agent_id.as_bytes(), user_id.as_bytes())
+                .map(|words| words.to_string()),
+            None => encoder
+                .encode_agent(agent_id.as_bytes())
+                .map(|words| words.to_string()),
+        };
+        if let Ok(words) = words {
+            return words;
+        }
+    }
+    let agent = hex_fallback(&hex::encode(agent_id.as_bytes()));
+    match user_id {
+        Some(user_id) => format!(
+            "{agent} @ {}",
+            hex_fallback(&hex::encode(user_id.as_bytes()))
+        ),
+        None => agent,
+    }
+}
+
+/// Location words for an `ip:port` address, or `None` when unavailable.
+#[must_use]
+pub fn encode_location(addr: &str) -> Option<String> {
+    #[cfg(feature = "words")]
+    {
+        four_word_networking::FourWordAdaptiveEncoder::new()
+            .ok()?
+            .encode(addr)
+            .ok()
+    }
+    #[cfg(not(feature = "words"))]
+    {
+        let _ = addr;
+        None
+    }
+}
+
+/// Decode four location words back to an `ip:port` address.
+///
+/// # Errors
+///
+/// [`WordsError::Disabled`] without the `words` feature;
+/// [`WordsError::Invalid`] if the words do not decode.
+pub fn decode_location(words: &str) -> Result<String, WordsError> {
+    #[cfg(feature = "words")]
+    {
+        four_word_networking::FourWordAdaptiveEncoder::new()
+            .map_err(|e| WordsError::Invalid(format!("failed to initialise address encoder: {e}")))?
+            .decode(words)
+            .map_err(|e| WordsError::Invalid(e.to_string()))
+    }
+    #[cfg(not(feature = "words"))]
+    {
+        let _ = words;
+        Err(WordsError::Disabled)
+    }
+}
+
+/// Decode four identity words to the hex id prefix they encode.
+///
+/// # Errors
+///
+/// [`WordsError::Disabled`] without the `words` feature;
+/// [`WordsError::Invalid`] if the words do not decode.
+pub fn decode_identity_prefix(words: &str) -> Result<String, WordsError> {
+    #[cfg(feature = "words")]
+    {
+        four_word_networking::IdentityEncoder::new()
+            .decode_to_prefix(words)
+            .map(hex::encode)
+            .map_err(|e| WordsError::Invalid(e.to_string()))
+    }
+    #[cfg(not(feature = "words"))]
+    {
+        let _ = words;
+        Err(WordsError::Disabled)
+    }
+}
+
+/// Resolve identity words, or a `hex:<id>` fallback, to a full 32-byte id.
+///
+/// A hex fallback carries the whole id and is returned as-is. Four words
+/// only carry a prefix, so they resolve to the single entry of `candidates`
+/// starting with it.
+///
+/// # Errors
+///
+/// [`WordsError::Invalid`] for a malformed hex fallback or words that do not
+/// decode; [`WordsError::Disabled`] for words without the `words` feature;
+/// [`WordsError::NoMatch`] / [`WordsError::Ambiguous`] when the prefix
+/// matches no candidate or several.
+pub fn resolve_identity<'a>(
+    words: &str,
+    candidates: impl IntoIterator<Item = &'a [u8; 32]>,
+) -> Result<[u8; 32], WordsError> {
+    let words = words.trim();
+    if let Some(hex_id) = words.strip_prefix(HEX_FALLBACK_PREFIX) {
+        let bytes =
+            hex::decode(hex_id).map_err(|e| WordsError::Invalid(format!("invalid hex id: {e}")))?;
+        return <[u8; 32]>::try_from(bytes.as_slice()).map_err(|_| {
+            WordsError::Invalid(format!("hex id must be 32 bytes, got {}", bytes.len()))
+        });
+    }
+
+    let prefix = decode_identity_prefix(words)?;
+    let mut matches = candidates
+        .into_iter()
+        .filter(|id| hex::encode(id).starts_with(&prefix));
+    match (matches.next(), matches.count()) {
+        (Some(id), 0) => Ok(*id),
+        (None, _) => Err(WordsError::NoMatch(prefix)),
+        (Some(_), others) => Err(WordsError::Ambiguous {
+            prefix,
+            count: others + 1,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AGENT_HEX: &str = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+
+    #[test]
+    fn invalid_hex_falls_back_with_indicator() {
+        let rendered = render_hex_id("not-hex");
+        assert_eq!(rendered, "hex:not-hex");
+        assert!(is_hex_fallback(&rendered));
+    }
+
+    #[cfg(not(feature = "words"))]
+    #[test]
+    fn ids_render_as_hex_without_words_feature() {
+        assert!(!WORDS_ENABLED);
+        assert_eq!(render_hex_id(AGENT_HEX), format!("hex:{AGENT_HEX}"));
+        let agent = AgentId([0xaa; 32]);
+        let user = UserId([0xbb; 32]);
+        assert_eq!(render_identity(&agent, None), format!("hex:{AGENT_HEX}"));
+        assert_eq!(
+            render_identity(&agent, Some(&user)),
+            format!("hex:{AGENT_HEX} @ hex:{}", "bb".repeat(32))
+        );
+        assert!(encode_location("192.168.1.1:5483").is_none());
+        assert!(matches!(
+            decode_identity_prefix("a b c d"),
+            Err(WordsError::Disabled)
+        ));
+    }
+
+    /// The hex fallback is the exact form of an id, so it resolves without
+    /// candidates and with or without the feature.
+    #[test]
+    fn hex_fallback_resolves_exactly() {
+        let agent = AgentId([0xaa; 32]);
+        assert_eq!(
+            AgentId::from_words(&hex_fallback(AGENT_HEX), []).expect("resolve"),
+            agent
+        );
+        assert!(matches!(
+            AgentId::from_words("hex:aabb", []),
+            Err(WordsError::Invalid(_))
+        ));
+    }
+
+    #[cfg(feature = "words")]
+    #[test]
+    fn ids_render_as_words_with_words_feature() {
+        assert!(WORDS_ENABLED);
+        let rendered = render_hex_id(AGENT_HEX);
+        assert!(!is_hex_fallback(&rendered), "expected words: {rendered}");
+        let identity = render_identity(&AgentId([0xaa; 32]), None);
+        assert!(!is_hex_fallback(&identity), "expected words: {identity}");
+
+        let user = UserId([0xbb; 32]);
+        assert!(!is_hex_fallback(&user.to_words()));
+
+        let location = encode_location("192.168.1.1:5483").expect("location words");
+        assert_eq!(
+            decode_location(&location).expect("decode"),
+            "192.168.1.1:5483"
+        );
+    }
+
+    /// Words carry only a prefix: they resolve against known ids, and say so
+    /// when the prefix is missing or shared rather than guessing.
+    #[cfg(feature = "words")]
+    #[test]
+    fn words_resolve_against_known_ids() {
+        let agent = AgentId([0xaa; 32]);
+        let other = AgentId([0x11; 32]);
+        let mut twin = agent;
+        twin.0[31] = 0x00;
+        let words = agent.to_words();
+
+        assert_eq!(
+            AgentId::from_words(&words, [&other, &agent]).expect("resolve"),
+            agent
+        );
+        assert!(matches!(
+            AgentId::from_words(&words, [&other]),
+            Err(WordsError::NoMatch(_))
+        ));
+        assert!(matches!(
+            AgentId::from_words(&words, [&agent, &twin]),
+            Err(WordsError::Ambiguous { count: 2, .. })
+        ));
+    }
+}
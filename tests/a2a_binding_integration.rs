@@ -68,6 +68,7 @@ fn discovered_agent(agent: &Agent, addr: std::net::SocketAddr, now_secs: u64) ->
         cert_not_after: None,
         agent_certificate: None,
         agent_public_key: Vec::new(),
+        status: None,
     }
 }
 
@@ -117,6 +117,7 @@ fn signed_identity_announcement_with_nat_fields() -> IdentityAnnouncement {
         reachable_via: unsigned.reachable_via,
         relay_candidates: unsigned.relay_candidates,
         agent_public_key: Vec::new(),
+        status: None,
     }
 }
 
@@ -298,6 +299,7 @@ fn announcement_nat_fields_round_trip() {
         reachable_via: vec![coord],
         relay_candidates: vec![coord],
         agent_public_key: Vec::new(),
+        status: None,
     };
 
     let bytes = bincode::serialize(&ann).unwrap();
@@ -413,6 +415,7 @@ async fn discovery_cache_insert_and_retrieve() {
         cert_not_after: None,
         agent_certificate: None,
         agent_public_key: Vec::new(),
+        status: None,
     };
 
     agent
@@ -463,6 +466,7 @@ async fn reachability_info_from_discovery_cache() {
         cert_not_after: None,
         agent_certificate: None,
         agent_public_key: Vec::new(),
+        status: None,
     };
 
     agent.insert_discovered_agent_for_testing(fake).await;
@@ -45,13 +45,16 @@ macro_rules! covered {
 const COVERED: &[CoveredEndpoint] = &[
     // ── Status ──────────────────────────────────────────────────────────
     covered!(Get, "/health", daemon_api_health),
+    covered!(Get, "/health/persistence", daemon_api_health_persistence),
     covered!(Get, "/status", daemon_api_status),
+    covered!(Get, "/metrics", daemon_api_metrics),
     covered!(Post, "/shutdown", daemon_api_shutdown_with_sse_client),
     covered!(Post, "/auth/session", daemon_api_auth_session_exchange),
     // ── Identity ────────────────────────────────────────────────────────
     covered!(Get, "/agent", daemon_api_agent),
     covered!(Post, "/announce", daemon_api_announce),
     covered!(Get, "/agent/user-id", "GET /agent/user-id"),
+    covered!(Get, "/agent/words", daemon_api_agent_words),
     covered!(Get, "/agent/card", "GET /agent/card"),
     covered!(Get, "/introduction", "GET /introduction"),
     covered!(
@@ -75,10 +78,13 @@ const COVERED: &[CoveredEndpoint] = &[
     covered!(Get, "/presence/foaf", "presence/foaf body shape"),
     covered!(Get, "/presence/find/:id", "GET /presence/find/:id"),
     covered!(Get, "/presence/status/:id", "GET /presence/status/:id"),
+    covered!(Post, "/presence/status", daemon_api_presence_set_status),
+    covered!(Delete, "/presence/status", daemon_api_presence_clear_status),
     covered!(Get, "/presence/events", "GET /presence/events"),
     // ── Network (cont.) ─────────────────────────────────────────────────
     covered!(Get, "/network/status", daemon_api_network_status),
     covered!(Get, "/network/bootstrap-cache", daemon_api_bootstrap_cache),
+    covered!(Get, "/bootstrap/health", daemon_api_bootstrap_health),
     covered!(
         Get,
         "/diagnostics/connectivity",
@@ -124,6 +130,7 @@ const COVERED: &[CoveredEndpoint] = &[
     covered!(Get, "/diagnostics/exec", daemon_api_diagnostics_exec),
     covered!(Get, "/diagnostics/connect", daemon_api_diagnostics_connect),
     covered!(Get, "/diagnostics/ws", daemon_api_diagnostics_ws),
+    covered!(Get, "/diagnostics/sse", daemon_api_diagnostics_sse),
     covered!(
         Post,
         "/peers/:peer_id/probe",
@@ -139,10 +146,17 @@ const COVERED: &[CoveredEndpoint] = &[
         "/peers/events",
         peer_events_sse_emits_established_on_new_connection
     ),
+    covered!(Get, "/peers/cache", daemon_api_peer_cache),
+    covered!(
+        Post,
+        "/peers/disconnect-all",
+        daemon_api_peers_disconnect_all
+    ),
     // ── Messaging ───────────────────────────────────────────────────────
     covered!(Post, "/publish", daemon_api_subscribe_publish),
     covered!(Post, "/subscribe", daemon_api_subscribe_publish),
     covered!(Delete, "/subscribe/:id", daemon_api_unsubscribe),
+    covered!(Get, "/subscriptions", daemon_api_subscriptions),
     covered!(Get, "/events", daemon_api_events_sse),
     // ── Discovery ───────────────────────────────────────────────────────
     covered!(Get, "/agents/discovered", daemon_api_discovered_agents),
@@ -194,6 +208,14 @@ const COVERED: &[CoveredEndpoint] = &[
         "/contacts/:agent_id/revocations",
         daemon_api_list_revocations
     ),
+    covered!(
+        Get,
+        "/contacts/:agent_id/reachability",
+        daemon_api_contact_reachability
+    ),
+    covered!(Get, "/contacts/audit", daemon_api_contacts_audit),
+    covered!(Get, "/contacts/export", daemon_api_export_import_contacts),
+    covered!(Post, "/contacts/import", daemon_api_export_import_contacts),
     // ── Machines ────────────────────────────────────────────────────────
     covered!(Get, "/contacts/:agent_id/machines", "machines GET"),
     covered!(Post, "/contacts/:agent_id/machines", daemon_api_add_machine),
@@ -429,6 +451,34 @@ const COVERED: &[CoveredEndpoint] = &[
     covered!(Get, "/task-lists/:id/tasks", "GET /task-lists/:id/tasks"),
     covered!(Post, "/task-lists/:id/tasks", daemon_api_add_task),
     covered!(Patch, "/task-lists/:id/tasks/:tid", daemon_api_claim_task),
+    covered!(Delete, "/task-lists/:id/tasks/:tid", daemon_api_remove_task),
+    covered!(
+        Get,
+        "/task-lists/:id/tasks/:tid/history",
+        daemon_api_task_history
+    ),
+    covered!(
+        Get,
+        "/task-lists/:id/validate",
+        daemon_api_validate_task_list
+    ),
+    covered!(
+        Get,
+        "/task-lists/:id/sync-stats",
+        daemon_api_task_list_sync_stats
+    ),
+    covered!(Get, "/task-lists/:id/export", daemon_api_export_task_list),
+    covered!(
+        Post,
+        "/task-lists/:id/checkpoint",
+        daemon_api_checkpoint_task_lists
+    ),
+    covered!(Post, "/checkpoint-all", daemon_api_checkpoint_task_lists),
+    covered!(
+        Patch,
+        "/persistence/policy",
+        daemon_api_update_persistence_policy
+    ),
     // ── Key-value stores ────────────────────────────────────────────────
     covered!(Get, "/stores", "GET /stores"),
     covered!(Post, "/stores", "POST /stores"),
@@ -56,6 +56,7 @@ fn fake_discovered(
         cert_not_after: None,
         agent_certificate: None,
         agent_public_key: Vec::new(),
+        status: None,
     }
 }
 
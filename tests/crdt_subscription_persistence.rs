@@ -197,6 +197,66 @@ async fn task_list_and_kv_store_survive_daemon_restart() {
     .await;
 }
 
+/// WHY: a daemon with no peers has nobody to re-sync a rehydrated list from,
+/// so its tasks must come back from the local snapshot on their own —
+/// without a re-create call and without waiting for a peer.
+#[tokio::test]
+#[ignore]
+async fn solo_task_list_tasks_survive_restart_from_snapshot() {
+    let (mut node, _bind) = cluster::solo().await;
+    let list_topic = format!("solo-restart-list-{}", rand::random::<u32>());
+
+    let r = node
+        .post(
+            "/task-lists",
+            serde_json::json!({ "name": "solo-restart-list", "topic": list_topic }),
+        )
+        .await;
+    assert!(r.status().is_success(), "create task list");
+    let r = node
+        .post(
+            &format!("/task-lists/{list_topic}/tasks"),
+            serde_json::json!({ "title": "persisted-task" }),
+        )
+        .await;
+    assert!(r.status().is_success(), "add task");
+
+    // The saver checks every 2s; restart() kills the process, so wait for a
+    // save that started after the add.
+    let list_id = x0x::crdt::TaskListId::from_topic(&list_topic);
+    let snapshot = node
+        .data_dir()
+        .join("task_lists")
+        .join(format!("{list_id}.bin"));
+    let added_at = std::time::SystemTime::now();
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(30);
+    loop {
+        let saved_after_add = std::fs::metadata(&snapshot)
+            .and_then(|m| m.modified())
+            .is_ok_and(|modified| modified > added_at);
+        if saved_after_add {
+            break;
+        }
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "snapshot not written at {}",
+            snapshot.display()
+        );
+        tokio::time::sleep(Duration::from_millis(250)).await;
+    }
+
+    node.restart().await;
+
+    poll_until(
+        &node,
+        &format!("/task-lists/{list_topic}/tasks"),
+        "post-restart task restored from snapshot",
+        30,
+        |json| tasks_contain(json, "persisted-task"),
+    )
+    .await;
+}
+
 /// WHY: the point of persisting subscriptions is that mutations made while an
 /// instance is DOWN still arrive after it comes back — without the manifest,
 /// a restarted daemon has no handle, so the offline delta has nowhere to
@@ -59,6 +59,25 @@ async fn daemon_api_health() {
     assert!(r["status"].is_string());
 }
 
+#[tokio::test]
+#[ignore]
+async fn daemon_api_health_persistence() {
+    let d = daemon().await;
+    let r: Value = ca(&d)
+        .get(d.url("/health/persistence"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(r["ok"], true);
+    assert_eq!(r["status"], "healthy");
+    assert_eq!(r["unsaved_task_lists"], 0);
+    assert!(r["storage_bytes"].is_u64());
+    assert!(r["failed_task_list_recoveries"].is_array());
+}
+
 #[tokio::test]
 #[ignore]
 async fn daemon_api_status() {
@@ -75,6 +94,19 @@ async fn daemon_api_status() {
     assert!(r["agent_id"].as_str().unwrap().len() == 64);
 }
 
+#[tokio::test]
+#[ignore]
+async fn daemon_api_metrics() {
+    let d = daemon().await;
+    let resp = ca(&d).get(d.url("/metrics")).send().await.unwrap();
+    assert!(resp.status().is_success());
+    let content_type = resp.headers()["content-type"].to_str().unwrap().to_string();
+    assert!(content_type.starts_with("text/plain"));
+    let body = resp.text().await.unwrap();
+    assert!(body.contains("# TYPE x0x_uptime_seconds gauge"));
+    assert!(body.contains("x0x_connected_peers "));
+}
+
 #[tokio::test]
 #[ignore]
 async fn daemon_api_agent() {
@@ -92,6 +124,27 @@ async fn daemon_api_agent() {
     assert!(r["machine_id"].is_string());
 }
 
+#[tokio::test]
+#[ignore]
+async fn daemon_api_agent_words() {
+    let d = daemon().await;
+    let r: Value = ca(&d)
+        .get(d.url("/agent/words"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(r["ok"], true);
+    assert!(r["agent_words"].is_string());
+    assert!(r["machine_words"].is_string());
+    assert!(
+        r.get("user_words").is_some(),
+        "user_words must be present: {r}"
+    );
+}
+
 #[tokio::test]
 #[ignore]
 async fn daemon_api_agent_sign_roundtrip() {
@@ -887,6 +940,74 @@ async fn daemon_api_peers() {
     assert_eq!(r.status(), StatusCode::OK);
 }
 
+#[tokio::test]
+#[ignore]
+async fn daemon_api_peer_cache() {
+    let d = daemon().await;
+    let r = ca(&d).get(d.url("/peers/cache")).send().await.unwrap();
+    assert_eq!(r.status(), StatusCode::OK);
+    let body: Value = r.json().await.unwrap();
+    assert_eq!(body["ok"], true);
+    assert!(body["peers"].is_array());
+}
+
+#[tokio::test]
+#[ignore]
+async fn daemon_api_peers_disconnect_all() {
+    let d = daemon().await;
+    let r = ca(&d)
+        .post(d.url("/peers/disconnect-all"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(r.status(), StatusCode::OK);
+    let body: Value = r.json().await.unwrap();
+    assert_eq!(body["ok"], true);
+    assert!(body["disconnected"].is_u64());
+
+    // The daemon keeps serving after isolating itself.
+    let r = ca(&d).get(d.url("/peers")).send().await.unwrap();
+    assert_eq!(r.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+#[ignore]
+async fn daemon_api_presence_set_status() {
+    let d = daemon().await;
+    let r = ca(&d)
+        .post(d.url("/presence/status"))
+        .json(&serde_json::json!({ "status": "on call" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(r.status(), StatusCode::OK);
+    let body: Value = r.json().await.unwrap();
+    assert_eq!(body["status"], "on call");
+
+    let r = ca(&d)
+        .post(d.url("/presence/status"))
+        .json(&serde_json::json!({ "status": "bell\u{7}" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(r.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+#[ignore]
+async fn daemon_api_presence_clear_status() {
+    let d = daemon().await;
+    let r = ca(&d)
+        .delete(d.url("/presence/status"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(r.status(), StatusCode::OK);
+    let body: Value = r.json().await.unwrap();
+    assert_eq!(body["ok"], true);
+    assert!(body["status"].is_null());
+}
+
 #[tokio::test]
 #[ignore]
 async fn daemon_api_network_status() {
@@ -1007,6 +1128,40 @@ async fn daemon_api_unsubscribe() {
     assert_eq!(r.status(), StatusCode::OK);
 }
 
+#[tokio::test]
+#[ignore]
+async fn daemon_api_subscriptions() {
+    let d = daemon().await;
+    let r: Value = ca(&d)
+        .post(d.url("/subscribe"))
+        .json(&serde_json::json!({"topic": "subs-list-test"}))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let sid = r["subscription_id"].as_str().unwrap().to_string();
+    let body: Value = ca(&d)
+        .get(d.url("/subscriptions"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(body["ok"], true);
+    let entry = body["subscriptions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|s| s["subscription_id"] == sid.as_str())
+        .expect("new subscription listed");
+    assert_eq!(entry["topic"], "subs-list-test");
+    assert_eq!(entry["dropped"], 0);
+    assert!(entry["buffered"].is_u64());
+}
+
 #[tokio::test]
 #[ignore]
 async fn daemon_api_events_sse() {
@@ -1034,6 +1189,33 @@ async fn daemon_api_publish_bad_base64() {
     assert_eq!(r.status(), StatusCode::BAD_REQUEST);
 }
 
+#[tokio::test]
+#[ignore]
+async fn daemon_api_publish_task_list_sync_topic() {
+    let d = daemon().await;
+    // Task-list sync traffic lives under `x0x/tasklist/v1/`; an app must
+    // not be able to inject into it.
+    for topic in ["x0x/tasklist/v1/shared", "local:x0x/tasklist/v1/shared"] {
+        let r = ca(&d)
+            .post(d.url("/publish"))
+            .json(&serde_json::json!({"topic": topic, "payload": b64(b"garbage")}))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(r.status(), StatusCode::BAD_REQUEST);
+        let body: Value = r.json().await.unwrap();
+        assert_eq!(body["ok"], false);
+    }
+    // Other x0x-named topics, like the GUI's space feeds, stay open.
+    let r = ca(&d)
+        .post(d.url("/publish"))
+        .json(&serde_json::json!({"topic": "x0x.space.abc.feed", "payload": b64(b"hi")}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(r.status(), StatusCode::OK);
+}
+
 // ===========================================================================
 // Direct Messaging (4)
 // ===========================================================================
@@ -1453,6 +1635,103 @@ async fn daemon_api_list_revocations() {
     assert!(r["revocations"].is_array());
 }
 
+#[tokio::test]
+#[ignore]
+async fn daemon_api_contact_reachability() {
+    let d = daemon().await;
+    let r: Value = ca(&d)
+        .get(d.url(&format!("/contacts/{}/reachability", fake_id())))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(r["ok"], true);
+    assert_eq!(r["status"], "not_found");
+    assert_eq!(r["reachable"], false);
+}
+
+#[tokio::test]
+#[ignore]
+async fn daemon_api_contacts_audit() {
+    let d = daemon().await;
+    let agent = fake_id();
+    ca(&d)
+        .post(d.url("/contacts"))
+        .json(&serde_json::json!({"agent_id": agent, "trust_level": "known"}))
+        .send()
+        .await
+        .unwrap();
+    ca(&d)
+        .post(d.url("/contacts/trust"))
+        .json(&serde_json::json!({"agent_id": agent, "level": "blocked"}))
+        .send()
+        .await
+        .unwrap();
+    let r: Value = ca(&d)
+        .get(d.url("/contacts/audit"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(r["ok"], true);
+    let entries: Vec<&Value> = r["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|e| e["agent_id"] == agent.as_str())
+        .collect();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["old_level"], Value::Null);
+    assert_eq!(entries[0]["new_level"], "known");
+    assert_eq!(entries[0]["source"], "add");
+    assert_eq!(entries[1]["old_level"], "known");
+    assert_eq!(entries[1]["new_level"], "blocked");
+    assert_eq!(entries[1]["source"], "set_trust");
+}
+
+#[tokio::test]
+#[ignore]
+async fn daemon_api_export_import_contacts() {
+    let d = daemon().await;
+    let agent = fake_id();
+    ca(&d)
+        .post(d.url("/contacts"))
+        .json(&serde_json::json!({"agent_id": agent, "trust_level": "trusted"}))
+        .send()
+        .await
+        .unwrap();
+    let mut export: Value = ca(&d)
+        .get(d.url("/contacts/export"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(export["ok"], true);
+    assert!(!export["contacts"].as_array().unwrap().is_empty());
+    assert!(export["revocations"].is_array());
+
+    // The export response is itself a valid import body.
+    export["strategy"] = "keep_existing".into();
+    let r: Value = ca(&d)
+        .post(d.url("/contacts/import"))
+        .json(&export)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(r["ok"], true);
+    assert_eq!(r["added"], 0);
+    assert!(r["kept"].as_u64().unwrap() >= 1);
+}
+
 #[tokio::test]
 #[ignore]
 async fn daemon_api_add_machine() {
@@ -1694,10 +1973,11 @@ async fn daemon_api_encrypt_decrypt() {
     assert_eq!(enc["ok"], true);
     let ct = enc["ciphertext"].as_str().unwrap();
     let epoch = enc["epoch"].as_u64().unwrap();
+    let counter = enc["counter"].as_u64().unwrap();
     // Decrypt
     let dec: Value = ca(&d)
         .post(d.url(&format!("/mls/groups/{gid}/decrypt")))
-        .json(&serde_json::json!({"ciphertext": ct, "epoch": epoch}))
+        .json(&serde_json::json!({"ciphertext": ct, "epoch": epoch, "counter": counter}))
         .send()
         .await
         .unwrap()
@@ -1758,7 +2038,7 @@ async fn daemon_api_group_not_found() {
 }
 
 // ===========================================================================
-// Task Lists (5)
+// Task Lists (8)
 // ===========================================================================
 
 async fn create_task_list_item(d: &DaemonFixture, title: &str) -> Result<(String, String)> {
@@ -1898,6 +2178,33 @@ async fn daemon_api_create_task_list() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+#[ignore]
+async fn daemon_api_create_task_list_sync_interval() -> Result<()> {
+    let d = daemon().await;
+    for (secs, expected) in [
+        (5, StatusCode::CREATED),
+        (0, StatusCode::BAD_REQUEST),
+        (3601, StatusCode::BAD_REQUEST),
+    ] {
+        let r = ca(&d)
+            .post(d.url("/task-lists"))
+            .json(&serde_json::json!({
+                "name": "paced",
+                "topic": format!("test-tasks-{}", rand::random::<u32>()),
+                "sync_interval_secs": secs,
+            }))
+            .send()
+            .await?;
+        ensure!(
+            r.status() == expected,
+            "create task list with {secs}s interval status: {}",
+            r.status()
+        );
+    }
+    Ok(())
+}
+
 #[tokio::test]
 #[ignore]
 async fn daemon_api_add_task() -> Result<()> {
@@ -1918,6 +2225,13 @@ async fn daemon_api_list_tasks() -> Result<()> {
         "list task lists status: {}",
         r.status()
     );
+    let body: Value = r.json().await?;
+    ensure!(
+        body["task_lists"].as_array().is_some_and(|lists| lists
+            .iter()
+            .any(|l| l["id"] == list_id.as_str() && l["rejoined"] == true)),
+        "task lists response: {body:?}"
+    );
 
     let listed = list_task_list_items(&d, &list_id).await?;
     ensure!(
@@ -1943,6 +2257,333 @@ async fn daemon_api_claim_task() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+#[ignore]
+async fn daemon_api_remove_task() -> Result<()> {
+    let d = daemon().await;
+    let (list_id, task_id) = create_task_list_item(&d, "Remove me").await?;
+
+    let url = d.url(&format!("/task-lists/{list_id}/tasks/{task_id}"));
+    let r = ca(&d).delete(url.clone()).send().await?;
+    ensure!(
+        r.status() == StatusCode::OK,
+        "remove task status: {}",
+        r.status()
+    );
+    let listed = list_task_list_items(&d, &list_id).await?;
+    ensure!(
+        task_state(&listed, &task_id).is_none(),
+        "removed task still listed: {listed:?}"
+    );
+
+    let r = ca(&d).delete(url).send().await?;
+    ensure!(
+        r.status() == StatusCode::NOT_FOUND,
+        "second remove status: {}",
+        r.status()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn daemon_api_task_history() -> Result<()> {
+    let d = daemon().await;
+    let (list_id, task_id) = create_task_list_item(&d, "Audit me").await?;
+    update_task_item(&d, &list_id, &task_id, "claim").await?;
+    update_task_item(&d, &list_id, &task_id, "complete").await?;
+
+    let r = ca(&d)
+        .get(d.url(&format!("/task-lists/{list_id}/tasks/{task_id}/history")))
+        .send()
+        .await?;
+    ensure!(
+        r.status() == StatusCode::OK,
+        "history status: {}",
+        r.status()
+    );
+    let body: Value = r.json().await?;
+    let kinds: Vec<&str> = body["events"]
+        .as_array()
+        .with_context(|| format!("history response: {body:?}"))?
+        .iter()
+        .filter_map(|e| e["kind"].as_str())
+        .collect();
+    ensure!(
+        kinds == ["added", "claimed", "completed"],
+        "history response: {body:?}"
+    );
+
+    let r = ca(&d)
+        .get(d.url(&format!(
+            "/task-lists/{list_id}/tasks/{}/history",
+            fake_id()
+        )))
+        .send()
+        .await?;
+    ensure!(
+        r.status() == StatusCode::NOT_FOUND,
+        "unknown task history status: {}",
+        r.status()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn daemon_api_reopen_task() -> Result<()> {
+    let d = daemon().await;
+    let (list_id, task_id) = create_task_list_item(&d, "Do it twice").await?;
+
+    // Only a done task can be reopened.
+    let r = ca(&d)
+        .patch(d.url(&format!("/task-lists/{list_id}/tasks/{task_id}")))
+        .json(&serde_json::json!({"action": "reopen"}))
+        .send()
+        .await?;
+    ensure!(
+        r.status() == StatusCode::INTERNAL_SERVER_ERROR,
+        "reopen open task status: {}",
+        r.status()
+    );
+
+    update_task_item(&d, &list_id, &task_id, "claim").await?;
+    update_task_item(&d, &list_id, &task_id, "complete").await?;
+    update_task_item(&d, &list_id, &task_id, "reopen").await?;
+    let listed = list_task_list_items(&d, &list_id).await?;
+    ensure!(
+        task_state(&listed, &task_id) == Some("empty"),
+        "reopened task state: {listed:?}"
+    );
+
+    // The reopened task can be claimed again.
+    update_task_item(&d, &list_id, &task_id, "claim").await?;
+    let listed = list_task_list_items(&d, &list_id).await?;
+    ensure!(
+        task_state(&listed, &task_id).is_some_and(|state| state.starts_with("claimed:")),
+        "reclaimed task state: {listed:?}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn daemon_api_task_list_sync_stats() -> Result<()> {
+    let d = daemon().await;
+    let (list_id, _task_id) = create_task_list_item(&d, "Count me").await?;
+
+    let r = ca(&d)
+        .get(d.url(&format!("/task-lists/{list_id}/sync-stats")))
+        .send()
+        .await?;
+    ensure!(
+        r.status() == StatusCode::OK,
+        "sync-stats status: {}",
+        r.status()
+    );
+    let body: Value = r.json().await?;
+    // Adding the task published one delta.
+    ensure!(
+        body["deltas_sent"].as_u64().is_some_and(|n| n >= 1),
+        "sync-stats response: {body:?}"
+    );
+    ensure!(
+        body["bytes_sent"].as_u64().is_some_and(|n| n > 0),
+        "sync-stats response: {body:?}"
+    );
+    ensure!(
+        body["last_sync_at"].as_u64().is_some(),
+        "sync-stats response: {body:?}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn daemon_api_export_task_list() -> Result<()> {
+    let d = daemon().await;
+    let (list_id, task_id) = create_task_list_item(&d, "Export me").await?;
+
+    let r = ca(&d)
+        .get(d.url(&format!("/task-lists/{list_id}/export")))
+        .send()
+        .await?;
+    ensure!(
+        r.status() == StatusCode::OK,
+        "export status: {}",
+        r.status()
+    );
+    let body: Value = r.json().await?;
+    let tasks = body["snapshot"]["tasks"]
+        .as_array()
+        .with_context(|| format!("export response: {body:?}"))?;
+    ensure!(
+        tasks.iter().any(|t| t["id"] == task_id.as_str()
+            && t["title"] == "Export me"
+            && t["order"].as_u64().is_some()),
+        "export response: {body:?}"
+    );
+
+    let r = ca(&d)
+        .get(d.url(&format!("/task-lists/{}/export", fake_id())))
+        .send()
+        .await?;
+    ensure!(
+        r.status() == StatusCode::NOT_FOUND,
+        "unknown list export status: {}",
+        r.status()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn daemon_api_checkpoint_task_lists() -> Result<()> {
+    let d = daemon().await;
+    let (list_id, _task_id) = create_task_list_item(&d, "Checkpoint me").await?;
+
+    let r = ca(&d)
+        .post(d.url(&format!("/task-lists/{list_id}/checkpoint")))
+        .send()
+        .await?;
+    ensure!(
+        r.status() == StatusCode::OK,
+        "checkpoint status: {}",
+        r.status()
+    );
+    let body: Value = r.json().await?;
+    ensure!(
+        body["id"] == list_id.as_str()
+            && body["task_count"].as_u64() >= Some(1)
+            && body["saved_at"].as_u64().is_some(),
+        "checkpoint response: {body:?}"
+    );
+
+    let r = ca(&d).post(d.url("/checkpoint-all")).send().await?;
+    ensure!(
+        r.status() == StatusCode::OK,
+        "checkpoint-all status: {}",
+        r.status()
+    );
+    let body: Value = r.json().await?;
+    let checkpoints = body["checkpoints"]
+        .as_array()
+        .with_context(|| format!("checkpoint-all response: {body:?}"))?;
+    ensure!(
+        checkpoints.iter().any(|c| c["id"] == list_id.as_str()),
+        "checkpoint-all response: {body:?}"
+    );
+
+    let r = ca(&d)
+        .post(d.url(&format!("/task-lists/{}/checkpoint", fake_id())))
+        .send()
+        .await?;
+    ensure!(
+        r.status() == StatusCode::NOT_FOUND,
+        "unknown list checkpoint status: {}",
+        r.status()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn daemon_api_update_persistence_policy() -> Result<()> {
+    let d = daemon().await;
+
+    let r = ca(&d)
+        .patch(d.url("/persistence/policy"))
+        .json(&serde_json::json!({ "save_interval_secs": 30 }))
+        .send()
+        .await?;
+    ensure!(
+        r.status() == StatusCode::OK,
+        "policy status: {}",
+        r.status()
+    );
+    let body: Value = r.json().await?;
+    ensure!(
+        body["save_interval_secs"] == 30 && body["max_save_interval_secs"] == 3600,
+        "policy response: {body:?}"
+    );
+
+    for secs in [0, 3601] {
+        let r = ca(&d)
+            .patch(d.url("/persistence/policy"))
+            .json(&serde_json::json!({ "save_interval_secs": secs }))
+            .send()
+            .await?;
+        ensure!(
+            r.status() == StatusCode::BAD_REQUEST,
+            "out-of-bounds policy {secs} status: {}",
+            r.status()
+        );
+    }
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn daemon_api_validate_task_list() -> Result<()> {
+    let d = daemon().await;
+    let (list_id, _task_id) = create_task_list_item(&d, "Validate me").await?;
+
+    let r = ca(&d)
+        .get(d.url(&format!("/task-lists/{list_id}/validate")))
+        .send()
+        .await?;
+    ensure!(
+        r.status() == StatusCode::OK,
+        "validate status: {}",
+        r.status()
+    );
+    let body: Value = r.json().await?;
+    ensure!(
+        body["consistent"].as_bool() == Some(true),
+        "validate response: {body:?}"
+    );
+    ensure!(
+        body["inconsistencies"]
+            .as_array()
+            .is_some_and(Vec::is_empty),
+        "validate response: {body:?}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn daemon_api_task_list_addressed_by_canonical_id() -> Result<()> {
+    let d = daemon().await;
+    let (topic, task_id) = create_task_list_item(&d, "Find me by id").await?;
+
+    let r = ca(&d).get(d.url("/task-lists")).send().await?;
+    let body: Value = r.json().await?;
+    let list_id = body["task_lists"]
+        .as_array()
+        .and_then(|lists| lists.iter().find(|list| list["topic"] == topic.as_str()))
+        .and_then(|list| list["list_id"].as_str())
+        .map(str::to_string)
+        .with_context(|| format!("task list missing from listing: {body:?}"))?;
+    ensure!(
+        list_id.len() == 64 && list_id != topic,
+        "canonical id: {list_id}"
+    );
+
+    let listed = list_task_list_items(&d, &list_id).await?;
+    ensure!(
+        task_state(&listed, &task_id).is_some(),
+        "tasks via canonical id: {listed:?}"
+    );
+    update_task_item(&d, &list_id, &task_id, "claim").await?;
+    let listed = list_task_list_items(&d, &topic).await?;
+    ensure!(
+        task_state(&listed, &task_id).is_some_and(|state| state.starts_with("claimed:")),
+        "claim via canonical id visible by topic: {listed:?}"
+    );
+    Ok(())
+}
+
 #[tokio::test]
 #[ignore]
 async fn daemon_api_complete_task() -> Result<()> {
@@ -2212,6 +2853,63 @@ async fn daemon_api_claim_fence_token() -> Result<()> {
     );
     Ok(())
 }
+
+// WHY: the same local fence guards adds, and may arrive as an `If-Match`
+// header instead of the body field. A stale token ⇒ 409, nothing added.
+#[tokio::test]
+#[ignore]
+async fn daemon_api_add_task_stale_if_match_conflicts() -> Result<()> {
+    let d = daemon().await;
+    let (list_id, _task_id) = create_task_list_item(&d, "fence add").await?;
+
+    let listed = list_task_list_items(&d, &list_id).await?;
+    let fence = listed["fence_token"]
+        .as_str()
+        .context("GET must return an opaque fence_token")?
+        .to_string();
+    let (epoch, rev) = fence.split_once(':').context("fence token shape")?;
+    let stale_fence = format!("{}:{}", epoch, rev.parse::<u64>()? + 1000);
+
+    let response = ca(&d)
+        .post(d.url(&format!("/task-lists/{list_id}/tasks")))
+        .header("If-Match", format!("\"{stale_fence}\""))
+        .json(&serde_json::json!({"title": "rejected"}))
+        .send()
+        .await?;
+    ensure!(
+        response.status() == StatusCode::CONFLICT,
+        "stale If-Match status: {}",
+        response.status()
+    );
+    let body: Value = response.json().await?;
+    ensure!(
+        body["error"] == "stale_local_version" && body["fence_token"] == fence.as_str(),
+        "conflict response: {body:?}"
+    );
+    let after = list_task_list_items(&d, &list_id).await?;
+    ensure!(
+        after["tasks"].as_array().map(Vec::len) == listed["tasks"].as_array().map(Vec::len),
+        "409 must not add a task: {after:?}"
+    );
+
+    let response = ca(&d)
+        .post(d.url(&format!("/task-lists/{list_id}/tasks")))
+        .header("If-Match", format!("\"{fence}\""))
+        .json(&serde_json::json!({"title": "accepted"}))
+        .send()
+        .await?;
+    ensure!(
+        response.status() == StatusCode::CREATED,
+        "fresh If-Match status: {}",
+        response.status()
+    );
+    let body: Value = response.json().await?;
+    ensure!(
+        body["fence_token"].is_string() && body["fence_token"] != fence.as_str(),
+        "add must return a new fence token: {body:?}"
+    );
+    Ok(())
+}
 // WHY (P1 fence bypass): the route parsed a provided `fence_token` with
 // `Option::and_then(FenceToken::from_wire)`, collapsing "present but
 // malformed/overflow" into `None` — and `None` means an *unfenced* advisory
@@ -2335,6 +3033,28 @@ async fn daemon_api_bootstrap_cache() {
     assert_eq!(r["ok"], true);
 }
 
+#[tokio::test]
+#[ignore]
+async fn daemon_api_bootstrap_health() {
+    let d = daemon().await;
+    let r: Value = ca(&d)
+        .get(d.url("/bootstrap/health?timeout_ms=500"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(r["ok"], true);
+    assert_eq!(r["timeout_ms"], 500);
+    let peers = r["peers"].as_array().expect("peers array");
+    assert_eq!(r["total"].as_u64(), Some(peers.len() as u64));
+    for peer in peers {
+        assert!(peer["addr"].is_string());
+        assert!(peer["reachable"].is_boolean());
+    }
+}
+
 #[tokio::test]
 #[ignore]
 async fn daemon_api_diagnostics_connectivity() {
@@ -2412,6 +3132,45 @@ async fn daemon_api_diagnostics_ws() {
     assert!(r["ws_slow_consumer_closes"].is_number());
 }
 
+#[tokio::test]
+#[ignore]
+async fn daemon_api_diagnostics_sse() {
+    async fn sse_clients(d: &DaemonFixture) -> u64 {
+        let r: Value = ca(d)
+            .get(d.url("/diagnostics/sse"))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(r["ok"], true);
+        assert!(r["sse_lagged_events"].is_number());
+        r["sse_clients"].as_u64().unwrap()
+    }
+
+    let d = daemon().await;
+    // `ca()` builds a fresh client (and connection pool) per call, so
+    // dropping a response closes that client's stream. The body is never
+    // read, so the request timeout does not end the stream early.
+    let first = ca(&d).get(d.url("/events")).send().await.unwrap();
+    let second = ca(&d).get(d.url("/events")).send().await.unwrap();
+    assert_eq!(sse_clients(&d).await, 2);
+
+    drop(first);
+    // The daemon notices the disconnect on its next write (at the latest the
+    // 15 s keep-alive).
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(30);
+    while sse_clients(&d).await != 1 {
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "sse_clients never dropped to 1 after a client disconnected"
+        );
+        tokio::time::sleep(Duration::from_millis(250)).await;
+    }
+    drop(second);
+}
+
 #[tokio::test]
 #[ignore]
 async fn daemon_api_auth_session_exchange() {
@@ -58,6 +58,7 @@ fn discovered_agent(agent: &Agent, addr: std::net::SocketAddr, now_secs: u64) ->
         cert_not_after: None,
         agent_certificate: None,
         agent_public_key: Vec::new(),
+        status: None,
     }
 }
 
@@ -250,6 +251,60 @@ async fn test_send_direct_loopback_delivery_between_agents(
     Ok(())
 }
 
+/// Two connected loopback agents report each other as connected.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_verify_contact_reachability_reports_connected_peer(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new().unwrap();
+    let Some(alice) = create_loopback_test_agent(&temp_dir, "alice").await? else {
+        return Ok(());
+    };
+    let Some(bob) = create_loopback_test_agent(&temp_dir, "bob").await? else {
+        return Ok(());
+    };
+
+    alice.join_network().await?;
+    bob.join_network().await?;
+
+    let alice_network = alice.network().expect("alice network").clone();
+    let bob_network = bob.network().expect("bob network").clone();
+    let bob_addr = normalize_loopback(bob_network.bound_addr().await.expect("bob bound"));
+    let bob_peer = ant_quic::PeerId(bob.machine_id().0);
+
+    let unknown = alice.verify_contact_reachability(&bob.agent_id()).await?;
+    assert_eq!(
+        unknown.status,
+        x0x::connectivity::ReachabilityStatus::NotFound
+    );
+
+    alice_network.connect_addr(bob_addr).await?;
+    let connected_deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < connected_deadline {
+        if alice_network.is_connected(&bob_peer).await {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(25)).await;
+    }
+    assert!(alice_network.is_connected(&bob_peer).await);
+
+    let now_secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system time after epoch")
+        .as_secs();
+    alice
+        .insert_discovered_agent_for_testing(discovered_agent(&bob, bob_addr, now_secs))
+        .await;
+
+    let report = alice.verify_contact_reachability(&bob.agent_id()).await?;
+    assert_eq!(
+        report.status,
+        x0x::connectivity::ReachabilityStatus::Connected
+    );
+    assert!(report.is_reachable());
+
+    Ok(())
+}
+
 /// Test the DirectMessageReceiver subscription mechanism.
 #[tokio::test]
 async fn test_subscribe_direct() {
@@ -81,6 +81,7 @@ fn beacon_discovered_agent(
         cert_not_after: None,
         agent_certificate: None,
         agent_public_key: Vec::new(),
+        status: None,
     }
 }
 
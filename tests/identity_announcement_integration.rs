@@ -359,6 +359,22 @@ async fn test_identity_announcement_round_trip() -> Result<(), Box<dyn std::erro
     Ok(())
 }
 
+#[ignore = "requires gossip overlay propagation between two agents"]
+#[tokio::test]
+async fn test_status_appears_in_peer_presence_view() -> Result<(), Box<dyn std::error::Error>> {
+    let (agent_a, agent_b, _dir_a, _dir_b) = two_local_agents().await?;
+    agent_a.set_status("busy".to_string())?;
+    agent_a.announce_identity(false, false).await?;
+    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+    let seen = agent_b
+        .cached_agent(&agent_a.agent_id())
+        .await
+        .ok_or("agent B should discover agent A after announcement")?;
+    assert_eq!(seen.status.as_deref(), Some("busy"));
+    assert!(agent_b.presence().await?.contains(&agent_a.agent_id()));
+    Ok(())
+}
+
 #[ignore = "requires live VPS bootstrap nodes"]
 #[tokio::test]
 async fn test_vps_round_trip() {
@@ -415,6 +431,7 @@ fn fake_agent_with_timestamps(announced_at: u64, last_seen: u64) -> DiscoveredAg
         cert_not_after: None,
         agent_certificate: None,
         agent_public_key: Vec::new(),
+        status: None,
     }
 }
 
@@ -462,3 +462,65 @@ async fn test_user_key_path_nonexistent_does_not_generate() {
         "User key file should not be auto-generated"
     );
 }
+
+/// A missing key file is the only case where `build()` may mint a new key.
+#[tokio::test]
+async fn test_missing_key_files_are_generated() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let temp_path = temp_dir.path();
+
+    Agent::builder()
+        .with_machine_key(temp_path.join("machine.key"))
+        .with_agent_key_path(temp_path.join("agent.key"))
+        .build()
+        .await
+        .expect("missing keys should be generated");
+
+    assert!(temp_path.join("machine.key").exists());
+    assert!(temp_path.join("agent.key").exists());
+}
+
+/// A key file that exists but can't be read or decoded must fail the build
+/// and stay untouched. Replacing it would silently swap the machine/agent
+/// identity after a transient error (permissions, lock, partial write).
+#[tokio::test]
+async fn test_unreadable_key_files_error_without_overwrite() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let temp_path = temp_dir.path();
+
+    // Corrupt machine key: exists, readable, undecodable.
+    let machine_path = temp_path.join("machine.key");
+    std::fs::write(&machine_path, b"not a keypair").unwrap();
+    let result = Agent::builder()
+        .with_machine_key(&machine_path)
+        .with_agent_key_path(temp_path.join("agent.key"))
+        .build()
+        .await;
+    assert!(result.is_err(), "corrupt machine key must not be replaced");
+    assert_eq!(std::fs::read(&machine_path).unwrap(), b"not a keypair");
+
+    // Unreadable agent key: the path exists but every read fails. A
+    // directory stands in for a locked file because permission bits are
+    // not enforced when tests run as root.
+    let agent_path = temp_path.join("agent-locked.key");
+    std::fs::create_dir(&agent_path).unwrap();
+    let result = Agent::builder()
+        .with_machine_key(temp_path.join("machine-ok.key"))
+        .with_agent_key_path(&agent_path)
+        .build()
+        .await;
+    assert!(result.is_err(), "unreadable agent key must not be replaced");
+    assert!(agent_path.is_dir(), "agent key path left untouched");
+
+    // Corrupt user key: previously dropped silently to a two-layer identity.
+    let user_path = temp_path.join("user.key");
+    std::fs::write(&user_path, b"garbage").unwrap();
+    let result = Agent::builder()
+        .with_machine_key(temp_path.join("machine-ok.key"))
+        .with_agent_key_path(temp_path.join("agent-ok.key"))
+        .with_user_key_path(&user_path)
+        .build()
+        .await;
+    assert!(result.is_err(), "corrupt user key must surface as an error");
+    assert_eq!(std::fs::read(&user_path).unwrap(), b"garbage");
+}
@@ -4,6 +4,7 @@
 //! key rotation, and encrypted task list synchronization.
 
 use x0x::crdt::{EncryptedTaskListDelta, TaskListDelta};
+use x0x::groups::kem_envelope::AgentKemKeypair;
 use x0x::identity::Identity;
 use x0x::mls::{MlsGroup, MlsKeySchedule, MlsWelcome};
 
@@ -38,13 +39,17 @@ async fn test_member_addition() {
     // Create invitee
     let invitee = Identity::generate().expect("identity generation failed");
     let invitee_id = invitee.agent_id();
+    let invitee_kem = AgentKemKeypair::generate().expect("KEM keypair generation failed");
 
     // Create and verify welcome message
-    let welcome = MlsWelcome::create(&group, &invitee_id).expect("welcome creation failed");
+    let welcome = MlsWelcome::create(&group, &invitee_id, &invitee_kem.public_bytes)
+        .expect("welcome creation failed");
     assert!(welcome.verify().is_ok());
 
     // Invitee accepts and reconstructs group context
-    let invitee_context = welcome.accept(&invitee_id).expect("welcome accept failed");
+    let invitee_context = welcome
+        .accept(&invitee_id, &invitee_kem)
+        .expect("welcome accept failed");
     assert_eq!(invitee_context.group_id(), group.context().group_id());
     assert_eq!(invitee_context.epoch(), group.current_epoch());
 
@@ -252,16 +257,19 @@ async fn test_welcome_wrong_recipient() {
 
     let invitee = Identity::generate().expect("identity generation failed");
     let invitee_id = invitee.agent_id();
+    let invitee_kem = AgentKemKeypair::generate().expect("KEM keypair generation failed");
 
     let wrong_agent = Identity::generate().expect("identity generation failed");
     let wrong_agent_id = wrong_agent.agent_id();
+    let wrong_agent_kem = AgentKemKeypair::generate().expect("KEM keypair generation failed");
 
     // Create welcome for invitee
-    let welcome = MlsWelcome::create(&group, &invitee_id).expect("welcome creation failed");
+    let welcome = MlsWelcome::create(&group, &invitee_id, &invitee_kem.public_bytes)
+        .expect("welcome creation failed");
 
-    // Wrong agent tries to accept
-    let result = welcome.accept(&wrong_agent_id);
-    assert!(result.is_err());
+    // Wrong agent tries to accept, under its own id or the invitee's
+    assert!(welcome.accept(&wrong_agent_id, &wrong_agent_kem).is_err());
+    assert!(welcome.accept(&invitee_id, &wrong_agent_kem).is_err());
 }
 
 /// Test encryption authentication prevents tampering.
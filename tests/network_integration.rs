@@ -6,6 +6,9 @@
 //! configuring network settings, and participating in the gossip network.
 
 use tempfile::TempDir;
+use x0x::groups::kem_envelope::AgentKemKeypair;
+use x0x::identity::AgentId;
+use x0x::mls::{MlsEpochMessage, MlsGroup};
 use x0x::{network, Agent};
 
 /// Test agent creation with default network configuration.
@@ -70,6 +73,227 @@ async fn test_agent_publish() {
     assert!(result.is_ok());
 }
 
+/// `publish_encrypted` puts an epoch-tagged MLS envelope on the topic that
+/// a member welcomed into the same epoch can open.
+#[tokio::test]
+async fn test_agent_publish_encrypted_roundtrip() {
+    const TOPIC: &str = "test-encrypted-topic";
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let agent = Agent::builder()
+        .with_machine_key(temp_dir.path().join("machine.key"))
+        .with_agent_key_path(temp_dir.path().join("agent.key"))
+        .with_contact_store_path(temp_dir.path().join("contacts.json"))
+        .with_peer_cache_disabled()
+        .with_network_config(network::NetworkConfig {
+            bind_addr: Some("127.0.0.1:0".parse().unwrap()),
+            bootstrap_nodes: Vec::new(),
+            ..Default::default()
+        })
+        .build()
+        .await
+        .expect("Failed to build agent");
+
+    let receiver_id = AgentId([2; 32]);
+    let receiver_kem = AgentKemKeypair::generate().unwrap();
+    let mut sender = MlsGroup::new(b"publish-encrypted".to_vec(), AgentId([1; 32]))
+        .await
+        .unwrap();
+    sender.add_member(receiver_id).await.unwrap();
+    let welcome = sender
+        .create_welcome(receiver_id, &receiver_kem.public_bytes)
+        .unwrap();
+    let receiver = MlsGroup::join_from_welcome(&welcome, receiver_id, &receiver_kem)
+        .await
+        .unwrap();
+
+    let mut sub = agent.subscribe(TOPIC).await.expect("subscribe");
+    agent
+        .publish_encrypted(&sender, TOPIC, b"for members only")
+        .await
+        .expect("publish_encrypted");
+
+    let msg = tokio::time::timeout(std::time::Duration::from_secs(5), sub.recv())
+        .await
+        .expect("timed out waiting for message")
+        .expect("subscription closed");
+    assert_ne!(msg.payload.as_ref(), b"for members only");
+    let envelope = MlsEpochMessage::from_bytes(&msg.payload).unwrap();
+    assert_eq!(envelope.epoch(), 1);
+    assert_eq!(
+        receiver.decrypt_epoch_message(&msg.payload).unwrap(),
+        b"for members only"
+    );
+}
+
+/// `subscribe_durable_with_config` bounds the topic log by the caller's
+/// config rather than the 16 MiB default.
+#[tokio::test]
+async fn test_agent_subscribe_durable_honours_log_config() {
+    const TOPIC: &str = "test-durable-config";
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let agent = Agent::builder()
+        .with_machine_key(temp_dir.path().join("machine.key"))
+        .with_agent_key_path(temp_dir.path().join("agent.key"))
+        .with_contact_store_path(temp_dir.path().join("contacts.json"))
+        .with_identity_dir(temp_dir.path())
+        .with_peer_cache_disabled()
+        .with_network_config(network::NetworkConfig {
+            bind_addr: Some("127.0.0.1:0".parse().unwrap()),
+            bootstrap_nodes: Vec::new(),
+            ..Default::default()
+        })
+        .build()
+        .await
+        .expect("Failed to build agent");
+
+    let config = x0x::gossip::DurableLogConfig {
+        max_bytes: 1024,
+        max_age_secs: 0,
+    };
+    let mut sub = agent
+        .subscribe_durable_with_config(TOPIC, 0, config)
+        .await
+        .expect("subscribe_durable_with_config");
+    for i in 0..40 {
+        agent
+            .publish(TOPIC, format!("message-{i:04}").into_bytes())
+            .await
+            .expect("publish");
+        let msg = tokio::time::timeout(std::time::Duration::from_secs(5), sub.recv())
+            .await
+            .expect("timed out waiting for message")
+            .expect("subscription closed");
+        assert_eq!(msg.seq, i + 1);
+    }
+
+    let log_dir = temp_dir
+        .path()
+        .join(x0x::gossip::durable::DURABLE_TOPICS_DIR);
+    for entry in std::fs::read_dir(log_dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().is_some_and(|ext| ext == "log") {
+            assert!(std::fs::metadata(&path).unwrap().len() <= 1024);
+        }
+    }
+}
+
+/// `connect_to` dials a peer by address and both sides see the connection.
+#[tokio::test]
+async fn test_agent_connect_to_lists_peers_on_both_sides() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let build = |name: &str| {
+        Agent::builder()
+            .with_machine_key(temp_dir.path().join(format!("{name}-machine.key")))
+            .with_agent_key_path(temp_dir.path().join(format!("{name}-agent.key")))
+            .with_contact_store_path(temp_dir.path().join(format!("{name}-contacts.json")))
+            .offline()
+            .build()
+    };
+    let alice = build("alice").await.expect("Failed to build alice");
+    let bob = build("bob").await.expect("Failed to build bob");
+
+    let bob_addr = bob.network().unwrap().bound_addr().await.unwrap();
+    let bob_machine = alice.connect_to(bob_addr).await.expect("connect_to");
+    assert_eq!(bob_machine, bob.machine_id());
+
+    let alice_machine = alice.machine_id();
+    let listed = |peers: Vec<saorsa_gossip_types::PeerId>, id: &x0x::identity::MachineId| {
+        peers.iter().any(|p| p.as_bytes() == id.as_bytes())
+    };
+    tokio::time::timeout(std::time::Duration::from_secs(5), async {
+        loop {
+            if listed(alice.peers().await.unwrap(), &bob_machine)
+                && listed(bob.peers().await.unwrap(), &alice_machine)
+            {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("both agents list each other as peers");
+
+    alice.shutdown().await;
+    bob.shutdown().await;
+}
+
+/// `connect_to` without a network is a clean error, not a panic.
+#[tokio::test]
+async fn test_agent_connect_to_requires_network() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let agent = Agent::builder()
+        .with_machine_key(temp_dir.path().join("machine.key"))
+        .with_agent_key_path(temp_dir.path().join("agent.key"))
+        .with_contact_store_path(temp_dir.path().join("contacts.json"))
+        .build()
+        .await
+        .expect("Failed to build agent");
+    assert!(matches!(
+        agent.connect_to("127.0.0.1:9".parse().unwrap()).await,
+        Err(x0x::error::IdentityError::NotInitialized(_))
+    ));
+}
+
+/// A subscription made before `join_network` receives remote messages
+/// published as soon as the join completes, with none missing.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_subscribe_before_join_receives_remote_messages() {
+    const TOPIC: &str = "x0x.test.subscribe-before-join.v1";
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let build = |name: &str, bootstrap_nodes: Vec<std::net::SocketAddr>| {
+        Agent::builder()
+            .with_machine_key(temp_dir.path().join(format!("{name}-machine.key")))
+            .with_agent_key_path(temp_dir.path().join(format!("{name}-agent.key")))
+            .with_contact_store_path(temp_dir.path().join(format!("{name}-contacts.json")))
+            .with_peer_cache_disabled()
+            .with_network_config(network::NetworkConfig {
+                bind_addr: Some("127.0.0.1:0".parse().unwrap()),
+                bootstrap_nodes,
+                ..Default::default()
+            })
+            .build()
+    };
+
+    let alice = build("alice", Vec::new()).await.expect("build alice");
+    alice.join_network().await.expect("alice joins");
+    let alice_addr = alice.bound_addr().await.expect("alice bound addr");
+
+    let bob = build("bob", vec![alice_addr]).await.expect("build bob");
+    let mut sub = bob.subscribe(TOPIC).await.expect("subscribe before join");
+    bob.join_network().await.expect("bob joins");
+
+    // Alice publishes as soon as she has registered the inbound connection.
+    let bob_peer = ant_quic::PeerId(bob.machine_id().0);
+    let alice_network = alice.network().expect("alice network");
+    let deadline = tokio::time::Instant::now() + std::time::Duration::from_secs(5);
+    while !alice_network.is_connected(&bob_peer).await {
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "alice never registered bob's connection"
+        );
+        tokio::time::sleep(std::time::Duration::from_millis(25)).await;
+    }
+
+    for i in 0..5u8 {
+        alice
+            .publish(TOPIC, vec![i])
+            .await
+            .expect("alice publishes");
+    }
+    let mut received = Vec::new();
+    let _ = tokio::time::timeout(std::time::Duration::from_secs(15), async {
+        while received.len() < 5 {
+            match sub.recv().await {
+                Some(msg) => received.extend_from_slice(&msg.payload),
+                None => break,
+            }
+        }
+    })
+    .await;
+    received.sort_unstable();
+    assert_eq!(received, vec![0, 1, 2, 3, 4], "no remote message missed");
+}
+
 /// Test agent identity stability across operations.
 ///
 /// Verifies that machine_id and agent_id remain constant after performing
@@ -85,6 +85,7 @@ fn discovered_for(agent: &Agent, addr: std::net::SocketAddr, now_secs: u64) -> D
         cert_not_after: None,
         agent_certificate: None,
         agent_public_key: Vec::new(),
+        status: None,
     }
 }
 
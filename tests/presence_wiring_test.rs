@@ -168,6 +168,7 @@ async fn test_online_agents_uses_presence_beacon_liveness() -> Result<(), Box<dy
             cert_not_after: None,
             agent_certificate: None,
             agent_public_key: Vec::new(),
+            status: None,
         })
         .await;
 
@@ -76,6 +76,7 @@ proptest! {
         cert_not_after: None,
         agent_certificate: None,
                         agent_public_key: Vec::new(),
+                status: None,
             },
         );
 
@@ -256,6 +256,7 @@ async fn synthetic_kill_restart_lands_on_new_connection_within_500ms() {
         cert_not_after: None,
         agent_certificate: None,
         agent_public_key: Vec::new(),
+        status: None,
     };
     alice.insert_discovered_agent_for_testing(bob_card).await;
     alice
@@ -282,6 +283,7 @@ async fn synthetic_kill_restart_lands_on_new_connection_within_500ms() {
         cert_not_after: None,
         agent_certificate: None,
         agent_public_key: Vec::new(),
+        status: None,
     };
     bob.insert_discovered_agent_for_testing(alice_card).await;
 